@@ -1,7 +1,3 @@
-#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
-#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
-#![allow(clippy::unwrap_used, reason = "Examples use unwrap for brevity")]
-
 //! Token approval example for Polymarket CLOB trading.
 //!
 //! This example demonstrates how to set the required token allowances for trading on Polymarket.
@@ -15,58 +11,39 @@
 //! - ERC-20 approval for USDC (collateral token)
 //! - ERC-1155 approval for Conditional Tokens (outcome tokens)
 //!
-//! You only need to run these approvals once per wallet.
+//! You only need to run these approvals once per wallet, and only the missing ones are sent.
 //!
 //! Run with tracing enabled:
 //! ```sh
-//! RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features tracing
+//! RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features approvals,tracing
 //! ```
 //!
 //! Dry run (no transactions executed):
 //! ```sh
-//! RUST_LOG=info cargo run --example approvals --features tracing -- --dry-run
+//! RUST_LOG=info cargo run --example approvals --features approvals,tracing -- --dry-run
 //! ```
 //!
 //! Optionally log to a file:
 //! ```sh
-//! LOG_FILE=approvals.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features tracing
+//! LOG_FILE=approvals.log RUST_LOG=info,hyper_util=off,hyper=off,reqwest=off,h2=off,rustls=off cargo run --example approvals --features approvals,tracing
 //! ```
 
 use std::env;
 use std::fs::File;
 use std::str::FromStr as _;
 
-use alloy::primitives::U256;
 use alloy::providers::ProviderBuilder;
 use alloy::signers::Signer as _;
 use alloy::signers::local::LocalSigner;
-use alloy::sol;
-use polymarket_client_sdk::types::{Address, address};
-use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR, contract_config};
-use tracing::{debug, info};
+use polymarket_client_sdk::approvals::{ApprovalManager, required_approvals};
+use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+use tracing::info;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::layer::SubscriberExt as _;
 use tracing_subscriber::util::SubscriberInitExt as _;
 
 const RPC_URL: &str = "https://polygon-rpc.com";
 
-const USDC_ADDRESS: Address = address!("0x2791Bca1f2de4661ED88A30C99A7a9449Aa84174");
-const TOKEN_TO_APPROVE: Address = USDC_ADDRESS;
-
-sol! {
-    #[sol(rpc)]
-    interface IERC20 {
-        function approve(address spender, uint256 value) external returns (bool);
-        function allowance(address owner, address spender) external view returns (uint256);
-    }
-
-    #[sol(rpc)]
-    interface IERC1155 {
-        function setApprovalForAll(address operator, bool approved) external;
-        function isApprovedForAll(address account, address operator) external view returns (bool);
-    }
-}
-
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     if let Ok(path) = std::env::var("LOG_FILE") {
@@ -87,26 +64,19 @@ async fn main() -> anyhow::Result<()> {
     let dry_run = args.iter().any(|arg| arg == "--dry-run");
 
     let chain = POLYGON;
-    let config = contract_config(chain, false).unwrap();
-    let neg_risk_config = contract_config(chain, true).unwrap();
-
-    // Collect all contracts that need approval
-    let mut targets: Vec<(&str, Address)> = vec![
-        ("CTF Exchange", config.exchange),
-        ("Neg Risk CTF Exchange", neg_risk_config.exchange),
-    ];
-
-    // Add the Neg Risk Adapter if available
-    if let Some(adapter) = neg_risk_config.neg_risk_adapter {
-        targets.push(("Neg Risk Adapter", adapter));
-    }
 
     if dry_run {
         info!(mode = "dry_run", "showing approvals without executing");
-        for (name, target) in &targets {
-            info!(contract = name, address = %target, "would receive approval");
+        let approvals = required_approvals(chain)?;
+        for approval in &approvals {
+            info!(
+                contract = %approval.spender,
+                address = %approval.spender_address,
+                asset = ?approval.asset,
+                "would receive approval"
+            );
         }
-        info!(total = targets.len(), "contracts would be approved");
+        info!(total = approvals.len(), "approvals would be granted");
         return Ok(());
     }
 
@@ -121,111 +91,26 @@ async fn main() -> anyhow::Result<()> {
     let owner = signer.address();
     info!(address = %owner, "wallet loaded");
 
-    let token = IERC20::new(TOKEN_TO_APPROVE, provider.clone());
-    let ctf = IERC1155::new(config.conditional_tokens, provider.clone());
-
-    info!(phase = "checking", "querying current allowances");
-
-    for (name, target) in &targets {
-        match check_allowance(&token, owner, *target).await {
-            Ok(allowance) => {
-                info!(contract = name, usdc_allowance = %allowance);
-            }
-            Err(e) => {
-                debug!(contract = name, error = %e, "failed to check USDC allowance");
-            }
-        }
+    let manager = ApprovalManager::new(provider, chain)?;
 
-        match check_approval_for_all(&ctf, owner, *target).await {
-            Ok(approved) => {
-                info!(contract = name, ctf_approved = approved);
-            }
-            Err(e) => {
-                debug!(contract = name, error = %e, "failed to check CTF approval");
-            }
-        }
+    info!(phase = "checking", "querying current approvals");
+    for status in manager.status(owner).await? {
+        info!(
+            contract = %status.approval.spender,
+            asset = ?status.approval.asset,
+            granted = status.granted
+        );
     }
 
-    info!(phase = "approving", "setting approvals");
-
-    for (name, target) in &targets {
-        info!(contract = name, address = %target, "approving");
-
-        match approve(&token, *target, U256::MAX).await {
-            Ok(tx_hash) => info!(contract = name, tx = %tx_hash, "USDC approved"),
-            Err(e) => debug!(contract = name, error = %e, "USDC approve failed"),
-        }
-
-        match set_approval_for_all(&ctf, *target, true).await {
-            Ok(tx_hash) => info!(contract = name, tx = %tx_hash, "CTF approved"),
-            Err(e) => debug!(contract = name, error = %e, "CTF setApprovalForAll failed"),
-        }
+    info!(phase = "approving", "setting missing approvals");
+    for pending in manager.approve_missing(owner).await? {
+        let tx_hash = pending.watch().await?;
+        info!(tx = %tx_hash, "approved");
     }
 
     info!(phase = "verifying", "confirming approvals");
-
-    for (name, target) in &targets {
-        match check_allowance(&token, owner, *target).await {
-            Ok(allowance) => {
-                info!(contract = name, usdc_allowance = %allowance, "verified");
-            }
-            Err(e) => {
-                debug!(contract = name, error = %e, "verification failed");
-            }
-        }
-
-        match check_approval_for_all(&ctf, owner, *target).await {
-            Ok(approved) => {
-                info!(contract = name, ctf_approved = approved, "verified");
-            }
-            Err(e) => {
-                debug!(contract = name, error = %e, "verification failed");
-            }
-        }
-    }
-
-    info!("all approvals complete");
+    let missing = manager.missing(owner).await?;
+    info!(missing = missing.len(), "all approvals complete");
 
     Ok(())
 }
-
-async fn check_allowance<P: alloy::providers::Provider>(
-    token: &IERC20::IERC20Instance<P>,
-    owner: Address,
-    spender: Address,
-) -> anyhow::Result<U256> {
-    let allowance = token.allowance(owner, spender).call().await?;
-    Ok(allowance)
-}
-
-async fn check_approval_for_all<P: alloy::providers::Provider>(
-    ctf: &IERC1155::IERC1155Instance<P>,
-    account: Address,
-    operator: Address,
-) -> anyhow::Result<bool> {
-    let approved = ctf.isApprovedForAll(account, operator).call().await?;
-    Ok(approved)
-}
-
-async fn approve<P: alloy::providers::Provider>(
-    usdc: &IERC20::IERC20Instance<P>,
-    spender: Address,
-    amount: U256,
-) -> anyhow::Result<alloy::primitives::FixedBytes<32>> {
-    let tx_hash = usdc.approve(spender, amount).send().await?.watch().await?;
-    Ok(tx_hash)
-}
-
-async fn set_approval_for_all<P: alloy::providers::Provider>(
-    ctf: &IERC1155::IERC1155Instance<P>,
-    operator: Address,
-    approved: bool,
-) -> anyhow::Result<alloy::primitives::FixedBytes<32>> {
-    let tx_hash = ctf
-        .setApprovalForAll(operator, approved)
-        .send()
-        .await?
-        .watch()
-        .await?;
-    Ok(tx_hash)
-}
@@ -11,13 +11,14 @@ use polymarket_client_sdk::clob::types::request::{
 };
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side};
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
 use rust_decimal_macros::dec;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let token_id = "15871154585880608648532107628464183779895785213830018178010423617714102767076";
+    let token_id: TokenId =
+        "15871154585880608648532107628464183779895785213830018178010423617714102767076".parse()?;
 
     let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need a private key");
     let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
@@ -138,7 +139,7 @@ async fn main() -> anyhow::Result<()> {
         "raw rewards -- {:?}",
         client
             .raw_rewards_for_market(
-                "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
+                "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1".parse()?,
                 None
             )
             .await
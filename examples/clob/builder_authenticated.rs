@@ -28,7 +28,12 @@ async fn main() -> anyhow::Result<()> {
     let keys = client.builder_api_keys().await?;
     println!("{keys:#?}");
 
-    let request = TradesRequest::builder().asset_id("asset").build();
+    let request = TradesRequest::builder()
+        .asset_id(
+            "15871154585880608648532107628464183779895785213830018178010423617714102767076"
+                .parse()?,
+        )
+        .build();
     println!(
         "builder_trades -- {:?}",
         client.builder_trades(&request, None).await?
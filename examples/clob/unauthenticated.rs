@@ -5,13 +5,16 @@ use polymarket_client_sdk::clob::types::request::{
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, PriceRequest, SpreadRequest,
 };
 use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::types::{ConditionId, TokenId};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let client = Client::new("https://clob.polymarket.com", Config::default())?;
 
-    let token_id = "102200530570339469387764365697342150521708074903735836831685780223982723092914";
-    let condition_id = "0x65805e37d6c891808a44064013a0c80babf87010fe6e69204b17381fd0761fdd";
+    let token_id: TokenId =
+        "102200530570339469387764365697342150521708074903735836831685780223982723092914".parse()?;
+    let condition_id: ConditionId =
+        "0x65805e37d6c891808a44064013a0c80babf87010fe6e69204b17381fd0761fdd".parse()?;
 
     println!("ok -- {:?}", client.ok().await);
     println!("server_time -- {:?}", client.server_time().await);
@@ -14,8 +14,8 @@ async fn main() -> anyhow::Result<()> {
 
     // Subscribe to orderbook updates
     let asset_ids = vec![
-        "92703761682322480664976766247614127878023988651992837287050266308961660624165".to_owned(),
-        "34551606549875928972193520396544368029176529083448203019529657908155427866742".to_owned(),
+        "92703761682322480664976766247614127878023988651992837287050266308961660624165".parse()?,
+        "34551606549875928972193520396544368029176529083448203019529657908155427866742".parse()?,
     ];
 
     let stream = client.subscribe_orderbook(asset_ids)?;
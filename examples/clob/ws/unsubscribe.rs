@@ -33,7 +33,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Asset IDs to subscribe to
     let asset_ids = vec![
-        "92703761682322480664976766247614127878023988651992837287050266308961660624165".to_owned(),
+        "92703761682322480664976766247614127878023988651992837287050266308961660624165".parse()?,
     ];
 
     // === FIRST SUBSCRIPTION ===
@@ -0,0 +1,303 @@
+//! Token approvals required to trade on Polymarket.
+//!
+//! Before the exchange contracts can move a wallet's funds, the wallet has to grant an ERC-20
+//! allowance of its collateral and an ERC-1155 operator approval of its conditional tokens to the
+//! exchange, the neg risk exchange and the neg risk adapter. [`required_approvals`] lists them for
+//! a chain, and an [`ApprovalManager`] checks which ones a wallet is missing and submits the
+//! transactions granting them.
+//!
+//! Each approval only has to be granted once per wallet. Proxy and Safe wallets created by
+//! Polymarket are approved when they are deployed.
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "`required_approvals` reads better than `approvals::required`"
+)]
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use std::fmt;
+
+use alloy::network::{Ethereum, TransactionBuilder as _};
+use alloy::primitives::U256;
+use alloy::providers::{PendingTransactionBuilder, Provider};
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+
+use crate::ctf::Call;
+use crate::error::{Error, Kind};
+use crate::types::{Address, ChainId};
+use crate::{Result, contract_config};
+
+sol! {
+    /// Allowance functions of the ERC-20 collateral.
+    interface IERC20 {
+        function approve(address spender, uint256 value) external returns (bool);
+        function allowance(address owner, address spender) external view returns (uint256);
+    }
+
+    /// Operator approval functions of the ERC-1155 conditional tokens.
+    interface IERC1155 {
+        function setApprovalForAll(address operator, bool approved) external;
+        function isApprovedForAll(address account, address operator) external view returns (bool);
+    }
+}
+
+/// A contract that needs to be approved to move a wallet's tokens.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Spender {
+    /// [`ContractConfig::exchange`](crate::ContractConfig::exchange) for regular markets
+    Exchange,
+    /// [`ContractConfig::exchange`](crate::ContractConfig::exchange) for neg risk markets
+    NegRiskExchange,
+    /// [`ContractConfig::neg_risk_adapter`](crate::ContractConfig::neg_risk_adapter)
+    NegRiskAdapter,
+}
+
+impl fmt::Display for Spender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Exchange => "CTF Exchange",
+            Self::NegRiskExchange => "Neg Risk CTF Exchange",
+            Self::NegRiskAdapter => "Neg Risk Adapter",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The token a [`Spender`] is approved for.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Asset {
+    /// An ERC-20 allowance of the collateral
+    Collateral,
+    /// An ERC-1155 operator approval of the conditional tokens
+    ConditionalTokens,
+}
+
+/// An approval of `token` for `spender` that a wallet needs to trade.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Approval {
+    pub spender: Spender,
+    pub spender_address: Address,
+    pub asset: Asset,
+    pub token: Address,
+}
+
+impl Approval {
+    /// The call granting this approval: an unlimited allowance of the collateral, or an operator
+    /// approval for all conditional tokens.
+    #[must_use]
+    pub fn call(&self) -> Call {
+        match self.asset {
+            Asset::Collateral => Call::new(
+                self.token,
+                &IERC20::approveCall {
+                    spender: self.spender_address,
+                    value: U256::MAX,
+                },
+            ),
+            Asset::ConditionalTokens => Call::new(
+                self.token,
+                &IERC1155::setApprovalForAllCall {
+                    operator: self.spender_address,
+                    approved: true,
+                },
+            ),
+        }
+    }
+}
+
+/// Whether a wallet has granted an [`Approval`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ApprovalStatus {
+    pub approval: Approval,
+    pub granted: bool,
+}
+
+/// Every approval needed to trade regular and neg risk markets on `chain_id`.
+pub fn required_approvals(chain_id: ChainId) -> Result<Vec<Approval>> {
+    let config =
+        contract_config(chain_id, false).ok_or(Error::missing_contract_config(chain_id, false))?;
+    let neg_risk_config =
+        contract_config(chain_id, true).ok_or(Error::missing_contract_config(chain_id, true))?;
+
+    let mut spenders = vec![
+        (Spender::Exchange, config, config.exchange),
+        (
+            Spender::NegRiskExchange,
+            neg_risk_config,
+            neg_risk_config.exchange,
+        ),
+    ];
+    if let Some(adapter) = neg_risk_config.neg_risk_adapter {
+        spenders.push((Spender::NegRiskAdapter, neg_risk_config, adapter));
+    }
+
+    Ok(spenders
+        .into_iter()
+        .flat_map(|(spender, config, spender_address)| {
+            [
+                Approval {
+                    spender,
+                    spender_address,
+                    asset: Asset::Collateral,
+                    token: config.collateral,
+                },
+                Approval {
+                    spender,
+                    spender_address,
+                    asset: Asset::ConditionalTokens,
+                    token: config.conditional_tokens,
+                },
+            ]
+        })
+        .collect())
+}
+
+/// Checks and grants the [`required_approvals`] of a wallet through an alloy [`Provider`].
+#[derive(Clone, Debug)]
+pub struct ApprovalManager<P> {
+    provider: P,
+    approvals: Vec<Approval>,
+    min_allowance: U256,
+}
+
+impl<P: Provider> ApprovalManager<P> {
+    /// Manages the [`required_approvals`] on `chain_id`.
+    pub fn new(provider: P, chain_id: ChainId) -> Result<Self> {
+        Ok(Self {
+            provider,
+            approvals: required_approvals(chain_id)?,
+            min_allowance: U256::from(1),
+        })
+    }
+
+    /// Sets the collateral allowance below which an allowance counts as missing. By default any
+    /// non-zero allowance counts as granted.
+    #[must_use]
+    pub fn with_min_allowance(mut self, min_allowance: U256) -> Self {
+        self.min_allowance = min_allowance;
+        self
+    }
+
+    #[must_use]
+    pub fn approvals(&self) -> &[Approval] {
+        &self.approvals
+    }
+
+    /// Whether `owner` has granted each of the [`approvals`](Self::approvals).
+    pub async fn status(&self, owner: Address) -> Result<Vec<ApprovalStatus>> {
+        let mut statuses = Vec::with_capacity(self.approvals.len());
+        for approval in &self.approvals {
+            let granted = match approval.asset {
+                Asset::Collateral => {
+                    let call = IERC20::allowanceCall {
+                        owner,
+                        spender: approval.spender_address,
+                    };
+                    self.read(approval.token, &call).await? >= self.min_allowance
+                }
+                Asset::ConditionalTokens => {
+                    let call = IERC1155::isApprovedForAllCall {
+                        account: owner,
+                        operator: approval.spender_address,
+                    };
+                    self.read(approval.token, &call).await?
+                }
+            };
+
+            statuses.push(ApprovalStatus {
+                approval: *approval,
+                granted,
+            });
+        }
+
+        Ok(statuses)
+    }
+
+    /// The approvals `owner` has yet to grant.
+    pub async fn missing(&self, owner: Address) -> Result<Vec<Approval>> {
+        Ok(self
+            .status(owner)
+            .await?
+            .into_iter()
+            .filter(|status| !status.granted)
+            .map(|status| status.approval)
+            .collect())
+    }
+
+    /// Sends a transaction from `owner` granting each approval it is missing, returning them in
+    /// the order of [`approvals`](Self::approvals). The provider has to sign for `owner`; a proxy
+    /// or Safe wallet has to execute the [`Approval::call`]s itself instead.
+    pub async fn approve_missing(
+        &self,
+        owner: Address,
+    ) -> Result<Vec<PendingTransactionBuilder<Ethereum>>> {
+        let mut pending = Vec::new();
+        for approval in self.missing(owner).await? {
+            let request: TransactionRequest = approval.call().into();
+            let request = request.with_from(owner);
+            pending.push(self.provider.send_transaction(request).await?);
+        }
+
+        Ok(pending)
+    }
+
+    async fn read<C: SolCall>(&self, to: Address, call: &C) -> Result<C::Return> {
+        let request = TransactionRequest::default()
+            .with_to(to)
+            .with_input(call.abi_encode());
+        let output = self.provider.call(request).await?;
+
+        C::abi_decode_returns(&output).map_err(|e| Error::with_source(Kind::Internal, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AMOY, POLYGON};
+
+    #[test]
+    fn required_approvals_should_cover_every_spender_and_asset() {
+        let approvals = required_approvals(POLYGON).unwrap();
+        let neg_risk_config = contract_config(POLYGON, true).unwrap();
+
+        assert_eq!(approvals.len(), 6);
+        assert!(approvals.contains(&Approval {
+            spender: Spender::NegRiskAdapter,
+            spender_address: neg_risk_config.neg_risk_adapter.unwrap(),
+            asset: Asset::ConditionalTokens,
+            token: neg_risk_config.conditional_tokens,
+        }));
+        assert_eq!(required_approvals(AMOY).unwrap().len(), 6);
+        required_approvals(1).unwrap_err();
+    }
+
+    #[test]
+    fn approval_call_should_grant_unlimited_access() {
+        let approvals = required_approvals(POLYGON).unwrap();
+
+        for approval in approvals {
+            let call = approval.call();
+            assert_eq!(call.to, approval.token);
+
+            match approval.asset {
+                Asset::Collateral => {
+                    let decoded = call.decode::<IERC20::approveCall>().unwrap();
+                    assert_eq!(decoded.spender, approval.spender_address);
+                    assert_eq!(decoded.value, U256::MAX);
+                }
+                Asset::ConditionalTokens => {
+                    let decoded = call.decode::<IERC1155::setApprovalForAllCall>().unwrap();
+                    assert_eq!(decoded.operator, approval.spender_address);
+                    assert!(decoded.approved);
+                }
+            }
+        }
+    }
+}
@@ -64,9 +64,22 @@ impl Credentials {
     }
 }
 
+/// A client authenticated with [`Credentials`] that can be replaced while it is in use.
+///
+/// Rotating the API key of a [`crate::clob::Client`] with
+/// [`rotate_api_key`](crate::clob::Client::rotate_api_key) swaps the new key into every attached
+/// implementor, and swaps the previous key back if the rotation fails.
+pub trait SwapCredentials: Send + Sync {
+    /// Replaces the credentials this client authenticates with, including those of its live
+    /// subscriptions.
+    fn swap_credentials(&self, credentials: &Credentials) -> Result<()>;
+}
+
 /// Each [`Client`] can exist in one state at a time, i.e. [`state::Unauthenticated`] or
 /// [`state::Authenticated`].
 pub mod state {
+    use std::sync::{Arc, PoisonError, RwLock};
+
     use crate::auth::{Credentials, Kind};
     use crate::types::Address;
 
@@ -86,13 +99,44 @@ pub mod state {
         /// The signer's address that created the credentials
         pub(crate) address: Address,
         /// The [`Credentials`]'s `secret` is used to generate an [`crate::signer::hmac`] which is
-        /// passed in the L2 headers ([`super::HeaderMap`]) `POLY_SIGNATURE` field.
-        pub(crate) credentials: Credentials,
+        /// passed in the L2 headers ([`super::HeaderMap`]) `POLY_SIGNATURE` field. They are
+        /// shared by clones of this state, and replaced in place when the API key is rotated.
+        pub(crate) credentials: Arc<RwLock<Credentials>>,
         /// The [`Kind`] that this [`Authenticated`] exhibits. Used to generate additional headers
         /// for different types of authentication, e.g. Builder.
         pub(crate) kind: K,
     }
 
+    impl<K: Kind> Authenticated<K> {
+        pub(crate) fn new(address: Address, credentials: Credentials, kind: K) -> Self {
+            Self {
+                address,
+                credentials: Arc::new(RwLock::new(credentials)),
+                kind,
+            }
+        }
+
+        /// Returns the [`Credentials`] currently in use.
+        pub(crate) fn credentials(&self) -> Credentials {
+            // We can recover from poisoned lock because Credentials has no inconsistent intermediate state.
+            self.credentials
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        }
+
+        /// Replaces the [`Credentials`] in use, returning the previous ones.
+        pub(crate) fn replace_credentials(&self, credentials: Credentials) -> Credentials {
+            std::mem::replace(
+                &mut *self
+                    .credentials
+                    .write()
+                    .unwrap_or_else(PoisonError::into_inner),
+                credentials,
+            )
+        }
+    }
+
     /// The clob state can only be [`Unauthenticated`] or [`Authenticated`].
     pub trait State: sealed::Sealed {}
 
@@ -218,7 +262,7 @@ pub(crate) mod l2 {
     use secrecy::ExposeSecret as _;
 
     use crate::auth::state::Authenticated;
-    use crate::auth::{Kind, hmac, to_message};
+    use crate::auth::{Credentials, Kind, hmac, to_message};
     use crate::{Result, Timestamp};
 
     pub(crate) const POLY_ADDRESS: &str = "POLY_ADDRESS";
@@ -233,7 +277,16 @@ pub(crate) mod l2 {
         request: &Request,
         timestamp: Timestamp,
     ) -> Result<HeaderMap> {
-        let credentials = &state.credentials;
+        create_headers_with(state, &state.credentials(), request, timestamp).await
+    }
+
+    /// Like [`create_headers`], but signs with `credentials` instead of the ones in use by `state`.
+    pub(crate) async fn create_headers_with<K: Kind>(
+        state: &Authenticated<K>,
+        credentials: &Credentials,
+        request: &Request,
+        timestamp: Timestamp,
+    ) -> Result<HeaderMap> {
         let signature = hmac(&credentials.secret, &to_message(request, timestamp))?;
 
         let mut map = HeaderMap::new();
@@ -242,10 +295,10 @@ pub(crate) mod l2 {
             POLY_ADDRESS,
             state.address.encode_hex_with_prefix().parse()?,
         );
-        map.insert(POLY_API_KEY, state.credentials.key.to_string().parse()?);
+        map.insert(POLY_API_KEY, credentials.key.to_string().parse()?);
         map.insert(
             POLY_PASSPHRASE,
-            state.credentials.passphrase.expose_secret().parse()?,
+            credentials.passphrase.expose_secret().parse()?,
         );
         map.insert(POLY_SIGNATURE, signature.parse()?);
         map.insert(POLY_TIMESTAMP, timestamp.to_string().parse()?);
@@ -462,9 +515,9 @@ mod tests {
     async fn l2_headers_should_succeed() -> anyhow::Result<()> {
         let signer = LocalSigner::from_str(PRIVATE_KEY)?;
 
-        let authenticated = Authenticated {
-            address: signer.address(),
-            credentials: Credentials {
+        let authenticated = Authenticated::new(
+            signer.address(),
+            Credentials {
                 key: Uuid::nil(),
                 passphrase: SecretString::from(
                     "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_owned(),
@@ -473,8 +526,8 @@ mod tests {
                     "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
                 ),
             },
-            kind: Normal,
-        };
+            Normal,
+        );
 
         let request = Request::new(Method::GET, Url::parse("http://localhost/")?);
         let headers = l2::create_headers(&authenticated, &request, 1).await?;
@@ -6,6 +6,8 @@ use url::Url;
 
 use super::types::{DepositRequest, DepositResponse, SupportedAssetsResponse};
 use crate::Result;
+use crate::retry::RetryPolicy;
+use crate::transport::HttpTransport;
 
 /// Client for the Polymarket Bridge API.
 ///
@@ -33,9 +35,15 @@ use crate::Result;
 /// # }
 /// ```
 #[derive(Clone, Debug)]
-pub struct Client {
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client, as in the other API clients"
+)]
+pub struct Client<T: HttpTransport = ReqwestClient> {
     host: Url,
     client: ReqwestClient,
+    transport: T,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl Default for Client {
@@ -62,9 +70,32 @@ impl Client {
 
         Ok(Self {
             host: Url::parse(host)?,
+            transport: client.clone(),
             client,
+            retry_policy: None,
         })
     }
+}
+
+impl<T: HttpTransport> Client<T> {
+    /// Sends requests through `transport` instead of the default [`ReqwestClient`].
+    pub fn with_transport<U: HttpTransport>(self, transport: U) -> Client<U> {
+        Client {
+            host: self.host,
+            client: self.client,
+            transport,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Retries requests that fail with `429` or `5xx` according to `retry_policy`.
+    ///
+    /// Requests are not retried unless a policy is set.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
 
     /// Returns the host URL for the client.
     #[must_use]
@@ -108,7 +139,13 @@ impl Client {
             .json(request)
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.transport,
+            request,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await
     }
 
     /// Get all supported chains and tokens for deposits.
@@ -143,6 +180,12 @@ impl Client {
             .request(Method::GET, format!("{}supported-assets", self.host()))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.transport,
+            request,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await
     }
 }
@@ -0,0 +1,638 @@
+//! Record and replay of REST and WebSocket traffic.
+//!
+//! A cassette is a JSONL file holding one [`Interaction`] per line. Wrapping a client's transport
+//! in a [`RecordingTransport`] appends every REST request/response pair it sends, and setting
+//! `recorder` on the WebSocket [`Config`](crate::ws::config::Config) appends every text frame a
+//! connection sends or receives. L1/L2 authentication headers, [`Credentials`] fields and the
+//! API key echoed in order owners and key listings are redacted before anything is written.
+//!
+//! A recorded [`Cassette`] is served back by a [`ReplayTransport`] for REST clients and, with the
+//! `ws` or `rtds` feature, by a `ReplayServer` for WebSocket clients, which makes a captured
+//! session reproducible in offline tests.
+//!
+//! [`Credentials`]: crate::auth::Credentials
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::sync::Arc;
+//!
+//! use polymarket_client_sdk::cassette::{Cassette, Recorder, RecordingTransport};
+//! use polymarket_client_sdk::clob::{Client, Config};
+//!
+//! # async fn run() -> polymarket_client_sdk::Result<()> {
+//! // Record a session
+//! let recorder = Arc::new(Recorder::create("session.jsonl")?);
+//! let client = Client::new("https://clob.polymarket.com", Config::default())?
+//!     .with_transport(RecordingTransport::new(reqwest::Client::new(), recorder))?;
+//! client.ok().await?;
+//!
+//! // Replay it later, without a network connection
+//! let cassette = Cassette::load("session.jsonl")?;
+//! let client = Client::new("https://clob.polymarket.com", Config::default())?
+//!     .with_transport(cassette.transport())?;
+//! client.ok().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead as _, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Client as ReqwestClient, Request, Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::Result;
+use crate::error::Error;
+use crate::transport::HttpTransport;
+
+/// Replacement for redacted header values and passphrases.
+const REDACTED: &str = "REDACTED";
+/// Replacement for redacted secrets, valid base64 so that replayed credentials can still sign.
+const REDACTED_SECRET: &str = "UkVEQUNURUQ=";
+/// Replacement for redacted API keys, a valid (nil) UUID so that replayed credentials parse.
+const REDACTED_API_KEY: &str = "00000000-0000-0000-0000-000000000000";
+
+/// L1, L2 and Builder headers carrying secrets or signatures derived from them.
+const SENSITIVE_HEADERS: [&str; 7] = [
+    "authorization",
+    "poly_api_key",
+    "poly_passphrase",
+    "poly_signature",
+    "poly_builder_api_key",
+    "poly_builder_passphrase",
+    "poly_builder_signature",
+];
+
+/// A single recorded exchange, i.e. one line of a cassette.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Interaction {
+    /// A REST request and the response it received
+    Http {
+        request: HttpRequest,
+        response: HttpResponse,
+    },
+    /// A WebSocket text frame
+    Ws {
+        /// The endpoint of the connection the frame was sent or received on
+        endpoint: String,
+        direction: Direction,
+        frame: String,
+    },
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+/// Whether a WebSocket frame was sent by the client or received from the server.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Direction {
+    Sent,
+    Received,
+}
+
+/// Appends [`Interaction`]s to a cassette, one JSON object per line.
+///
+/// A single [`Recorder`] can be shared by several transports and WebSocket connections, in which
+/// case their interactions are interleaved in the order they happen.
+pub struct Recorder {
+    writer: Mutex<Box<dyn Write + Send>>,
+}
+
+impl fmt::Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Recorder").finish_non_exhaustive()
+    }
+}
+
+impl Recorder {
+    /// Creates a [`Recorder`] writing to a new cassette at `path`, truncating any existing file.
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Creates a [`Recorder`] writing to `writer`.
+    pub fn new<W: Write + Send + 'static>(writer: W) -> Self {
+        Self {
+            writer: Mutex::new(Box::new(writer)),
+        }
+    }
+
+    /// Writes `interaction` as is, without redacting it, and flushes the cassette.
+    pub fn record(&self, interaction: &Interaction) -> Result<()> {
+        let line = serde_json::to_string(interaction)?;
+
+        let mut writer = self.writer.lock().unwrap_or_else(PoisonError::into_inner);
+        writeln!(writer, "{line}")?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    /// Records a redacted WebSocket frame. Failures are logged rather than returned, so that a
+    /// broken cassette never interrupts the connection.
+    #[cfg(any(feature = "ws", feature = "rtds"))]
+    pub(crate) fn record_frame(&self, endpoint: &str, direction: Direction, frame: &str) {
+        let interaction = Interaction::Ws {
+            endpoint: endpoint.to_owned(),
+            direction,
+            frame: redact_body(frame),
+        };
+
+        self.record_or_log(&interaction);
+    }
+
+    fn record_or_log(&self, interaction: &Interaction) {
+        if let Err(e) = self.record(interaction) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, "Failed to record interaction");
+            #[cfg(not(feature = "tracing"))]
+            let _ = &e;
+        }
+    }
+}
+
+/// [`HttpTransport`] that sends requests through `inner` and records every exchange.
+///
+/// Requests and responses are recorded with their authentication headers and credentials
+/// redacted. A failure to write the cassette is logged and does not fail the request.
+#[derive(Debug)]
+pub struct RecordingTransport<T: HttpTransport = ReqwestClient> {
+    inner: T,
+    recorder: Arc<Recorder>,
+}
+
+impl<T: HttpTransport> RecordingTransport<T> {
+    pub fn new(inner: T, recorder: Arc<Recorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for RecordingTransport<T> {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        let recorded_request = HttpRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: to_headers(request.headers()),
+            body: request
+                .body()
+                .and_then(reqwest::Body::as_bytes)
+                .map(|body| redact_body(&String::from_utf8_lossy(body))),
+        };
+
+        let response = self.inner.execute(request).await?;
+
+        let status = response.status();
+        let version = response.version();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+
+        self.recorder.record_or_log(&Interaction::Http {
+            request: recorded_request,
+            response: HttpResponse {
+                status: status.as_u16(),
+                headers: to_headers(&headers),
+                body: redact_body(&String::from_utf8_lossy(&body)),
+            },
+        });
+
+        let mut response = http::Response::new(body);
+        *response.status_mut() = status;
+        *response.version_mut() = version;
+        *response.headers_mut() = headers;
+
+        Ok(Response::from(response))
+    }
+}
+
+/// A loaded cassette.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    #[must_use]
+    pub fn new(interactions: Vec<Interaction>) -> Self {
+        Self { interactions }
+    }
+
+    /// Loads the cassette at `path`, skipping blank lines.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut interactions = Vec::new();
+
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                interactions.push(serde_json::from_str(&line)?);
+            }
+        }
+
+        Ok(Self::new(interactions))
+    }
+
+    /// Returns a [`ReplayTransport`] serving the REST responses in this cassette.
+    #[must_use]
+    pub fn transport(&self) -> ReplayTransport {
+        let exchanges = self
+            .interactions
+            .iter()
+            .filter_map(|interaction| match interaction {
+                Interaction::Http { request, response } => {
+                    Some((request.clone(), response.clone()))
+                }
+                Interaction::Ws { .. } => None,
+            })
+            .collect();
+
+        ReplayTransport {
+            exchanges: Mutex::new(exchanges),
+        }
+    }
+}
+
+/// [`HttpTransport`] answering requests with the responses recorded in a [`Cassette`].
+///
+/// Each request is answered with the earliest unused recorded response for the same method and
+/// URL, so repeated requests replay in the order they were recorded. Requests without a matching
+/// recording fail with a validation error instead of reaching the network.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    exchanges: Mutex<VecDeque<(HttpRequest, HttpResponse)>>,
+}
+
+impl ReplayTransport {
+    /// Number of recorded responses that have not been served yet.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.exchanges
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReplayTransport {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        let method = request.method().as_str();
+        let url = request.url().as_str();
+
+        let recorded = {
+            let mut exchanges = self
+                .exchanges
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+
+            exchanges
+                .iter()
+                .position(|(recorded, _)| recorded.method == method && recorded.url == url)
+                .and_then(|index| exchanges.remove(index))
+        };
+
+        let Some((_, recorded)) = recorded else {
+            return Err(Error::validation(format!(
+                "No recorded response for {method} {url}"
+            )));
+        };
+
+        let mut response = http::Response::new(recorded.body);
+        *response.status_mut() = StatusCode::from_u16(recorded.status)
+            .map_err(|e| Error::validation(format!("Invalid recorded status: {e}")))?;
+        for (name, value) in &recorded.headers {
+            let name = HeaderName::try_from(name.as_str())
+                .map_err(|e| Error::validation(format!("Invalid recorded header {name}: {e}")))?;
+            response.headers_mut().insert(name, value.parse()?);
+        }
+
+        Ok(Response::from(response))
+    }
+}
+
+#[cfg(any(feature = "ws", feature = "rtds"))]
+pub use replay_server::ReplayServer;
+
+#[cfg(any(feature = "ws", feature = "rtds"))]
+mod replay_server {
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    use futures::{SinkExt as _, StreamExt as _};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::task::JoinHandle;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::{WebSocketStream, accept_hdr_async};
+    use url::Url;
+
+    use super::{Cassette, Direction, Interaction};
+    use crate::Result;
+
+    type Frames = Arc<Vec<(String, Direction, String)>>;
+
+    /// Local WebSocket server replaying the frames recorded in a [`Cassette`].
+    ///
+    /// Each connection is served the frames recorded for the endpoint with the same path, e.g.
+    /// `/ws/market`, in their recorded order: received frames are sent to the client, while a
+    /// recorded sent frame makes the server wait for the client to send its next frame, so
+    /// responses to a subscription are only replayed once the client has subscribed. `PING`s
+    /// are answered with `PONG`s throughout.
+    ///
+    /// The server stops when dropped.
+    #[derive(Debug)]
+    pub struct ReplayServer {
+        addr: SocketAddr,
+        handle: JoinHandle<()>,
+    }
+
+    impl ReplayServer {
+        /// Starts serving `cassette` on a random local port.
+        pub async fn start(cassette: &Cassette) -> Result<Self> {
+            let frames: Frames = Arc::new(
+                cassette
+                    .interactions
+                    .iter()
+                    .filter_map(|interaction| match interaction {
+                        Interaction::Ws {
+                            endpoint,
+                            direction,
+                            frame,
+                        } => Some((path(endpoint), *direction, frame.clone())),
+                        Interaction::Http { .. } => None,
+                    })
+                    .collect(),
+            );
+
+            let listener = TcpListener::bind("127.0.0.1:0").await?;
+            let addr = listener.local_addr()?;
+
+            let handle = tokio::spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    tokio::spawn(serve(stream, Arc::clone(&frames)));
+                }
+            });
+
+            Ok(Self { addr, handle })
+        }
+
+        /// The base endpoint to point a WebSocket client at, e.g. `ws://127.0.0.1:1234`.
+        #[must_use]
+        pub fn endpoint(&self) -> String {
+            format!("ws://{}", self.addr)
+        }
+    }
+
+    impl Drop for ReplayServer {
+        fn drop(&mut self) {
+            self.handle.abort();
+        }
+    }
+
+    fn path(endpoint: &str) -> String {
+        Url::parse(endpoint).map_or_else(|_| "/".to_owned(), |url| url.path().to_owned())
+    }
+
+    async fn serve(stream: TcpStream, frames: Frames) {
+        let mut path = String::new();
+        #[expect(
+            clippy::result_large_err,
+            reason = "The signature is dictated by tungstenite's handshake callback"
+        )]
+        let callback = |request: &Request, response: Response| {
+            request.uri().path().clone_into(&mut path);
+            Ok(response)
+        };
+
+        let Ok(mut ws) = accept_hdr_async(stream, callback).await else {
+            return;
+        };
+
+        for (_, direction, frame) in frames.iter().filter(|(p, ..)| *p == path) {
+            let replayed = match direction {
+                Direction::Received => ws.send(Message::Text(frame.as_str().into())).await.is_ok(),
+                Direction::Sent => next_frame(&mut ws).await,
+            };
+
+            if !replayed {
+                return;
+            }
+        }
+
+        while next_frame(&mut ws).await {}
+    }
+
+    /// Waits for the client's next text frame, answering `PING`s along the way. Returns `false`
+    /// once the connection is closed.
+    async fn next_frame(ws: &mut WebSocketStream<TcpStream>) -> bool {
+        while let Some(Ok(message)) = ws.next().await {
+            let open = match message {
+                Message::Text(text) if text == "PING" => {
+                    ws.send(Message::Text("PONG".into())).await.is_ok()
+                }
+                Message::Text(_) => return true,
+                Message::Close(_) => false,
+                _ => true,
+            };
+
+            if !open {
+                return false;
+            }
+        }
+
+        false
+    }
+}
+
+fn to_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if SENSITIVE_HEADERS.contains(&name.as_str()) {
+                REDACTED.to_owned()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Redacts [`Credentials`](crate::auth::Credentials) fields from a JSON `body`, along with the
+/// API key wherever it is echoed: the `owner` of orders, the `key` of RTDS `clob_auth` and the
+/// `apiKeys` listing. Other bodies are returned unchanged.
+fn redact_body(body: &str) -> String {
+    match serde_json::from_str::<Value>(body) {
+        Ok(mut value) => {
+            redact(&mut value);
+            value.to_string()
+        }
+        Err(_) => body.to_owned(),
+    }
+}
+
+fn redact(value: &mut Value) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                match (key.as_str(), &*value) {
+                    ("apiKey" | "owner", Value::String(_)) => *value = REDACTED_API_KEY.into(),
+                    ("secret", Value::String(_)) => *value = REDACTED_SECRET.into(),
+                    ("passphrase", Value::String(_)) => *value = REDACTED.into(),
+                    ("apiKeys", Value::Array(_)) => redact_api_keys(value),
+                    ("clob_auth", Value::Object(_)) => {
+                        if let Some(key @ Value::String(_)) = value.get_mut("key") {
+                            *key = REDACTED_API_KEY.into();
+                        }
+                        redact(value);
+                    }
+                    _ => redact(value),
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact),
+        _ => {}
+    }
+}
+
+fn redact_api_keys(value: &mut Value) {
+    if let Value::Array(keys) = value {
+        for key in keys.iter_mut().filter(|key| key.is_string()) {
+            *key = REDACTED_API_KEY.into();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn redact_should_replace_credentials_at_any_depth() {
+        let body = json!({
+            "type": "user",
+            "markets": ["0x1"],
+            "auth": {
+                "apiKey": "f4f6e5b1-0000-4000-8000-000000000000",
+                "secret": "c2VjcmV0",
+                "passphrase": "hunter2"
+            }
+        })
+        .to_string();
+
+        let redacted: Value = serde_json::from_str(&redact_body(&body)).unwrap();
+
+        assert_eq!(
+            redacted,
+            json!({
+                "type": "user",
+                "markets": ["0x1"],
+                "auth": {
+                    "apiKey": REDACTED_API_KEY,
+                    "secret": REDACTED_SECRET,
+                    "passphrase": REDACTED
+                }
+            })
+        );
+        assert_eq!(redact_body("PONG"), "PONG");
+    }
+
+    #[test]
+    fn redact_should_replace_echoed_api_keys() {
+        let key = "f4f6e5b1-0000-4000-8000-000000000000";
+        let body = json!({
+            "order": { "maker": "0x1" },
+            "owner": key,
+            "apiKeys": [key, key],
+            "subscriptions": [{
+                "topic": "clob_user",
+                "clob_auth": { "key": key, "secret": "c2VjcmV0", "passphrase": "hunter2" }
+            }],
+            "key": "unrelated"
+        })
+        .to_string();
+
+        let redacted: Value = serde_json::from_str(&redact_body(&body)).unwrap();
+
+        assert_eq!(
+            redacted,
+            json!({
+                "order": { "maker": "0x1" },
+                "owner": REDACTED_API_KEY,
+                "apiKeys": [REDACTED_API_KEY, REDACTED_API_KEY],
+                "subscriptions": [{
+                    "topic": "clob_user",
+                    "clob_auth": {
+                        "key": REDACTED_API_KEY,
+                        "secret": REDACTED_SECRET,
+                        "passphrase": REDACTED
+                    }
+                }],
+                "key": "unrelated"
+            })
+        );
+    }
+
+    #[test]
+    fn to_headers_should_redact_authentication_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert("POLY_ADDRESS", HeaderValue::from_static("0xabc"));
+        headers.insert("POLY_API_KEY", HeaderValue::from_static("key"));
+        headers.insert("POLY_BUILDER_SIGNATURE", HeaderValue::from_static("sig"));
+
+        let headers = to_headers(&headers);
+
+        assert_eq!(headers["poly_address"], "0xabc");
+        assert_eq!(headers["poly_api_key"], REDACTED);
+        assert_eq!(headers["poly_builder_signature"], REDACTED);
+    }
+
+    #[test]
+    fn interaction_should_round_trip_as_a_single_line() {
+        let interaction = Interaction::Ws {
+            endpoint: "wss://example.com/ws/market".to_owned(),
+            direction: Direction::Received,
+            frame: "{\"event_type\":\"book\"}".to_owned(),
+        };
+
+        let line = serde_json::to_string(&interaction).unwrap();
+
+        assert!(!line.contains('\n'));
+        assert!(line.starts_with("{\"type\":\"ws\""));
+        assert_eq!(
+            serde_json::from_str::<Interaction>(&line).unwrap(),
+            interaction
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Per-token caches of the market parameters needed to build orders.
+//!
+//! Tick sizes, fee rates and neg risk flags rarely change, so the [`Client`](super::Client) keeps
+//! them once fetched. Entries live until they are invalidated, or for the
+//! [`cache_ttl`](super::ConfigBuilder::cache_ttl) of the client's [`Config`](super::Config) when
+//! one is set.
+
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::types::TokenId;
+
+#[derive(Debug)]
+pub(crate) struct TokenCache<V> {
+    entries: DashMap<TokenId, (V, Instant)>,
+    ttl: Option<Duration>,
+}
+
+impl<V: Copy> TokenCache<V> {
+    pub(crate) fn new(ttl: Option<Duration>) -> Self {
+        Self {
+            entries: DashMap::new(),
+            ttl,
+        }
+    }
+
+    /// Returns the value cached for `token_id`, dropping it if it has expired.
+    pub(crate) fn get(&self, token_id: TokenId) -> Option<V> {
+        let (value, inserted_at) = *self.entries.get(&token_id)?;
+
+        if self.ttl.is_some_and(|ttl| inserted_at.elapsed() >= ttl) {
+            self.entries
+                .remove_if(&token_id, |_, (_, at)| *at == inserted_at);
+            return None;
+        }
+
+        Some(value)
+    }
+
+    pub(crate) fn insert(&self, token_id: TokenId, value: V) {
+        self.entries.insert(token_id, (value, Instant::now()));
+    }
+
+    pub(crate) fn remove(&self, token_id: TokenId) {
+        self.entries.remove(&token_id);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_should_return_entries_within_ttl() {
+        let cache = TokenCache::new(Some(Duration::from_secs(60)));
+        cache.insert(TokenId::from(1), 10_u32);
+
+        assert_eq!(cache.get(TokenId::from(1)), Some(10));
+        assert_eq!(cache.get(TokenId::from(2)), None);
+    }
+
+    #[test]
+    fn get_should_drop_expired_entries() {
+        let cache = TokenCache::new(Some(Duration::ZERO));
+        cache.insert(TokenId::from(1), 10_u32);
+
+        assert_eq!(cache.get(TokenId::from(1)), None);
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn remove_should_only_drop_one_token() {
+        let cache = TokenCache::new(None);
+        cache.insert(TokenId::from(1), true);
+        cache.insert(TokenId::from(2), false);
+
+        cache.remove(TokenId::from(1));
+
+        assert_eq!(cache.get(TokenId::from(1)), None);
+        assert_eq!(cache.get(TokenId::from(2)), Some(false));
+    }
+}
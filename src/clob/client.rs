@@ -1,26 +1,29 @@
-use std::borrow::Cow;
 use std::marker::PhantomData;
-use std::mem;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use alloy::dyn_abi::Eip712Domain;
-use alloy::primitives::U256;
 use alloy::signers::Signer;
 use alloy::sol_types::SolStruct as _;
-use async_stream::try_stream;
 use bon::Builder;
-use chrono::{NaiveDate, Utc};
-use dashmap::DashMap;
-use futures::Stream;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use futures::{Stream, TryStreamExt as _, future};
 use reqwest::header::{HeaderMap, HeaderValue};
 use reqwest::{Client as ReqwestClient, Method, Request};
+use serde::de::DeserializeOwned;
 use serde_json::json;
 use url::Url;
 
 use crate::auth::builder::{Builder, Config as BuilderConfig};
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind, Normal};
-use crate::clob::order_builder::{Limit, Market, OrderBuilder, generate_seed};
+use crate::auth::{Credentials, Kind, Normal, SwapCredentials};
+use crate::clob::cache::TokenCache;
+use crate::clob::clock::{Clock, ClockSkew, ClockSync};
+use crate::clob::order_builder::{FillPreview, Limit, Market, OrderBuilder, generate_seed};
+use crate::clob::rate_limit::{
+    Config as RateLimitConfig, EndpointFamily, RateLimiter, Stats as RateLimitStats, Throttled,
+};
+use crate::clob::risk::{OrderRisk, RiskCheck, RiskGuard, RiskLimits, open_notional};
+use crate::clob::rotation::ApiKeyRotation;
 use crate::clob::types::request::{
     BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
     LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, OrdersRequest,
@@ -38,24 +41,32 @@ use crate::clob::types::response::{
     SpreadsResponse, TickSizeResponse, TotalUserEarningResponse, TradeResponse,
     UserEarningResponse, UserRewardsEarningResponse,
 };
-use crate::clob::types::{SignableOrder, SignatureType, SignedOrder, TickSize};
+use crate::clob::types::{
+    Amount, MarketParams, Order, Side, SignableOrder, SignatureType, SignedOrder, TickSize,
+    order_domain,
+};
 use crate::error::{Error, Synchronization};
-use crate::types::Address;
+use crate::pagination::{self, Paginated};
+use crate::retry::{Idempotency, RetryPolicy};
+use crate::transport::HttpTransport;
+use crate::types::{Address, ConditionId, TokenId};
 use crate::{
-    AMOY, POLYGON, Result, Timestamp, ToQueryParams as _, auth, contract_config,
+    AMOY, POLYGON, Result, Sign, Timestamp, ToQueryParams as _, Unsigned, auth,
     derive_proxy_wallet, derive_safe_wallet,
 };
 
-const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
-const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
-
-const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
+pub(crate) const TERMINAL_CURSOR: &str = "LTE="; // base64("-1")
 
 /// The type used to build a request to authenticate the inner [`Client<Unauthorized>`]. Calling
 /// `authenticate` on this will elevate that inner `client` into an [`Client<Authenticated<K>>`].
-pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
+pub struct AuthenticationBuilder<
+    'signer,
+    S: Signer,
+    K: Kind = Normal,
+    T: HttpTransport = ReqwestClient,
+> {
     /// The initially unauthenticated client that is "carried forward" into the authenticated client.
-    client: Client<Unauthenticated>,
+    client: Client<Unauthenticated, T>,
     /// The signer used to generate the L1 headers that will return a set of [`Credentials`].
     signer: &'signer S,
     /// If [`Credentials`] are supplied, then those are used instead of making new calls to obtain one.
@@ -76,7 +87,7 @@ pub struct AuthenticationBuilder<'signer, S: Signer, K: Kind = Normal> {
     salt_generator: Option<fn() -> u64>,
 }
 
-impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
+impl<S: Signer, K: Kind, T: HttpTransport> AuthenticationBuilder<'_, S, K, T> {
     #[must_use]
     pub fn nonce(mut self, nonce: u32) -> Self {
         self.nonce = Some(nonce);
@@ -113,7 +124,7 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
         clippy::missing_panics_doc,
         reason = "chain_id panic is guarded by prior validation"
     )]
-    pub async fn authenticate(self) -> Result<Client<Authenticated<K>>> {
+    pub async fn authenticate(self) -> Result<Client<Authenticated<K>, T>> {
         let inner = Arc::into_inner(self.client.inner).ok_or(Synchronization)?;
 
         match self.signer.chain_id() {
@@ -192,11 +203,7 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
             }
         };
 
-        let state = Authenticated {
-            address: self.signer.address(),
-            credentials,
-            kind: self.kind,
-        };
+        let state = Authenticated::new(self.signer.address(), credentials, self.kind);
 
         Ok(Client {
             inner: Arc::new(ClientInner {
@@ -205,12 +212,16 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
                 host: inner.host,
                 geoblock_host: inner.geoblock_host,
                 client: inner.client,
+                transport: inner.transport,
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
                 funder,
                 signature_type: self.signature_type.unwrap_or(SignatureType::Eoa),
                 salt_generator: self.salt_generator.unwrap_or(generate_seed),
+                rate_limiter: inner.rate_limiter,
+                risk: inner.risk,
+                clock: inner.clock,
             }),
         })
     }
@@ -272,8 +283,8 @@ impl<S: Signer, K: Kind> AuthenticationBuilder<'_, S, K> {
 /// }
 /// ```
 #[derive(Clone, Debug)]
-pub struct Client<S: State = Unauthenticated> {
-    inner: Arc<ClientInner<S>>,
+pub struct Client<S: State = Unauthenticated, T: HttpTransport = ReqwestClient> {
+    inner: Arc<ClientInner<S, T>>,
 }
 
 impl Default for Client<Unauthenticated> {
@@ -287,20 +298,35 @@ impl Default for Client<Unauthenticated> {
 #[derive(Clone, Debug, Default, Builder)]
 pub struct Config {
     /// Whether the [`Client`] will use the server time provided by Polymarket when creating auth
-    /// headers. This adds another round trip to the requests.
+    /// headers. This adds another round trip to the requests. Ignored when `clock_sync` is set.
     #[builder(default)]
     use_server_time: bool,
+    /// Calibrates the local clock against the server's, and applies the measured offset to auth
+    /// headers and GTD order expirations without a round trip per request.
+    clock_sync: Option<ClockSync>,
     /// Override for the geoblock API host. Defaults to `https://polymarket.com`.
     /// This is primarily useful for testing.
     #[builder(into)]
     geoblock_host: Option<String>,
+    /// Policy for retrying requests that fail with `429` or `5xx`. Requests are not retried
+    /// unless a policy is set.
+    retry_policy: Option<RetryPolicy>,
+    /// Client-side budgets per endpoint family. Requests exceeding a budget wait for their turn
+    /// instead of failing. Requests are not limited unless budgets are set.
+    rate_limits: Option<RateLimitConfig>,
+    /// Pre-trade limits checked before an order is signed. Orders are not checked unless limits
+    /// are set.
+    risk_limits: Option<RiskLimits>,
+    /// How long a cached tick size, fee rate or neg risk flag is used before it is fetched again.
+    /// Cached values are kept until invalidated unless a TTL is set.
+    cache_ttl: Option<Duration>,
 }
 
 /// The default geoblock API host (separate from CLOB host)
 const DEFAULT_GEOBLOCK_HOST: &str = "https://polymarket.com";
 
 #[derive(Debug)]
-struct ClientInner<S: State> {
+struct ClientInner<S: State, T: HttpTransport> {
     config: Config,
     /// The current [`State`] of this client
     state: S,
@@ -308,14 +334,16 @@ struct ClientInner<S: State> {
     host: Url,
     /// The [`Url`] for the geoblock API endpoint.
     geoblock_host: Url,
-    /// The inner [`ReqwestClient`] used to make requests to `host`.
+    /// The inner [`ReqwestClient`] used to build requests to `host`.
     client: ReqwestClient,
+    /// The [`HttpTransport`] used to send requests built by `client`.
+    transport: T,
     /// Local cache of [`TickSize`] per token ID
-    tick_sizes: DashMap<String, TickSize>,
+    tick_sizes: TokenCache<TickSize>,
     /// Local cache representing whether this token is part of a `neg_risk` market
-    neg_risk: DashMap<String, bool>,
+    neg_risk: TokenCache<bool>,
     /// Local cache representing the fee rate in basis points per token ID
-    fee_rate_bps: DashMap<String, u32>,
+    fee_rate_bps: TokenCache<u32>,
     /// The funder for this [`ClientInner`]. If funder is present, then `signature_type` cannot
     /// be [`SignatureType::Eoa`]. Conversely, if funder is absent, then `signature_type` cannot be
     /// [`SignatureType::Proxy`] or [`SignatureType::GnosisSafe`].
@@ -324,20 +352,145 @@ struct ClientInner<S: State> {
     signature_type: SignatureType,
     /// The salt/seed generator for use in creating [`SignableOrder`]s
     salt_generator: fn() -> u64,
+    /// Client-side rate limiter shared by all requests made through this client
+    rate_limiter: RateLimiter,
+    /// Pre-trade risk checks and kill switch for orders signed by this client
+    risk: RiskGuard,
+    /// Offset of the server's clock from the local one, when `clock_sync` is configured
+    clock: Option<Clock>,
 }
 
-impl<S: State> ClientInner<S> {
+impl<S: State, T: HttpTransport> ClientInner<S, T> {
+    /// Sends `request` once the rate limiter allows it, retrying according to the configured
+    /// [`RetryPolicy`], and deserializes the response. Every retry waits for the rate limiter
+    /// again and is signed again by `sign`. A stale clock offset is measured again while the
+    /// request is in flight.
+    async fn request<Response: DeserializeOwned>(
+        &self,
+        request: Request,
+        sign: &impl Sign,
+    ) -> Result<Response> {
+        let transport = self.throttled();
+        let request = crate::request(&transport, request, sign, self.config.retry_policy.as_ref());
+
+        future::join(request, self.refresh_clock()).await.0
+    }
+
+    /// Like [`Self::request`], for endpoints that only read data but are sent as `POST`, such as
+    /// `/books`. These are [`Idempotency::Idempotent`] and thus retried under any policy.
+    async fn read<Response: DeserializeOwned>(&self, request: Request) -> Result<Response> {
+        let transport = self.throttled();
+        let request = crate::request_with(
+            &transport,
+            request,
+            &Unsigned,
+            self.config.retry_policy.as_ref(),
+            Idempotency::Idempotent,
+        );
+
+        future::join(request, self.refresh_clock()).await.0
+    }
+
+    /// Like [`Self::request`], but returns the raw response for endpoints without a JSON body.
+    async fn execute(&self, request: Request, sign: &impl Sign) -> Result<reqwest::Response> {
+        let transport = self.throttled();
+        let request = crate::execute(&transport, request, sign, self.config.retry_policy.as_ref());
+
+        future::join(request, self.refresh_clock()).await.0
+    }
+
+    fn throttled(&self) -> Throttled<'_, T> {
+        Throttled::new(&self.transport, &self.rate_limiter, self.host.path())
+    }
+
     pub async fn server_time(&self) -> Result<Timestamp> {
         let request = self
             .client
             .request(Method::GET, format!("{}time", self.host))
             .build()?;
 
-        crate::request(&self.client, request, None).await
+        // Not `Self::request`, which may measure the clock with this very request
+        crate::request(
+            &self.throttled(),
+            request,
+            &Unsigned,
+            self.config.retry_policy.as_ref(),
+        )
+        .await
+    }
+
+    /// The current time on the server's clock in seconds, as used in auth headers.
+    async fn timestamp(&self) -> Result<Timestamp> {
+        if let Some(clock) = &self.clock {
+            let offset = self.clock_offset(clock).await?;
+            return Ok((Utc::now() + offset).timestamp());
+        }
+
+        if self.config.use_server_time {
+            return self.server_time().await;
+        }
+
+        Ok(Utc::now().timestamp())
+    }
+
+    /// Returns the measured clock offset, even if it is stale, measuring it first only if it has
+    /// never been measured.
+    async fn clock_offset(&self, clock: &Clock) -> Result<TimeDelta> {
+        if let Some(skew) = clock.skew() {
+            return Ok(skew.offset);
+        }
+
+        let measured = self.measure_clock(clock.samples()).await;
+        clock.record(measured.as_ref().ok().copied());
+        measured.map(|skew| skew.offset)
+    }
+
+    /// Measures the clock offset again if it is stale, unless another request already does. A
+    /// failed refresh keeps the stale offset.
+    async fn refresh_clock(&self) {
+        let Some(clock) = &self.clock else {
+            return;
+        };
+        if !clock.refresh_due() {
+            return;
+        }
+
+        let measured = self.measure_clock(clock.samples()).await;
+        if let Err(e) = &measured {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, "Failed to refresh clock offset, using the previous one");
+            #[cfg(not(feature = "tracing"))]
+            let _: &Error = e;
+        }
+        clock.record(measured.ok());
+    }
+
+    /// Takes `samples` readings of the server's clock and keeps the one with the shortest round
+    /// trip.
+    async fn measure_clock(&self, samples: u32) -> Result<ClockSkew> {
+        let mut best: Option<ClockSkew> = None;
+
+        for _ in 0..samples.max(1) {
+            let sent_at = Utc::now();
+            let started = Instant::now();
+            let server_time = self.server_time().await?;
+            let skew = ClockSkew::estimate(server_time, sent_at, started.elapsed());
+
+            if best.is_none_or(|best| skew.round_trip < best.round_trip) {
+                best = Some(skew);
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        if let Some(skew) = &best {
+            tracing::debug!(offset_ms = skew.offset.num_milliseconds(), round_trip = ?skew.round_trip, "measured clock offset");
+        }
+
+        best.ok_or_else(|| Error::validation("No clock samples were taken"))
     }
 }
 
-impl ClientInner<Unauthenticated> {
+impl<T: HttpTransport> ClientInner<Unauthenticated, T> {
     pub async fn create_api_key<S: Signer>(
         &self,
         signer: &S,
@@ -347,9 +500,8 @@ impl ClientInner<Unauthenticated> {
             .client
             .request(Method::POST, format!("{}auth/api-key", self.host))
             .build()?;
-        let headers = self.create_headers(signer, nonce).await?;
-
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, &L1Headers::new(self, signer, nonce))
+            .await
     }
 
     pub async fn derive_api_key<S: Signer>(
@@ -361,9 +513,8 @@ impl ClientInner<Unauthenticated> {
             .client
             .request(Method::GET, format!("{}auth/derive-api-key", self.host))
             .build()?;
-        let headers = self.create_headers(signer, nonce).await?;
-
-        crate::request(&self.client, request, Some(headers)).await
+        self.request(request, &L1Headers::new(self, signer, nonce))
+            .await
     }
 
     async fn create_or_derive_api_key<S: Signer>(
@@ -376,41 +527,170 @@ impl ClientInner<Unauthenticated> {
             Err(_) => self.derive_api_key(signer, nonce).await,
         }
     }
+}
+
+/// Signs requests with the L1 headers of `signer`, which prove ownership of its address.
+struct L1Headers<'client, St: State, T: HttpTransport, S: Signer> {
+    inner: &'client ClientInner<St, T>,
+    signer: &'client S,
+    nonce: Option<u32>,
+}
+
+impl<'client, St: State, T: HttpTransport, S: Signer> L1Headers<'client, St, T, S> {
+    const fn new(
+        inner: &'client ClientInner<St, T>,
+        signer: &'client S,
+        nonce: Option<u32>,
+    ) -> Self {
+        Self {
+            inner,
+            signer,
+            nonce,
+        }
+    }
+}
 
-    async fn create_headers<S: Signer>(&self, signer: &S, nonce: Option<u32>) -> Result<HeaderMap> {
-        let chain_id = signer.chain_id().ok_or(Error::validation(
+impl<St: State, T: HttpTransport, S: Signer> Sign for L1Headers<'_, St, T, S> {
+    async fn headers(&self, _request: &Request) -> Result<HeaderMap> {
+        let chain_id = self.signer.chain_id().ok_or(Error::validation(
             "Chain id not set, be sure to provide one on the signer",
         ))?;
 
-        let timestamp = if self.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+        let timestamp = self.inner.timestamp().await?;
+
+        auth::l1::create_headers(self.signer, chain_id, timestamp, self.nonce).await
+    }
+}
+
+/// Signs requests with the L2 headers of `credentials`, or of the ones in use when `None`.
+struct L2Headers<'client, K: Kind, T: HttpTransport> {
+    client: &'client Client<Authenticated<K>, T>,
+    credentials: Option<&'client Credentials>,
+}
+
+impl<K: Kind, T: HttpTransport> Sign for L2Headers<'_, K, T> {
+    async fn headers(&self, request: &Request) -> Result<HeaderMap> {
+        let timestamp = self.client.inner.timestamp().await?;
+        let state = self.client.state();
 
-        auth::l1::create_headers(signer, chain_id, timestamp, nonce).await
+        match self.credentials {
+            Some(credentials) => {
+                auth::l2::create_headers_with(state, credentials, request, timestamp).await
+            }
+            None => auth::l2::create_headers(state, request, timestamp).await,
+        }
     }
 }
 
-impl<S: State> Client<S> {
+impl<S: State, T: HttpTransport> Client<S, T> {
     #[must_use]
     pub fn host(&self) -> &Url {
         &self.inner.host
     }
 
+    /// Returns how many requests of `family` went through the client-side rate limiter and how
+    /// long they had to wait for their turn.
+    #[must_use]
+    pub fn rate_limit_stats(&self, family: EndpointFamily) -> RateLimitStats {
+        self.inner.rate_limiter.stats(family)
+    }
+
+    /// Returns the last measured offset of the server's clock from the local one, if the client
+    /// is configured with [`ClockSync`] and has measured it.
+    #[must_use]
+    pub fn clock_skew(&self) -> Option<ClockSkew> {
+        self.inner.clock.as_ref().and_then(Clock::skew)
+    }
+
+    /// Measures the offset of the server's clock from the local one now, instead of waiting for
+    /// the configured refresh interval to pass.
+    pub async fn calibrate_clock(&self) -> Result<ClockSkew> {
+        let clock = self.inner.clock.as_ref().ok_or_else(|| {
+            Error::validation("Clock calibration requires `clock_sync` to be set on the Config")
+        })?;
+
+        let measured = self.inner.measure_clock(clock.samples()).await;
+        clock.record(measured.as_ref().ok().copied());
+        measured
+    }
+
+    /// Converts a local time to the server's clock, using the last measured clock offset when
+    /// the client is configured with [`ClockSync`]. Never measures the offset, so that orders can
+    /// be built without requests.
+    pub(crate) fn to_server_time(&self, local: DateTime<Utc>) -> DateTime<Utc> {
+        self.clock_skew().map_or(local, |skew| local + skew.offset)
+    }
+
     pub fn invalidate_internal_caches(&self) {
         self.inner.tick_sizes.clear();
         self.inner.fee_rate_bps.clear();
         self.inner.neg_risk.clear();
     }
 
+    /// Drops the cached tick size, fee rate and neg risk flag of `token_id`, so that they are
+    /// fetched again the next time they are needed.
+    pub fn invalidate_market_params(&self, token_id: TokenId) {
+        self.inner.tick_sizes.remove(token_id);
+        self.inner.fee_rate_bps.remove(token_id);
+        self.inner.neg_risk.remove(token_id);
+    }
+
+    /// Replaces the cached tick size of `token_id`, e.g. after the market channel announced a
+    /// change.
+    #[cfg(feature = "ws")]
+    pub(crate) fn cache_tick_size(&self, token_id: TokenId, tick_size: TickSize) {
+        self.inner.tick_sizes.insert(token_id, tick_size);
+    }
+
+    /// Subscribe to `asset_ids` on the market channel of `ws` and keep their cached market
+    /// parameters up to date.
+    ///
+    /// A `tick_size_change` replaces the cached tick size of its asset, and a `market_resolved`
+    /// drops the cached parameters of all the market's assets. If the subscription lags behind
+    /// the channel, the cached parameters of all `asset_ids` are dropped, since changes may have
+    /// been missed.
+    ///
+    /// The returned stream must be polled for the caches to be updated. It yields every message
+    /// that changed them, and unsubscribes from `asset_ids` once dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use futures::StreamExt as _;
+    /// use polymarket_client_sdk::clob::Client;
+    /// use polymarket_client_sdk::clob::ws::Client as WsClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::default();
+    ///     let ws = WsClient::default();
+    ///
+    ///     let stream = client.sync_caches(&ws, vec!["asset_id".parse()?])?;
+    ///     let mut stream = Box::pin(stream);
+    ///
+    ///     while let Some(message) = stream.next().await {
+    ///         println!("Caches updated by {:?}", message?);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "ws")]
+    pub fn sync_caches<'client, W: State>(
+        &'client self,
+        ws: &'client crate::clob::ws::Client<W>,
+        asset_ids: Vec<TokenId>,
+    ) -> Result<impl Stream<Item = Result<crate::clob::ws::WsMessage>> + 'client> {
+        crate::clob::ws::cache::sync_caches(self, ws, asset_ids)
+    }
+
     pub async fn ok(&self) -> Result<String> {
         let request = self
             .client()
             .request(Method::GET, self.host().to_owned())
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn server_time(&self) -> Result<Timestamp> {
@@ -421,10 +701,10 @@ impl<S: State> Client<S> {
         let request = self
             .client()
             .request(Method::GET, format!("{}midpoint", self.host()))
-            .query(&[("token_id", request.token_id.as_str())])
+            .query(&[("token_id", request.token_id)])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn midpoints(&self, requests: &[MidpointRequest]) -> Result<MidpointsResponse> {
@@ -434,20 +714,17 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.read(request).await
     }
 
     pub async fn price(&self, request: &PriceRequest) -> Result<PriceResponse> {
         let request = self
             .client()
             .request(Method::GET, format!("{}price", self.host()))
-            .query(&[
-                ("token_id", request.token_id.as_str()),
-                ("side", &request.side.to_string()),
-            ])
+            .query(request)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn prices(&self, requests: &[PriceRequest]) -> Result<PricesResponse> {
@@ -457,7 +734,7 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.read(request).await
     }
 
     pub async fn all_prices(&self) -> Result<PricesResponse> {
@@ -466,7 +743,7 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}prices", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn price_history(
@@ -478,7 +755,7 @@ impl<S: State> Client<S> {
         let mut req = self
             .client()
             .request(Method::GET, format!("{}prices-history", self.host()))
-            .query(&[("market", request.market.as_str())]);
+            .query(&[("market", request.market)]);
 
         match request.time_range {
             TimeRange::Interval { interval } => {
@@ -493,17 +770,17 @@ impl<S: State> Client<S> {
             req = req.query(&[("fidelity", fidelity)]);
         }
 
-        crate::request(&self.inner.client, req.build()?, None).await
+        self.inner.request(req.build()?, &Unsigned).await
     }
 
     pub async fn spread(&self, request: &SpreadRequest) -> Result<SpreadResponse> {
         let request = self
             .client()
             .request(Method::GET, format!("{}spread", self.host()))
-            .query(&[("token_id", request.token_id.as_str())])
+            .query(&[("token_id", request.token_id)])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn spreads(&self, requests: &[SpreadRequest]) -> Result<SpreadsResponse> {
@@ -513,15 +790,15 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.read(request).await
     }
 
-    pub async fn tick_size(&self, token_id: &str) -> Result<TickSizeResponse> {
+    pub async fn tick_size(&self, token_id: TokenId) -> Result<TickSizeResponse> {
         if let Some(tick_size) = self.inner.tick_sizes.get(token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, tick_size = ?tick_size.value(), "cache hit: tick_size");
+            tracing::trace!(token_id = %token_id, tick_size = ?tick_size, "cache hit: tick_size");
             return Ok(TickSizeResponse {
-                minimum_tick_size: *tick_size,
+                minimum_tick_size: tick_size,
             });
         }
 
@@ -534,12 +811,14 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response =
-            crate::request::<TickSizeResponse>(&self.inner.client, request, None).await?;
+        let response = self
+            .inner
+            .request::<TickSizeResponse>(request, &Unsigned)
+            .await?;
 
         self.inner
             .tick_sizes
-            .insert(token_id.to_owned(), response.minimum_tick_size);
+            .insert(token_id, response.minimum_tick_size);
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached tick_size");
@@ -547,13 +826,11 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
-    pub async fn neg_risk(&self, token_id: &str) -> Result<NegRiskResponse> {
+    pub async fn neg_risk(&self, token_id: TokenId) -> Result<NegRiskResponse> {
         if let Some(neg_risk) = self.inner.neg_risk.get(token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, neg_risk = *neg_risk, "cache hit: neg_risk");
-            return Ok(NegRiskResponse {
-                neg_risk: *neg_risk,
-            });
+            tracing::trace!(token_id = %token_id, neg_risk, "cache hit: neg_risk");
+            return Ok(NegRiskResponse { neg_risk });
         }
 
         #[cfg(feature = "tracing")]
@@ -565,11 +842,12 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<NegRiskResponse>(&self.inner.client, request, None).await?;
+        let response = self
+            .inner
+            .request::<NegRiskResponse>(request, &Unsigned)
+            .await?;
 
-        self.inner
-            .neg_risk
-            .insert(token_id.to_owned(), response.neg_risk);
+        self.inner.neg_risk.insert(token_id, response.neg_risk);
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached neg_risk");
@@ -577,13 +855,25 @@ impl<S: State> Client<S> {
         Ok(response)
     }
 
-    pub async fn fee_rate_bps(&self, token_id: &str) -> Result<FeeRateResponse> {
+    /// Fetches the tick size, fee rate and neg risk flag of `token_id` so that orders for it can
+    /// later be built and signed without further requests. The order book is not included.
+    pub async fn market_params(&self, token_id: TokenId) -> Result<MarketParams> {
+        let tick_size = self.tick_size(token_id).await?.minimum_tick_size;
+        let fee_rate_bps = self.fee_rate_bps(token_id).await?.base_fee;
+        let neg_risk = self.neg_risk(token_id).await?.neg_risk;
+
+        Ok(MarketParams::builder()
+            .tick_size(tick_size)
+            .fee_rate_bps(fee_rate_bps)
+            .neg_risk(neg_risk)
+            .build())
+    }
+
+    pub async fn fee_rate_bps(&self, token_id: TokenId) -> Result<FeeRateResponse> {
         if let Some(base_fee) = self.inner.fee_rate_bps.get(token_id) {
             #[cfg(feature = "tracing")]
-            tracing::trace!(token_id = %token_id, base_fee = *base_fee, "cache hit: fee_rate_bps");
-            return Ok(FeeRateResponse {
-                base_fee: *base_fee,
-            });
+            tracing::trace!(token_id = %token_id, base_fee, "cache hit: fee_rate_bps");
+            return Ok(FeeRateResponse { base_fee });
         }
 
         #[cfg(feature = "tracing")]
@@ -595,11 +885,12 @@ impl<S: State> Client<S> {
             .query(&[("token_id", token_id)])
             .build()?;
 
-        let response = crate::request::<FeeRateResponse>(&self.inner.client, request, None).await?;
+        let response = self
+            .inner
+            .request::<FeeRateResponse>(request, &Unsigned)
+            .await?;
 
-        self.inner
-            .fee_rate_bps
-            .insert(token_id.to_owned(), response.base_fee);
+        self.inner.fee_rate_bps.insert(token_id, response.base_fee);
 
         #[cfg(feature = "tracing")]
         tracing::trace!(token_id = %token_id, "cached fee_rate_bps");
@@ -660,7 +951,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn order_book(
@@ -670,10 +961,10 @@ impl<S: State> Client<S> {
         let request = self
             .client()
             .request(Method::GET, format!("{}book", self.host()))
-            .query(&[("token_id", request.token_id.as_str())])
+            .query(&[("token_id", request.token_id)])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn order_books(
@@ -686,7 +977,25 @@ impl<S: State> Client<S> {
             .json(requests)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.read(request).await
+    }
+
+    /// Previews how a market order for `amount` of `token_id` would fill against the current
+    /// order book, including its VWAP, slippage versus the midpoint and taker fee, without
+    /// placing it.
+    pub async fn preview_market_order(
+        &self,
+        token_id: TokenId,
+        side: Side,
+        amount: Amount,
+    ) -> Result<FillPreview> {
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(token_id)
+            .build();
+        let book = self.order_book(&request).await?;
+        let fee_rate_bps = self.fee_rate_bps(token_id).await?.base_fee;
+
+        FillPreview::from_book(&book, side, amount, fee_rate_bps)
     }
 
     pub async fn last_trade_price(
@@ -696,10 +1005,10 @@ impl<S: State> Client<S> {
         let request = self
             .client()
             .request(Method::GET, format!("{}last-trade-price", self.host()))
-            .query(&[("token_id", request.token_id.as_str())])
+            .query(&[("token_id", request.token_id)])
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn last_trades_prices(
@@ -712,10 +1021,10 @@ impl<S: State> Client<S> {
             .json(token_ids)
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.read(request).await
     }
 
-    pub async fn market(&self, condition_id: &str) -> Result<MarketResponse> {
+    pub async fn market(&self, condition_id: ConditionId) -> Result<MarketResponse> {
         let request = self
             .client()
             .request(
@@ -724,7 +1033,7 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
     }
 
     pub async fn markets(&self, next_cursor: Option<String>) -> Result<Page<MarketResponse>> {
@@ -734,7 +1043,12 @@ impl<S: State> Client<S> {
             .request(Method::GET, format!("{}markets{cursor}", self.host()))
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
+    }
+
+    /// Streams every market, fetching a page at a time.
+    pub fn markets_stream(&self) -> Paginated<impl Stream<Item = Result<MarketResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.markets(cursor))
     }
 
     pub async fn sampling_markets(
@@ -750,7 +1064,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
+    }
+
+    /// Streams every sampling market, fetching a page at a time.
+    pub fn sampling_markets_stream(
+        &self,
+    ) -> Paginated<impl Stream<Item = Result<MarketResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.sampling_markets(cursor))
     }
 
     pub async fn simplified_markets(
@@ -766,7 +1087,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
+    }
+
+    /// Streams every simplified market, fetching a page at a time.
+    pub fn simplified_markets_stream(
+        &self,
+    ) -> Paginated<impl Stream<Item = Result<SimplifiedMarketResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.simplified_markets(cursor))
     }
 
     pub async fn sampling_simplified_markets(
@@ -782,7 +1110,14 @@ impl<S: State> Client<S> {
             )
             .build()?;
 
-        crate::request(&self.inner.client, request, None).await
+        self.inner.request(request, &Unsigned).await
+    }
+
+    /// Streams every simplified sampling market, fetching a page at a time.
+    pub fn sampling_simplified_markets_stream(
+        &self,
+    ) -> Paginated<impl Stream<Item = Result<SimplifiedMarketResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.sampling_simplified_markets(cursor))
     }
 
     /// Returns a stream of results, using `self` to repeatedly invoke the provided closure,
@@ -791,29 +1126,13 @@ impl<S: State> Client<S> {
     pub fn stream_data<'client, Call, Fut, Data>(
         &'client self,
         call: Call,
-    ) -> impl Stream<Item = Result<Data>> + 'client
+    ) -> Paginated<impl Stream<Item = Result<Data>> + 'client>
     where
-        Call: Fn(&'client Client<S>, Option<String>) -> Fut + 'client,
+        Call: Fn(&'client Client<S, T>, Option<String>) -> Fut + 'client,
         Fut: Future<Output = Result<Page<Data>>> + 'client,
         Data: 'client,
     {
-        try_stream! {
-            let mut cursor: Option<String> = None;
-
-            loop {
-                let page = call(self, mem::take(&mut cursor)).await?;
-
-                for item in page.data {
-                    yield item
-                }
-
-                if page.next_cursor == TERMINAL_CURSOR {
-                    break;
-                }
-
-                cursor = Some(page.next_cursor);
-            }
-        }
+        pagination::by_cursor(move |cursor| call(self, cursor))
     }
 
     fn client(&self) -> &ReqwestClient {
@@ -832,26 +1151,74 @@ impl Client<Unauthenticated> {
 
         let client = ReqwestClient::builder().default_headers(headers).build()?;
 
+        let rate_limiter = config
+            .rate_limits
+            .as_ref()
+            .map(RateLimiter::new)
+            .unwrap_or_default();
+        let risk = config
+            .risk_limits
+            .as_ref()
+            .map(RiskGuard::new)
+            .unwrap_or_default();
+
         let geoblock_host = Url::parse(
             config
                 .geoblock_host
                 .as_deref()
                 .unwrap_or(DEFAULT_GEOBLOCK_HOST),
         )?;
+        let cache_ttl = config.cache_ttl;
+        let clock = config.clock_sync.map(Clock::new);
 
         Ok(Self {
             inner: Arc::new(ClientInner {
                 config,
                 host: Url::parse(host)?,
                 geoblock_host,
+                transport: client.clone(),
                 client,
-                tick_sizes: DashMap::new(),
-                neg_risk: DashMap::new(),
-                fee_rate_bps: DashMap::new(),
+                tick_sizes: TokenCache::new(cache_ttl),
+                neg_risk: TokenCache::new(cache_ttl),
+                fee_rate_bps: TokenCache::new(cache_ttl),
                 state: Unauthenticated,
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                rate_limiter,
+                risk,
+                clock,
+            }),
+        })
+    }
+}
+
+impl<T: HttpTransport> Client<Unauthenticated, T> {
+    /// Replaces the [`HttpTransport`] used to send requests, e.g. with an in-memory fake for
+    /// testing. Returns an error if this client has been cloned.
+    pub fn with_transport<U: HttpTransport>(
+        self,
+        transport: U,
+    ) -> Result<Client<Unauthenticated, U>> {
+        let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
+
+        Ok(Client {
+            inner: Arc::new(ClientInner {
+                config: inner.config,
+                state: inner.state,
+                host: inner.host,
+                geoblock_host: inner.geoblock_host,
+                client: inner.client,
+                transport,
+                tick_sizes: inner.tick_sizes,
+                neg_risk: inner.neg_risk,
+                fee_rate_bps: inner.fee_rate_bps,
+                funder: inner.funder,
+                signature_type: inner.signature_type,
+                salt_generator: inner.salt_generator,
+                rate_limiter: inner.rate_limiter,
+                risk: inner.risk,
+                clock: inner.clock,
             }),
         })
     }
@@ -859,7 +1226,7 @@ impl Client<Unauthenticated> {
     pub fn authentication_builder<S: Signer>(
         self,
         signer: &S,
-    ) -> AuthenticationBuilder<'_, S, Normal> {
+    ) -> AuthenticationBuilder<'_, S, Normal, T> {
         AuthenticationBuilder {
             signer,
             credentials: None,
@@ -872,6 +1239,18 @@ impl Client<Unauthenticated> {
         }
     }
 
+    /// Starts authenticating with the signer and [`Credentials`] of a decrypted
+    /// [`Keystore`](crate::keystore::Keystore), instead of creating or deriving credentials.
+    #[cfg(feature = "keystore")]
+    #[must_use]
+    pub fn authentication_builder_from_keystore(
+        self,
+        keystore: &crate::keystore::Keystore,
+    ) -> AuthenticationBuilder<'_, alloy::signers::local::PrivateKeySigner, Normal, T> {
+        self.authentication_builder(keystore.signer())
+            .credentials(keystore.credentials().clone())
+    }
+
     /// Attempts to create a new set of [`Credentials`] and returns an error if there already is one
     /// for the particular L2 header's (signer) `address` and `nonce`.
     pub async fn create_api_key<S: Signer>(
@@ -904,17 +1283,18 @@ impl Client<Unauthenticated> {
     }
 }
 
-impl<K: Kind> Client<Authenticated<K>> {
+impl<K: Kind, T: HttpTransport> Client<Authenticated<K>, T> {
     /// Demotes this authenticated [`Client<Authenticated<K>>`] to an unauthenticated one
-    pub fn deauthenticate(self) -> Result<Client<Unauthenticated>> {
+    pub fn deauthenticate(self) -> Result<Client<Unauthenticated, T>> {
         let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
-        Ok(Client::<Unauthenticated> {
+        Ok(Client::<Unauthenticated, T> {
             inner: Arc::new(ClientInner {
                 state: Unauthenticated,
                 host: inner.host,
                 geoblock_host: inner.geoblock_host,
                 config: inner.config,
                 client: inner.client,
+                transport: inner.transport,
                 tick_sizes: inner.tick_sizes,
                 neg_risk: inner.neg_risk,
                 fee_rate_bps: inner.fee_rate_bps,
@@ -922,6 +1302,9 @@ impl<K: Kind> Client<Authenticated<K>> {
                 funder: None,
                 signature_type: SignatureType::Eoa,
                 salt_generator: generate_seed,
+                rate_limiter: inner.rate_limiter,
+                risk: inner.risk,
+                clock: inner.clock,
             }),
         })
     }
@@ -939,23 +1322,64 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// Return all API keys associated with the address corresponding to the inner signer in
     /// [`Authenticated<K>`].
     pub async fn api_keys(&self) -> Result<ApiKeysResponse> {
+        self.api_keys_with(&self.state().credentials()).await
+    }
+
+    pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
+        self.delete_api_key_with(&self.state().credentials()).await
+    }
+
+    /// Starts rotating the API key of this client, which must have been authenticated by
+    /// `signer`. See [`ApiKeyRotation`] for the steps involved.
+    pub fn rotate_api_key<'client, S: Signer>(
+        &'client self,
+        signer: &'client S,
+    ) -> ApiKeyRotation<'client, S, K, T>
+    where
+        K: Send + Sync,
+    {
+        ApiKeyRotation::new(self, signer)
+    }
+
+    /// Creates a new API key for `signer` and `nonce`, without replacing the credentials in use.
+    pub(crate) async fn create_api_key_with<S: Signer>(
+        &self,
+        signer: &S,
+        nonce: u32,
+    ) -> Result<Credentials> {
         let request = self
             .client()
-            .request(Method::GET, format!("{}auth/api-keys", self.host()))
+            .request(Method::POST, format!("{}auth/api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner
+            .request(request, &L1Headers::new(&self.inner, signer, Some(nonce)))
+            .await
     }
 
-    pub async fn delete_api_key(&self) -> Result<serde_json::Value> {
+    /// Like [`Self::api_keys`], but authenticated with `credentials` instead of the ones in use.
+    pub(crate) async fn api_keys_with(&self, credentials: &Credentials) -> Result<ApiKeysResponse> {
         let request = self
             .client()
-            .request(Method::DELETE, format!("{}auth/api-key", self.host()))
+            .request(Method::GET, format!("{}auth/api-keys", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner
+            .request(request, &self.l2_headers_with(credentials))
+            .await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Deletes the API key of `credentials`, which need not be the one in use.
+    pub(crate) async fn delete_api_key_with(
+        &self,
+        credentials: &Credentials,
+    ) -> Result<serde_json::Value> {
+        let request = self
+            .client()
+            .request(Method::DELETE, format!("{}auth/api-key", self.host()))
+            .build()?;
+        self.inner
+            .request(request, &self.l2_headers_with(credentials))
+            .await
     }
 
     pub async fn closed_only_mode(&self) -> Result<BanStatusResponse> {
@@ -966,20 +1390,18 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}auth/ban-status/closed-only", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     /// Creates an [`OrderBuilder<Limit, K>`] used to construct a limit order.
     #[must_use]
-    pub fn limit_order(&self) -> OrderBuilder<Limit, K> {
+    pub fn limit_order(&self) -> OrderBuilder<Limit, K, T> {
         self.order_builder()
     }
 
     /// Creates an [`OrderBuilder<Market, K>`] used to construct a market order.
     #[must_use]
-    pub fn market_order(&self) -> OrderBuilder<Market, K> {
+    pub fn market_order(&self) -> OrderBuilder<Market, K, T> {
         self.order_builder()
     }
 
@@ -992,26 +1414,23 @@ impl<K: Kind> Client<Authenticated<K>> {
     pub async fn sign<S: Signer>(
         &self,
         signer: &S,
-        SignableOrder { order, order_type }: SignableOrder,
+        SignableOrder {
+            order,
+            order_type,
+            neg_risk,
+        }: SignableOrder,
     ) -> Result<SignedOrder> {
-        let token_id = order.tokenId.to_string();
-        let neg_risk = self.neg_risk(&token_id).await?.neg_risk;
+        self.check_risk(&order).await?;
+
+        let neg_risk = match neg_risk {
+            Some(neg_risk) => neg_risk,
+            None => self.neg_risk(order.tokenId.into()).await?.neg_risk,
+        };
         let chain_id = signer
             .chain_id()
             .expect("Validated not none in `authenticate`");
 
-        let exchange_contract = contract_config(chain_id, neg_risk)
-            .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
-            .exchange;
-
-        let domain = Eip712Domain {
-            name: ORDER_NAME,
-            version: VERSION,
-            chain_id: Some(U256::from(chain_id)),
-            verifying_contract: Some(exchange_contract),
-            ..Eip712Domain::default()
-        };
-
+        let domain = order_domain(chain_id, neg_risk)?;
         let signature = signer
             .sign_hash(&order.eip712_signing_hash(&domain))
             .await?;
@@ -1020,30 +1439,97 @@ impl<K: Kind> Client<Authenticated<K>> {
             order,
             signature,
             order_type,
-            owner: self.state().credentials.key,
+            owner: self.state().credentials().key,
         })
     }
 
     pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        self.inner.risk.check_halted()?;
+
         let request = self
             .client()
             .request(Method::POST, format!("{}order", self.host()))
             .json(&order)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        self.inner.risk.check_halted()?;
+
         let request = self
             .client()
             .request(Method::POST, format!("{}orders", self.host()))
             .json(&orders)
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
+
+    /// Engages the kill switch: every order is rejected by [`Self::sign`], [`Self::post_order`] and
+    /// [`Self::post_orders`] until [`Self::resume_trading`] is called.
+    pub fn halt_trading(&self) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!("Kill switch engaged, rejecting all orders");
+
+        self.inner.risk.halt();
+    }
+
+    /// Disengages the kill switch set by [`Self::halt_trading`] or [`RiskLimits::halted`].
+    pub fn resume_trading(&self) {
+        self.inner.risk.resume();
+    }
+
+    #[must_use]
+    pub fn is_trading_halted(&self) -> bool {
+        self.inner.risk.is_halted()
+    }
+
+    /// Checks `order` against the configured [`RiskLimits`], only fetching the midpoint and open
+    /// orders for the limits that need them.
+    async fn check_risk(&self, order: &Order) -> Result<()> {
+        let guard = &self.inner.risk;
+        let limits = guard.limits();
+        let risk = OrderRisk::new(order)?;
+        guard.check_order(risk)?;
+
+        let token_id = TokenId::from(order.tokenId);
+        if limits.price_band.is_some() {
+            let request = MidpointRequest::builder().token_id(token_id).build();
+            let midpoint = self.midpoint(&request).await?.mid;
+            guard.check_price(risk, midpoint)?;
+        }
+
+        if limits.max_token_notional.is_some() || limits.max_market_notional.is_some() {
+            // Open orders across the whole market also cover the token's own
+            let request = if limits.max_market_notional.is_some() {
+                let request = OrderBookSummaryRequest::builder()
+                    .token_id(token_id)
+                    .build();
+                let market = self.order_book(&request).await?.market;
+                OrdersRequest::builder().market(market).build()
+            } else {
+                OrdersRequest::builder().asset_id(token_id).build()
+            };
+
+            let open: Vec<OpenOrderResponse> = self
+                .stream_data(|client, cursor| client.orders(&request, cursor))
+                .try_collect()
+                .await?;
+
+            let token_notional = open
+                .iter()
+                .filter(|open| open.asset_id == token_id)
+                .map(open_notional)
+                .sum();
+            let market_notional = open.iter().map(open_notional).sum();
+
+            guard.check_open_notional(RiskCheck::TokenNotional, token_notional, risk)?;
+            guard.check_open_notional(RiskCheck::MarketNotional, market_notional, risk)?;
+        }
+
+        guard.record(Instant::now())?;
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+        Ok(())
     }
 
     /// Attempts to return the corresponding order at the provided `order_id`
@@ -1052,9 +1538,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}data/order/{order_id}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn orders(
@@ -1067,9 +1551,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}data/orders{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams every open order matching `request`, fetching a page at a time.
+    pub fn orders_stream<'client>(
+        &'client self,
+        request: &'client OrdersRequest,
+    ) -> Paginated<impl Stream<Item = Result<OpenOrderResponse>> + 'client> {
+        pagination::by_cursor(move |cursor| self.orders(request, cursor))
     }
 
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
@@ -1078,9 +1568,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}order", self.host()))
             .json(&json!({ "orderId": order_id }))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn cancel_orders(&self, order_ids: &[&str]) -> Result<CancelOrdersResponse> {
@@ -1089,9 +1577,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}orders", self.host()))
             .json(&json!(order_ids))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn cancel_all_orders(&self) -> Result<CancelOrdersResponse> {
@@ -1099,9 +1585,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::DELETE, format!("{}cancel-all", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     /// Attempts to cancel all open orders for a particular [`CancelMarketOrderRequest::market`]
@@ -1118,9 +1602,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             )
             .json(&request)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn trades(
@@ -1133,9 +1615,15 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}data/trades{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams every trade matching `request`, fetching a page at a time.
+    pub fn trades_stream<'client>(
+        &'client self,
+        request: &'client TradesRequest,
+    ) -> Paginated<impl Stream<Item = Result<TradeResponse>> + 'client> {
+        pagination::by_cursor(move |cursor| self.trades(request, cursor))
     }
 
     pub async fn notifications(&self) -> Result<Vec<NotificationResponse>> {
@@ -1144,14 +1632,12 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::GET, format!("{}notifications", self.host()))
             .query(&[("signature_type", self.inner.signature_type as u8)])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn delete_notifications(&self, request: &DeleteNotificationsRequest) -> Result<()> {
         let params = request.query_params(None);
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::DELETE,
@@ -1159,12 +1645,10 @@ impl<K: Kind> Client<Authenticated<K>> {
             )
             .json(&request)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-        *request.headers_mut() = headers;
 
         // We have to send the request separately from `self.request` because this endpoint does
         // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner.execute(request, &self.l2_headers()).await?;
 
         Ok(())
     }
@@ -1185,9 +1669,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}balance-allowance{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn update_balance_allowance(
@@ -1199,20 +1681,17 @@ impl<K: Kind> Client<Authenticated<K>> {
         }
 
         let params = request.query_params(None);
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::GET,
                 format!("{}balance-allowance/update{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        *request.headers_mut() = headers;
 
         // We have to send the request separately from `self.request` because this endpoint does
         // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner.execute(request, &self.l2_headers()).await?;
 
         Ok(())
     }
@@ -1223,9 +1702,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::GET, format!("{}order-scoring", self.host()))
             .query(&[("order_id", order_id)])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn are_orders_scoring(&self, order_ids: &[&str]) -> Result<OrdersScoringResponse> {
@@ -1234,9 +1711,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}orders-scoring", self.host()))
             .json(&order_ids)
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn earnings_for_user_for_day(
@@ -1256,9 +1731,15 @@ impl<K: Kind> Client<Authenticated<K>> {
                 ),
             ])
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams the user's earnings on `date`, fetching a page at a time.
+    pub fn earnings_for_user_for_day_stream(
+        &self,
+        date: NaiveDate,
+    ) -> Paginated<impl Stream<Item = Result<UserEarningResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.earnings_for_user_for_day(date, cursor))
     }
 
     pub async fn total_earnings_for_user_for_day(
@@ -1276,9 +1757,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 ),
             ])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn user_earnings_and_markets_config(
@@ -1298,9 +1777,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 (self.inner.signature_type as u8).to_string(),
             )])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn reward_percentages(&self) -> Result<RewardsPercentagesResponse> {
@@ -1315,9 +1792,7 @@ impl<K: Kind> Client<Authenticated<K>> {
                 (self.inner.signature_type as u8).to_string(),
             )])
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn current_rewards(
@@ -1332,14 +1807,19 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rewards/markets/current{cursor}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams every market's current rewards, fetching a page at a time.
+    pub fn current_rewards_stream(
+        &self,
+    ) -> Paginated<impl Stream<Item = Result<CurrentRewardResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.current_rewards(cursor))
     }
 
     pub async fn raw_rewards_for_market(
         &self,
-        condition_id: &str,
+        condition_id: ConditionId,
         next_cursor: Option<String>,
     ) -> Result<Page<MarketRewardResponse>> {
         let cursor = next_cursor.map_or(String::new(), |c| format!("?next_cursor={c}"));
@@ -1350,9 +1830,15 @@ impl<K: Kind> Client<Authenticated<K>> {
                 format!("{}rewards/markets/{condition_id}{cursor}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams the rewards of the market `condition_id`, fetching a page at a time.
+    pub fn raw_rewards_for_market_stream(
+        &self,
+        condition_id: ConditionId,
+    ) -> Paginated<impl Stream<Item = Result<MarketRewardResponse>> + '_> {
+        pagination::by_cursor(move |cursor| self.raw_rewards_for_market(condition_id, cursor))
     }
 
     pub async fn create_builder_api_key(&self) -> Result<Credentials> {
@@ -1360,22 +1846,29 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::POST, format!("{}auth/builder-api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
-    async fn create_headers(&self, request: &Request) -> Result<HeaderMap> {
-        let timestamp = if self.inner.config.use_server_time {
-            self.server_time().await?
-        } else {
-            Utc::now().timestamp()
-        };
+    /// Signs requests with the credentials in use.
+    const fn l2_headers(&self) -> L2Headers<'_, K, T> {
+        L2Headers {
+            client: self,
+            credentials: None,
+        }
+    }
 
-        auth::l2::create_headers(self.state(), request, timestamp).await
+    /// Signs requests with `credentials` instead of the ones in use.
+    const fn l2_headers_with<'client>(
+        &'client self,
+        credentials: &'client Credentials,
+    ) -> L2Headers<'client, K, T> {
+        L2Headers {
+            client: self,
+            credentials: Some(credentials),
+        }
     }
 
-    fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K> {
+    fn order_builder<OrderKind>(&self) -> OrderBuilder<OrderKind, K, T> {
         OrderBuilder {
             signer: self.address(),
             signature_type: self.inner.signature_type,
@@ -1390,6 +1883,8 @@ impl<K: Kind> Client<Authenticated<K>> {
             expiration: None,
             taker: None,
             order_type: None,
+            market_params: None,
+            max_slippage_bps: None,
             client: Client {
                 inner: Arc::clone(&self.inner),
             },
@@ -1398,11 +1893,18 @@ impl<K: Kind> Client<Authenticated<K>> {
     }
 }
 
-impl Client<Authenticated<Normal>> {
+impl<K: Kind + Send + Sync, T: HttpTransport> SwapCredentials for Client<Authenticated<K>, T> {
+    fn swap_credentials(&self, credentials: &Credentials) -> Result<()> {
+        self.state().replace_credentials(credentials.clone());
+        Ok(())
+    }
+}
+
+impl<T: HttpTransport> Client<Authenticated<Normal>, T> {
     pub fn promote_to_builder(
         self,
         config: BuilderConfig,
-    ) -> Result<Client<Authenticated<Builder>>> {
+    ) -> Result<Client<Authenticated<Builder>, T>> {
         let inner = Arc::into_inner(self.inner).ok_or(Synchronization)?;
 
         let state = Authenticated {
@@ -1420,12 +1922,16 @@ impl Client<Authenticated<Normal>> {
             host: inner.host,
             geoblock_host: inner.geoblock_host,
             client: inner.client,
+            transport: inner.transport,
             tick_sizes: inner.tick_sizes,
             neg_risk: inner.neg_risk,
             fee_rate_bps: inner.fee_rate_bps,
             funder: inner.funder,
             signature_type: inner.signature_type,
             salt_generator: inner.salt_generator,
+            rate_limiter: inner.rate_limiter,
+            risk: inner.risk,
+            clock: inner.clock,
         };
 
         Ok(Client {
@@ -1434,32 +1940,27 @@ impl Client<Authenticated<Normal>> {
     }
 }
 
-impl Client<Authenticated<Builder>> {
+impl<T: HttpTransport> Client<Authenticated<Builder>, T> {
     pub async fn builder_api_keys(&self) -> Result<Vec<BuilderApiKeyResponse>> {
         let request = self
             .client()
             .request(Method::GET, format!("{}auth/builder-api-key", self.host()))
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        crate::request(&self.inner.client, request, Some(headers)).await
+        self.inner.request(request, &self.l2_headers()).await
     }
 
     pub async fn revoke_builder_api_key(&self) -> Result<()> {
-        let mut request = self
+        let request = self
             .client()
             .request(
                 Method::DELETE,
                 format!("{}auth/builder-api-key", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
-
-        *request.headers_mut() = headers;
 
         // We have to send the request separately from `self.request` because this endpoint does
         // not return anything in the response body. Otherwise, we would get an EOF error from reqwest
-        self.client().execute(request).await?;
+        self.inner.execute(request, &self.l2_headers()).await?;
 
         Ok(())
     }
@@ -1478,14 +1979,20 @@ impl Client<Authenticated<Builder>> {
                 format!("{}builder/trades{params}", self.host()),
             )
             .build()?;
-        let headers = self.create_headers(&request).await?;
+        self.inner.request(request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, request, Some(headers)).await
+    /// Streams every trade attributed to the builder matching `request`, fetching a page at a time.
+    pub fn builder_trades_stream<'client>(
+        &'client self,
+        request: &'client TradesRequest,
+    ) -> Paginated<impl Stream<Item = Result<BuilderTradeResponse>> + 'client> {
+        pagination::by_cursor(move |cursor| self.builder_trades(request, cursor))
     }
 }
 
 #[cfg(feature = "rfq")]
-impl<K: Kind> Client<Authenticated<K>> {
+impl<K: Kind, T: HttpTransport> Client<Authenticated<K>, T> {
     /// Creates an RFQ Request to buy or sell outcome tokens.
     ///
     /// This initiates the RFQ flow where market makers can provide quotes.
@@ -1502,9 +2009,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/request", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.inner.request(http_request, &self.l2_headers()).await
     }
 
     /// Cancels an RFQ request.
@@ -1523,9 +2028,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}rfq/request", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        self.rfq_request_text(http_request, headers).await
+        self.rfq_request_text(http_request).await
     }
 
     /// Gets RFQ requests.
@@ -1546,9 +2049,17 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}rfq/request{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
+        self.inner.request(http_request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+    /// Streams every RFQ request matching `request`, fetching a page at a time.
+    pub fn requests_stream<'client>(
+        &'client self,
+        request: &'client crate::clob::types::RfqRequestsRequest,
+    ) -> Paginated<impl Stream<Item = Result<crate::clob::types::RfqRequest>> + 'client> {
+        pagination::by_cursor(move |cursor: Option<String>| async move {
+            self.requests(request, cursor.as_deref()).await
+        })
     }
 
     /// Creates an RFQ Quote in response to a Request.
@@ -1565,9 +2076,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/quote", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.inner.request(http_request, &self.l2_headers()).await
     }
 
     /// Cancels an RFQ quote.
@@ -1584,9 +2093,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::DELETE, format!("{}rfq/quote", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        self.rfq_request_text(http_request, headers).await
+        self.rfq_request_text(http_request).await
     }
 
     /// Gets RFQ quotes.
@@ -1607,9 +2114,17 @@ impl<K: Kind> Client<Authenticated<K>> {
             .client()
             .request(Method::GET, format!("{}rfq/quote{params}", self.host()))
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
+        self.inner.request(http_request, &self.l2_headers()).await
+    }
 
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+    /// Streams every RFQ quote matching `request`, fetching a page at a time.
+    pub fn quotes_stream<'client>(
+        &'client self,
+        request: &'client crate::clob::types::RfqQuotesRequest,
+    ) -> Paginated<impl Stream<Item = Result<crate::clob::types::RfqQuote>> + 'client> {
+        pagination::by_cursor(move |cursor: Option<String>| async move {
+            self.quotes(request, cursor.as_deref()).await
+        })
     }
 
     /// Requester accepts an RFQ Quote.
@@ -1629,9 +2144,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/request/accept", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        self.rfq_request_text(http_request, headers).await?;
+        self.rfq_request_text(http_request).await?;
         Ok(crate::clob::types::AcceptRfqQuoteResponse)
     }
 
@@ -1651,9 +2164,7 @@ impl<K: Kind> Client<Authenticated<K>> {
             .request(Method::POST, format!("{}rfq/quote/approve", self.host()))
             .json(request)
             .build()?;
-        let headers = self.create_headers(&http_request).await?;
-
-        crate::request(&self.inner.client, http_request, Some(headers)).await
+        self.inner.request(http_request, &self.l2_headers()).await
     }
 
     /// Helper method for RFQ endpoints that return plain text instead of JSON.
@@ -1662,17 +2173,11 @@ impl<K: Kind> Client<Authenticated<K>> {
     /// and accept quote which return "OK" as plain text rather than a JSON response.
     /// The standard `crate::request` helper expects JSON responses and would fail
     /// to deserialize plain text.
-    async fn rfq_request_text(
-        &self,
-        mut request: reqwest::Request,
-        headers: reqwest::header::HeaderMap,
-    ) -> Result<()> {
+    async fn rfq_request_text(&self, request: reqwest::Request) -> Result<()> {
         let method = request.method().clone();
         let path = request.url().path().to_owned();
 
-        *request.headers_mut() = headers;
-
-        let response = self.inner.client.execute(request).await?;
+        let response = self.inner.execute(request, &self.l2_headers()).await?;
         let status = response.status();
 
         if !status.is_success() {
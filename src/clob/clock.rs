@@ -0,0 +1,182 @@
+//! Calibration of the local clock against the CLOB server's clock.
+//!
+//! Authentication headers carry a timestamp that the server rejects when it strays too far from
+//! its own clock. Instead of fetching `/time` before every request, as
+//! [`use_server_time`](super::ConfigBuilder::use_server_time) does, a client configured with
+//! [`ClockSync`] estimates the offset between the two clocks the way NTP does: it takes a few
+//! `/time` samples, keeps the one with the shortest round trip and assumes the server read its
+//! clock halfway through that round trip. The offset is applied to L1 and L2 header timestamps
+//! and to the expiration of GTD orders.
+//!
+//! Only the first request waits for a measurement. Once the offset is older than
+//! [`ClockSync::refresh_interval`], requests keep signing with it while the next request to go
+//! out measures it again alongside its own round trip. Building an order never measures the
+//! offset, so a GTD order built from [`MarketParams`](super::types::MarketParams) makes no
+//! requests; without a measured offset, its expiration is left on the local clock.
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "Clock types include the module name to indicate their scope"
+)]
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// How the offset between the local and the server's clock is measured.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::clob::Config;
+/// use polymarket_client_sdk::clob::clock::ClockSync;
+///
+/// let config = Config::builder()
+///     .clock_sync(
+///         ClockSync::builder()
+///             .refresh_interval(Duration::from_secs(60))
+///             .build(),
+///     )
+///     .build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Builder)]
+pub struct ClockSync {
+    /// How long a measured offset is used before it is measured again. Defaults to five minutes.
+    #[builder(default = Duration::from_secs(300))]
+    pub refresh_interval: Duration,
+    /// Number of `/time` requests per measurement, of which the one with the shortest round trip
+    /// is kept. Defaults to three.
+    #[builder(default = 3)]
+    pub samples: u32,
+}
+
+impl Default for ClockSync {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// A measured offset between the server's clock and the local one.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClockSkew {
+    /// Server time minus local time, i.e. positive when the local clock is behind
+    pub offset: TimeDelta,
+    /// Round trip of the `/time` request the offset was estimated from
+    pub round_trip: Duration,
+    /// Local time at which the server is assumed to have read its clock
+    pub measured_at: DateTime<Utc>,
+}
+
+impl ClockSkew {
+    /// Estimates the skew from a `/time` response of `server_time` seconds to a request sent at
+    /// `sent_at` that took `round_trip` to complete.
+    pub(crate) fn estimate(server_time: i64, sent_at: DateTime<Utc>, round_trip: Duration) -> Self {
+        let half_trip = TimeDelta::from_std(round_trip / 2).unwrap_or(TimeDelta::MAX);
+        let measured_at = sent_at + half_trip;
+        // `/time` truncates to the second, so on average the server read its clock halfway into it
+        let server_time = TimeDelta::seconds(server_time) + TimeDelta::milliseconds(500);
+
+        Self {
+            offset: server_time - (measured_at - DateTime::<Utc>::UNIX_EPOCH),
+            round_trip,
+            measured_at,
+        }
+    }
+}
+
+/// The latest [`ClockSkew`] of a client and when it was measured.
+#[derive(Debug)]
+pub(crate) struct Clock {
+    config: ClockSync,
+    skew: Mutex<Option<(ClockSkew, Instant)>>,
+    refreshing: AtomicBool,
+}
+
+impl Clock {
+    pub(crate) fn new(config: ClockSync) -> Self {
+        Self {
+            config,
+            skew: Mutex::new(None),
+            refreshing: AtomicBool::new(false),
+        }
+    }
+
+    pub(crate) fn samples(&self) -> u32 {
+        self.config.samples.max(1)
+    }
+
+    pub(crate) fn skew(&self) -> Option<ClockSkew> {
+        self.lock().map(|(skew, _)| skew)
+    }
+
+    /// Whether the skew is stale and the caller should measure it again. Only one caller at a
+    /// time is asked to, until it [records](Self::record) the outcome.
+    pub(crate) fn refresh_due(&self) -> bool {
+        match self.lock() {
+            Some((_, at)) if at.elapsed() >= self.config.refresh_interval => {
+                !self.refreshing.swap(true, Ordering::AcqRel)
+            }
+            _ => false,
+        }
+    }
+
+    /// Records a measurement, or the end of a failed one when `skew` is `None`.
+    pub(crate) fn record(&self, skew: Option<ClockSkew>) {
+        if let Some(skew) = skew {
+            *self.skew.lock().unwrap_or_else(PoisonError::into_inner) =
+                Some((skew, Instant::now()));
+        }
+        self.refreshing.store(false, Ordering::Release);
+    }
+
+    fn lock(&self) -> Option<(ClockSkew, Instant)> {
+        *self.skew.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimate_should_use_round_trip_midpoint() {
+        let sent_at = DateTime::from_timestamp(1_000, 0).unwrap();
+        let skew = ClockSkew::estimate(1_003, sent_at, Duration::from_secs(1));
+
+        assert_eq!(
+            skew.measured_at,
+            DateTime::from_timestamp(1_000, 500_000_000).unwrap()
+        );
+        assert_eq!(skew.offset, TimeDelta::seconds(3));
+        assert_eq!(skew.round_trip, Duration::from_secs(1));
+    }
+
+    #[test]
+    fn refresh_due_should_ask_one_caller_to_refresh_a_stale_skew() {
+        let clock = Clock::new(
+            ClockSync::builder()
+                .refresh_interval(Duration::ZERO)
+                .build(),
+        );
+        assert!(
+            !clock.refresh_due(),
+            "an unmeasured skew is measured inline"
+        );
+
+        let skew = ClockSkew::estimate(1_000, DateTime::UNIX_EPOCH, Duration::ZERO);
+        clock.record(Some(skew));
+
+        assert!(clock.refresh_due());
+        assert!(!clock.refresh_due());
+
+        clock.record(None);
+        assert!(clock.refresh_due());
+        assert_eq!(clock.skew(), Some(skew));
+    }
+}
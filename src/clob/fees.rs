@@ -0,0 +1,106 @@
+//! Taker fees charged by the CTF exchange.
+//!
+//! The exchange applies the order's `fee_rate_bps` to the smaller of the fill price and its
+//! complement, so buying a token at `p` costs the same as selling the other outcome at `1 - p`:
+//!
+//! - buyers pay `fee_rate_bps / 10_000 * min(p, 1 - p) * size / p` in shares of the token bought
+//! - sellers pay `fee_rate_bps / 10_000 * min(p, 1 - p) * size` in collateral
+//!
+//! Either way the fee is worth `fee_rate_bps / 10_000 * min(p, 1 - p) * size` in collateral. Like
+//! the exchange, which computes fees on fixed point amounts, fees are truncated to
+//! [`USDC_DECIMALS`] decimal places.
+
+use rust_decimal_macros::dec;
+
+use crate::clob::order_builder::USDC_DECIMALS;
+use crate::clob::types::Side;
+use crate::types::Decimal;
+
+/// A fee in the asset the exchange collects it in.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fee {
+    /// Paid by buyers out of the shares they receive
+    Shares(Decimal),
+    /// Paid by sellers out of the collateral they receive
+    Usdc(Decimal),
+}
+
+impl Fee {
+    #[must_use]
+    pub fn amount(&self) -> Decimal {
+        match self {
+            Fee::Shares(amount) | Fee::Usdc(amount) => *amount,
+        }
+    }
+
+    /// Collateral value of this fee for a fill at `price`.
+    #[must_use]
+    pub fn usdc_value(&self, price: Decimal) -> Decimal {
+        match self {
+            Fee::Shares(shares) => shares * price,
+            Fee::Usdc(usdc) => *usdc,
+        }
+    }
+}
+
+/// Fee charged to the taker of a `side` fill of `size` shares at `price`, with a base fee of
+/// `fee_rate_bps`.
+#[must_use]
+pub fn fee(side: Side, price: Decimal, size: Decimal, fee_rate_bps: Decimal) -> Fee {
+    match side {
+        Side::Buy if price.is_zero() => Fee::Shares(Decimal::ZERO),
+        Side::Buy => Fee::Shares(truncate(rate(price, fee_rate_bps) * size / price)),
+        _ => Fee::Usdc(usdc_fee(price, size, fee_rate_bps)),
+    }
+}
+
+/// Collateral value of the fee on a fill of `size` shares at `price`, on either side.
+#[must_use]
+pub fn usdc_fee(price: Decimal, size: Decimal, fee_rate_bps: Decimal) -> Decimal {
+    truncate(rate(price, fee_rate_bps) * size)
+}
+
+/// Effective fee per share, in collateral.
+fn rate(price: Decimal, fee_rate_bps: Decimal) -> Decimal {
+    fee_rate_bps / dec!(10_000) * price.min(Decimal::ONE - price).max(Decimal::ZERO)
+}
+
+fn truncate(fee: Decimal) -> Decimal {
+    fee.trunc_with_scale(USDC_DECIMALS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_should_be_symmetric_around_one_half() {
+        let low = usdc_fee(dec!(0.2), dec!(100), dec!(100));
+        let high = usdc_fee(dec!(0.8), dec!(100), dec!(100));
+
+        assert_eq!(low, dec!(0.2));
+        assert_eq!(low, high);
+    }
+
+    #[test]
+    fn buyers_should_pay_in_shares_and_sellers_in_usdc() {
+        let buy = fee(Side::Buy, dec!(0.4), dec!(50), dec!(200));
+        let sell = fee(Side::Sell, dec!(0.4), dec!(50), dec!(200));
+
+        // 2% of 0.4 * 50 is 0.4 collateral, or 1 share at 0.4
+        assert_eq!(buy, Fee::Shares(dec!(1)));
+        assert_eq!(sell, Fee::Usdc(dec!(0.4)));
+        assert_eq!(buy.usdc_value(dec!(0.4)), sell.amount());
+    }
+
+    #[test]
+    fn fee_should_truncate_to_usdc_decimals() {
+        assert_eq!(
+            fee(Side::Buy, dec!(0.7), dec!(1), dec!(1)),
+            Fee::Shares(dec!(0.000042))
+        );
+        assert_eq!(usdc_fee(dec!(0.5), dec!(0.01), dec!(1)), Decimal::ZERO);
+        assert_eq!(usdc_fee(dec!(0.5), dec!(100), Decimal::ZERO), Decimal::ZERO);
+    }
+}
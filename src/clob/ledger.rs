@@ -0,0 +1,582 @@
+//! Per-token positions and P&L computed from the user's own fills.
+//!
+//! A [`Ledger`] consumes trades from the REST [`trades`](crate::clob::Client::trades) endpoint
+//! ([`TradeResponse`]) and, with the `ws` feature, from the user channel
+//! ([`TradeMessage`](crate::clob::ws::TradeMessage)). Each trade is reduced to the fills that
+//! belong to the ledger's owner: the taker side when [`TraderSide::Taker`], otherwise the
+//! [`MakerOrder`]s owned by the same API key. Fills are booked once per trade and order, so
+//! the same trade may be fed from both sources and through every status update.
+//!
+//! Positions use average cost accounting. Fees are computed by [`fees::fee`], paid in shares when
+//! buying and in collateral when selling, and are tracked in collateral terms either way.
+//! Unrealized P&L is marked to the last midpoint supplied via [`Ledger::mark`].
+//!
+//! Every fill is charged the fee rate of its own order. The user channel does not carry the fee
+//! rate of maker orders, so a maker fill first seen there is booked without fees until the same
+//! fill arrives from REST, which supplies the rate and recomputes the position. The P&L thus
+//! ends up the same whichever source delivers a fill first.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::auth::ApiKey;
+use crate::clob::fees;
+use crate::clob::types::response::{MakerOrder, MidpointsResponse, TradeResponse};
+use crate::clob::types::{Side, TraderSide};
+use crate::types::{ConditionId, Decimal, TokenId};
+
+/// Inventory and P&L for a single token.
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Position {
+    pub asset_id: TokenId,
+    /// Market of the token, once a fill for it has been seen
+    pub market: Option<ConditionId>,
+    /// Shares held, net of fees paid in shares
+    pub size: Decimal,
+    /// Average cost per share held
+    pub avg_price: Decimal,
+    /// P&L locked in by reducing the position, net of fees
+    pub realized_pnl: Decimal,
+    /// Fees paid on this token's fills, in collateral
+    pub fees: Decimal,
+    /// Last midpoint the position was marked to
+    pub mark: Option<Decimal>,
+}
+
+impl Position {
+    fn new(asset_id: TokenId) -> Self {
+        Self {
+            asset_id,
+            ..Self::default()
+        }
+    }
+
+    /// Cost of the shares currently held.
+    #[must_use]
+    pub fn cost_basis(&self) -> Decimal {
+        self.size * self.avg_price
+    }
+
+    /// P&L of the shares currently held at the last mark, if the position has been marked.
+    #[must_use]
+    pub fn unrealized_pnl(&self) -> Option<Decimal> {
+        self.mark.map(|mark| (mark - self.avg_price) * self.size)
+    }
+
+    fn book(&mut self, fill: &Fill) {
+        self.market.get_or_insert(fill.market);
+
+        let fee_rate_bps = fill.fee_rate_bps.unwrap_or_default();
+        let fee = fees::fee(fill.side, fill.price, fill.size, fee_rate_bps);
+        self.fees += fee.usdc_value(fill.price);
+
+        match fill.side {
+            Side::Buy => {
+                // Buyers pay the fee in shares, so receive fewer of them for the same cost
+                let shares = fill.size - fee.amount();
+                let cost = fill.size * fill.price;
+                let total = self.size + shares;
+                if !total.is_zero() {
+                    self.avg_price = (self.cost_basis() + cost) / total;
+                }
+                self.size = total;
+            }
+            Side::Sell => {
+                let closed = fill.size.min(self.size.max(Decimal::ZERO));
+                self.realized_pnl += (fill.price - self.avg_price) * closed - fee.amount();
+                self.size -= fill.size;
+                if self.size <= Decimal::ZERO {
+                    // Selling more than was ever recorded, e.g. inventory acquired before the
+                    // ledger started; the excess is carried at the sale price
+                    self.avg_price = if self.size.is_zero() {
+                        Decimal::ZERO
+                    } else {
+                        fill.price
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A single fill belonging to the ledger's owner.
+#[derive(Clone, Debug, PartialEq)]
+struct Fill {
+    trade_id: String,
+    order_id: String,
+    market: ConditionId,
+    asset_id: TokenId,
+    side: Side,
+    price: Decimal,
+    size: Decimal,
+    /// Fee rate of the filled order, unless the source of the fill did not carry it
+    fee_rate_bps: Option<Decimal>,
+}
+
+/// Positions and P&L for one API key, built from its fills.
+#[derive(Clone, Debug)]
+pub struct Ledger {
+    owner: ApiKey,
+    /// Positions held before the first fill, from [`Ledger::seed`]
+    seeds: HashMap<TokenId, Position>,
+    positions: HashMap<TokenId, Position>,
+    /// Every fill booked, in the order it was received
+    fills: Vec<Fill>,
+    /// `(trade_id, order_id)` of every fill booked
+    booked: HashSet<(String, String)>,
+}
+
+impl Ledger {
+    /// Creates an empty ledger for the fills of `owner`, the API key the orders were placed with.
+    #[must_use]
+    pub fn new(owner: ApiKey) -> Self {
+        Self {
+            owner,
+            seeds: HashMap::new(),
+            positions: HashMap::new(),
+            fills: Vec::new(),
+            booked: HashSet::new(),
+        }
+    }
+
+    /// Starts `asset_id` from an existing holding of `size` shares bought at `avg_price`, e.g.
+    /// from the data API. Fills already booked for it are replayed on top of the seed.
+    pub fn seed(&mut self, asset_id: TokenId, size: Decimal, avg_price: Decimal) {
+        let seed = Position {
+            size,
+            avg_price,
+            ..Position::new(asset_id)
+        };
+        self.seeds.insert(asset_id, seed);
+        self.rebuild(asset_id);
+    }
+
+    /// Books the owner's fills in a REST trade. Returns whether anything new was booked.
+    pub fn apply_trade(&mut self, trade: &TradeResponse) -> bool {
+        let fills: Vec<Fill> = if trade.trader_side == TraderSide::Taker {
+            vec![Fill {
+                trade_id: trade.id.clone(),
+                order_id: trade.taker_order_id.clone(),
+                market: trade.market,
+                asset_id: trade.asset_id,
+                side: trade.side,
+                price: trade.price,
+                size: trade.size,
+                fee_rate_bps: Some(trade.fee_rate_bps),
+            }]
+        } else {
+            trade
+                .maker_orders
+                .iter()
+                .filter(|maker| maker.owner == self.owner)
+                .map(|maker: &MakerOrder| Fill {
+                    trade_id: trade.id.clone(),
+                    order_id: maker.order_id.clone(),
+                    market: trade.market,
+                    asset_id: maker.asset_id,
+                    side: maker.side,
+                    price: maker.price,
+                    size: maker.matched_amount,
+                    fee_rate_bps: Some(maker.fee_rate_bps),
+                })
+                .collect()
+        };
+
+        self.book(fills)
+    }
+
+    /// Books the owner's fills in a user channel trade. Returns whether anything changed.
+    ///
+    /// Maker orders on the user channel carry no side or fee rate. A maker on the taker's token
+    /// took the opposite side, while a maker on the complementary token took the same side, and
+    /// maker fills are booked without fees until [`Self::apply_trade`] supplies their rate. A
+    /// `FAILED` trade reverses its fills.
+    #[cfg(feature = "ws")]
+    pub fn apply_trade_message(&mut self, trade: &crate::clob::ws::TradeMessage) -> bool {
+        if trade.status.eq_ignore_ascii_case("FAILED") {
+            return self.reverse(&trade.id);
+        }
+
+        let taker = trade.trader_side == Some(TraderSide::Taker)
+            || (trade.trader_side.is_none() && trade.trade_owner == Some(self.owner));
+        let fills: Vec<Fill> = if taker {
+            vec![Fill {
+                trade_id: trade.id.clone(),
+                order_id: trade.taker_order_id.clone().unwrap_or_default(),
+                market: trade.market,
+                asset_id: trade.asset_id,
+                side: trade.side,
+                price: trade.price,
+                size: trade.size,
+                fee_rate_bps: trade
+                    .fee_rate_bps
+                    .as_deref()
+                    .and_then(|bps| bps.parse().ok()),
+            }]
+        } else {
+            let opposite = match trade.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+                other => other,
+            };
+            trade
+                .maker_orders
+                .iter()
+                .filter(|maker| maker.owner == self.owner)
+                .map(|maker| Fill {
+                    trade_id: trade.id.clone(),
+                    order_id: maker.order_id.clone(),
+                    market: trade.market,
+                    asset_id: maker.asset_id,
+                    side: if maker.asset_id == trade.asset_id {
+                        opposite
+                    } else {
+                        trade.side
+                    },
+                    price: maker.price,
+                    size: maker.matched_amount,
+                    fee_rate_bps: None,
+                })
+                .collect()
+        };
+
+        self.book(fills)
+    }
+
+    /// Marks `asset_id` to `midpoint` for unrealized P&L.
+    pub fn mark(&mut self, asset_id: TokenId, midpoint: Decimal) {
+        self.positions
+            .entry(asset_id)
+            .or_insert_with(|| Position::new(asset_id))
+            .mark = Some(midpoint);
+    }
+
+    /// Marks every position in `midpoints`, as returned by
+    /// [`midpoints`](crate::clob::Client::midpoints).
+    pub fn mark_midpoints(&mut self, midpoints: &MidpointsResponse) {
+        for (asset_id, midpoint) in &midpoints.midpoints {
+            self.mark(*asset_id, *midpoint);
+        }
+    }
+
+    #[must_use]
+    pub fn position(&self, asset_id: TokenId) -> Option<&Position> {
+        self.positions.get(&asset_id)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &Position> {
+        self.positions.values()
+    }
+
+    /// Realized P&L across all positions, net of fees.
+    #[must_use]
+    pub fn realized_pnl(&self) -> Decimal {
+        self.positions.values().map(|p| p.realized_pnl).sum()
+    }
+
+    /// Unrealized P&L across all marked positions.
+    #[must_use]
+    pub fn unrealized_pnl(&self) -> Decimal {
+        self.positions
+            .values()
+            .filter_map(Position::unrealized_pnl)
+            .sum()
+    }
+
+    /// Fees paid across all positions, in collateral.
+    #[must_use]
+    pub fn fees(&self) -> Decimal {
+        self.positions.values().map(|p| p.fees).sum()
+    }
+
+    fn book(&mut self, fills: Vec<Fill>) -> bool {
+        let mut booked = false;
+        for fill in fills {
+            if !self
+                .booked
+                .insert((fill.trade_id.clone(), fill.order_id.clone()))
+            {
+                booked |= self.complete_fee_rate(&fill);
+                continue;
+            }
+
+            self.positions
+                .entry(fill.asset_id)
+                .or_insert_with(|| Position::new(fill.asset_id))
+                .book(&fill);
+            self.fills.push(fill);
+            booked = true;
+        }
+
+        booked
+    }
+
+    /// Supplies the fee rate of an already booked fill that was booked without one.
+    fn complete_fee_rate(&mut self, fill: &Fill) -> bool {
+        let Some(fee_rate_bps) = fill.fee_rate_bps else {
+            return false;
+        };
+        let Some(booked) = self.fills.iter_mut().find(|booked| {
+            booked.trade_id == fill.trade_id
+                && booked.order_id == fill.order_id
+                && booked.fee_rate_bps.is_none()
+        }) else {
+            return false;
+        };
+
+        booked.fee_rate_bps = Some(fee_rate_bps);
+        let asset_id = booked.asset_id;
+        self.rebuild(asset_id);
+        true
+    }
+
+    #[cfg(feature = "ws")]
+    fn reverse(&mut self, trade_id: &str) -> bool {
+        let (reversed, kept): (Vec<_>, Vec<_>) = std::mem::take(&mut self.fills)
+            .into_iter()
+            .partition(|fill| fill.trade_id == trade_id);
+        self.fills = kept;
+
+        let mut assets: Vec<TokenId> = Vec::new();
+        for fill in reversed {
+            self.booked.remove(&(fill.trade_id, fill.order_id));
+            if !assets.contains(&fill.asset_id) {
+                assets.push(fill.asset_id);
+            }
+        }
+        for asset_id in &assets {
+            self.rebuild(*asset_id);
+        }
+
+        !assets.is_empty()
+    }
+
+    /// Recomputes `asset_id` from its seed and the fills booked for it.
+    fn rebuild(&mut self, asset_id: TokenId) {
+        let mark = self.positions.get(&asset_id).and_then(|p| p.mark);
+        let mut position = self
+            .seeds
+            .get(&asset_id)
+            .cloned()
+            .unwrap_or_else(|| Position::new(asset_id));
+        for fill in self.fills.iter().filter(|fill| fill.asset_id == asset_id) {
+            position.book(fill);
+        }
+        position.mark = mark;
+
+        self.positions.insert(asset_id, position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{B256, U256};
+    use chrono::DateTime;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::clob::types::OrderStatusType;
+    use crate::types::Address;
+
+    const OWNER: ApiKey = Uuid::nil();
+    const TOKEN: TokenId = TokenId::new(U256::from_limbs([123, 0, 0, 0]));
+    const MARKET: ConditionId = ConditionId::new(B256::repeat_byte(0x11));
+
+    fn trade(
+        id: &str,
+        side: Side,
+        price: Decimal,
+        size: Decimal,
+        fee_rate_bps: Decimal,
+    ) -> TradeResponse {
+        TradeResponse::builder()
+            .id(id)
+            .taker_order_id(format!("order-{id}"))
+            .market(MARKET)
+            .asset_id(TOKEN)
+            .side(side)
+            .size(size)
+            .fee_rate_bps(fee_rate_bps)
+            .price(price)
+            .status(OrderStatusType::Matched)
+            .match_time(DateTime::UNIX_EPOCH)
+            .last_update(DateTime::UNIX_EPOCH)
+            .outcome("YES")
+            .bucket_index(0)
+            .owner(OWNER)
+            .maker_address(Address::ZERO)
+            .maker_orders(Vec::new())
+            .transaction_hash("0x")
+            .trader_side(TraderSide::Taker)
+            .build()
+    }
+
+    #[test]
+    fn taker_fills_should_track_average_cost_and_realized_pnl() {
+        let mut ledger = Ledger::new(OWNER);
+
+        assert!(ledger.apply_trade(&trade("1", Side::Buy, dec!(0.4), dec!(10), Decimal::ZERO)));
+        assert!(ledger.apply_trade(&trade("2", Side::Buy, dec!(0.6), dec!(10), Decimal::ZERO)));
+        assert!(!ledger.apply_trade(&trade("2", Side::Buy, dec!(0.6), dec!(10), Decimal::ZERO)));
+
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, dec!(20));
+        assert_eq!(position.avg_price, dec!(0.5));
+
+        ledger.apply_trade(&trade("3", Side::Sell, dec!(0.7), dec!(5), dec!(100)));
+        ledger.mark(TOKEN, dec!(0.55));
+
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, dec!(15));
+        // 0.2 gain on 5 shares, less 1% of min(0.7, 0.3) * 5
+        assert_eq!(position.realized_pnl, dec!(0.985));
+        assert_eq!(position.unrealized_pnl(), Some(dec!(0.75)));
+        assert_eq!(ledger.fees(), dec!(0.015));
+    }
+
+    #[test]
+    fn maker_fills_should_only_book_owned_orders() {
+        let mut ledger = Ledger::new(OWNER);
+        let maker = |owner: ApiKey, order_id: &str| {
+            MakerOrder::builder()
+                .order_id(order_id)
+                .owner(owner)
+                .maker_address(Address::ZERO)
+                .matched_amount(dec!(4))
+                .price(dec!(0.3))
+                .fee_rate_bps(Decimal::ZERO)
+                .asset_id(TOKEN)
+                .outcome("YES")
+                .side(Side::Buy)
+                .build()
+        };
+
+        let mut maker_trade = trade("1", Side::Sell, dec!(0.3), dec!(8), Decimal::ZERO);
+        maker_trade.trader_side = TraderSide::Maker;
+        maker_trade.maker_orders = vec![maker(OWNER, "mine"), maker(Uuid::max(), "theirs")];
+
+        assert!(ledger.apply_trade(&maker_trade));
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, dec!(4));
+        assert_eq!(position.avg_price, dec!(0.3));
+    }
+
+    #[test]
+    fn buy_fees_should_be_paid_in_shares() {
+        let mut ledger = Ledger::new(OWNER);
+        ledger.seed(TOKEN, dec!(10), dec!(0.5));
+
+        ledger.apply_trade(&trade("1", Side::Buy, dec!(0.5), dec!(10), dec!(200)));
+
+        // 2% of 0.5 * 10 is 0.1 collateral, or 0.2 shares at 0.5, so 10 collateral bought 19.8 shares
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, dec!(19.8));
+        assert_eq!(position.avg_price, dec!(10) / dec!(19.8));
+        assert_eq!(position.fees, dec!(0.1));
+    }
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn maker_fee_rate_should_not_depend_on_fill_source_order() {
+        use crate::clob::ws::TradeMessage;
+
+        let message: TradeMessage = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "market": MARKET,
+            "asset_id": TOKEN,
+            "side": "BUY",
+            "price": "0.5",
+            "size": "10",
+            "status": "MATCHED",
+            "taker_order_id": "taker",
+            "trader_side": "MAKER",
+            "maker_orders": [{
+                "asset_id": TOKEN,
+                "matched_amount": "10",
+                "order_id": "mine",
+                "outcome": "YES",
+                "owner": OWNER,
+                "price": "0.5"
+            }]
+        }))
+        .unwrap();
+        let mut rest = trade("1", Side::Buy, dec!(0.5), dec!(10), Decimal::ZERO);
+        rest.trader_side = TraderSide::Maker;
+        rest.maker_orders = vec![
+            MakerOrder::builder()
+                .order_id("mine")
+                .owner(OWNER)
+                .maker_address(Address::ZERO)
+                .matched_amount(dec!(10))
+                .price(dec!(0.5))
+                .fee_rate_bps(dec!(100))
+                .asset_id(TOKEN)
+                .outcome("YES")
+                .side(Side::Sell)
+                .build(),
+        ];
+
+        let mut ledger = Ledger::new(OWNER);
+        ledger.seed(TOKEN, dec!(10), dec!(0.4));
+        let mut rest_first = ledger.clone();
+
+        assert!(ledger.apply_trade_message(&message));
+        assert_eq!(ledger.fees(), Decimal::ZERO, "rate unknown until REST");
+        assert!(ledger.apply_trade(&rest));
+        assert!(!ledger.apply_trade(&rest));
+
+        assert!(rest_first.apply_trade(&rest));
+        assert!(!rest_first.apply_trade_message(&message));
+
+        // 1% of min(0.5, 0.5) * 10
+        assert_eq!(ledger.fees(), dec!(0.05));
+        assert_eq!(ledger.position(TOKEN), rest_first.position(TOKEN));
+        assert_eq!(ledger.realized_pnl(), dec!(0.95));
+    }
+
+    #[cfg(feature = "ws")]
+    #[test]
+    fn failed_trade_message_should_reverse_its_fills() {
+        use crate::clob::ws::TradeMessage;
+
+        let message = |status: &str| -> TradeMessage {
+            serde_json::from_value(serde_json::json!({
+                "id": "1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "asset_id": "456",
+                "side": "BUY",
+                "price": "0.6",
+                "size": "10",
+                "status": status,
+                "taker_order_id": "taker",
+                "trader_side": "MAKER",
+                "maker_orders": [{
+                    "asset_id": TOKEN,
+                    "matched_amount": "10",
+                    "order_id": "mine",
+                    "outcome": "NO",
+                    "owner": OWNER,
+                    "price": "0.4"
+                }]
+            }))
+            .unwrap()
+        };
+
+        let mut ledger = Ledger::new(OWNER);
+        assert!(ledger.apply_trade_message(&message("MATCHED")));
+        assert!(!ledger.apply_trade_message(&message("MINED")));
+
+        // Matched against a buyer of the complementary token, so this maker bought too
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, dec!(10));
+        assert_eq!(position.avg_price, dec!(0.4));
+
+        ledger.mark(TOKEN, dec!(0.5));
+        assert!(ledger.apply_trade_message(&message("FAILED")));
+        let position = ledger.position(TOKEN).unwrap();
+        assert_eq!(position.size, Decimal::ZERO);
+        assert_eq!(position.mark, Some(dec!(0.5)));
+    }
+}
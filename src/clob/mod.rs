@@ -1,5 +1,15 @@
+mod cache;
 pub mod client;
+pub mod clock;
+pub mod fees;
+pub mod ledger;
 pub mod order_builder;
+#[cfg(feature = "paper")]
+pub mod paper;
+pub mod rate_limit;
+pub mod registry;
+pub mod risk;
+pub mod rotation;
 pub mod types;
 #[cfg(feature = "ws")]
 pub mod ws;
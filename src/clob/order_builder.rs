@@ -1,3 +1,4 @@
+use std::cmp::Reverse;
 use std::marker::PhantomData;
 use std::str::FromStr as _;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -5,18 +6,23 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use rand::Rng as _;
+use reqwest::Client as ReqwestClient;
 use rust_decimal::prelude::ToPrimitive as _;
+use rust_decimal_macros::dec;
 
 use crate::Result;
 use crate::auth::Kind as AuthKind;
 use crate::auth::state::Authenticated;
 use crate::clob::Client;
+use crate::clob::fees;
 use crate::clob::types::request::OrderBookSummaryRequest;
+use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
 use crate::clob::types::{
-    Amount, AmountInner, Order, OrderType, Side, SignableOrder, SignatureType,
+    Amount, AmountInner, MarketParams, Order, OrderType, Side, SignableOrder, SignatureType,
 };
 use crate::error::Error;
-use crate::types::{Address, Decimal};
+use crate::transport::HttpTransport;
+use crate::types::{Address, Decimal, TokenId};
 
 pub(crate) const USDC_DECIMALS: u32 = 6;
 
@@ -35,12 +41,12 @@ pub struct Market;
 
 /// Used to create an order iteratively and ensure validity with respect to its order kind.
 #[derive(Debug)]
-pub struct OrderBuilder<OrderKind, K: AuthKind> {
-    pub(crate) client: Client<Authenticated<K>>,
+pub struct OrderBuilder<OrderKind, K: AuthKind, T: HttpTransport = ReqwestClient> {
+    pub(crate) client: Client<Authenticated<K>, T>,
     pub(crate) signer: Address,
     pub(crate) signature_type: SignatureType,
     pub(crate) salt_generator: fn() -> u64,
-    pub(crate) token_id: Option<String>,
+    pub(crate) token_id: Option<TokenId>,
     pub(crate) price: Option<Decimal>,
     pub(crate) size: Option<Decimal>,
     pub(crate) amount: Option<Amount>,
@@ -50,13 +56,15 @@ pub struct OrderBuilder<OrderKind, K: AuthKind> {
     pub(crate) taker: Option<Address>,
     pub(crate) order_type: Option<OrderType>,
     pub(crate) funder: Option<Address>,
+    pub(crate) market_params: Option<MarketParams>,
+    pub(crate) max_slippage_bps: Option<Decimal>,
     pub(crate) _kind: PhantomData<OrderKind>,
 }
 
-impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
+impl<OrderKind, K: AuthKind, T: HttpTransport> OrderBuilder<OrderKind, K, T> {
     /// Sets the `token_id` for this builder. This is a required field.
     #[must_use]
-    pub fn token_id<ID: Into<String>>(mut self, token_id: ID) -> Self {
+    pub fn token_id<ID: Into<TokenId>>(mut self, token_id: ID) -> Self {
         self.token_id = Some(token_id.into());
         self
     }
@@ -92,9 +100,45 @@ impl<OrderKind, K: AuthKind> OrderBuilder<OrderKind, K> {
         self.order_type = Some(order_type);
         self
     }
+
+    /// Uses `market_params` instead of querying the tick size, fee rate, neg risk flag and, for
+    /// market orders without a price, the order book, so that building and signing the order
+    /// makes no requests. Risk limits that depend on the midpoint or on open orders still query
+    /// the CLOB when signing.
+    #[must_use]
+    pub fn market_params(mut self, market_params: MarketParams) -> Self {
+        self.market_params = Some(market_params);
+        self
+    }
+
+    /// Returns the fee rate in basis points and the minimum tick size of `token_id`, taken from
+    /// the [`MarketParams`] when set.
+    async fn fee_rate_and_tick_size(&self, token_id: TokenId) -> Result<(u32, Decimal)> {
+        if let Some(params) = &self.market_params {
+            return Ok((params.fee_rate_bps, params.tick_size.as_decimal()));
+        }
+
+        let fee_rate_bps = self.client.fee_rate_bps(token_id).await?.base_fee;
+        let minimum_tick_size = self
+            .client
+            .tick_size(token_id)
+            .await?
+            .minimum_tick_size
+            .as_decimal();
+
+        Ok((fee_rate_bps, minimum_tick_size))
+    }
+
+    fn signable(&self, order: Order, order_type: OrderType) -> SignableOrder {
+        SignableOrder {
+            order,
+            order_type,
+            neg_risk: self.market_params.as_ref().map(|params| params.neg_risk),
+        }
+    }
 }
 
-impl<K: AuthKind> OrderBuilder<Limit, K> {
+impl<K: AuthKind, T: HttpTransport> OrderBuilder<Limit, K, T> {
     /// Sets the price for this limit builder. This is a required field.
     #[must_use]
     pub fn price(mut self, price: Decimal) -> Self {
@@ -115,7 +159,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         tracing::instrument(skip(self), err(level = "warn"))
     )]
     pub async fn build(self) -> Result<SignableOrder> {
-        let Some(token_id) = self.token_id.clone() else {
+        let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
             ));
@@ -139,13 +183,7 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             )));
         }
 
-        let fee_rate = self.client.fee_rate_bps(&token_id).await?;
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
+        let (fee_rate_bps, minimum_tick_size) = self.fee_rate_and_tick_size(token_id).await?;
 
         let decimals = minimum_tick_size.scale();
 
@@ -184,7 +222,10 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         }
 
         let nonce = self.nonce.unwrap_or(0);
-        let expiration = self.expiration.unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+        let expiration = match self.expiration {
+            Some(expiration) => self.client.to_server_time(expiration),
+            None => DateTime::<Utc>::UNIX_EPOCH,
+        };
         let taker = self.taker.unwrap_or(Address::ZERO);
         let order_type = self.order_type.unwrap_or(OrderType::GTC);
 
@@ -221,11 +262,11 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
             salt: U256::from(salt),
             maker: self.funder.unwrap_or(self.signer),
             taker,
-            tokenId: U256::from_str(&token_id)?,
+            tokenId: token_id.into(),
             makerAmount: U256::from(to_fixed_u128(maker_amount)),
             takerAmount: U256::from(to_fixed_u128(taker_amount)),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::from(expiration.timestamp().to_u64().ok_or(Error::validation(
@@ -237,11 +278,11 @@ impl<K: AuthKind> OrderBuilder<Limit, K> {
         #[cfg(feature = "tracing")]
         tracing::debug!(token_id = %token_id, side = ?side, price = %price, size = %size, "limit order built");
 
-        Ok(SignableOrder { order, order_type })
+        Ok(self.signable(order, order_type))
     }
 }
 
-impl<K: AuthKind> OrderBuilder<Market, K> {
+impl<K: AuthKind, T: HttpTransport> OrderBuilder<Market, K, T> {
     /// Sets the price for this market builder. This is an optional field.
     #[must_use]
     pub fn price(mut self, price: Decimal) -> Self {
@@ -256,71 +297,52 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         self
     }
 
+    /// Sets the maximum [`FillPreview::slippage_bps`] of this market order versus the midpoint.
+    /// The order is rejected when built if the expected fill against the current book exceeds
+    /// it. This is an optional field.
+    #[must_use]
+    pub fn max_slippage_bps(mut self, max_slippage_bps: Decimal) -> Self {
+        self.max_slippage_bps = Some(max_slippage_bps);
+        self
+    }
+
+    /// Returns the book from the [`MarketParams`] when set, or queries it otherwise.
+    async fn book(&self, token_id: TokenId) -> Result<OrderBookSummaryResponse> {
+        match &self.market_params {
+            Some(params) => params.book.clone().ok_or(Error::validation(
+                "Unable to price a market order from MarketParams without a book",
+            )),
+            None => {
+                self.client
+                    .order_book(&OrderBookSummaryRequest {
+                        token_id,
+                        side: None,
+                    })
+                    .await
+            }
+        }
+    }
+
     // Attempts to calculate the market price from the top of the book for the particular token.
     // - Uses an orderbook depth search to find the cutoff price:
     //   - BUY + USDC: walk asks until notional >= USDC
     //   - BUY + Shares: walk asks until shares >= N
     //   - SELL + Shares: walk bids until shares >= N
-    async fn calculate_price(&self, order_type: OrderType) -> Result<Decimal> {
-        let token_id = self
-            .token_id
-            .as_ref()
-            .expect("Token ID was already validated in `build`");
-        let side = self.side.expect("Side was already validated in `build`");
-        let amount = self
-            .amount
-            .as_ref()
-            .expect("Amount was already validated in `build`");
-
-        let book = self
-            .client
-            .order_book(&OrderBookSummaryRequest {
-                token_id: token_id.to_owned(),
-                side: None,
-            })
-            .await?;
-
-        if !matches!(order_type, OrderType::FAK | OrderType::FOK) {
-            return Err(Error::validation(
-                "Cannot set an order type other than FAK/FOK for a market order",
-            ));
-        }
-
-        let (levels, amount) = match side {
-            Side::Buy => (book.asks, amount.0),
-            Side::Sell => match amount.0 {
-                a @ AmountInner::Shares(_) => (book.bids, a),
-                AmountInner::Usdc(_) => {
-                    return Err(Error::validation(
-                        "Sell Orders must specify their `amount`s in shares",
-                    ));
-                }
-            },
-
-            side => return Err(Error::validation(format!("Invalid side: {side}"))),
-        };
-
-        let first = levels.first().ok_or(Error::validation(format!(
-            "No opposing orders for {token_id} which means there is no market price"
-        )))?;
-
-        let mut sum = Decimal::ZERO;
-        let cutoff_price = levels.iter().rev().find_map(|level| {
-            match amount {
-                AmountInner::Usdc(_) => sum += level.size * level.price,
-                AmountInner::Shares(_) => sum += level.size,
-            }
-            (sum >= amount.as_inner()).then_some(level.price)
-        });
-
-        match cutoff_price {
-            Some(price) => Ok(price),
-            None if matches!(order_type, OrderType::FOK) => Err(Error::validation(format!(
-                "Insufficient liquidity to fill order for {token_id} at {}",
+    fn calculate_price(
+        preview: &FillPreview,
+        order_type: OrderType,
+        amount: Amount,
+    ) -> Result<Decimal> {
+        if !preview.fills_fully && matches!(order_type, OrderType::FOK) {
+            return Err(Error::validation(format!(
+                "Insufficient liquidity to fill order for {} at {}",
+                preview.asset_id,
                 amount.as_inner()
-            ))),
-            None => Ok(first.price),
+            )));
         }
+
+        // Without enough liquidity, a FAK order sweeps the whole book
+        Ok(preview.worst_price)
     }
 
     /// Validates and transforms this market builder into a [`SignableOrder`]
@@ -329,7 +351,7 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         tracing::instrument(skip(self), err(level = "warn"))
     )]
     pub async fn build(self) -> Result<SignableOrder> {
-        let Some(token_id) = self.token_id.clone() else {
+        let Some(token_id) = self.token_id else {
             return Err(Error::validation(
                 "Unable to build Order due to missing token ID",
             ));
@@ -349,18 +371,42 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         let taker = self.taker.unwrap_or(Address::ZERO);
 
         let order_type = self.order_type.unwrap_or(OrderType::FAK);
-        let price = match self.price {
-            Some(price) => price,
-            None => self.calculate_price(order_type).await?,
+        if self.price.is_none() && !matches!(order_type, OrderType::FAK | OrderType::FOK) {
+            return Err(Error::validation(
+                "Cannot set an order type other than FAK/FOK for a market order",
+            ));
+        }
+
+        let (fee_rate_bps, minimum_tick_size) = self.fee_rate_and_tick_size(token_id).await?;
+
+        let (price, preview) = match self.price {
+            Some(price) if self.max_slippage_bps.is_none() => (price, None),
+            price => {
+                let book = self.book(token_id).await?;
+                let preview = FillPreview::from_book(&book, side, amount, fee_rate_bps)?;
+                let price = match price {
+                    Some(price) => price,
+                    None => Self::calculate_price(&preview, order_type, amount)?,
+                };
+                (price, Some(preview))
+            }
         };
 
-        let minimum_tick_size = self
-            .client
-            .tick_size(&token_id)
-            .await?
-            .minimum_tick_size
-            .as_decimal();
-        let fee_rate = self.client.fee_rate_bps(&token_id).await?;
+        if let (Some(max), Some(preview)) = (self.max_slippage_bps, &preview) {
+            match preview.slippage_bps {
+                Some(slippage) if slippage <= max => {}
+                Some(slippage) => {
+                    return Err(Error::validation(format!(
+                        "Expected slippage of {slippage} bps for {token_id} exceeds the maximum of {max} bps"
+                    )));
+                }
+                None => {
+                    return Err(Error::validation(format!(
+                        "Unable to check slippage for {token_id} without a midpoint"
+                    )));
+                }
+            }
+        }
 
         let decimals = minimum_tick_size.scale();
 
@@ -424,11 +470,11 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
             salt: U256::from(salt),
             maker: self.funder.unwrap_or(self.signer),
             taker,
-            tokenId: U256::from_str(&token_id)?,
+            tokenId: token_id.into(),
             makerAmount: U256::from(to_fixed_u128(maker_amount)),
             takerAmount: U256::from(to_fixed_u128(taker_amount)),
             side: side as u8,
-            feeRateBps: U256::from(fee_rate.base_fee),
+            feeRateBps: U256::from(fee_rate_bps),
             nonce: U256::from(nonce),
             signer: self.signer,
             expiration: U256::ZERO,
@@ -438,7 +484,136 @@ impl<K: AuthKind> OrderBuilder<Market, K> {
         #[cfg(feature = "tracing")]
         tracing::debug!(token_id = %token_id, side = ?side, price = %price, amount = %amount.as_inner(), "market order built");
 
-        Ok(SignableOrder { order, order_type })
+        Ok(self.signable(order, order_type))
+    }
+}
+
+/// Expected fill of a market order against an order book, see
+/// [`Client::preview_market_order`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FillPreview {
+    pub asset_id: TokenId,
+    pub side: Side,
+    /// Levels the order would take liquidity from, best price first, each with the size taken
+    pub levels: Vec<OrderSummary>,
+    /// Shares bought or sold
+    pub shares: Decimal,
+    /// Collateral spent or received, before fees
+    pub notional: Decimal,
+    /// Volume weighted average price of the fill
+    pub vwap: Decimal,
+    /// Price of the last level reached
+    pub worst_price: Decimal,
+    /// Midpoint of the book, if it has both bids and asks
+    pub midpoint: Option<Decimal>,
+    /// How much worse the VWAP is than the midpoint, in basis points
+    pub slippage_bps: Option<Decimal>,
+    /// Taker fee on the fill, in collateral
+    pub fee: Decimal,
+    /// Whether the book can fill the whole amount, i.e. whether a `FOK` order would fill
+    pub fills_fully: bool,
+}
+
+impl FillPreview {
+    /// Walks `book` from the best opposing price until `amount` is filled or the book runs out.
+    pub fn from_book(
+        book: &OrderBookSummaryResponse,
+        side: Side,
+        amount: Amount,
+        fee_rate_bps: u32,
+    ) -> Result<Self> {
+        let mut levels = match (side, amount.0) {
+            (Side::Buy, _) => book.asks.clone(),
+            (Side::Sell, AmountInner::Shares(_)) => book.bids.clone(),
+            (Side::Sell, AmountInner::Usdc(_)) => {
+                return Err(Error::validation(
+                    "Sell Orders must specify their `amount`s in shares",
+                ));
+            }
+            (side, _) => return Err(Error::validation(format!("Invalid side: {side}"))),
+        };
+
+        if levels.is_empty() {
+            return Err(Error::validation(format!(
+                "No opposing orders for {} which means there is no market price",
+                book.asset_id
+            )));
+        }
+
+        match side {
+            Side::Buy => levels.sort_by_key(|level| level.price),
+            _ => levels.sort_by_key(|level| Reverse(level.price)),
+        }
+
+        let mut remaining = amount.as_inner();
+        let (mut shares, mut notional, mut fee) = (Decimal::ZERO, Decimal::ZERO, Decimal::ZERO);
+        let mut consumed = Vec::new();
+        for level in levels {
+            if remaining <= Decimal::ZERO {
+                break;
+            }
+
+            // Only the last level is partially taken, which is priced off what remains so that
+            // dividing by the price leaves no residue
+            let (size, cost) = match amount.0 {
+                AmountInner::Usdc(_) if level.size * level.price > remaining => {
+                    (remaining / level.price, remaining)
+                }
+                AmountInner::Shares(_) if level.size > remaining => {
+                    (remaining, remaining * level.price)
+                }
+                _ => (level.size, level.size * level.price),
+            };
+            remaining -= match amount.0 {
+                AmountInner::Usdc(_) => cost,
+                AmountInner::Shares(_) => size,
+            };
+
+            shares += size;
+            notional += cost;
+            fee += fees::usdc_fee(level.price, size, Decimal::from(fee_rate_bps));
+            consumed.push(OrderSummary {
+                price: level.price,
+                size,
+            });
+        }
+
+        let vwap = if shares.is_zero() {
+            Decimal::ZERO
+        } else {
+            notional / shares
+        };
+        let worst_price = consumed.last().map_or(Decimal::ZERO, |level| level.price);
+
+        let best_bid = book.bids.iter().map(|level| level.price).max();
+        let best_ask = book.asks.iter().map(|level| level.price).min();
+        let midpoint = best_bid
+            .zip(best_ask)
+            .map(|(bid, ask)| (bid + ask) / Decimal::TWO);
+        let slippage_bps = midpoint
+            .filter(|midpoint| !midpoint.is_zero())
+            .map(|midpoint| {
+                let slippage = match side {
+                    Side::Buy => vwap - midpoint,
+                    _ => midpoint - vwap,
+                };
+                slippage / midpoint * dec!(10_000)
+            });
+
+        Ok(Self {
+            asset_id: book.asset_id,
+            side,
+            levels: consumed,
+            shares,
+            notional,
+            vwap,
+            worst_price,
+            midpoint,
+            slippage_bps,
+            fee,
+            fills_fully: remaining <= Decimal::ZERO,
+        })
     }
 }
 
@@ -452,6 +627,17 @@ fn to_fixed_u128(d: Decimal) -> u128 {
         .expect("The `build` call in `OrderBuilder<S, OrderKind, K>` ensures that only positive values are being multiplied/divided")
 }
 
+/// Converts an amount quantized by [`to_fixed_u128`] back into its decimal value.
+pub(crate) fn from_fixed(value: U256) -> Result<Decimal> {
+    let mut amount = Decimal::from_str(&value.to_string())
+        .map_err(|e| Error::validation(format!("Unable to convert {value} to a decimal: {e}")))?;
+    amount
+        .set_scale(USDC_DECIMALS)
+        .map_err(|e| Error::validation(format!("Unable to scale order amount {value}: {e}")))?;
+
+    Ok(amount)
+}
+
 /// Mask the salt to be <= 2^53 - 1, as the backend parses as an IEEE 754.
 fn to_ieee_754_int(salt: u64) -> u64 {
     salt & ((1 << 53) - 1)
@@ -480,9 +666,8 @@ pub(crate) fn generate_seed() -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use rust_decimal_macros::dec;
-
     use super::*;
+    use crate::types::ConditionId;
 
     #[test]
     fn to_fixed_u128_should_succeed() {
@@ -501,6 +686,68 @@ mod tests {
         to_fixed_u128(dec!(-123.456));
     }
 
+    fn level(price: Decimal, size: Decimal) -> OrderSummary {
+        OrderSummary { price, size }
+    }
+
+    fn book() -> OrderBookSummaryResponse {
+        OrderBookSummaryResponse::builder()
+            .market(ConditionId::default())
+            .asset_id(TokenId::from(1))
+            .timestamp(DateTime::<Utc>::UNIX_EPOCH)
+            .bids(vec![
+                level(dec!(0.38), dec!(50)),
+                level(dec!(0.4), dec!(100)),
+            ])
+            .asks(vec![
+                level(dec!(0.5), dec!(100)),
+                level(dec!(0.44), dec!(100)),
+            ])
+            .min_order_size(dec!(5))
+            .neg_risk(false)
+            .tick_size(crate::clob::types::TickSize::Hundredth)
+            .build()
+    }
+
+    #[test]
+    fn preview_should_walk_asks_for_usdc_buys() {
+        let amount = Amount::usdc(dec!(64)).unwrap();
+        let preview = FillPreview::from_book(&book(), Side::Buy, amount, 100).unwrap();
+
+        // $44 buys the 0.44 level, the remaining $20 buys 40 shares at 0.5
+        assert_eq!(
+            preview.levels,
+            vec![level(dec!(0.44), dec!(100)), level(dec!(0.5), dec!(40))]
+        );
+        assert_eq!(preview.shares, dec!(140));
+        assert_eq!(preview.notional, dec!(64));
+        assert_eq!(preview.worst_price, dec!(0.5));
+        assert_eq!(preview.midpoint, Some(dec!(0.42)));
+        assert_eq!(preview.vwap, dec!(64) / dec!(140));
+        // 1% of min(p, 1 - p) on each level
+        assert_eq!(preview.fee, dec!(0.64));
+        assert!(preview.fills_fully);
+    }
+
+    #[test]
+    fn preview_should_report_shortfall_for_sells() {
+        let amount = Amount::shares(dec!(200)).unwrap();
+        let preview = FillPreview::from_book(&book(), Side::Sell, amount, 0).unwrap();
+
+        assert_eq!(preview.shares, dec!(150));
+        assert_eq!(preview.notional, dec!(59));
+        assert_eq!(preview.worst_price, dec!(0.38));
+        assert!(!preview.fills_fully);
+        assert!(preview.slippage_bps.unwrap() > Decimal::ZERO);
+    }
+
+    #[test]
+    fn from_fixed_should_invert_to_fixed_u128() {
+        let fixed = to_fixed_u128(dec!(123.456789));
+
+        assert_eq!(from_fixed(U256::from(fixed)).unwrap(), dec!(123.456789));
+    }
+
     #[test]
     fn order_salt_should_be_less_than_or_equal_to_2_to_the_53_minus_1() {
         let raw_salt = u64::MAX;
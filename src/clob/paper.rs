@@ -0,0 +1,979 @@
+//! Paper trading against a local matching engine.
+//!
+//! [`Client`] mirrors the order management surface of the authenticated CLOB client
+//! ([`post_order`](Client::post_order), [`cancel_order`](Client::cancel_order),
+//! [`orders`](Client::orders), [`trades`](Client::trades) and
+//! [`balance_allowance`](Client::balance_allowance)), but nothing is sent to the exchange.
+//! [`SignedOrder`]s are matched against order books fed from
+//! [`order_book`](crate::clob::Client::order_book) snapshots via [`Client::update_book`] or from
+//! the market WebSocket channel via [`Client::apply`], and balances, fills and order updates are
+//! simulated locally.
+//!
+//! Matching follows the exchange's [`OrderType`] semantics:
+//!
+//! - `FOK` orders are filled in full immediately or rejected.
+//! - `FAK` orders are filled as far as possible and the remainder is cancelled.
+//! - `GTC` and `GTD` orders rest on the book until filled, cancelled or, for `GTD`, expired.
+//!
+//! Crossing orders take liquidity at the book's prices and pay the taker
+//! [`fee`](crate::clob::fees::fee), which buyers pay in shares and sellers in collateral. Resting
+//! orders are filled at their own price, without fees, once book updates cross them. Placements,
+//! fills and cancellations are published as synthetic [`OrderMessage`] and [`TradeMessage`]
+//! events on [`Client::subscribe_user_events`].
+//!
+//! Liquidity taken by paper fills is removed from the local book until the next update for that
+//! price level arrives. Paper orders are never visible to the rest of the market.
+
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr as _;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use alloy::primitives::U256;
+use async_stream::try_stream;
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use reqwest::{Method, StatusCode};
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::Uuid;
+
+use crate::auth::ApiKey;
+use crate::clob::client::TERMINAL_CURSOR;
+use crate::clob::fees::{self, Fee};
+use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS, from_fixed};
+use crate::clob::types::request::{BalanceAllowanceRequest, OrdersRequest, TradesRequest};
+use crate::clob::types::response::{
+    BalanceAllowanceResponse, CancelOrdersResponse, MakerOrder, OpenOrderResponse,
+    OrderBookSummaryResponse, Page, PostOrderResponse, TradeResponse,
+};
+use crate::clob::types::{
+    AssetType, OrderStatusType, OrderType, Side, SignedOrder, TickSize, TraderSide,
+};
+use crate::clob::ws::{
+    BookUpdate, MakerOrder as WsMakerOrder, OrderMessage, TradeMessage, WsError, WsMessage,
+};
+use crate::error::Error;
+use crate::types::{Address, ChainId, ConditionId, Decimal, TokenId};
+use crate::{POLYGON, Result};
+
+/// Number of events buffered for slow [`Client::subscribe_user_events`] consumers.
+const EVENT_CAPACITY: usize = 1024;
+
+const NOT_FOUND: &str = "order can't be found - already canceled or matched";
+
+/// Configuration for the paper [`Client`]
+#[derive(Clone, Debug, Builder)]
+pub struct Config {
+    /// Starting collateral balance, in USDC.
+    #[builder(default)]
+    collateral: Decimal,
+    /// Starting conditional token balances, keyed by token ID.
+    #[builder(default)]
+    positions: HashMap<TokenId, Decimal>,
+    /// Source of the current time, used for `GTD` expiry and event timestamps.
+    #[builder(default = Utc::now)]
+    clock: fn() -> DateTime<Utc>,
+    /// Chain the orders are signed for, which determines their order hashes and thus IDs.
+    #[builder(default = POLYGON)]
+    chain_id: ChainId,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Paper-trading client that matches [`SignedOrder`]s against locally maintained order books.
+///
+/// Cloning is cheap and clones share the same books, balances and orders.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: Arc<ClientInner>,
+}
+
+#[derive(Debug)]
+struct ClientInner {
+    clock: fn() -> DateTime<Utc>,
+    state: Mutex<State>,
+    events: broadcast::Sender<WsMessage>,
+}
+
+#[derive(Debug, Default)]
+struct Book {
+    market: ConditionId,
+    tick_size: Option<TickSize>,
+    neg_risk: bool,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl Book {
+    fn set_level(&mut self, side: Side, price: Decimal, size: Decimal) {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            _ => &mut self.asks,
+        };
+
+        if size.is_zero() {
+            levels.remove(&price);
+        } else {
+            levels.insert(price, size);
+        }
+    }
+
+    /// Price levels an order on `side` with a `limit` price could take, best first.
+    fn crossing(&self, side: Side, limit: Decimal) -> Vec<(Decimal, Decimal)> {
+        match side {
+            Side::Buy => self
+                .asks
+                .range(..=limit)
+                .map(|(price, size)| (*price, *size))
+                .collect(),
+            _ => self
+                .bids
+                .range(limit..)
+                .rev()
+                .map(|(price, size)| (*price, *size))
+                .collect(),
+        }
+    }
+
+    fn take(&mut self, side: Side, price: Decimal, size: Decimal) {
+        let levels = match side {
+            Side::Buy => &mut self.asks,
+            _ => &mut self.bids,
+        };
+
+        if let Some(level) = levels.get_mut(&price) {
+            *level -= size;
+            if level.is_zero() {
+                levels.remove(&price);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct PaperOrder {
+    id: String,
+    owner: ApiKey,
+    maker: Address,
+    market: ConditionId,
+    asset_id: TokenId,
+    side: Side,
+    price: Decimal,
+    original_size: Decimal,
+    size_matched: Decimal,
+    fee_rate_bps: Decimal,
+    order_type: OrderType,
+    status: OrderStatusType,
+    created_at: DateTime<Utc>,
+    expiration: Option<DateTime<Utc>>,
+    associate_trades: Vec<String>,
+}
+
+impl PaperOrder {
+    fn remaining(&self) -> Decimal {
+        self.original_size - self.size_matched
+    }
+
+    fn is_live(&self) -> bool {
+        self.status == OrderStatusType::Live
+    }
+
+    fn message(&self, msg_type: &str, now: DateTime<Utc>) -> WsMessage {
+        WsMessage::Order(OrderMessage {
+            id: self.id.clone(),
+            market: self.market,
+            asset_id: self.asset_id,
+            side: self.side,
+            price: self.price,
+            msg_type: Some(msg_type.to_owned()),
+            outcome: None,
+            owner: Some(self.owner),
+            order_owner: Some(self.owner),
+            original_size: Some(self.original_size),
+            size_matched: Some(self.size_matched),
+            timestamp: Some(now.timestamp().to_string()),
+            associate_trades: Some(self.associate_trades.clone()),
+        })
+    }
+
+    fn to_response(&self) -> OpenOrderResponse {
+        OpenOrderResponse::builder()
+            .id(&self.id)
+            .status(self.status)
+            .owner(self.owner)
+            .maker_address(self.maker)
+            .market(self.market)
+            .asset_id(self.asset_id)
+            .side(self.side)
+            .original_size(self.original_size)
+            .size_matched(self.size_matched)
+            .price(self.price)
+            .associate_trades(self.associate_trades.clone())
+            .outcome("")
+            .created_at(self.created_at)
+            .expiration(self.expiration.unwrap_or(DateTime::UNIX_EPOCH))
+            .order_type(self.order_type)
+            .build()
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    chain_id: ChainId,
+    books: HashMap<TokenId, Book>,
+    /// Every order accepted by the engine, in creation order.
+    orders: Vec<PaperOrder>,
+    trades: Vec<TradeResponse>,
+    collateral: Decimal,
+    positions: HashMap<TokenId, Decimal>,
+}
+
+/// Outcome of a single fill, from the point of view of the paper order.
+struct Fill {
+    price: Decimal,
+    size: Decimal,
+    fee: Fee,
+    trader_side: TraderSide,
+}
+
+impl State {
+    fn position(&self, asset_id: TokenId) -> Decimal {
+        self.positions.get(&asset_id).copied().unwrap_or_default()
+    }
+
+    /// Collateral not reserved by resting buy orders.
+    fn available_collateral(&self) -> Decimal {
+        self.orders
+            .iter()
+            .filter(|order| order.is_live() && order.side == Side::Buy)
+            .fold(self.collateral, |available, order| {
+                available - order.remaining() * order.price
+            })
+    }
+
+    /// Shares of `asset_id` not reserved by resting sell orders.
+    fn available_position(&self, asset_id: TokenId) -> Decimal {
+        self.orders
+            .iter()
+            .filter(|order| {
+                order.is_live() && order.side == Side::Sell && order.asset_id == asset_id
+            })
+            .fold(self.position(asset_id), |available, order| {
+                available - order.remaining()
+            })
+    }
+
+    fn post(
+        &mut self,
+        signed: &SignedOrder,
+        now: DateTime<Utc>,
+    ) -> Result<(PostOrderResponse, Vec<WsMessage>)> {
+        let mut events = self.expire(now);
+
+        let mut order = decode(signed, now)?;
+        let Some(book) = self.books.get(&order.asset_id) else {
+            return Err(rejected(format!(
+                "no order book exists for token {}",
+                order.asset_id
+            )));
+        };
+
+        order.id = signed.order_hash(self.chain_id, book.neg_risk)?.to_string();
+        if self.orders.iter().any(|existing| existing.id == order.id) {
+            return Err(rejected("order already exists"));
+        }
+        order.market = book.market;
+
+        if let Some(tick_size) = book.tick_size {
+            let tick = tick_size.as_decimal();
+            match tick_price(signed, order.side, order.price, tick)? {
+                Some(price) if price >= tick && price <= Decimal::ONE - tick => {
+                    order.price = price;
+                }
+                _ => {
+                    return Err(rejected(format!(
+                        "invalid price ({}), min: {tick} - max: {}",
+                        order.price,
+                        Decimal::ONE - tick
+                    )));
+                }
+            }
+        }
+
+        match order.order_type {
+            OrderType::GTD => {
+                if order.expiration.is_none_or(|expiration| expiration <= now) {
+                    return Err(rejected(
+                        "invalid expiration, GTD orders must expire in the future",
+                    ));
+                }
+            }
+            OrderType::GTC | OrderType::FOK | OrderType::FAK => order.expiration = None,
+            _ => {
+                return Err(rejected(format!(
+                    "unsupported order type {}",
+                    order.order_type
+                )));
+            }
+        }
+
+        let enough = match order.side {
+            Side::Buy => self.available_collateral() >= order.original_size * order.price,
+            _ => self.available_position(order.asset_id) >= order.original_size,
+        };
+        if !enough {
+            return Err(rejected("not enough balance / allowance"));
+        }
+
+        let crossing = book.crossing(order.side, order.price);
+        let liquidity: Decimal = crossing.iter().map(|(_, size)| size).sum();
+        match order.order_type {
+            OrderType::FOK if liquidity < order.original_size => {
+                return Err(rejected(
+                    "order couldn't be fully filled. FOK orders are fully filled or killed.",
+                ));
+            }
+            OrderType::FAK if liquidity.is_zero() => {
+                return Err(rejected(
+                    "no orders found to match with FAK order. FAK orders are partially filled or killed if no match is found.",
+                ));
+            }
+            _ => {}
+        }
+
+        let (mut making, mut taking) = (Decimal::ZERO, Decimal::ZERO);
+        let mut trade_ids = Vec::new();
+        for (price, available) in crossing {
+            let size = available.min(order.remaining());
+            if size.is_zero() {
+                break;
+            }
+
+            if let Some(book) = self.books.get_mut(&order.asset_id) {
+                book.take(order.side, price, size);
+            }
+            let fill = Fill {
+                price,
+                size,
+                fee: fees::fee(order.side, price, size, order.fee_rate_bps),
+                trader_side: TraderSide::Taker,
+            };
+            let (made, taken) = match order.side {
+                Side::Buy => (size * price, size - fill.fee.amount()),
+                _ => (size, size * price - fill.fee.amount()),
+            };
+            making += made;
+            taking += taken;
+
+            let trade = self.settle(&mut order, &fill, now);
+            trade_ids.push(trade.id.clone());
+            events.push(trade_message(&trade));
+            self.trades.push(trade);
+        }
+
+        // Unfilled `FAK` remainders are dropped, leaving the order matched for what it filled
+        order.status = if order.remaining().is_zero()
+            || !matches!(order.order_type, OrderType::GTC | OrderType::GTD)
+        {
+            OrderStatusType::Matched
+        } else {
+            events.push(order.message("PLACEMENT", now));
+            OrderStatusType::Live
+        };
+
+        let response = PostOrderResponse::builder()
+            .making_amount(making.normalize())
+            .taking_amount(taking.normalize())
+            .order_id(&order.id)
+            .status(order.status)
+            .success(true)
+            .trade_ids(trade_ids)
+            .build();
+        self.orders.push(order);
+
+        Ok((response, events))
+    }
+
+    /// Books a fill against `order`, moving balances and recording the resulting trade.
+    fn settle(&mut self, order: &mut PaperOrder, fill: &Fill, now: DateTime<Utc>) -> TradeResponse {
+        let cost = fill.size * fill.price;
+        let position = self.positions.entry(order.asset_id).or_default();
+        if order.side == Side::Buy {
+            self.collateral -= cost;
+            *position += fill.size - fill.fee.amount();
+        } else {
+            self.collateral += cost - fill.fee.amount();
+            *position -= fill.size;
+        }
+        order.size_matched += fill.size;
+
+        let id = Uuid::new_v4().to_string();
+        order.associate_trades.push(id.clone());
+
+        let (taker_order_id, maker_orders) = match fill.trader_side {
+            TraderSide::Maker => (
+                Uuid::new_v4().to_string(),
+                vec![
+                    MakerOrder::builder()
+                        .order_id(&order.id)
+                        .owner(order.owner)
+                        .maker_address(order.maker)
+                        .matched_amount(fill.size)
+                        .price(order.price)
+                        .fee_rate_bps(order.fee_rate_bps)
+                        .asset_id(order.asset_id)
+                        .outcome("")
+                        .side(order.side)
+                        .build(),
+                ],
+            ),
+            _ => (order.id.clone(), Vec::new()),
+        };
+
+        TradeResponse::builder()
+            .id(id)
+            .taker_order_id(taker_order_id)
+            .market(order.market)
+            .asset_id(order.asset_id)
+            .side(order.side)
+            .size(fill.size)
+            .fee_rate_bps(order.fee_rate_bps)
+            .price(fill.price)
+            .status(OrderStatusType::Matched)
+            .match_time(now)
+            .last_update(now)
+            .outcome("")
+            .bucket_index(0)
+            .owner(order.owner)
+            .maker_address(order.maker)
+            .maker_orders(maker_orders)
+            .transaction_hash("")
+            .trader_side(fill.trader_side.clone())
+            .build()
+    }
+
+    /// Fills resting orders on `asset_id` that the current book crosses, oldest first.
+    fn sweep(&mut self, asset_id: TokenId, now: DateTime<Utc>) -> Vec<WsMessage> {
+        let mut events = self.expire(now);
+
+        for index in 0..self.orders.len() {
+            let mut order = self.orders[index].clone();
+            if !order.is_live() || order.asset_id != asset_id {
+                continue;
+            }
+            let Some(book) = self.books.get(&asset_id) else {
+                break;
+            };
+
+            for (price, available) in book.crossing(order.side, order.price) {
+                let size = available.min(order.remaining());
+                if size.is_zero() {
+                    break;
+                }
+
+                if let Some(book) = self.books.get_mut(&asset_id) {
+                    book.take(order.side, price, size);
+                }
+                let fill = Fill {
+                    price: order.price,
+                    size,
+                    fee: fees::fee(order.side, order.price, size, Decimal::ZERO),
+                    trader_side: TraderSide::Maker,
+                };
+                let trade = self.settle(&mut order, &fill, now);
+                if order.remaining().is_zero() {
+                    order.status = OrderStatusType::Matched;
+                }
+                events.push(trade_message(&trade));
+                events.push(order.message("UPDATE", now));
+                self.trades.push(trade);
+            }
+
+            self.orders[index] = order;
+        }
+
+        events
+    }
+
+    /// Cancels `GTD` orders whose expiration has passed.
+    fn expire(&mut self, now: DateTime<Utc>) -> Vec<WsMessage> {
+        self.orders
+            .iter_mut()
+            .filter(|order| {
+                order.is_live() && order.expiration.is_some_and(|expiration| expiration <= now)
+            })
+            .map(|order| {
+                order.status = OrderStatusType::Canceled;
+                order.message("CANCELLATION", now)
+            })
+            .collect()
+    }
+
+    fn cancel(
+        &mut self,
+        order_id: &str,
+        now: DateTime<Utc>,
+    ) -> (CancelOrdersResponse, Vec<WsMessage>) {
+        let mut events = self.expire(now);
+
+        let response = match self
+            .orders
+            .iter_mut()
+            .find(|order| order.id == order_id && order.is_live())
+        {
+            Some(order) => {
+                order.status = OrderStatusType::Canceled;
+                events.push(order.message("CANCELLATION", now));
+                CancelOrdersResponse::builder()
+                    .canceled(vec![order_id.to_owned()])
+                    .build()
+            }
+            None => CancelOrdersResponse::builder()
+                .not_canceled(HashMap::from([(order_id.to_owned(), NOT_FOUND.to_owned())]))
+                .build(),
+        };
+
+        (response, events)
+    }
+}
+
+impl Client {
+    #[must_use]
+    pub fn new(config: Config) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        let state = State {
+            chain_id: config.chain_id,
+            collateral: config.collateral,
+            positions: config.positions,
+            ..State::default()
+        };
+
+        Self {
+            inner: Arc::new(ClientInner {
+                clock: config.clock,
+                state: Mutex::new(state),
+                events,
+            }),
+        }
+    }
+
+    /// Replaces the book for the snapshot's token, as returned by
+    /// [`order_book`](crate::clob::Client::order_book), and fills any resting orders it crosses.
+    pub fn update_book(&self, snapshot: &OrderBookSummaryResponse) {
+        self.with_book(snapshot.asset_id, |book| {
+            book.market = snapshot.market;
+            book.tick_size = Some(snapshot.tick_size);
+            book.neg_risk = snapshot.neg_risk;
+            book.bids = snapshot
+                .bids
+                .iter()
+                .map(|level| (level.price, level.size))
+                .collect();
+            book.asks = snapshot
+                .asks
+                .iter()
+                .map(|level| (level.price, level.size))
+                .collect();
+        });
+    }
+
+    /// Applies a market channel message to the local books. Messages that do not affect a book
+    /// are ignored.
+    pub fn apply(&self, message: &WsMessage) -> Result<()> {
+        match message {
+            WsMessage::Book(book) => self.apply_book(book),
+            WsMessage::PriceChange(change) => {
+                // Entries without a size do not say what is left at their level
+                for entry in &change.price_changes {
+                    let Some(size) = entry.size else {
+                        continue;
+                    };
+                    self.with_book(entry.asset_id, |book| {
+                        book.market = change.market;
+                        book.set_level(entry.side, entry.price, size);
+                    });
+                }
+            }
+            WsMessage::TickSizeChange(change) => {
+                let tick_size = TickSize::try_from(change.new_tick_size)?;
+                self.with_book(change.asset_id, |book| {
+                    book.market = change.market;
+                    book.tick_size = Some(tick_size);
+                });
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the book for the update's token with a market channel `book` snapshot.
+    pub fn apply_book(&self, update: &BookUpdate) {
+        self.with_book(update.asset_id, |book| {
+            book.market = update.market;
+            book.bids = update
+                .bids
+                .iter()
+                .map(|level| (level.price, level.size))
+                .collect();
+            book.asks = update
+                .asks
+                .iter()
+                .map(|level| (level.price, level.size))
+                .collect();
+        });
+    }
+
+    /// Subscribes to the synthetic `order` and `trade` events produced by the engine, in the
+    /// same shape as the authenticated user channel.
+    pub fn subscribe_user_events(&self) -> impl Stream<Item = Result<WsMessage>> {
+        let mut rx = self.inner.events.subscribe();
+
+        try_stream! {
+            loop {
+                match rx.recv().await {
+                    Ok(msg) => yield msg,
+                    Err(RecvError::Lagged(n)) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("Subscription lagged, missed {n} messages");
+                        Err(WsError::Lagged { count: n })?;
+                    }
+                    Err(RecvError::Closed) => {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn with_book(&self, asset_id: TokenId, update: impl FnOnce(&mut Book)) {
+        let now = self.now();
+        let events = {
+            let mut state = self.state();
+            update(state.books.entry(asset_id).or_default());
+            state.sweep(asset_id, now)
+        };
+        self.publish(events);
+    }
+
+    fn now(&self) -> DateTime<Utc> {
+        (self.inner.clock)()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, State> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn publish(&self, events: Vec<WsMessage>) {
+        for event in events {
+            // Nobody listening is not an error
+            _ = self.inner.events.send(event);
+        }
+    }
+
+    #[expect(
+        clippy::unused_async,
+        reason = "Mirrors the async surface of the authenticated CLOB client"
+    )]
+    pub async fn post_order(&self, order: SignedOrder) -> Result<PostOrderResponse> {
+        let now = self.now();
+        let result = self.state().post(&order, now);
+        let (response, events) = result?;
+        self.publish(events);
+
+        Ok(response)
+    }
+
+    /// Posts each order in turn. Rejected orders are reported in their response rather than
+    /// failing the whole batch.
+    pub async fn post_orders(&self, orders: Vec<SignedOrder>) -> Result<Vec<PostOrderResponse>> {
+        let mut responses = Vec::with_capacity(orders.len());
+        for order in orders {
+            let response = match self.post_order(order).await {
+                Ok(response) => response,
+                Err(e) => PostOrderResponse::builder()
+                    .error_msg(e.to_string())
+                    .making_amount(Decimal::ZERO)
+                    .taking_amount(Decimal::ZERO)
+                    .order_id("")
+                    .status(OrderStatusType::Unmatched)
+                    .success(false)
+                    .build(),
+            };
+            responses.push(response);
+        }
+
+        Ok(responses)
+    }
+
+    #[expect(
+        clippy::unused_async,
+        reason = "Mirrors the async surface of the authenticated CLOB client"
+    )]
+    pub async fn cancel_order(&self, order_id: &str) -> Result<CancelOrdersResponse> {
+        let now = self.now();
+        let (response, events) = self.state().cancel(order_id, now);
+        self.publish(events);
+
+        Ok(response)
+    }
+
+    /// Returns the live orders matching `request`. Every order is returned on a single page.
+    #[expect(
+        clippy::unused_async,
+        reason = "Mirrors the async surface of the authenticated CLOB client"
+    )]
+    pub async fn orders(
+        &self,
+        request: &OrdersRequest,
+        _next_cursor: Option<String>,
+    ) -> Result<Page<OpenOrderResponse>> {
+        let now = self.now();
+        let (data, events) = {
+            let mut state = self.state();
+            let events = state.expire(now);
+            let data: Vec<_> = state
+                .orders
+                .iter()
+                .filter(|order| order.is_live())
+                .filter(|order| request.order_id.as_ref().is_none_or(|id| &order.id == id))
+                .filter(|order| request.market.is_none_or(|market| order.market == market))
+                .filter(|order| {
+                    request
+                        .asset_id
+                        .is_none_or(|asset_id| order.asset_id == asset_id)
+                })
+                .map(PaperOrder::to_response)
+                .collect();
+            (data, events)
+        };
+        self.publish(events);
+
+        Ok(page(data))
+    }
+
+    /// Returns the paper trades matching `request`. Every trade is returned on a single page.
+    #[expect(
+        clippy::unused_async,
+        reason = "Mirrors the async surface of the authenticated CLOB client"
+    )]
+    pub async fn trades(
+        &self,
+        request: &TradesRequest,
+        _next_cursor: Option<String>,
+    ) -> Result<Page<TradeResponse>> {
+        let data = self
+            .state()
+            .trades
+            .iter()
+            .filter(|trade| request.id.as_ref().is_none_or(|id| &trade.id == id))
+            .filter(|trade| {
+                request.taker_address.is_none_or(|taker| {
+                    trade.trader_side == TraderSide::Taker && trade.maker_address == taker
+                })
+            })
+            .filter(|trade| {
+                request
+                    .maker_address
+                    .is_none_or(|maker| trade.maker_address == maker)
+            })
+            .filter(|trade| request.market.is_none_or(|market| trade.market == market))
+            .filter(|trade| {
+                request
+                    .asset_id
+                    .is_none_or(|asset_id| trade.asset_id == asset_id)
+            })
+            .filter(|trade| {
+                request
+                    .before
+                    .is_none_or(|before| trade.match_time.timestamp() < before)
+            })
+            .filter(|trade| {
+                request
+                    .after
+                    .is_none_or(|after| trade.match_time.timestamp() > after)
+            })
+            .cloned()
+            .collect();
+
+        Ok(page(data))
+    }
+
+    /// Returns the simulated balance in base units, as the exchange does. Allowances are always
+    /// empty since paper orders need none.
+    #[expect(
+        clippy::unused_async,
+        reason = "Mirrors the async surface of the authenticated CLOB client"
+    )]
+    pub async fn balance_allowance(
+        &self,
+        request: BalanceAllowanceRequest,
+    ) -> Result<BalanceAllowanceResponse> {
+        let state = self.state();
+        let balance = match request.asset_type {
+            AssetType::Collateral => state.collateral,
+            AssetType::Conditional => {
+                let token_id = request.token_id.ok_or(Error::validation(
+                    "token_id is required for conditional balances",
+                ))?;
+                state.position(token_id)
+            }
+            other => {
+                return Err(Error::validation(format!(
+                    "Unable to query balance for asset type {other}"
+                )));
+            }
+        };
+
+        let scale = Decimal::from(10_u64.pow(USDC_DECIMALS));
+        Ok(BalanceAllowanceResponse::builder()
+            .balance((balance * scale).normalize())
+            .build())
+    }
+}
+
+fn page<T>(data: Vec<T>) -> Page<T> {
+    let count = data.len() as u64;
+    Page::builder()
+        .data(data)
+        .next_cursor(TERMINAL_CURSOR)
+        .limit(count)
+        .count(count)
+        .build()
+}
+
+/// Error returned for orders the exchange would reject, mirroring its `400` response.
+fn rejected<S: Into<String>>(message: S) -> Error {
+    Error::status(
+        StatusCode::BAD_REQUEST,
+        Method::POST,
+        "/order".to_owned(),
+        message,
+    )
+}
+
+fn decimal(value: U256) -> Result<Decimal> {
+    Decimal::from_str(&value.to_string())
+        .map_err(|e| Error::validation(format!("Unable to convert {value} to a decimal: {e}")))
+}
+
+/// Recovers the price, size and bookkeeping fields of a [`SignedOrder`]. Its ID is the order hash,
+/// which depends on the market it is posted to.
+fn decode(signed: &SignedOrder, now: DateTime<Utc>) -> Result<PaperOrder> {
+    let order = &signed.order;
+    let side = Side::try_from(order.side)?;
+    let maker_amount = from_fixed(order.makerAmount)?;
+    let taker_amount = from_fixed(order.takerAmount)?;
+    if maker_amount.is_zero() || taker_amount.is_zero() {
+        return Err(rejected("invalid order amounts"));
+    }
+
+    let (price, size) = match side {
+        Side::Buy => (maker_amount / taker_amount, taker_amount),
+        _ => (taker_amount / maker_amount, maker_amount),
+    };
+
+    let expiration = match u64::try_from(order.expiration)
+        .ok()
+        .and_then(|s| i64::try_from(s).ok())
+    {
+        Some(0) => None,
+        Some(seconds) => DateTime::from_timestamp(seconds, 0),
+        None => return Err(rejected("invalid expiration")),
+    };
+
+    Ok(PaperOrder {
+        id: String::new(),
+        owner: signed.owner,
+        maker: order.maker,
+        market: ConditionId::default(),
+        asset_id: order.tokenId.into(),
+        side,
+        price: price.normalize(),
+        original_size: size.normalize(),
+        size_matched: Decimal::ZERO,
+        fee_rate_bps: decimal(order.feeRateBps)?,
+        order_type: signed.order_type,
+        status: OrderStatusType::Live,
+        created_at: now,
+        expiration,
+        associate_trades: Vec::new(),
+    })
+}
+
+/// The price on the `tick` grid a [`SignedOrder`] on `side` was built at, if its signed amounts
+/// agree with one.
+///
+/// The order builder truncates the amount it derives from the price to the tick size plus the
+/// lot size, so `price` as decoded from the amounts may be slightly off the tick, e.g. a market
+/// buy of $10 at 0.57 takes 17.5438 shares and decodes to about 0.570002. The nearest tick is
+/// accepted when rebuilding the amounts from it reproduces the signed ones.
+fn tick_price(
+    signed: &SignedOrder,
+    side: Side,
+    price: Decimal,
+    tick: Decimal,
+) -> Result<Option<Decimal>> {
+    let maker_amount = from_fixed(signed.order.makerAmount)?;
+    let taker_amount = from_fixed(signed.order.takerAmount)?;
+    let scale = tick.scale() + LOT_SIZE_SCALE;
+
+    let snapped = price.round_dp(tick.scale());
+    if snapped.is_zero() {
+        return Ok(None);
+    }
+
+    let agrees = match side {
+        // Limit buys and market buys of shares derive the collateral, market buys of collateral
+        // derive the shares
+        Side::Buy => {
+            maker_amount == (taker_amount * snapped).trunc_with_scale(scale)
+                || taker_amount == (maker_amount / snapped).trunc_with_scale(scale)
+        }
+        _ => taker_amount == (maker_amount * snapped).trunc_with_scale(scale),
+    };
+
+    Ok(agrees.then(|| snapped.normalize()))
+}
+
+fn trade_message(trade: &TradeResponse) -> WsMessage {
+    WsMessage::Trade(TradeMessage {
+        id: trade.id.clone(),
+        market: trade.market,
+        asset_id: trade.asset_id,
+        side: trade.side,
+        size: trade.size,
+        price: trade.price,
+        status: OrderStatusType::Matched.to_string(),
+        msg_type: Some("TRADE".to_owned()),
+        last_update: Some(trade.last_update.timestamp().to_string()),
+        matchtime: Some(trade.match_time.timestamp().to_string()),
+        timestamp: Some(trade.match_time.timestamp().to_string()),
+        outcome: None,
+        owner: Some(trade.owner),
+        trade_owner: Some(trade.owner),
+        taker_order_id: Some(trade.taker_order_id.clone()),
+        maker_orders: trade
+            .maker_orders
+            .iter()
+            .map(|maker| WsMakerOrder {
+                asset_id: maker.asset_id,
+                matched_amount: maker.matched_amount,
+                order_id: maker.order_id.clone(),
+                outcome: maker.outcome.clone(),
+                owner: maker.owner,
+                price: maker.price,
+            })
+            .collect(),
+        fee_rate_bps: Some(trade.fee_rate_bps.to_string()),
+        transaction_hash: None,
+        trader_side: Some(trade.trader_side.clone()),
+    })
+}
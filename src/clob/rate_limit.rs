@@ -0,0 +1,376 @@
+//! Client-side rate limiting for the CLOB API.
+//!
+//! Requests are grouped into [`EndpointFamily`]s, each with its own optional [`Quota`]. When a
+//! family's budget is exhausted, requests wait in line for their turn instead of failing, so a
+//! single [`Client`](super::Client) can safely be shared across many tasks.
+//!
+//! Each quota is enforced with a token bucket (implemented as a generic cell rate algorithm):
+//! up to `burst` requests are let through immediately, after which requests are spaced out
+//! evenly so that no more than `burst` requests are sent within any `period`.
+
+use std::num::NonZeroU32;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bon::Builder;
+use reqwest::{Method, Request, Response};
+
+use crate::Result;
+use crate::transport::HttpTransport;
+
+/// Group of CLOB endpoints sharing a rate limit budget.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EndpointFamily {
+    /// Public market data, e.g. `/book`, `/prices` and `/markets`
+    MarketData,
+    /// Order placement, i.e. `POST /order` and `POST /orders`
+    OrderPlacement,
+    /// Order cancellation, e.g. `DELETE /order` and `DELETE /cancel-all`
+    Cancel,
+    /// API key management under `/auth`
+    Auth,
+    /// Every other endpoint, e.g. open orders, trades, balances and rewards
+    Account,
+}
+
+impl EndpointFamily {
+    const ALL: [Self; 5] = [
+        Self::MarketData,
+        Self::OrderPlacement,
+        Self::Cancel,
+        Self::Auth,
+        Self::Account,
+    ];
+
+    /// Classifies a request by its method and its path relative to the CLOB host.
+    #[must_use]
+    pub fn classify(method: &Method, path: &str) -> Self {
+        let path = path.trim_start_matches('/');
+        let endpoint = path.split('/').next().unwrap_or_default();
+
+        match (method, endpoint) {
+            (_, "auth") => Self::Auth,
+            (&Method::POST, "order" | "orders") => Self::OrderPlacement,
+            (&Method::DELETE, "order" | "orders" | "cancel-all" | "cancel-market-orders") => {
+                Self::Cancel
+            }
+            (
+                _,
+                "time"
+                | "book"
+                | "books"
+                | "price"
+                | "prices"
+                | "prices-history"
+                | "midpoint"
+                | "midpoints"
+                | "spread"
+                | "spreads"
+                | "last-trade-price"
+                | "last-trades-prices"
+                | "tick-size"
+                | "neg-risk"
+                | "fee-rate"
+                | "markets"
+                | "sampling-markets"
+                | "simplified-markets"
+                | "sampling-simplified-markets",
+            ) => Self::MarketData,
+            _ => Self::Account,
+        }
+    }
+
+    const fn index(self) -> usize {
+        match self {
+            Self::MarketData => 0,
+            Self::OrderPlacement => 1,
+            Self::Cancel => 2,
+            Self::Auth => 3,
+            Self::Account => 4,
+        }
+    }
+}
+
+/// Allows up to `burst` requests within any `period`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quota {
+    pub burst: NonZeroU32,
+    pub period: Duration,
+}
+
+impl Quota {
+    #[must_use]
+    pub const fn new(burst: NonZeroU32, period: Duration) -> Self {
+        Self { burst, period }
+    }
+
+    /// Allows up to `requests` requests per second.
+    #[must_use]
+    pub const fn per_second(requests: NonZeroU32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+}
+
+/// Per [`EndpointFamily`] budgets. Families without a [`Quota`] are not limited.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::clob::Config;
+/// use polymarket_client_sdk::clob::rate_limit::{Config as RateLimitConfig, Quota};
+///
+/// let rate_limits = RateLimitConfig::builder()
+///     .market_data(Quota::new(NonZeroU32::new(100).unwrap(), Duration::from_secs(10)))
+///     .order_placement(Quota::per_second(NonZeroU32::new(20).unwrap()))
+///     .build();
+///
+/// let config = Config::builder().rate_limits(rate_limits).build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct Config {
+    pub market_data: Option<Quota>,
+    pub order_placement: Option<Quota>,
+    pub cancel: Option<Quota>,
+    pub auth: Option<Quota>,
+    pub account: Option<Quota>,
+}
+
+impl Config {
+    fn quota(&self, family: EndpointFamily) -> Option<Quota> {
+        match family {
+            EndpointFamily::MarketData => self.market_data,
+            EndpointFamily::OrderPlacement => self.order_placement,
+            EndpointFamily::Cancel => self.cancel,
+            EndpointFamily::Auth => self.auth,
+            EndpointFamily::Account => self.account,
+        }
+    }
+}
+
+/// Wait time metrics for a single [`EndpointFamily`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Number of requests that went through the limiter
+    pub requests: u64,
+    /// Number of requests that had to wait for their turn
+    pub throttled: u64,
+    /// Cumulative time requests spent waiting
+    pub total_wait: Duration,
+    /// Longest time a single request spent waiting
+    pub max_wait: Duration,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Time between two requests once the burst is used up
+    interval: Duration,
+    /// How far ahead of the schedule requests may be sent, i.e. the burst
+    tolerance: Duration,
+    /// Theoretical arrival time of the next request
+    next: Mutex<Option<Instant>>,
+}
+
+impl Bucket {
+    fn new(quota: Quota) -> Self {
+        let interval = quota.period / quota.burst.get();
+        Self {
+            interval,
+            tolerance: interval.saturating_mul(quota.burst.get() - 1),
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Reserves the next slot and returns how long to wait for it.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let arrival = next.map_or(now, |next| next.max(now));
+        let allowed_at = arrival.checked_sub(self.tolerance).unwrap_or(now);
+        *next = Some(arrival + self.interval);
+
+        allowed_at.saturating_duration_since(now)
+    }
+}
+
+/// Token bucket rate limiter holding one bucket per [`EndpointFamily`].
+#[derive(Debug, Default)]
+pub(crate) struct RateLimiter {
+    buckets: [Option<Bucket>; 5],
+    stats: [Mutex<Stats>; 5],
+}
+
+impl RateLimiter {
+    pub(crate) fn new(config: &Config) -> Self {
+        Self {
+            buckets: EndpointFamily::ALL.map(|family| config.quota(family).map(Bucket::new)),
+            stats: Default::default(),
+        }
+    }
+
+    /// Waits until a request of `family` may be sent.
+    pub(crate) async fn acquire(&self, family: EndpointFamily) {
+        let wait = self.buckets[family.index()]
+            .as_ref()
+            .map_or(Duration::ZERO, |bucket| bucket.reserve(Instant::now()));
+
+        self.record(family, wait);
+
+        if !wait.is_zero() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?family, ?wait, "Rate limit reached, delaying request");
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    pub(crate) fn stats(&self, family: EndpointFamily) -> Stats {
+        *self.stats[family.index()]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn record(&self, family: EndpointFamily, wait: Duration) {
+        let mut stats = self.stats[family.index()]
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        stats.requests += 1;
+        if !wait.is_zero() {
+            stats.throttled += 1;
+            stats.total_wait += wait;
+            stats.max_wait = stats.max_wait.max(wait);
+        }
+    }
+}
+
+/// [`HttpTransport`] that waits for its [`RateLimiter`] before sending each request, so that
+/// retries draw from the same budget as first attempts.
+#[derive(Debug)]
+pub(crate) struct Throttled<'client, T> {
+    transport: &'client T,
+    limiter: &'client RateLimiter,
+    /// Path of the host, stripped from request paths before classifying them
+    base_path: &'client str,
+}
+
+impl<'client, T: HttpTransport> Throttled<'client, T> {
+    pub(crate) fn new(
+        transport: &'client T,
+        limiter: &'client RateLimiter,
+        base_path: &'client str,
+    ) -> Self {
+        Self {
+            transport,
+            limiter,
+            base_path,
+        }
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport> HttpTransport for Throttled<'_, T> {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        let path = request.url().path();
+        let path = path.strip_prefix(self.base_path).unwrap_or(path);
+        self.limiter
+            .acquire(EndpointFamily::classify(request.method(), path))
+            .await;
+
+        self.transport.execute(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_endpoints() {
+        assert_eq!(
+            EndpointFamily::classify(&Method::GET, "/book"),
+            EndpointFamily::MarketData
+        );
+        assert_eq!(
+            EndpointFamily::classify(&Method::GET, "markets/0xabc"),
+            EndpointFamily::MarketData
+        );
+        assert_eq!(
+            EndpointFamily::classify(&Method::POST, "/orders"),
+            EndpointFamily::OrderPlacement
+        );
+        assert_eq!(
+            EndpointFamily::classify(&Method::DELETE, "/orders"),
+            EndpointFamily::Cancel
+        );
+        assert_eq!(
+            EndpointFamily::classify(&Method::GET, "/auth/derive-api-key"),
+            EndpointFamily::Auth
+        );
+        assert_eq!(
+            EndpointFamily::classify(&Method::GET, "/data/orders"),
+            EndpointFamily::Account
+        );
+    }
+
+    #[test]
+    fn bucket_allows_burst_then_spaces_requests() {
+        let bucket = Bucket::new(Quota::new(
+            NonZeroU32::new(3).unwrap(),
+            Duration::from_millis(300),
+        ));
+        let now = Instant::now();
+
+        let waits: Vec<_> = std::iter::repeat_with(|| bucket.reserve(now))
+            .take(5)
+            .collect();
+
+        assert_eq!(
+            waits,
+            vec![
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+            ]
+        );
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let bucket = Bucket::new(Quota::new(
+            NonZeroU32::new(2).unwrap(),
+            Duration::from_millis(200),
+        ));
+        let now = Instant::now();
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(100));
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn unlimited_family_records_stats_without_waiting() {
+        let limiter = RateLimiter::new(&Config::default());
+
+        limiter.acquire(EndpointFamily::MarketData).await;
+        limiter.acquire(EndpointFamily::MarketData).await;
+
+        let stats = limiter.stats(EndpointFamily::MarketData);
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.throttled, 0);
+        assert_eq!(stats.total_wait, Duration::ZERO);
+    }
+}
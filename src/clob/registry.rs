@@ -0,0 +1,499 @@
+//! Lookups between outcome tokens, their markets and their outcomes.
+//!
+//! A [`MarketRegistry`] maps every outcome [`TokenId`] to its market's [`ConditionId`], outcome
+//! name, complementary token and neg-risk flag, and every market back to its tokens. It is hydrated
+//! from the CLOB [`markets`](super::Client::markets_stream) and
+//! [`sampling_markets`](super::Client::sampling_markets_stream) streams and, with the `gamma`
+//! feature, from Gamma [`Market`](crate::gamma::types::response::Market)s. A registry can be
+//! [saved](MarketRegistry::save) to a JSON snapshot and [loaded](MarketRegistry::load) back on the
+//! next start, without walking every page again.
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "Registry types include the module name to indicate their scope"
+)]
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, Write as _};
+use std::path::Path;
+
+use futures::{Stream, TryStreamExt as _};
+use serde::{Deserialize, Serialize};
+
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob::Client;
+use crate::clob::types::response::MarketResponse;
+use crate::error::Error;
+use crate::transport::HttpTransport;
+use crate::types::{ConditionId, Decimal, TokenId};
+
+/// Version of the snapshot format written by [`MarketRegistry::save`].
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// One outcome of a market and the token that pays out on it.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OutcomeToken {
+    pub token_id: TokenId,
+    pub outcome: String,
+}
+
+impl OutcomeToken {
+    #[must_use]
+    pub fn new<O: Into<String>>(token_id: TokenId, outcome: O) -> Self {
+        Self {
+            token_id,
+            outcome: outcome.into(),
+        }
+    }
+}
+
+/// What the registry knows about a market.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MarketInfo {
+    pub condition_id: ConditionId,
+    pub question: Option<String>,
+    pub slug: Option<String>,
+    /// Outcome tokens, in the order of the market's outcomes
+    pub tokens: Vec<OutcomeToken>,
+    pub neg_risk: bool,
+    pub neg_risk_market_id: Option<String>,
+    pub minimum_tick_size: Option<Decimal>,
+    pub minimum_order_size: Option<Decimal>,
+    /// Base fee charged to makers, in basis points
+    #[serde(default)]
+    pub maker_base_fee: Option<Decimal>,
+    /// Base fee charged to takers, in basis points
+    #[serde(default)]
+    pub taker_base_fee: Option<Decimal>,
+    pub closed: bool,
+}
+
+impl MarketInfo {
+    #[must_use]
+    pub fn new(condition_id: ConditionId, tokens: Vec<OutcomeToken>) -> Self {
+        Self {
+            condition_id,
+            question: None,
+            slug: None,
+            tokens,
+            neg_risk: false,
+            neg_risk_market_id: None,
+            minimum_tick_size: None,
+            minimum_order_size: None,
+            maker_base_fee: None,
+            taker_base_fee: None,
+            closed: false,
+        }
+    }
+
+    /// Builds the registry entry of a CLOB market, or `None` if it lacks a condition ID or any of
+    /// its tokens lacks a token ID, as legacy and unlisted markets do.
+    #[must_use]
+    pub fn from_clob(market: &MarketResponse) -> Option<Self> {
+        let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_owned());
+
+        let tokens = market
+            .tokens
+            .iter()
+            .map(|token| Some(OutcomeToken::new(token.token_id?, token.outcome.clone())))
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            condition_id: market.condition_id?,
+            question: non_empty(&market.question),
+            slug: non_empty(&market.market_slug),
+            tokens,
+            neg_risk: market.neg_risk,
+            neg_risk_market_id: non_empty(&market.neg_risk_market_id),
+            minimum_tick_size: Some(market.minimum_tick_size),
+            minimum_order_size: Some(market.minimum_order_size),
+            maker_base_fee: Some(market.maker_base_fee),
+            taker_base_fee: Some(market.taker_base_fee),
+            closed: market.closed,
+        })
+    }
+
+    /// Builds the registry entry of a Gamma market, or `None` if it lacks a condition ID or its
+    /// token IDs do not line up with its outcomes.
+    #[cfg(feature = "gamma")]
+    #[must_use]
+    pub fn from_gamma(market: &crate::gamma::types::response::Market) -> Option<Self> {
+        let condition_id = market.condition_id?;
+        let token_ids = market.clob_token_ids.as_ref()?;
+        let outcomes: Vec<String> = serde_json::from_str(market.outcomes.as_deref()?).ok()?;
+        if token_ids.is_empty() || token_ids.len() != outcomes.len() {
+            return None;
+        }
+
+        let tokens = token_ids
+            .iter()
+            .zip(outcomes)
+            .map(|(token_id, outcome)| OutcomeToken::new(*token_id, outcome))
+            .collect();
+
+        Some(Self {
+            condition_id,
+            question: market.question.clone(),
+            slug: market.slug.clone(),
+            tokens,
+            neg_risk: market.neg_risk.unwrap_or(false),
+            neg_risk_market_id: market
+                .neg_risk_market_id
+                .clone()
+                .filter(|id| !id.is_empty()),
+            minimum_tick_size: market.order_price_min_tick_size,
+            minimum_order_size: market.order_min_size,
+            maker_base_fee: market.maker_base_fee.map(Decimal::from),
+            taker_base_fee: market.taker_base_fee.map(Decimal::from),
+            closed: market.closed.unwrap_or(false),
+        })
+    }
+
+    /// Returns the token paying out on `outcome`, compared case-insensitively.
+    #[must_use]
+    pub fn token(&self, outcome: &str) -> Option<&OutcomeToken> {
+        self.tokens
+            .iter()
+            .find(|token| token.outcome.eq_ignore_ascii_case(outcome))
+    }
+}
+
+#[derive(Serialize)]
+struct SnapshotRef<'registry> {
+    version: u32,
+    markets: Vec<&'registry MarketInfo>,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    version: u32,
+    markets: Vec<MarketInfo>,
+}
+
+/// Bidirectional lookups between markets, outcome tokens and outcomes.
+///
+/// # Examples
+///
+/// ```
+/// use alloy::primitives::B256;
+/// use polymarket_client_sdk::clob::registry::{MarketInfo, MarketRegistry, OutcomeToken};
+/// use polymarket_client_sdk::types::{ConditionId, TokenId};
+///
+/// let condition_id = ConditionId::new(B256::repeat_byte(1));
+/// let (yes, no) = (TokenId::from(1), TokenId::from(2));
+///
+/// let mut registry = MarketRegistry::new();
+/// registry.insert(MarketInfo::new(
+///     condition_id,
+///     vec![OutcomeToken::new(yes, "Yes"), OutcomeToken::new(no, "No")],
+/// ));
+///
+/// assert_eq!(registry.condition_id(yes), Some(condition_id));
+/// assert_eq!(registry.outcome(no), Some("No"));
+/// assert_eq!(registry.complement(yes), Some(no));
+/// assert_eq!(registry.token_id(condition_id, "yes"), Some(yes));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MarketRegistry {
+    markets: HashMap<ConditionId, MarketInfo>,
+    tokens: HashMap<TokenId, ConditionId>,
+}
+
+impl MarketRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of markets in the registry.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.markets.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.markets.is_empty()
+    }
+
+    /// Adds `market`, replacing any entry for the same condition ID and returning it. Optional
+    /// fields the new entry lacks are kept from the one it replaces.
+    pub fn insert(&mut self, mut market: MarketInfo) -> Option<MarketInfo> {
+        let previous = self.markets.remove(&market.condition_id);
+
+        if let Some(previous) = &previous {
+            for token in &previous.tokens {
+                self.tokens.remove(&token.token_id);
+            }
+
+            market.question = market.question.or_else(|| previous.question.clone());
+            market.slug = market.slug.or_else(|| previous.slug.clone());
+            market.neg_risk_market_id = market
+                .neg_risk_market_id
+                .or_else(|| previous.neg_risk_market_id.clone());
+            market.minimum_tick_size = market.minimum_tick_size.or(previous.minimum_tick_size);
+            market.minimum_order_size = market.minimum_order_size.or(previous.minimum_order_size);
+        }
+
+        for token in &market.tokens {
+            self.tokens.insert(token.token_id, market.condition_id);
+        }
+        self.markets.insert(market.condition_id, market);
+
+        previous
+    }
+
+    /// Removes the market with `condition_id` and its tokens.
+    pub fn remove(&mut self, condition_id: ConditionId) -> Option<MarketInfo> {
+        let market = self.markets.remove(&condition_id)?;
+        for token in &market.tokens {
+            self.tokens.remove(&token.token_id);
+        }
+
+        Some(market)
+    }
+
+    /// Iterates over the markets in the registry, in no particular order.
+    pub fn markets(&self) -> impl Iterator<Item = &MarketInfo> {
+        self.markets.values()
+    }
+
+    #[must_use]
+    pub fn market(&self, condition_id: ConditionId) -> Option<&MarketInfo> {
+        self.markets.get(&condition_id)
+    }
+
+    /// Returns the market `token_id` is an outcome of.
+    #[must_use]
+    pub fn market_of(&self, token_id: TokenId) -> Option<&MarketInfo> {
+        self.markets.get(self.tokens.get(&token_id)?)
+    }
+
+    #[must_use]
+    pub fn condition_id(&self, token_id: TokenId) -> Option<ConditionId> {
+        self.tokens.get(&token_id).copied()
+    }
+
+    /// Returns the name of the outcome `token_id` pays out on.
+    #[must_use]
+    pub fn outcome(&self, token_id: TokenId) -> Option<&str> {
+        self.market_of(token_id)?
+            .tokens
+            .iter()
+            .find(|token| token.token_id == token_id)
+            .map(|token| token.outcome.as_str())
+    }
+
+    /// Returns the other token of the binary market `token_id` belongs to.
+    #[must_use]
+    pub fn complement(&self, token_id: TokenId) -> Option<TokenId> {
+        match self.market_of(token_id)?.tokens.as_slice() {
+            [first, second] if first.token_id == token_id => Some(second.token_id),
+            [first, second] if second.token_id == token_id => Some(first.token_id),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn neg_risk(&self, token_id: TokenId) -> Option<bool> {
+        self.market_of(token_id).map(|market| market.neg_risk)
+    }
+
+    #[must_use]
+    pub fn minimum_tick_size(&self, token_id: TokenId) -> Option<Decimal> {
+        self.market_of(token_id)?.minimum_tick_size
+    }
+
+    /// Returns the maker base fee of the market `token_id` belongs to, in basis points.
+    #[must_use]
+    pub fn maker_base_fee(&self, token_id: TokenId) -> Option<Decimal> {
+        self.market_of(token_id)?.maker_base_fee
+    }
+
+    /// Returns the taker base fee of the market `token_id` belongs to, in basis points.
+    #[must_use]
+    pub fn taker_base_fee(&self, token_id: TokenId) -> Option<Decimal> {
+        self.market_of(token_id)?.taker_base_fee
+    }
+
+    /// Returns the token paying out on `outcome` of the market with `condition_id`, with the
+    /// outcome compared case-insensitively.
+    #[must_use]
+    pub fn token_id(&self, condition_id: ConditionId, outcome: &str) -> Option<TokenId> {
+        self.markets
+            .get(&condition_id)?
+            .token(outcome)
+            .map(|token| token.token_id)
+    }
+
+    /// Adds every market of the CLOB, returning how many were added. Legacy and unlisted markets
+    /// without a condition ID or token IDs are skipped.
+    pub async fn hydrate<S: State, T: HttpTransport>(
+        &mut self,
+        client: &Client<S, T>,
+    ) -> Result<usize> {
+        self.hydrate_from(client.markets_stream()).await
+    }
+
+    /// Adds the markets currently eligible for rewards, returning how many were added.
+    pub async fn hydrate_sampling<S: State, T: HttpTransport>(
+        &mut self,
+        client: &Client<S, T>,
+    ) -> Result<usize> {
+        self.hydrate_from(client.sampling_markets_stream()).await
+    }
+
+    /// Adds the Gamma markets matching `request`, returning how many were added. Markets without
+    /// a condition ID or with token IDs that do not line up with their outcomes are skipped.
+    #[cfg(feature = "gamma")]
+    pub async fn hydrate_gamma<T: HttpTransport>(
+        &mut self,
+        client: &crate::gamma::Client<T>,
+        request: &crate::gamma::types::request::MarketsRequest,
+    ) -> Result<usize> {
+        let mut count = 0;
+
+        let mut markets = client.markets_stream(request);
+        while let Some(market) = markets.try_next().await? {
+            if let Some(market) = MarketInfo::from_gamma(&market) {
+                self.insert(market);
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    async fn hydrate_from(
+        &mut self,
+        markets: impl Stream<Item = Result<MarketResponse>>,
+    ) -> Result<usize> {
+        let mut count = 0;
+
+        let mut markets = std::pin::pin!(markets);
+        while let Some(market) = markets.try_next().await? {
+            if let Some(market) = MarketInfo::from_clob(&market) {
+                self.insert(market);
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Writes a snapshot of the registry to `path`, replacing any existing file only once the
+    /// snapshot has been written in full.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut markets: Vec<_> = self.markets.values().collect();
+        markets.sort_by_key(|market| market.condition_id);
+
+        let snapshot = SnapshotRef {
+            version: SNAPSHOT_VERSION,
+            markets,
+        };
+
+        let partial = path.with_extension("partial");
+        let mut writer = BufWriter::new(File::create(&partial)?);
+        serde_json::to_writer(&mut writer, &snapshot)?;
+        writer.flush()?;
+        drop(writer);
+
+        fs::rename(partial, path)?;
+        Ok(())
+    }
+
+    /// Loads a registry from a snapshot written by [`save`](Self::save).
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let snapshot: Snapshot = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(Error::validation(format!(
+                "Unsupported market registry snapshot version {}, expected {SNAPSHOT_VERSION}",
+                snapshot.version
+            )));
+        }
+
+        Ok(snapshot.markets.into_iter().collect())
+    }
+}
+
+impl Extend<MarketInfo> for MarketRegistry {
+    fn extend<I: IntoIterator<Item = MarketInfo>>(&mut self, iter: I) {
+        for market in iter {
+            self.insert(market);
+        }
+    }
+}
+
+impl FromIterator<MarketInfo> for MarketRegistry {
+    fn from_iter<I: IntoIterator<Item = MarketInfo>>(iter: I) -> Self {
+        let mut registry = Self::new();
+        registry.extend(iter);
+        registry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::B256;
+
+    use super::*;
+
+    fn market(byte: u8, tokens: &[(u64, &str)]) -> MarketInfo {
+        MarketInfo::new(
+            ConditionId::new(B256::repeat_byte(byte)),
+            tokens
+                .iter()
+                .map(|(token_id, outcome)| OutcomeToken::new(TokenId::from(*token_id), *outcome))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn insert_should_drop_stale_tokens_and_keep_missing_fields() {
+        let mut registry = MarketRegistry::new();
+        let mut first = market(1, &[(1, "Yes"), (2, "No")]);
+        first.minimum_tick_size = Some(Decimal::new(1, 2));
+        registry.insert(first);
+
+        registry.insert(market(1, &[(3, "Yes"), (4, "No")]));
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.condition_id(TokenId::from(1)), None);
+        assert_eq!(
+            registry.complement(TokenId::from(4)),
+            Some(TokenId::from(3))
+        );
+        assert_eq!(
+            registry.minimum_tick_size(TokenId::from(3)),
+            Some(Decimal::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn complement_should_be_none_for_multi_outcome_markets() {
+        let registry: MarketRegistry = [market(1, &[(1, "A"), (2, "B"), (3, "C")])]
+            .into_iter()
+            .collect();
+
+        assert_eq!(registry.complement(TokenId::from(1)), None);
+        assert_eq!(registry.outcome(TokenId::from(3)), Some("C"));
+    }
+
+    #[test]
+    fn remove_should_drop_tokens() {
+        let mut registry: MarketRegistry =
+            [market(1, &[(1, "Yes"), (2, "No")])].into_iter().collect();
+
+        assert!(
+            registry
+                .remove(ConditionId::new(B256::repeat_byte(1)))
+                .is_some()
+        );
+        assert!(registry.is_empty());
+        assert_eq!(registry.market_of(TokenId::from(2)), None);
+    }
+}
@@ -0,0 +1,360 @@
+//! Pre-trade risk checks for order submission.
+//!
+//! When [`RiskLimits`] are set on the [`Config`](super::Config), every order passed to
+//! [`sign`](super::Client::sign) is checked against them before it is signed, and
+//! [`post_order`](super::Client::post_order) refuses to send anything while the kill switch is
+//! engaged. An order breaching a limit fails with a [`Kind::Validation`](crate::error::Kind)
+//! error whose source is a [`RiskViolation`] naming the [`RiskCheck`] that failed.
+//!
+//! Notional is measured in collateral, i.e. `price * size` for limit orders and the amount spent or
+//! received at the worst acceptable price for market orders. Open notional is the unmatched
+//! notional of the account's open orders, on both sides of the book, plus the order being checked.
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "Risk types include the module name to indicate their scope"
+)]
+
+use std::collections::VecDeque;
+use std::error::Error as StdError;
+use std::fmt;
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use bon::Builder;
+
+use crate::clob::order_builder::from_fixed;
+use crate::clob::types::response::OpenOrderResponse;
+use crate::clob::types::{Order, Side};
+use crate::error::{Error, Kind};
+use crate::types::Decimal;
+
+/// Limits enforced on every order before it is signed. Limits that are not set are not checked.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use polymarket_client_sdk::clob::Config;
+/// use polymarket_client_sdk::clob::risk::RiskLimits;
+/// use rust_decimal_macros::dec;
+///
+/// let risk_limits = RiskLimits::builder()
+///     .max_order_notional(dec!(500))
+///     .max_market_notional(dec!(2_000))
+///     .price_band(dec!(0.1))
+///     .max_orders_per_second(NonZeroU32::new(5).unwrap())
+///     .build();
+///
+/// let config = Config::builder().risk_limits(risk_limits).build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Builder)]
+pub struct RiskLimits {
+    /// Maximum notional of a single order
+    pub max_order_notional: Option<Decimal>,
+    /// Maximum open notional on a single token, including the new order
+    pub max_token_notional: Option<Decimal>,
+    /// Maximum open notional across all tokens of a market, including the new order
+    pub max_market_notional: Option<Decimal>,
+    /// Maximum distance between an order's price and the token's current midpoint, e.g. `0.05`
+    /// only accepts orders within five cents of the midpoint
+    pub price_band: Option<Decimal>,
+    /// Maximum number of orders signed within any one second window
+    pub max_orders_per_second: Option<NonZeroU32>,
+    /// Whether the kill switch starts engaged, rejecting every order until
+    /// [`resume_trading`](super::Client::resume_trading) is called
+    #[builder(default)]
+    pub halted: bool,
+}
+
+/// The risk check an order failed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RiskCheck {
+    /// [`RiskLimits::max_order_notional`]
+    OrderNotional,
+    /// [`RiskLimits::max_token_notional`]
+    TokenNotional,
+    /// [`RiskLimits::max_market_notional`]
+    MarketNotional,
+    /// [`RiskLimits::price_band`]
+    PriceBand,
+    /// [`RiskLimits::max_orders_per_second`]
+    OrderRate,
+    /// The kill switch is engaged
+    KillSwitch,
+}
+
+impl fmt::Display for RiskCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::OrderNotional => "max_order_notional",
+            Self::TokenNotional => "max_token_notional",
+            Self::MarketNotional => "max_market_notional",
+            Self::PriceBand => "price_band",
+            Self::OrderRate => "max_orders_per_second",
+            Self::KillSwitch => "kill_switch",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Error returned for an order rejected by a pre-trade risk check.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct RiskViolation {
+    pub check: RiskCheck,
+    pub reason: String,
+}
+
+impl RiskViolation {
+    fn new<S: Into<String>>(check: RiskCheck, reason: S) -> Self {
+        Self {
+            check,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for RiskViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "risk limit {} violated: {}", self.check, self.reason)
+    }
+}
+
+impl StdError for RiskViolation {}
+
+impl From<RiskViolation> for Error {
+    fn from(err: RiskViolation) -> Self {
+        Error::with_source(Kind::Validation, err)
+    }
+}
+
+/// Price and notional of an order about to be signed.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct OrderRisk {
+    pub(crate) price: Decimal,
+    pub(crate) notional: Decimal,
+}
+
+impl OrderRisk {
+    pub(crate) fn new(order: &Order) -> crate::Result<Self> {
+        let maker_amount = from_fixed(order.makerAmount)?;
+        let taker_amount = from_fixed(order.takerAmount)?;
+
+        let (notional, size) = match Side::try_from(order.side)? {
+            Side::Buy => (maker_amount, taker_amount),
+            _ => (taker_amount, maker_amount),
+        };
+        let price = if size.is_zero() {
+            Decimal::ZERO
+        } else {
+            notional / size
+        };
+
+        Ok(Self { price, notional })
+    }
+}
+
+/// Unmatched notional of an open order.
+pub(crate) fn open_notional(order: &OpenOrderResponse) -> Decimal {
+    (order.original_size - order.size_matched).max(Decimal::ZERO) * order.price
+}
+
+/// Enforces [`RiskLimits`] for a single client.
+#[derive(Debug, Default)]
+pub(crate) struct RiskGuard {
+    limits: RiskLimits,
+    halted: AtomicBool,
+    /// When each order within the last second passed the checks
+    recent: Mutex<VecDeque<Instant>>,
+}
+
+impl RiskGuard {
+    pub(crate) fn new(limits: &RiskLimits) -> Self {
+        Self {
+            halted: AtomicBool::new(limits.halted),
+            limits: limits.clone(),
+            recent: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    pub(crate) fn halt(&self) {
+        self.halted.store(true, Ordering::SeqCst);
+    }
+
+    pub(crate) fn resume(&self) {
+        self.halted.store(false, Ordering::SeqCst);
+    }
+
+    pub(crate) fn is_halted(&self) -> bool {
+        self.halted.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn check_halted(&self) -> Result<(), RiskViolation> {
+        if self.is_halted() {
+            return Err(RiskViolation::new(
+                RiskCheck::KillSwitch,
+                "trading is halted",
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_order(&self, order: OrderRisk) -> Result<(), RiskViolation> {
+        self.check_halted()?;
+
+        if let Some(max) = self.limits.max_order_notional
+            && order.notional > max
+        {
+            return Err(RiskViolation::new(
+                RiskCheck::OrderNotional,
+                format!("order notional {} exceeds {max}", order.notional),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_price(
+        &self,
+        order: OrderRisk,
+        midpoint: Decimal,
+    ) -> Result<(), RiskViolation> {
+        if let Some(band) = self.limits.price_band
+            && (order.price - midpoint).abs() > band
+        {
+            return Err(RiskViolation::new(
+                RiskCheck::PriceBand,
+                format!(
+                    "price {} is more than {band} away from the midpoint {midpoint}",
+                    order.price
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_open_notional(
+        &self,
+        check: RiskCheck,
+        open: Decimal,
+        order: OrderRisk,
+    ) -> Result<(), RiskViolation> {
+        let max = match check {
+            RiskCheck::TokenNotional => self.limits.max_token_notional,
+            RiskCheck::MarketNotional => self.limits.max_market_notional,
+            _ => None,
+        };
+
+        if let Some(max) = max
+            && open + order.notional > max
+        {
+            return Err(RiskViolation::new(
+                check,
+                format!(
+                    "open notional {open} plus order notional {} exceeds {max}",
+                    order.notional
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Counts an order towards [`RiskLimits::max_orders_per_second`], unless it would exceed it.
+    pub(crate) fn record(&self, now: Instant) -> Result<(), RiskViolation> {
+        let Some(max) = self.limits.max_orders_per_second else {
+            return Ok(());
+        };
+
+        let mut recent = self.recent.lock().unwrap_or_else(PoisonError::into_inner);
+        while recent
+            .front()
+            .is_some_and(|at| now.saturating_duration_since(*at) >= Duration::from_secs(1))
+        {
+            recent.pop_front();
+        }
+
+        if recent.len() >= max.get() as usize {
+            return Err(RiskViolation::new(
+                RiskCheck::OrderRate,
+                format!("more than {max} orders within one second"),
+            ));
+        }
+        recent.push_back(now);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::U256;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn order_risk_should_recover_price_and_notional() {
+        let buy = Order {
+            makerAmount: U256::from(34_000_000),
+            takerAmount: U256::from(100_000_000),
+            side: Side::Buy as u8,
+            ..Order::default()
+        };
+        let sell = Order {
+            makerAmount: U256::from(100_000_000),
+            takerAmount: U256::from(34_000_000),
+            side: Side::Sell as u8,
+            ..Order::default()
+        };
+
+        for order in [buy, sell] {
+            let risk = OrderRisk::new(&order).unwrap();
+            assert_eq!(risk.price, dec!(0.34));
+            assert_eq!(risk.notional, dec!(34));
+        }
+    }
+
+    #[test]
+    fn record_should_enforce_orders_per_second() {
+        let guard = RiskGuard::new(
+            &RiskLimits::builder()
+                .max_orders_per_second(NonZeroU32::new(2).unwrap())
+                .build(),
+        );
+        let now = Instant::now();
+
+        guard.record(now).unwrap();
+        guard.record(now).unwrap();
+        let violation = guard.record(now).unwrap_err();
+        assert_eq!(violation.check, RiskCheck::OrderRate);
+
+        guard.record(now + Duration::from_secs(1)).unwrap();
+    }
+
+    #[test]
+    fn kill_switch_should_reject_until_resumed() {
+        let guard = RiskGuard::new(&RiskLimits::builder().halted(true).build());
+        let order = OrderRisk {
+            price: dec!(0.5),
+            notional: dec!(1),
+        };
+
+        let violation = guard.check_order(order).unwrap_err();
+        assert_eq!(violation.check, RiskCheck::KillSwitch);
+
+        guard.resume();
+        guard.check_order(order).unwrap();
+    }
+}
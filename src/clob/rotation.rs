@@ -0,0 +1,184 @@
+//! Zero-downtime rotation of the API key of an authenticated CLOB client.
+//!
+//! Rotating a key by hand means deleting the old one and deriving a new one, which leaves the
+//! client, and any websocket or RTDS connection sharing its credentials, unable to authenticate
+//! in between. [`ApiKeyRotation`] instead creates the new key first, checks that the server
+//! accepts it, swaps it into the client and every [attached](ApiKeyRotation::attach) client
+//! through [`SwapCredentials`], and only then deletes the old key. If any of these steps fails,
+//! the swapped clients are switched back to the old key and the new key is deleted again, so the
+//! rotation either completes or leaves everything as it was.
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "Rotation types include the module name to indicate their scope"
+)]
+
+use rand::Rng as _;
+
+use crate::Result;
+use crate::auth::state::Authenticated;
+use crate::auth::{ApiKey, Credentials, Kind, Signer, SwapCredentials};
+use crate::clob::Client;
+use crate::error::Error;
+use crate::transport::HttpTransport;
+
+/// Rotates the API key of a [`Client`], created by [`Client::rotate_api_key`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::str::FromStr as _;
+///
+/// use alloy::signers::Signer as _;
+/// use alloy::signers::local::LocalSigner;
+/// use polymarket_client_sdk::clob::{Client, Config};
+/// use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let private_key = std::env::var(PRIVATE_KEY_VAR).expect("Need a private key");
+///     let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+///     let client = Client::new("https://clob.polymarket.com", Config::default())?
+///         .authentication_builder(&signer)
+///         .authenticate()
+///         .await?;
+///
+///     let rotated = client.rotate_api_key(&signer).rotate().await?;
+///     println!("Replaced {} with {}", rotated.previous_key, rotated.credentials.key());
+///
+///     Ok(())
+/// }
+/// ```
+#[must_use = "the rotation does nothing until `rotate` is awaited"]
+pub struct ApiKeyRotation<'client, S: Signer, K: Kind, T: HttpTransport> {
+    client: &'client Client<Authenticated<K>, T>,
+    signer: &'client S,
+    nonce: Option<u32>,
+    attached: Vec<&'client dyn SwapCredentials>,
+}
+
+/// The outcome of a successful [`ApiKeyRotation`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct RotatedApiKey {
+    /// The credentials now in use by the client and every attached client.
+    pub credentials: Credentials,
+    /// The nonce the new key was created with, needed to derive it again later.
+    pub nonce: u32,
+    /// The key that was deleted.
+    pub previous_key: ApiKey,
+}
+
+impl<'client, S: Signer, K: Kind + Send + Sync, T: HttpTransport> ApiKeyRotation<'client, S, K, T> {
+    pub(crate) fn new(client: &'client Client<Authenticated<K>, T>, signer: &'client S) -> Self {
+        Self {
+            client,
+            signer,
+            nonce: None,
+            attached: Vec::new(),
+        }
+    }
+
+    /// Creates the new key with `nonce` instead of a random one.
+    pub fn nonce(mut self, nonce: u32) -> Self {
+        self.nonce = Some(nonce);
+        self
+    }
+
+    /// Swaps the new key into `client` as well, e.g. a [`crate::clob::ws::Client`] or
+    /// [`crate::rtds::Client`] whose user subscriptions authenticate with the rotated key.
+    pub fn attach<C: SwapCredentials>(mut self, client: &'client C) -> Self {
+        self.attached.push(client);
+        self
+    }
+
+    /// Creates the new key, swaps it into the client and every attached client, and deletes the
+    /// old key, rolling everything back if a step fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the signer does not match the client, or if creating or verifying the
+    /// new key, swapping it in or deleting the old key fails. The client and every attached
+    /// client then keep using the old key.
+    pub async fn rotate(self) -> Result<RotatedApiKey> {
+        let client = self.client;
+        if self.signer.address() != client.address() {
+            return Err(Error::validation(format!(
+                "Signer {} does not match the authenticated address {}",
+                self.signer.address(),
+                client.address()
+            )));
+        }
+
+        let nonce = self.nonce.unwrap_or_else(|| rand::rng().random());
+        let previous = client.state().credentials();
+        let credentials = client.create_api_key_with(self.signer, nonce).await?;
+        if credentials.key() == previous.key() {
+            return Err(Error::validation(format!(
+                "Nonce {nonce} derives the API key already in use, pick another one"
+            )));
+        }
+
+        if let Err(e) = client.api_keys_with(&credentials).await {
+            self.discard(&credentials).await;
+            return Err(e);
+        }
+
+        let targets = self.targets();
+        for (swapped, target) in targets.iter().enumerate() {
+            if let Err(e) = target.swap_credentials(&credentials) {
+                Self::swap_back(&targets[..swapped], &previous);
+                self.discard(&credentials).await;
+                return Err(e);
+            }
+        }
+
+        if let Err(e) = client.delete_api_key_with(&previous).await {
+            // The delete may have gone through even though its response did not make it back.
+            let deleted = client
+                .api_keys_with(&credentials)
+                .await
+                .ok()
+                .and_then(|response| response.keys)
+                .is_some_and(|keys| !keys.contains(&previous.key()));
+            if !deleted {
+                Self::swap_back(&targets, &previous);
+                self.discard(&credentials).await;
+                return Err(e);
+            }
+        }
+
+        Ok(RotatedApiKey {
+            previous_key: previous.key(),
+            credentials,
+            nonce,
+        })
+    }
+
+    fn targets(&self) -> Vec<&'client dyn SwapCredentials> {
+        let client: &'client dyn SwapCredentials = self.client;
+        std::iter::once(client)
+            .chain(self.attached.iter().copied())
+            .collect()
+    }
+
+    fn swap_back(targets: &[&dyn SwapCredentials], previous: &Credentials) {
+        for target in targets {
+            if let Err(e) = target.swap_credentials(previous) {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %e, "Failed to swap the previous API key back in");
+                #[cfg(not(feature = "tracing"))]
+                let _: &Error = &e;
+            }
+        }
+    }
+
+    /// Deletes the new key after a failed rotation, so it does not linger on the account.
+    async fn discard(&self, credentials: &Credentials) {
+        if let Err(e) = self.client.delete_api_key_with(credentials).await {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, key = %credentials.key(), "Failed to delete the API key of a failed rotation");
+            #[cfg(not(feature = "tracing"))]
+            let _: &Error = &e;
+        }
+    }
+}
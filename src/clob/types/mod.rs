@@ -1,7 +1,10 @@
+use std::borrow::Cow;
 use std::fmt;
 
 use alloy::core::sol;
-use alloy::primitives::{Signature, U256};
+use alloy::dyn_abi::Eip712Domain;
+use alloy::primitives::{B256, ChainId, Signature, U256};
+use alloy::sol_types::SolStruct as _;
 use bon::Builder;
 use rust_decimal::prelude::ToPrimitive as _;
 use rust_decimal_macros::dec;
@@ -12,11 +15,12 @@ use serde_repr::Serialize_repr;
 use serde_with::{DisplayFromStr, serde_as};
 use strum_macros::Display;
 
-use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::order_builder::{LOT_SIZE_SCALE, USDC_DECIMALS};
+use crate::clob::types::response::OrderBookSummaryResponse;
 use crate::error::Error;
 use crate::types::Decimal;
+use crate::{Result, contract_config, derive_proxy_wallet, derive_safe_wallet};
 
 pub mod request;
 pub mod response;
@@ -240,6 +244,21 @@ pub enum SignatureType {
     GnosisSafe = 2,
 }
 
+impl TryFrom<u8> for SignatureType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(SignatureType::Eoa),
+            1 => Ok(SignatureType::Proxy),
+            2 => Ok(SignatureType::GnosisSafe),
+            other => Err(Error::validation(format!(
+                "Unable to create SignatureType from {other}"
+            ))),
+        }
+    }
+}
+
 /// RFQ state filter for queries.
 #[cfg(feature = "rfq")]
 #[non_exhaustive]
@@ -407,9 +426,9 @@ sol! {
     /// -->
     #[non_exhaustive]
     #[serde_as]
-    #[derive(Serialize, Debug, Default, PartialEq)]
+    #[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
     struct Order {
-        #[serde(serialize_with = "ser_salt")]
+        #[serde(serialize_with = "ser_salt", deserialize_with = "de_salt")]
         uint256 salt;
         address maker;
         address signer;
@@ -431,6 +450,25 @@ sol! {
     }
 }
 
+const ORDER_NAME: Option<Cow<'static, str>> = Some(Cow::Borrowed("Polymarket CTF Exchange"));
+const VERSION: Option<Cow<'static, str>> = Some(Cow::Borrowed("1"));
+
+/// EIP-712 domain of the exchange contract that orders for `chain_id` and `neg_risk` are signed
+/// against.
+pub(crate) fn order_domain(chain_id: ChainId, neg_risk: bool) -> Result<Eip712Domain> {
+    let exchange_contract = contract_config(chain_id, neg_risk)
+        .ok_or(Error::missing_contract_config(chain_id, neg_risk))?
+        .exchange;
+
+    Ok(Eip712Domain {
+        name: ORDER_NAME,
+        version: VERSION,
+        chain_id: Some(U256::from(chain_id)),
+        verifying_contract: Some(exchange_contract),
+        ..Eip712Domain::default()
+    })
+}
+
 // CLOB expects salt as a JSON number. U256 as an integer will not fit as a JSON number. Since
 // we generated the salt as a u64 originally (see `salt_generator`), we can be very confident that
 // we can invert the conversion to U256 and return a u64 when serializing.
@@ -441,11 +479,33 @@ fn ser_salt<S: Serializer>(value: &U256, serializer: S) -> std::result::Result<S
     serializer.serialize_u64(v)
 }
 
+fn de_salt<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<U256, D::Error> {
+    u64::deserialize(deserializer).map(U256::from)
+}
+
+/// Market metadata for building and signing orders without querying the CLOB, e.g. from a
+/// previous call to [`Client::market_params`](crate::clob::Client::market_params).
 #[non_exhaustive]
-#[derive(Clone, Debug, Default, Serialize, Builder, PartialEq)]
+#[derive(Clone, Debug, Builder, PartialEq)]
+pub struct MarketParams {
+    pub tick_size: TickSize,
+    pub fee_rate_bps: u32,
+    /// Whether the token trades on the neg risk exchange, which determines the exchange the
+    /// order is signed for
+    pub neg_risk: bool,
+    /// Order book used to price market orders that have no explicit price
+    pub book: Option<OrderBookSummaryResponse>,
+}
+
+#[non_exhaustive]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Builder, PartialEq)]
 pub struct SignableOrder {
     pub order: Order,
     pub order_type: OrderType,
+    /// Whether the token trades on the neg risk exchange, when known from [`MarketParams`].
+    /// Otherwise it is looked up when signing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub neg_risk: Option<bool>,
 }
 
 #[non_exhaustive]
@@ -457,6 +517,54 @@ pub struct SignedOrder {
     pub owner: ApiKey,
 }
 
+impl SignedOrder {
+    /// Hash the exchange identifies this order by, i.e. the EIP-712 hash of [`Self::order`] that
+    /// [`Self::signature`] signs, on the `neg_risk` or regular exchange for `chain_id`.
+    pub fn order_hash(&self, chain_id: ChainId, neg_risk: bool) -> Result<B256> {
+        Ok(self
+            .order
+            .eip712_signing_hash(&order_domain(chain_id, neg_risk)?))
+    }
+
+    /// Checks that [`Self::signature`] was made by `order.signer` for the `neg_risk` or regular
+    /// exchange for `chain_id`, and that `order.maker` is the wallet the exchange expects for
+    /// `order.signatureType`: the signer itself for [`SignatureType::Eoa`], or its proxy or Safe
+    /// wallet for [`SignatureType::Proxy`] and [`SignatureType::GnosisSafe`].
+    pub fn verify(&self, chain_id: ChainId, neg_risk: bool) -> Result<()> {
+        let hash = self.order_hash(chain_id, neg_risk)?;
+        let signer = self.order.signer;
+
+        let recovered = self
+            .signature
+            .recover_address_from_prehash(&hash)
+            .map_err(|e| Error::validation(format!("Unable to recover order signer: {e}")))?;
+        if recovered != signer {
+            return Err(Error::validation(format!(
+                "Order is signed by {recovered}, not by its signer {signer}"
+            )));
+        }
+
+        let signature_type = SignatureType::try_from(self.order.signatureType)?;
+        let expected_maker = match signature_type {
+            SignatureType::Eoa => Some(signer),
+            SignatureType::Proxy => derive_proxy_wallet(signer, chain_id),
+            SignatureType::GnosisSafe => derive_safe_wallet(signer, chain_id),
+        }
+        .ok_or(Error::validation(format!(
+            "No {signature_type} wallet for signer {signer} on chain {chain_id}"
+        )))?;
+
+        if self.order.maker != expected_maker {
+            return Err(Error::validation(format!(
+                "Order maker {} is not the {signature_type} wallet {expected_maker} of signer {signer}",
+                self.order.maker
+            )));
+        }
+
+        Ok(())
+    }
+}
+
 // CLOB expects a struct that has the `signature` "folded" into the `order` key
 impl Serialize for SignedOrder {
     fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
@@ -490,11 +598,80 @@ impl Serialize for SignedOrder {
     }
 }
 
+// Inverse of the `Serialize` impl above, accepting exactly what `post_order` sends
+impl<'de> Deserialize<'de> for SignedOrder {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Inner {
+            order: Value,
+            order_type: OrderType,
+            owner: ApiKey,
+        }
+
+        let Inner {
+            mut order,
+            order_type,
+            owner,
+        } = Inner::deserialize(deserializer)?;
+
+        // extract the signature folded into the order object
+        let signature = match order
+            .as_object_mut()
+            .and_then(|map| map.remove("signature"))
+        {
+            Some(Value::String(signature)) => signature.parse().map_err(de::Error::custom)?,
+            Some(other) => return Err(de::Error::custom(format!("invalid signature {other}"))),
+            None => return Err(de::Error::missing_field("signature")),
+        };
+
+        // restore the integer representation of the side that was signed
+        if let Some(value) = order.get_mut("side")
+            && value.is_string()
+        {
+            let side = Side::deserialize(value.take()).map_err(de::Error::custom)?;
+            if side == Side::Unknown {
+                return Err(de::Error::custom("unknown order side"));
+            }
+            *value = Value::from(side as u8);
+        }
+
+        Ok(Self {
+            order: Order::deserialize(order).map_err(de::Error::custom)?,
+            signature,
+            order_type,
+            owner,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use alloy::primitives::address;
+    use uuid::Uuid;
+
     use super::*;
     use crate::error::Validation;
 
+    fn order(side: Side) -> Order {
+        Order {
+            salt: U256::from(479_249_096_354_u64),
+            maker: address!("0xd1615A7B6146cDbA40a559eC876A3bcca4050890"),
+            signer: address!("0x2e86D2cFC4E9b9E1Bd6c2d9e3B1e7C9F8a7d5c41"),
+            tokenId: U256::from_str_radix(
+                "15871154585880608648532107628464183779895785213830018178010423617714102767076",
+                10,
+            )
+            .unwrap(),
+            makerAmount: U256::from(5_000_000),
+            takerAmount: U256::from(10_000_000),
+            feeRateBps: U256::from(100),
+            side: side as u8,
+            signatureType: SignatureType::GnosisSafe as u8,
+            ..Order::default()
+        }
+    }
+
     #[test]
     fn tick_size_decimals_should_succeed() {
         assert_eq!(TickSize::Tenth.as_decimal().scale(), 1);
@@ -584,4 +761,84 @@ mod tests {
         assert_eq!(Side::Buy.to_string(), "BUY");
         assert_eq!(Side::Sell.to_string(), "SELL");
     }
+
+    #[test]
+    fn signature_type_should_round_trip_through_u8() {
+        for signature_type in [
+            SignatureType::Eoa,
+            SignatureType::Proxy,
+            SignatureType::GnosisSafe,
+        ] {
+            assert_eq!(
+                SignatureType::try_from(signature_type as u8).unwrap(),
+                signature_type
+            );
+        }
+
+        let err = SignatureType::try_from(3).unwrap_err();
+        let message = err.downcast_ref::<Validation>().unwrap();
+        assert_eq!(message.reason, "Unable to create SignatureType from 3");
+    }
+
+    #[test]
+    fn signed_order_should_round_trip() {
+        for side in [Side::Buy, Side::Sell] {
+            let signed_order = SignedOrder::builder()
+                .order(order(side))
+                .signature(Signature::new(U256::from(1), U256::from(2), true))
+                .order_type(OrderType::GTD)
+                .owner(Uuid::max())
+                .build();
+
+            let json = serde_json::to_string(&signed_order).unwrap();
+            assert!(json.contains(&format!("\"side\":\"{side}\"")), "{json}");
+
+            let deserialized: SignedOrder = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, signed_order);
+            assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn signed_order_without_valid_signature_should_fail() {
+        let signed_order = SignedOrder::builder()
+            .order(order(Side::Buy))
+            .signature(Signature::new(U256::from(1), U256::from(2), false))
+            .order_type(OrderType::GTC)
+            .owner(Uuid::nil())
+            .build();
+        let json = serde_json::to_value(&signed_order).unwrap();
+
+        let mut missing = json.clone();
+        missing["order"]
+            .as_object_mut()
+            .unwrap()
+            .remove("signature");
+        let err = serde_json::from_value::<SignedOrder>(missing).unwrap_err();
+        assert!(
+            err.to_string().contains("missing field `signature`"),
+            "{err}"
+        );
+
+        let mut invalid = json;
+        invalid["order"]["signature"] = Value::from("0x1234");
+        serde_json::from_value::<SignedOrder>(invalid).unwrap_err();
+    }
+
+    #[test]
+    fn signable_order_should_round_trip() {
+        for neg_risk in [None, Some(true)] {
+            let signable_order = SignableOrder::builder()
+                .order(order(Side::Sell))
+                .order_type(OrderType::FAK)
+                .maybe_neg_risk(neg_risk)
+                .build();
+
+            let json = serde_json::to_string(&signable_order).unwrap();
+            let deserialized: SignableOrder = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(deserialized, signable_order);
+            assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+        }
+    }
 }
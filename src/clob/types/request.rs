@@ -13,22 +13,22 @@ use crate::auth::ApiKey;
 use crate::clob::types::{AssetType, Side, SignatureType, TimeRange};
 #[cfg(feature = "rfq")]
 use crate::clob::types::{RfqSortBy, RfqSortDir, RfqState};
-use crate::types::Address;
 #[cfg(feature = "rfq")]
 use crate::types::Decimal;
+use crate::types::{Address, ConditionId, TokenId};
 
 #[non_exhaustive]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct MidpointRequest {
-    pub token_id: String,
+    pub token_id: TokenId,
 }
 
 #[non_exhaustive]
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct PriceRequest {
-    pub token_id: String,
+    pub token_id: TokenId,
     pub side: Side,
 }
 
@@ -37,7 +37,7 @@ pub struct PriceRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct SpreadRequest {
-    pub token_id: String,
+    pub token_id: TokenId,
     pub side: Option<Side>,
 }
 
@@ -46,7 +46,7 @@ pub struct SpreadRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct OrderBookSummaryRequest {
-    pub token_id: String,
+    pub token_id: TokenId,
     pub side: Option<Side>,
 }
 
@@ -54,7 +54,7 @@ pub struct OrderBookSummaryRequest {
 #[derive(Debug, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct LastTradePriceRequest {
-    pub token_id: String,
+    pub token_id: TokenId,
 }
 
 #[non_exhaustive]
@@ -63,7 +63,7 @@ pub struct LastTradePriceRequest {
 #[builder(on(String, into))]
 pub struct PriceHistoryRequest {
     /// The market (condition ID) to get price history for.
-    pub market: String,
+    pub market: ConditionId,
     /// The time range for the price history query.
     /// Either a predefined interval or explicit start/end timestamps.
     #[serde(flatten)]
@@ -77,8 +77,8 @@ pub struct PriceHistoryRequest {
 #[derive(Debug, Default, Serialize, Builder)]
 #[builder(on(String, into))]
 pub struct CancelMarketOrderRequest {
-    pub market: Option<String>,
-    pub asset_id: Option<String>,
+    pub market: Option<ConditionId>,
+    pub asset_id: Option<TokenId>,
 }
 
 #[non_exhaustive]
@@ -90,8 +90,8 @@ pub struct TradesRequest {
     pub taker_address: Option<Address>,
     #[serde(rename = "maker")]
     pub maker_address: Option<Address>,
-    pub market: Option<String>,
-    pub asset_id: Option<String>,
+    pub market: Option<ConditionId>,
+    pub asset_id: Option<TokenId>,
     pub before: Option<i64>,
     pub after: Option<i64>,
 }
@@ -102,8 +102,8 @@ pub struct TradesRequest {
 pub struct OrdersRequest {
     #[serde(rename = "id")]
     pub order_id: Option<String>,
-    pub market: Option<String>,
-    pub asset_id: Option<String>,
+    pub market: Option<ConditionId>,
+    pub asset_id: Option<TokenId>,
 }
 
 #[non_exhaustive]
@@ -121,7 +121,7 @@ pub struct DeleteNotificationsRequest {
 #[builder(on(String, into))]
 pub struct BalanceAllowanceRequest {
     pub asset_type: AssetType,
-    pub token_id: Option<String>,
+    pub token_id: Option<TokenId>,
     pub signature_type: Option<SignatureType>,
 }
 
@@ -150,9 +150,9 @@ pub struct UserRewardsEarningRequest {
 #[builder(on(String, into))]
 pub struct CreateRfqRequestRequest {
     /// Token ID the Requester wants to receive. "0" indicates USDC.
-    pub asset_in: String,
+    pub asset_in: TokenId,
     /// Token ID the Requester wants to give. "0" indicates USDC.
-    pub asset_out: String,
+    pub asset_out: TokenId,
     /// Amount of asset to receive (in base units).
     pub amount_in: Decimal,
     /// Amount of asset to give (in base units).
@@ -193,7 +193,7 @@ pub struct RfqRequestsRequest {
     /// Filter by condition IDs.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
-    pub markets: Vec<String>,
+    pub markets: Vec<ConditionId>,
     /// Minimum size in tokens.
     pub size_min: Option<Decimal>,
     /// Maximum size in tokens.
@@ -222,9 +222,9 @@ pub struct CreateRfqQuoteRequest {
     /// ID of the Request to quote.
     pub request_id: String,
     /// Token ID the Quoter wants to receive. "0" indicates USDC.
-    pub asset_in: String,
+    pub asset_in: TokenId,
     /// Token ID the Quoter wants to give. "0" indicates USDC.
-    pub asset_out: String,
+    pub asset_out: TokenId,
     /// Amount of asset to receive (in base units).
     pub amount_in: Decimal,
     /// Amount of asset to give (in base units).
@@ -269,7 +269,7 @@ pub struct RfqQuotesRequest {
     /// Filter by condition IDs.
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
-    pub markets: Vec<String>,
+    pub markets: Vec<ConditionId>,
     /// Minimum size in tokens.
     pub size_min: Option<Decimal>,
     /// Maximum size in tokens.
@@ -306,7 +306,7 @@ pub struct AcceptRfqQuoteRequest {
     /// Taker's amount in base units.
     pub taker_amount: Decimal,
     /// Outcome token ID.
-    pub token_id: String,
+    pub token_id: TokenId,
     /// Maker's address.
     pub maker: Address,
     /// Signer's address.
@@ -347,7 +347,7 @@ pub struct ApproveRfqOrderRequest {
     /// Taker's amount in base units.
     pub taker_amount: Decimal,
     /// Outcome token ID.
-    pub token_id: String,
+    pub token_id: TokenId,
     /// Maker's address.
     pub maker: Address,
     /// Signer's address.
@@ -378,37 +378,45 @@ mod tests {
     #[test]
     fn trades_request_as_params_should_succeed() {
         let request = TradesRequest::builder()
-            .market("10000")
-            .asset_id("100")
+            .market(
+                "0x1111111111111111111111111111111111111111111111111111111111111111"
+                    .parse()
+                    .unwrap(),
+            )
+            .asset_id(TokenId::from(100))
             .id("aa-bb")
             .maker_address(Address::ZERO)
             .build();
 
         assert_eq!(
             request.query_params(None),
-            "?id=aa-bb&maker=0x0000000000000000000000000000000000000000&market=10000&asset_id=100"
+            "?id=aa-bb&maker=0x0000000000000000000000000000000000000000&market=0x1111111111111111111111111111111111111111111111111111111111111111&asset_id=100"
         );
         assert_eq!(
             request.query_params(Some("1")),
-            "?id=aa-bb&maker=0x0000000000000000000000000000000000000000&market=10000&asset_id=100&next_cursor=1"
+            "?id=aa-bb&maker=0x0000000000000000000000000000000000000000&market=0x1111111111111111111111111111111111111111111111111111111111111111&asset_id=100&next_cursor=1"
         );
     }
 
     #[test]
     fn orders_request_as_params_should_succeed() {
         let request = OrdersRequest::builder()
-            .market("10000")
-            .asset_id("100")
+            .market(
+                "0x1111111111111111111111111111111111111111111111111111111111111111"
+                    .parse()
+                    .unwrap(),
+            )
+            .asset_id(TokenId::from(100))
             .order_id("aa-bb")
             .build();
 
         assert_eq!(
             request.query_params(None),
-            "?id=aa-bb&market=10000&asset_id=100"
+            "?id=aa-bb&market=0x1111111111111111111111111111111111111111111111111111111111111111&asset_id=100"
         );
         assert_eq!(
             request.query_params(Some("1")),
-            "?id=aa-bb&market=10000&asset_id=100&next_cursor=1"
+            "?id=aa-bb&market=0x1111111111111111111111111111111111111111111111111111111111111111&asset_id=100&next_cursor=1"
         );
     }
 
@@ -427,7 +435,7 @@ mod tests {
     fn balance_allowance_request_as_params_should_succeed() {
         let request = BalanceAllowanceRequest::builder()
             .asset_type(AssetType::Collateral)
-            .token_id("1".to_owned())
+            .token_id(TokenId::from(1))
             .signature_type(SignatureType::Eoa)
             .build();
 
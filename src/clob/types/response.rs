@@ -15,7 +15,7 @@ use crate::Result;
 use crate::auth::ApiKey;
 use crate::clob::types::{OrderStatusType, OrderType, Side, TickSize, TraderSide};
 use crate::serde_helpers::StringFromAny;
-use crate::types::{Address, Decimal};
+use crate::types::{Address, ConditionId, Decimal, TokenId};
 
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
@@ -27,7 +27,7 @@ pub struct MidpointResponse {
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct MidpointsResponse {
-    pub midpoints: HashMap<String, Decimal>,
+    pub midpoints: HashMap<TokenId, Decimal>,
 }
 
 #[non_exhaustive]
@@ -40,7 +40,7 @@ pub struct PriceResponse {
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 #[serde(transparent)]
 pub struct PricesResponse {
-    pub prices: Option<HashMap<String, HashMap<Side, Decimal>>>,
+    pub prices: Option<HashMap<TokenId, HashMap<Side, Decimal>>>,
 }
 
 #[non_exhaustive]
@@ -52,7 +52,7 @@ pub struct SpreadResponse {
 #[non_exhaustive]
 #[derive(Clone, Debug, Deserialize, Builder, PartialEq)]
 pub struct SpreadsResponse {
-    pub spreads: Option<HashMap<String, Decimal>>,
+    pub spreads: Option<HashMap<TokenId, Decimal>>,
 }
 
 #[non_exhaustive]
@@ -109,8 +109,8 @@ pub struct GeoblockResponse {
 #[derive(Clone, Debug, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct OrderBookSummaryResponse {
-    pub market: String,
-    pub asset_id: String,
+    pub market: ConditionId,
+    pub asset_id: TokenId,
     #[serde_as(as = "TimestampMilliSeconds<String>")]
     pub timestamp: DateTime<Utc>,
     #[serde(default)]
@@ -159,7 +159,7 @@ pub struct LastTradePriceResponse {
 #[derive(Debug, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct LastTradesPricesResponse {
-    pub token_id: String,
+    pub token_id: TokenId,
     pub price: Decimal,
     pub side: Side,
 }
@@ -181,7 +181,12 @@ pub struct MarketResponse {
     pub accepting_order_timestamp: Option<DateTime<Utc>>,
     pub minimum_order_size: Decimal,
     pub minimum_tick_size: Decimal,
-    pub condition_id: String,
+    /// `None` for legacy and unlisted markets, which the CLOB returns with an empty condition ID.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::empty_string_as_none"
+    )]
+    pub condition_id: Option<ConditionId>,
     pub question_id: String,
     pub question: String,
     pub description: String,
@@ -212,7 +217,12 @@ pub struct MarketResponse {
 #[derive(Debug, Serialize, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct Token {
-    pub token_id: String,
+    /// `None` for tokens of legacy markets, which the CLOB returns with an empty token ID.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::empty_string_as_none"
+    )]
+    pub token_id: Option<TokenId>,
     pub outcome: String,
     pub price: Decimal,
     #[serde(default)]
@@ -228,7 +238,12 @@ pub struct Token {
 #[derive(Debug, Default, Deserialize, Clone, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct SimplifiedMarketResponse {
-    pub condition_id: String,
+    /// `None` for legacy and unlisted markets, which the CLOB returns with an empty condition ID.
+    #[serde(
+        default,
+        deserialize_with = "crate::serde_helpers::empty_string_as_none"
+    )]
+    pub condition_id: Option<ConditionId>,
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub tokens: Vec<Token>,
@@ -243,7 +258,7 @@ pub struct SimplifiedMarketResponse {
 #[derive(Clone, Debug, Default, Deserialize, Builder, PartialEq)]
 pub struct ApiKeysResponse {
     #[serde(rename = "apiKeys")]
-    keys: Option<Vec<ApiKey>>,
+    pub keys: Option<Vec<ApiKey>>,
 }
 
 #[non_exhaustive]
@@ -300,8 +315,8 @@ pub struct OpenOrderResponse {
     pub status: OrderStatusType,
     pub owner: ApiKey,
     pub maker_address: Address,
-    pub market: String,
-    pub asset_id: String,
+    pub market: ConditionId,
+    pub asset_id: TokenId,
     pub side: Side,
     pub original_size: Decimal,
     pub size_matched: Decimal,
@@ -340,8 +355,8 @@ pub struct CancelOrdersResponse {
 pub struct TradeResponse {
     pub id: String,
     pub taker_order_id: String,
-    pub market: String,
-    pub asset_id: String,
+    pub market: ConditionId,
+    pub asset_id: TokenId,
     pub side: Side,
     pub size: Decimal,
     pub fee_rate_bps: Decimal,
@@ -376,13 +391,13 @@ pub struct NotificationResponse {
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct NotificationPayload {
-    pub asset_id: String,
-    pub condition_id: String,
+    pub asset_id: TokenId,
+    pub condition_id: ConditionId,
     #[serde(rename = "eventSlug")]
     pub event_slug: String,
     pub icon: String,
     pub image: String,
-    pub market: String,
+    pub market: ConditionId,
     pub market_slug: String,
     pub matched_size: Decimal,
     pub order_id: String,
@@ -457,8 +472,8 @@ pub struct Rewards {
 #[derive(Debug, Clone, Serialize, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct MarketInfo {
-    pub condition_id: String,
-    pub asset_id: String,
+    pub condition_id: ConditionId,
+    pub asset_id: TokenId,
     pub question: String,
     pub icon: String,
     pub slug: String,
@@ -485,7 +500,7 @@ pub struct MakerOrder {
     pub matched_amount: Decimal,
     pub price: Decimal,
     pub fee_rate_bps: Decimal,
-    pub asset_id: String,
+    pub asset_id: TokenId,
     pub outcome: String,
     pub side: Side,
 }
@@ -495,7 +510,7 @@ pub struct MakerOrder {
 #[builder(on(String, into))]
 pub struct UserEarningResponse {
     pub date: NaiveDate,
-    pub condition_id: String,
+    pub condition_id: ConditionId,
     pub asset_address: Address,
     pub maker_address: Address,
     pub earnings: Decimal,
@@ -518,7 +533,7 @@ pub struct TotalUserEarningResponse {
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct UserRewardsEarningResponse {
-    pub condition_id: String,
+    pub condition_id: ConditionId,
     pub question: String,
     pub market_slug: String,
     pub event_slug: String,
@@ -579,7 +594,7 @@ pub type RewardsPercentagesResponse = HashMap<String, Decimal>;
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct CurrentRewardResponse {
-    pub condition_id: String,
+    pub condition_id: ConditionId,
     #[serde(default)]
     #[serde_as(deserialize_as = "DefaultOnNull")]
     pub rewards_config: Vec<RewardsConfig>,
@@ -592,7 +607,7 @@ pub struct CurrentRewardResponse {
 #[derive(Debug, Clone, Deserialize, Builder, PartialEq)]
 #[builder(on(String, into))]
 pub struct MarketRewardResponse {
-    pub condition_id: String,
+    pub condition_id: ConditionId,
     pub question: String,
     pub market_slug: String,
     pub event_slug: String,
@@ -630,8 +645,8 @@ pub struct BuilderTradeResponse {
     pub trade_type: String,
     pub taker_order_hash: String,
     pub builder: String,
-    pub market: String,
-    pub asset_id: String,
+    pub market: ConditionId,
+    pub asset_id: TokenId,
     pub side: Side,
     pub size: Decimal,
     pub size_usdc: Decimal,
@@ -725,11 +740,11 @@ pub struct RfqRequest {
     /// Proxy address (may be same as user).
     pub proxy: Address,
     /// Market condition ID.
-    pub market: String,
+    pub market: ConditionId,
     /// Token ID for the outcome token.
-    pub token: String,
+    pub token: TokenId,
     /// Complement token ID.
-    pub complement: String,
+    pub complement: TokenId,
     /// Order side (BUY or SELL).
     pub side: Side,
     /// Size of tokens to receive.
@@ -758,11 +773,11 @@ pub struct RfqQuote {
     /// Proxy address (may be same as user).
     pub proxy: Address,
     /// Market condition ID.
-    pub market: String,
+    pub market: ConditionId,
     /// Token ID for the outcome token.
-    pub token: String,
+    pub token: TokenId,
     /// Complement token ID.
-    pub complement: String,
+    pub complement: TokenId,
     /// Order side (BUY or SELL).
     pub side: Side,
     /// Size of tokens to receive.
@@ -0,0 +1,96 @@
+//! Keeps the REST client's cached market parameters current from the market channel.
+//!
+//! The [`Client`] caches tick sizes, fee rates and neg risk flags per token, so an order built
+//! after a `tick_size_change` would otherwise still be rounded to the old tick and rejected.
+//! [`Client::sync_caches`] subscribes to the market channel and replaces the cached tick size as
+//! soon as a change is announced, and drops everything cached for a market once it resolves.
+
+use std::collections::HashSet;
+
+use async_stream::try_stream;
+use futures::{Stream, StreamExt as _};
+
+use super::Client as WsClient;
+use super::error::WsError;
+use super::types::response::WsMessage;
+use crate::Result;
+use crate::auth::state::State;
+use crate::clob::Client;
+use crate::clob::types::TickSize;
+use crate::transport::HttpTransport;
+use crate::types::TokenId;
+
+/// Releases the assets subscribed by [`sync_caches`] once its stream is dropped.
+struct Subscription<'ws, W: State> {
+    ws: &'ws WsClient<W>,
+    asset_ids: Vec<TokenId>,
+}
+
+impl<W: State> Drop for Subscription<'_, W> {
+    fn drop(&mut self) {
+        if let Err(e) = self.ws.unsubscribe_market_messages(&self.asset_ids) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, "Failed to unsubscribe the cache sync from the market channel");
+            #[cfg(not(feature = "tracing"))]
+            let _: &crate::error::Error = &e;
+        }
+    }
+}
+
+/// See [`Client::sync_caches`].
+pub(crate) fn sync_caches<'client, S: State, T: HttpTransport, W: State>(
+    client: &'client Client<S, T>,
+    ws: &'client WsClient<W>,
+    asset_ids: Vec<TokenId>,
+) -> Result<impl Stream<Item = Result<WsMessage>> + 'client> {
+    let stream = ws.subscribe_market_events(asset_ids.clone())?;
+    let subscription = Subscription {
+        ws,
+        asset_ids: asset_ids.clone(),
+    };
+    let assets: HashSet<TokenId> = asset_ids.iter().copied().collect();
+
+    Ok(try_stream! {
+        let _subscription = subscription;
+        let mut stream = stream.boxed();
+
+        while let Some(message) = stream.next().await {
+            let message = match message {
+                Ok(message) => message,
+                Err(e) if matches!(e.downcast_ref::<WsError>(), Some(WsError::Lagged { .. })) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %e, "Cache sync lagged, invalidating all assets");
+                    for asset_id in &asset_ids {
+                        client.invalidate_market_params(*asset_id);
+                    }
+                    stream = ws.resume_market_messages(asset_ids.clone())?.boxed();
+                    continue;
+                }
+                Err(e) => Err(e)?,
+            };
+
+            let changed = match &message {
+                WsMessage::TickSizeChange(change) if assets.contains(&change.asset_id) => {
+                    match TickSize::try_from(change.new_tick_size) {
+                        Ok(tick_size) => client.cache_tick_size(change.asset_id, tick_size),
+                        Err(_) => client.invalidate_market_params(change.asset_id),
+                    }
+                    true
+                }
+                WsMessage::MarketResolved(resolved)
+                    if resolved.asset_ids.iter().any(|id| assets.contains(id)) =>
+                {
+                    for asset_id in &resolved.asset_ids {
+                        client.invalidate_market_params(*asset_id);
+                    }
+                    true
+                }
+                _ => false,
+            };
+
+            if changed {
+                yield message;
+            }
+        }
+    })
+}
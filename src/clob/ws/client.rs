@@ -5,6 +5,7 @@ use async_stream::try_stream;
 use futures::Stream;
 use futures::StreamExt as _;
 use once_cell::sync::OnceCell;
+use tokio::sync::watch;
 
 use super::interest::InterestTracker;
 use super::subscription::{ChannelType, SubscriptionManager};
@@ -14,9 +15,9 @@ use super::types::response::{
 };
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Kind as AuthKind, Normal};
+use crate::auth::{Credentials, Kind as AuthKind, Normal, SwapCredentials};
 use crate::error::Error;
-use crate::types::{Address, Decimal};
+use crate::types::{Address, ConditionId, Decimal, TokenId};
 use crate::ws::ConnectionManager;
 use crate::ws::config::Config;
 use crate::ws::connection::ConnectionState;
@@ -38,7 +39,7 @@ use crate::ws::connection::ConnectionState;
 ///     // Create unauthenticated client
 ///     let client = Client::default();
 ///
-///     let stream = client.subscribe_orderbook(vec!["asset_id".to_owned()])?;
+///     let stream = client.subscribe_orderbook(vec!["asset_id".parse()?])?;
 ///     let mut stream = Box::pin(stream);
 ///
 ///     while let Some(book) = stream.next().await {
@@ -134,11 +135,7 @@ impl Client<Unauthenticated> {
 
         Ok(Client {
             inner: Arc::new(ClientInner {
-                state: Authenticated {
-                    address,
-                    credentials,
-                    kind: Normal,
-                },
+                state: Authenticated::new(address, credentials, Normal),
                 config,
                 base_endpoint,
                 channels,
@@ -152,7 +149,7 @@ impl<S: State> Client<S> {
     /// Subscribe to orderbook updates for specific assets.
     pub fn subscribe_orderbook(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<BookUpdate>>> {
         let resources = self.market_resources()?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -169,7 +166,7 @@ impl<S: State> Client<S> {
     /// Subscribe to price changes for specific assets.
     pub fn subscribe_prices(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<PriceChange>>> {
         let resources = self.market_resources()?;
         let stream = resources.subscriptions.subscribe_market(asset_ids)?;
@@ -186,7 +183,7 @@ impl<S: State> Client<S> {
     /// Subscribe to midpoint updates (calculated from best bid/ask).
     pub fn subscribe_midpoints(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<MidpointUpdate>>> {
         let stream = self.subscribe_orderbook(asset_ids)?;
 
@@ -213,7 +210,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_best_bid_ask(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<BestBidAsk>>> {
         let stream = self
             .market_resources()?
@@ -234,7 +231,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_new_markets(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<NewMarket>>> {
         let stream = self
             .market_resources()?
@@ -255,7 +252,7 @@ impl<S: State> Client<S> {
     /// Requires `custom_feature_enabled` flag on the server side.
     pub fn subscribe_market_resolutions(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<MarketResolved>>> {
         let stream = self
             .market_resources()?
@@ -308,7 +305,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_orderbook(&self, asset_ids: &[String]) -> Result<()> {
+    pub fn unsubscribe_orderbook(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.market_resources()?
             .subscriptions
             .unsubscribe_market(asset_ids)
@@ -318,7 +315,7 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_prices(&self, asset_ids: &[String]) -> Result<()> {
+    pub fn unsubscribe_prices(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.market_resources()?
             .subscriptions
             .unsubscribe_market(asset_ids)
@@ -328,12 +325,53 @@ impl<S: State> Client<S> {
     ///
     /// This decrements the reference count for each asset. The server unsubscribe
     /// is only sent when no other subscriptions are using those assets.
-    pub fn unsubscribe_midpoints(&self, asset_ids: &[String]) -> Result<()> {
+    pub fn unsubscribe_midpoints(&self, asset_ids: &[TokenId]) -> Result<()> {
         self.market_resources()?
             .subscriptions
             .unsubscribe_market(asset_ids)
     }
 
+    /// Subscribe to every market channel message for specific assets.
+    pub(crate) fn subscribe_market_messages(
+        &self,
+        asset_ids: Vec<TokenId>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.market_resources()?
+            .subscriptions
+            .subscribe_market(asset_ids)
+    }
+
+    /// Subscribe to every market channel message for specific assets, including the custom
+    /// `best_bid_ask`, `new_market` and `market_resolved` events.
+    pub(crate) fn subscribe_market_events(
+        &self,
+        asset_ids: Vec<TokenId>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        self.market_resources()?
+            .subscriptions
+            .subscribe_market_with_options(asset_ids, true)
+    }
+
+    /// Release assets subscribed by [`Self::subscribe_market_messages`] or
+    /// [`Self::subscribe_market_events`].
+    pub(crate) fn unsubscribe_market_messages(&self, asset_ids: &[TokenId]) -> Result<()> {
+        self.market_resources()?
+            .subscriptions
+            .unsubscribe_market(asset_ids)
+    }
+
+    /// Resume consuming market channel messages for already subscribed assets, e.g. after
+    /// a stream returned by [`Self::subscribe_market_messages`] lagged and ended.
+    pub(crate) fn resume_market_messages(
+        &self,
+        asset_ids: Vec<TokenId>,
+    ) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        Ok(self
+            .market_resources()?
+            .subscriptions
+            .market_stream(asset_ids))
+    }
+
     fn market_resources(&self) -> Result<&LazyChannelResources> {
         self.inner
             .channel(ChannelType::Market)
@@ -347,19 +385,19 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     /// Subscribe to raw user channel events (orders and trades).
     pub fn subscribe_user_events(
         &self,
-        markets: Vec<String>,
+        markets: Vec<ConditionId>,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         let resources = self.user_resources()?;
 
         resources
             .subscriptions
-            .subscribe_user(markets, &self.inner.state.credentials)
+            .subscribe_user(markets, self.inner.state.credentials())
     }
 
     /// Subscribe to user's order updates.
     pub fn subscribe_orders(
         &self,
-        markets: Vec<String>,
+        markets: Vec<ConditionId>,
     ) -> Result<impl Stream<Item = Result<OrderMessage>>> {
         let stream = self.subscribe_user_events(markets)?;
 
@@ -375,7 +413,7 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     /// Subscribe to user's trade executions.
     pub fn subscribe_trades(
         &self,
-        markets: Vec<String>,
+        markets: Vec<ConditionId>,
     ) -> Result<impl Stream<Item = Result<TradeMessage>>> {
         let stream = self.subscribe_user_events(markets)?;
 
@@ -392,12 +430,23 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     ///
     /// This decrements the reference count for each market. The server unsubscribe
     /// is only sent when no other subscriptions are using those markets.
-    pub fn unsubscribe_user_events(&self, markets: &[String]) -> Result<()> {
+    pub fn unsubscribe_user_events(&self, markets: &[ConditionId]) -> Result<()> {
         self.user_resources()?
             .subscriptions
             .unsubscribe_user(markets)
     }
 
+    /// Resume consuming user channel events for already subscribed markets, e.g. after a
+    /// stream returned by [`Self::subscribe_user_events`] lagged and ended.
+    pub(crate) fn resume_user_events(&self) -> Result<impl Stream<Item = Result<WsMessage>>> {
+        Ok(self.user_resources()?.subscriptions.user_stream())
+    }
+
+    /// Receiver notified whenever the user channel connects, drops or reconnects.
+    pub(crate) fn user_connection_states(&self) -> Result<watch::Receiver<ConnectionState>> {
+        Ok(self.user_resources()?.connection.state_receiver())
+    }
+
     fn user_handles(&self) -> Result<&ChannelHandles> {
         self.inner
             .channel(ChannelType::User)
@@ -412,7 +461,7 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     ///
     /// This decrements the reference count for each market. The server unsubscribe
     /// is only sent when no other subscriptions are using those markets.
-    pub fn unsubscribe_orders(&self, markets: &[String]) -> Result<()> {
+    pub fn unsubscribe_orders(&self, markets: &[ConditionId]) -> Result<()> {
         self.unsubscribe_user_events(markets)
     }
 
@@ -420,7 +469,7 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     ///
     /// This decrements the reference count for each market. The server unsubscribe
     /// is only sent when no other subscriptions are using those markets.
-    pub fn unsubscribe_trades(&self, markets: &[String]) -> Result<()> {
+    pub fn unsubscribe_trades(&self, markets: &[ConditionId]) -> Result<()> {
         self.unsubscribe_user_events(markets)
     }
 
@@ -452,6 +501,19 @@ impl<K: AuthKind> Client<Authenticated<K>> {
     }
 }
 
+impl<K: AuthKind + Send + Sync> SwapCredentials for Client<Authenticated<K>> {
+    /// Replaces the credentials of this client, and re-authenticates its live user channel
+    /// subscriptions with them.
+    fn swap_credentials(&self, credentials: &Credentials) -> Result<()> {
+        if let Some(resources) = self.user_handles()?.resources.get() {
+            resources.subscriptions.replace_auth(credentials.clone())?;
+        }
+        self.inner.state.replace_credentials(credentials.clone());
+
+        Ok(())
+    }
+}
+
 impl<S: State> ClientInner<S> {
     fn channel(&self, kind: ChannelType) -> Option<&ChannelHandles> {
         self.channels.get(&kind)
@@ -6,6 +6,8 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use crate::types::TokenId;
+
 /// WebSocket error variants.
 #[non_exhaustive]
 #[derive(Debug)]
@@ -29,6 +31,13 @@ pub enum WsError {
         /// Number of messages that were missed
         count: u64,
     },
+    /// Locally maintained orderbook diverged from the server state
+    OrderBookDesync {
+        /// Asset whose orderbook diverged
+        asset_id: TokenId,
+        /// Description of the detected divergence
+        reason: String,
+    },
 }
 
 impl fmt::Display for WsError {
@@ -42,6 +51,9 @@ impl fmt::Display for WsError {
             Self::Timeout => write!(f, "WebSocket operation timed out"),
             Self::InvalidMessage(msg) => write!(f, "Invalid WebSocket message: {msg}"),
             Self::Lagged { count } => write!(f, "Subscription lagged, missed {count} messages"),
+            Self::OrderBookDesync { asset_id, reason } => {
+                write!(f, "Orderbook for {asset_id} out of sync: {reason}")
+            }
         }
     }
 }
@@ -3,16 +3,21 @@
     reason = "Re-exported names intentionally match their modules for API clarity"
 )]
 
+pub(crate) mod cache;
 pub mod client;
 pub mod error;
 pub mod interest;
+pub mod orderbook;
 pub mod subscription;
+pub mod tracker;
 pub mod types;
 
 // Re-export commonly used types
 pub use client::Client;
 pub use error::WsError;
+pub use orderbook::{OrderBook, OrderBookManager, OrderBookSnapshot};
 pub use subscription::{ChannelType, SubscriptionInfo, SubscriptionTarget};
+pub use tracker::{Exposure, OrderLifecycle, OrderTracker, TrackedOrder};
 pub use types::request::SubscriptionRequest;
 pub use types::response::{
     BestBidAsk, BookUpdate, EventMessage, LastTradePrice, MakerOrder, MarketResolved,
@@ -0,0 +1,726 @@
+//! Locally maintained L2 orderbooks driven by the market channel.
+//!
+//! An [`OrderBook`] is seeded from a full `book` snapshot (or the REST `/book` endpoint) and
+//! then kept current by applying `price_change` deltas. Every delta that carries the server's
+//! best bid/ask is checked against the local top of book, and a `book` snapshot that carries
+//! the hash we already hold must match our levels. Any divergence is reported as
+//! [`WsError::OrderBookDesync`] so the book can be reseeded.
+//!
+//! The server hash is kept as an opaque version tag and is not recomputed from the local levels,
+//! so a divergence below the top of book goes unnoticed until the next snapshot repeats a hash or
+//! replaces the book.
+//!
+//! [`OrderBookManager`] wires this up end to end: it subscribes to the market channel, keeps
+//! one book per asset and transparently resyncs from REST when a book diverges, a delta arrives
+//! for a book that was never seeded or the subscription lags behind the broadcast channel.
+
+use std::collections::{BTreeMap, HashSet};
+
+use async_stream::try_stream;
+use chrono::DateTime;
+use dashmap::DashMap;
+use futures::{Stream, StreamExt as _};
+use reqwest::Client as ReqwestClient;
+
+use super::Client as WsClient;
+use super::error::WsError;
+use super::types::response::{BookUpdate, PriceChangeBatchEntry, TickSizeChange, WsMessage};
+use crate::Result;
+use crate::auth::state::{State, Unauthenticated};
+use crate::clob::Client;
+use crate::clob::types::request::OrderBookSummaryRequest;
+use crate::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+use crate::clob::types::{Side, TickSize};
+use crate::error::Error;
+use crate::transport::HttpTransport;
+use crate::types::{ConditionId, Decimal, TokenId};
+
+/// A single asset's L2 orderbook, maintained locally.
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct OrderBook {
+    asset_id: TokenId,
+    market: ConditionId,
+    timestamp: i64,
+    hash: Option<String>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    min_order_size: Option<Decimal>,
+    neg_risk: Option<bool>,
+    tick_size: Option<TickSize>,
+}
+
+impl OrderBook {
+    /// Seed a book from a market channel `book` snapshot.
+    #[must_use]
+    pub fn from_book_update(book: &BookUpdate) -> Self {
+        let mut order_book = Self {
+            asset_id: book.asset_id,
+            market: book.market,
+            timestamp: book.timestamp,
+            hash: None,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            min_order_size: None,
+            neg_risk: None,
+            tick_size: None,
+        };
+        order_book.reseed(book);
+        order_book
+    }
+
+    /// Seed a book from a REST `/book` response, which also carries market metadata.
+    #[must_use]
+    pub fn from_summary(summary: &OrderBookSummaryResponse) -> Self {
+        Self {
+            asset_id: summary.asset_id,
+            market: summary.market,
+            timestamp: summary.timestamp.timestamp_millis(),
+            hash: summary.hash.clone(),
+            bids: to_levels(summary.bids.iter().map(|level| (level.price, level.size))),
+            asks: to_levels(summary.asks.iter().map(|level| (level.price, level.size))),
+            min_order_size: Some(summary.min_order_size),
+            neg_risk: Some(summary.neg_risk),
+            tick_size: Some(summary.tick_size),
+        }
+    }
+
+    /// Apply a full `book` snapshot.
+    ///
+    /// Snapshots older than the current book are ignored. A snapshot carrying the hash of the
+    /// current book must describe the same levels, otherwise [`WsError::OrderBookDesync`] is
+    /// returned and the book is left untouched. Returns whether the snapshot was applied.
+    pub fn apply_book(&mut self, book: &BookUpdate) -> std::result::Result<bool, WsError> {
+        if book.timestamp < self.timestamp {
+            return Ok(false);
+        }
+
+        if book.hash.is_some() && book.hash == self.hash {
+            let bids = to_levels(book.bids.iter().map(|level| (level.price, level.size)));
+            let asks = to_levels(book.asks.iter().map(|level| (level.price, level.size)));
+            if bids != self.bids || asks != self.asks {
+                return Err(self.desync("snapshot levels differ for an identical hash"));
+            }
+        }
+
+        self.reseed(book);
+        Ok(true)
+    }
+
+    /// Apply a single `price_change` entry observed at `timestamp`.
+    ///
+    /// The entry sets the absolute size of its price level; a size of zero removes the level.
+    /// Entries older than the current book are ignored. When the entry carries the server's
+    /// best bid/ask, they must match the local top of book after applying it, otherwise
+    /// [`WsError::OrderBookDesync`] is returned. Returns whether the entry was applied.
+    pub fn apply_price_change(
+        &mut self,
+        entry: &PriceChangeBatchEntry,
+        timestamp: i64,
+    ) -> std::result::Result<bool, WsError> {
+        if entry.asset_id != self.asset_id {
+            return Err(WsError::InvalidMessage(format!(
+                "price change for {} applied to orderbook of {}",
+                entry.asset_id, self.asset_id
+            )));
+        }
+
+        if timestamp < self.timestamp {
+            return Ok(false);
+        }
+
+        if let Some(size) = entry.size {
+            let levels = match entry.side {
+                Side::Buy => &mut self.bids,
+                Side::Sell => &mut self.asks,
+                Side::Unknown => {
+                    return Err(WsError::InvalidMessage(format!(
+                        "price change for {} has an unknown side",
+                        entry.asset_id
+                    )));
+                }
+            };
+
+            if size.is_zero() {
+                levels.remove(&entry.price);
+            } else {
+                levels.insert(entry.price, size);
+            }
+        }
+
+        self.timestamp = timestamp;
+        if entry.hash.is_some() {
+            self.hash.clone_from(&entry.hash);
+        }
+
+        let best_bid = self.best_bid().map(|level| level.price);
+        if let Some(expected) = entry.best_bid
+            && !matches_top(best_bid, expected)
+        {
+            return Err(self.desync(&format!(
+                "best bid {best_bid:?} does not match server best bid {expected}"
+            )));
+        }
+
+        let best_ask = self.best_ask().map(|level| level.price);
+        if let Some(expected) = entry.best_ask
+            && !matches_top(best_ask, expected)
+        {
+            return Err(self.desync(&format!(
+                "best ask {best_ask:?} does not match server best ask {expected}"
+            )));
+        }
+
+        Ok(true)
+    }
+
+    /// Apply a `tick_size_change` event.
+    pub fn apply_tick_size_change(&mut self, change: &TickSizeChange) -> Result<()> {
+        self.tick_size = Some(TickSize::try_from(change.new_tick_size)?);
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn asset_id(&self) -> TokenId {
+        self.asset_id
+    }
+
+    #[must_use]
+    pub fn market(&self) -> ConditionId {
+        self.market
+    }
+
+    /// Unix timestamp in milliseconds of the last applied update.
+    #[must_use]
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Server hash of the last applied update, if any.
+    ///
+    /// The hash is passed through as received; it is not verified against the local levels.
+    #[must_use]
+    pub fn hash(&self) -> Option<&str> {
+        self.hash.as_deref()
+    }
+
+    #[must_use]
+    pub fn tick_size(&self) -> Option<TickSize> {
+        self.tick_size
+    }
+
+    #[must_use]
+    pub fn best_bid(&self) -> Option<OrderSummary> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(price, size)| level(*price, *size))
+    }
+
+    #[must_use]
+    pub fn best_ask(&self) -> Option<OrderSummary> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(price, size)| level(*price, *size))
+    }
+
+    /// Midpoint between the best bid and best ask, if both sides are present.
+    #[must_use]
+    pub fn midpoint(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        Some((bid.price + ask.price) / Decimal::TWO)
+    }
+
+    /// Difference between the best ask and best bid, if both sides are present.
+    #[must_use]
+    pub fn spread(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        Some(ask.price - bid.price)
+    }
+
+    /// Up to `depth` bid levels, best (highest) price first.
+    #[must_use]
+    pub fn bids(&self, depth: usize) -> Vec<OrderSummary> {
+        self.bids
+            .iter()
+            .rev()
+            .take(depth)
+            .map(|(price, size)| level(*price, *size))
+            .collect()
+    }
+
+    /// Up to `depth` ask levels, best (lowest) price first.
+    #[must_use]
+    pub fn asks(&self, depth: usize) -> Vec<OrderSummary> {
+        self.asks
+            .iter()
+            .take(depth)
+            .map(|(price, size)| level(*price, *size))
+            .collect()
+    }
+
+    /// Point-in-time copy of the full book.
+    #[must_use]
+    pub fn snapshot(&self) -> OrderBookSnapshot {
+        OrderBookSnapshot {
+            asset_id: self.asset_id,
+            market: self.market,
+            timestamp: self.timestamp,
+            hash: self.hash.clone(),
+            bids: self.bids(usize::MAX),
+            asks: self.asks(usize::MAX),
+            min_order_size: self.min_order_size,
+            neg_risk: self.neg_risk,
+            tick_size: self.tick_size,
+        }
+    }
+
+    fn reseed(&mut self, book: &BookUpdate) {
+        self.market = book.market;
+        self.timestamp = book.timestamp;
+        self.hash.clone_from(&book.hash);
+        self.bids = to_levels(book.bids.iter().map(|level| (level.price, level.size)));
+        self.asks = to_levels(book.asks.iter().map(|level| (level.price, level.size)));
+    }
+
+    fn desync(&self, reason: &str) -> WsError {
+        WsError::OrderBookDesync {
+            asset_id: self.asset_id,
+            reason: reason.to_owned(),
+        }
+    }
+}
+
+/// Point-in-time copy of an [`OrderBook`].
+///
+/// Levels are ordered best price first. Market metadata is only known once the book has been
+/// seeded from REST at least once, which is required to convert into an
+/// [`OrderBookSummaryResponse`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderBookSnapshot {
+    pub asset_id: TokenId,
+    pub market: ConditionId,
+    /// Unix timestamp in milliseconds
+    pub timestamp: i64,
+    pub hash: Option<String>,
+    /// Bid levels, highest price first
+    pub bids: Vec<OrderSummary>,
+    /// Ask levels, lowest price first
+    pub asks: Vec<OrderSummary>,
+    pub min_order_size: Option<Decimal>,
+    pub neg_risk: Option<bool>,
+    pub tick_size: Option<TickSize>,
+}
+
+impl TryFrom<OrderBookSnapshot> for OrderBookSummaryResponse {
+    type Error = Error;
+
+    /// Levels are reordered to match the REST layout, where the best price is listed last.
+    fn try_from(snapshot: OrderBookSnapshot) -> std::result::Result<Self, Self::Error> {
+        let missing = |field: &str| {
+            Error::validation(format!(
+                "Unable to convert orderbook for {} without {field}; resync it from REST first",
+                snapshot.asset_id
+            ))
+        };
+
+        let min_order_size = snapshot
+            .min_order_size
+            .ok_or_else(|| missing("min_order_size"))?;
+        let neg_risk = snapshot.neg_risk.ok_or_else(|| missing("neg_risk"))?;
+        let tick_size = snapshot.tick_size.ok_or_else(|| missing("tick_size"))?;
+        let timestamp = DateTime::from_timestamp_millis(snapshot.timestamp).ok_or_else(|| {
+            Error::validation(format!(
+                "Invalid orderbook timestamp {}",
+                snapshot.timestamp
+            ))
+        })?;
+
+        let mut bids = snapshot.bids;
+        bids.reverse();
+        let mut asks = snapshot.asks;
+        asks.reverse();
+
+        Ok(OrderBookSummaryResponse {
+            market: snapshot.market,
+            asset_id: snapshot.asset_id,
+            timestamp,
+            hash: snapshot.hash,
+            bids,
+            asks,
+            min_order_size,
+            neg_risk,
+            tick_size,
+        })
+    }
+}
+
+/// Maintains local orderbooks for a set of assets from the market channel.
+///
+/// Books are resynced through [`Client::order_book`] whenever they diverge from the server,
+/// receive deltas before being seeded, or the underlying subscription lags.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// use futures::StreamExt as _;
+/// use polymarket_client_sdk::clob::Client;
+/// use polymarket_client_sdk::clob::ws::{Client as WsClient, OrderBookManager};
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let books = OrderBookManager::new(Client::default());
+///     let ws = WsClient::default();
+///
+///     let stream = books.sync(&ws, vec!["asset_id".parse()?])?;
+///     let mut stream = Box::pin(stream);
+///
+///     while let Some(snapshot) = stream.next().await {
+///         let snapshot = snapshot?;
+///         println!("{}: {:?}", snapshot.asset_id, books.midpoint(snapshot.asset_id));
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderBookManager<S: State = Unauthenticated, T: HttpTransport = ReqwestClient> {
+    client: Client<S, T>,
+    books: DashMap<TokenId, OrderBook>,
+}
+
+impl<S: State, T: HttpTransport> OrderBookManager<S, T> {
+    #[must_use]
+    pub fn new(client: Client<S, T>) -> Self {
+        Self {
+            client,
+            books: DashMap::new(),
+        }
+    }
+
+    /// Subscribe to `asset_ids` on the market channel and keep their books up to date.
+    ///
+    /// The returned stream must be polled for the books to advance. It yields a snapshot of
+    /// every book changed by an incoming message or a resync.
+    pub fn sync<'client, W: State>(
+        &'client self,
+        ws: &'client WsClient<W>,
+        asset_ids: Vec<TokenId>,
+    ) -> Result<impl Stream<Item = Result<OrderBookSnapshot>> + 'client> {
+        let stream = ws.subscribe_market_messages(asset_ids.clone())?;
+        let assets: HashSet<TokenId> = asset_ids.iter().copied().collect();
+
+        Ok(try_stream! {
+            let mut stream = stream.boxed();
+
+            while let Some(message) = stream.next().await {
+                let message = match message {
+                    Ok(message) => message,
+                    Err(e) if matches!(e.downcast_ref::<WsError>(), Some(WsError::Lagged { .. })) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %e, "Orderbook stream lagged, resyncing all books");
+                        stream = ws.resume_market_messages(asset_ids.clone())?.boxed();
+                        for asset_id in &asset_ids {
+                            yield self.resync(*asset_id).await?;
+                        }
+                        continue;
+                    }
+                    Err(e) => Err(e)?,
+                };
+
+                match message {
+                    WsMessage::Book(book) if assets.contains(&book.asset_id) => {
+                        match self.apply_book(&book) {
+                            Ok(Some(snapshot)) => yield snapshot,
+                            Ok(None) => {}
+                            Err(e) => {
+                                #[cfg(feature = "tracing")]
+                                tracing::warn!(error = %e, "Resyncing orderbook");
+                                #[cfg(not(feature = "tracing"))]
+                                let _ = &e;
+                                yield self.resync(book.asset_id).await?;
+                            }
+                        }
+                    }
+                    WsMessage::PriceChange(change) => {
+                        let mut changed: Vec<TokenId> = Vec::new();
+                        for entry in change
+                            .price_changes
+                            .iter()
+                            .filter(|entry| assets.contains(&entry.asset_id))
+                        {
+                            match self.apply_price_change(entry, change.timestamp) {
+                                Ok(false) => continue,
+                                Ok(true) => {}
+                                Err(e) => {
+                                    #[cfg(feature = "tracing")]
+                                    tracing::warn!(error = %e, "Resyncing orderbook");
+                                    #[cfg(not(feature = "tracing"))]
+                                    let _ = &e;
+                                    self.resync(entry.asset_id).await?;
+                                }
+                            }
+
+                            if !changed.contains(&entry.asset_id) {
+                                changed.push(entry.asset_id);
+                            }
+                        }
+
+                        for asset_id in changed {
+                            if let Some(snapshot) = self.snapshot(asset_id) {
+                                yield snapshot;
+                            }
+                        }
+                    }
+                    WsMessage::TickSizeChange(change) if assets.contains(&change.asset_id) => {
+                        let snapshot = match self.books.get_mut(&change.asset_id) {
+                            Some(mut book) => {
+                                book.apply_tick_size_change(&change)?;
+                                Some(book.snapshot())
+                            }
+                            None => None,
+                        };
+                        if let Some(snapshot) = snapshot {
+                            yield snapshot;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        })
+    }
+
+    /// Reseed the book for `asset_id` from the REST `/book` endpoint.
+    pub async fn resync(&self, asset_id: TokenId) -> Result<OrderBookSnapshot> {
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(asset_id)
+            .build();
+        let summary = self.client.order_book(&request).await?;
+
+        let book = OrderBook::from_summary(&summary);
+        let snapshot = book.snapshot();
+        self.books.insert(asset_id, book);
+
+        Ok(snapshot)
+    }
+
+    /// Snapshot of the current book for `asset_id`, if it has been seeded.
+    #[must_use]
+    pub fn snapshot(&self, asset_id: TokenId) -> Option<OrderBookSnapshot> {
+        self.books.get(&asset_id).map(|book| book.snapshot())
+    }
+
+    #[must_use]
+    pub fn best_bid(&self, asset_id: TokenId) -> Option<OrderSummary> {
+        self.books.get(&asset_id)?.best_bid()
+    }
+
+    #[must_use]
+    pub fn best_ask(&self, asset_id: TokenId) -> Option<OrderSummary> {
+        self.books.get(&asset_id)?.best_ask()
+    }
+
+    #[must_use]
+    pub fn midpoint(&self, asset_id: TokenId) -> Option<Decimal> {
+        self.books.get(&asset_id)?.midpoint()
+    }
+
+    /// Up to `depth` bid and ask levels for `asset_id`, best price first.
+    #[must_use]
+    pub fn depth(
+        &self,
+        asset_id: TokenId,
+        depth: usize,
+    ) -> Option<(Vec<OrderSummary>, Vec<OrderSummary>)> {
+        let book = self.books.get(&asset_id)?;
+        Some((book.bids(depth), book.asks(depth)))
+    }
+
+    /// Stop tracking the book for `asset_id`.
+    #[must_use]
+    pub fn remove(&self, asset_id: TokenId) -> Option<OrderBook> {
+        self.books.remove(&asset_id).map(|(_, book)| book)
+    }
+
+    fn apply_book(
+        &self,
+        book: &BookUpdate,
+    ) -> std::result::Result<Option<OrderBookSnapshot>, WsError> {
+        if let Some(mut existing) = self.books.get_mut(&book.asset_id) {
+            let applied = existing.apply_book(book)?;
+            Ok(applied.then(|| existing.snapshot()))
+        } else {
+            let seeded = OrderBook::from_book_update(book);
+            let snapshot = seeded.snapshot();
+            self.books.insert(book.asset_id, seeded);
+            Ok(Some(snapshot))
+        }
+    }
+
+    fn apply_price_change(
+        &self,
+        entry: &PriceChangeBatchEntry,
+        timestamp: i64,
+    ) -> std::result::Result<bool, WsError> {
+        let mut book =
+            self.books
+                .get_mut(&entry.asset_id)
+                .ok_or_else(|| WsError::OrderBookDesync {
+                    asset_id: entry.asset_id,
+                    reason: "received a price change before the book was seeded".to_owned(),
+                })?;
+
+        book.apply_price_change(entry, timestamp)
+    }
+}
+
+fn to_levels(levels: impl Iterator<Item = (Decimal, Decimal)>) -> BTreeMap<Decimal, Decimal> {
+    levels.filter(|(_, size)| !size.is_zero()).collect()
+}
+
+fn level(price: Decimal, size: Decimal) -> OrderSummary {
+    OrderSummary { price, size }
+}
+
+/// The server reports an empty side as a zero price.
+fn matches_top(local: Option<Decimal>, expected: Decimal) -> bool {
+    local.map_or(expected.is_zero(), |price| price == expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn book() -> BookUpdate {
+        serde_json::from_str(
+            r#"{
+                "asset_id": "123",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "timestamp": "1000",
+                "bids": [{"price": "0.48", "size": "30"}, {"price": "0.49", "size": "20"}],
+                "asks": [{"price": "0.52", "size": "25"}, {"price": "0.51", "size": "10"}],
+                "hash": "h1"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    fn entry(
+        price: &str,
+        size: &str,
+        side: &str,
+        best_bid: &str,
+        best_ask: &str,
+    ) -> PriceChangeBatchEntry {
+        serde_json::from_str(&format!(
+            r#"{{
+                "asset_id": "123",
+                "price": "{price}",
+                "size": "{size}",
+                "side": "{side}",
+                "hash": "h2",
+                "best_bid": "{best_bid}",
+                "best_ask": "{best_ask}"
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn seed_orders_levels_best_first() {
+        let book = OrderBook::from_book_update(&book());
+
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.51));
+        assert_eq!(book.midpoint(), Some(dec!(0.50)));
+        assert_eq!(book.spread(), Some(dec!(0.02)));
+        assert_eq!(book.bids(1), vec![level(dec!(0.49), dec!(20))]);
+        assert_eq!(book.asks(5).len(), 2, "both ask levels should be returned");
+    }
+
+    #[test]
+    fn price_change_updates_and_removes_levels() {
+        let mut book = OrderBook::from_book_update(&book());
+
+        let applied = book
+            .apply_price_change(&entry("0.50", "5", "BUY", "0.50", "0.51"), 1001)
+            .unwrap();
+        assert!(applied, "newer delta should be applied");
+        assert_eq!(book.best_bid().unwrap(), level(dec!(0.50), dec!(5)));
+        assert_eq!(book.hash(), Some("h2"));
+
+        book.apply_price_change(&entry("0.51", "0", "SELL", "0.50", "0.52"), 1002)
+            .unwrap();
+        assert_eq!(book.best_ask().unwrap().price, dec!(0.52));
+        assert_eq!(book.timestamp(), 1002);
+    }
+
+    #[test]
+    fn stale_price_change_is_ignored() {
+        let mut book = OrderBook::from_book_update(&book());
+
+        let applied = book
+            .apply_price_change(&entry("0.50", "5", "BUY", "0.50", "0.51"), 999)
+            .unwrap();
+        assert!(!applied, "delta older than the book should be skipped");
+        assert_eq!(book.best_bid().unwrap().price, dec!(0.49));
+    }
+
+    #[test]
+    fn mismatched_top_of_book_is_desync() {
+        let mut book = OrderBook::from_book_update(&book());
+
+        let err = book
+            .apply_price_change(&entry("0.50", "5", "BUY", "0.49", "0.51"), 1001)
+            .unwrap_err();
+        assert!(
+            matches!(&err, WsError::OrderBookDesync { asset_id, .. } if *asset_id == TokenId::from(123)),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn snapshot_with_same_hash_but_different_levels_is_desync() {
+        let mut order_book = OrderBook::from_book_update(&book());
+
+        let mut update = book();
+        update.timestamp = 1001;
+        update.bids.pop();
+
+        order_book.apply_book(&update).unwrap_err();
+
+        update.hash = Some("h3".to_owned());
+        assert!(
+            order_book.apply_book(&update).unwrap(),
+            "new hash should reseed"
+        );
+        assert_eq!(order_book.best_bid().unwrap().price, dec!(0.48));
+    }
+
+    #[test]
+    fn snapshot_converts_to_rest_layout() {
+        let mut book = OrderBook::from_book_update(&book());
+        OrderBookSummaryResponse::try_from(book.snapshot()).unwrap_err();
+
+        book.min_order_size = Some(dec!(5));
+        book.neg_risk = Some(false);
+        book.tick_size = Some(TickSize::Hundredth);
+
+        let response = OrderBookSummaryResponse::try_from(book.snapshot()).unwrap();
+        assert_eq!(response.timestamp.timestamp_millis(), 1000);
+        assert_eq!(
+            response.bids.last().unwrap().price,
+            dec!(0.49),
+            "REST lists the best bid last"
+        );
+        assert_eq!(
+            response.asks.last().unwrap().price,
+            dec!(0.51),
+            "REST lists the best ask last"
+        );
+    }
+}
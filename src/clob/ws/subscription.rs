@@ -4,11 +4,12 @@
 )]
 
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Instant;
 
 use async_stream::try_stream;
-use dashmap::{DashMap, Entry};
+use dashmap::{DashMap, DashSet, Entry};
 use futures::Stream;
 use tokio::sync::broadcast::error::RecvError;
 
@@ -18,6 +19,7 @@ use super::types::request::SubscriptionRequest;
 use super::types::response::WsMessage;
 use crate::Result;
 use crate::auth::Credentials;
+use crate::types::{ConditionId, TokenId};
 use crate::ws::ConnectionManager;
 use crate::ws::connection::ConnectionState;
 
@@ -26,9 +28,9 @@ use crate::ws::connection::ConnectionState;
 #[derive(Debug, Clone)]
 pub enum SubscriptionTarget {
     /// Subscribed to market data for specific assets.
-    Assets(Vec<String>),
+    Assets(Vec<TokenId>),
     /// Subscribed to user events for specific markets.
-    Markets(Vec<String>),
+    Markets(Vec<ConditionId>),
 }
 
 impl SubscriptionTarget {
@@ -75,9 +77,11 @@ pub struct SubscriptionManager {
     active_subs: DashMap<String, SubscriptionInfo>,
     interest: Arc<InterestTracker>,
     /// Subscribed assets with reference counts (for multiplexing)
-    subscribed_assets: DashMap<String, usize>,
+    subscribed_assets: DashMap<TokenId, usize>,
+    /// Subscribed assets with custom features enabled
+    custom_feature_assets: DashSet<TokenId>,
     /// Subscribed markets with reference counts (for multiplexing)
-    subscribed_markets: DashMap<String, usize>,
+    subscribed_markets: DashMap<ConditionId, usize>,
     last_auth: Arc<RwLock<Option<Credentials>>>,
 }
 
@@ -93,6 +97,7 @@ impl SubscriptionManager {
             active_subs: DashMap::new(),
             interest,
             subscribed_assets: DashMap::new(),
+            custom_feature_assets: DashSet::new(),
             subscribed_markets: DashMap::new(),
             last_auth: Arc::new(RwLock::new(None)),
         }
@@ -148,33 +153,44 @@ impl SubscriptionManager {
     /// to ensure a clean re-subscription.
     fn resubscribe_all(&self) {
         // Collect all unique assets and markets from active subscriptions
-        let mut assets: HashSet<String> = HashSet::new();
-        let mut markets: HashSet<String> = HashSet::new();
+        let mut assets: HashSet<TokenId> = HashSet::new();
+        let mut markets: HashSet<ConditionId> = HashSet::new();
 
         for entry in self.active_subs.iter() {
             match &entry.value().target {
-                SubscriptionTarget::Assets(a) => assets.extend(a.iter().cloned()),
-                SubscriptionTarget::Markets(m) => markets.extend(m.iter().cloned()),
+                SubscriptionTarget::Assets(a) => assets.extend(a.iter().copied()),
+                SubscriptionTarget::Markets(m) => markets.extend(m.iter().copied()),
             }
         }
 
         // Re-subscribe to market assets
         if !assets.is_empty() {
-            let assets_vec: Vec<String> = assets.into_iter().collect();
+            let assets_vec: Vec<TokenId> = assets.into_iter().collect();
             #[cfg(feature = "tracing")]
             tracing::debug!(count = assets_vec.len(), "Re-subscribing to market assets");
 
             // Rebuild subscribed_assets tracking
             for asset in &assets_vec {
-                self.subscribed_assets.insert(asset.clone(), 1);
+                self.subscribed_assets.insert(*asset, 1);
             }
 
-            let request = SubscriptionRequest::market(assets_vec);
-            if let Err(e) = self.connection.send(&request) {
-                #[cfg(feature = "tracing")]
-                tracing::warn!(%e, "Failed to re-subscribe to market channel");
-                #[cfg(not(feature = "tracing"))]
-                let _ = &e;
+            // Assets that had custom features enabled keep them
+            let (custom, plain): (Vec<TokenId>, Vec<TokenId>) = assets_vec
+                .into_iter()
+                .partition(|asset| self.custom_feature_assets.contains(asset));
+
+            let requests = [
+                (!plain.is_empty()).then(|| SubscriptionRequest::market(plain)),
+                (!custom.is_empty())
+                    .then(|| SubscriptionRequest::market(custom).with_custom_features(true)),
+            ];
+            for request in requests.iter().flatten() {
+                if let Err(e) = self.connection.send(request) {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(%e, "Failed to re-subscribe to market channel");
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = &e;
+                }
             }
         }
 
@@ -188,7 +204,7 @@ impl SubscriptionManager {
 
         if let Some(auth) = auth {
             if !markets.is_empty() {
-                let markets_vec: Vec<String> = markets.into_iter().collect();
+                let markets_vec: Vec<ConditionId> = markets.into_iter().collect();
                 #[cfg(feature = "tracing")]
                 tracing::debug!(
                     markets_count = markets_vec.len(),
@@ -197,7 +213,7 @@ impl SubscriptionManager {
 
                 // Rebuild subscribed_markets tracking
                 for market in &markets_vec {
-                    self.subscribed_markets.insert(market.clone(), 1);
+                    self.subscribed_markets.insert(*market, 1);
                 }
 
                 let request = SubscriptionRequest::user(markets_vec);
@@ -216,7 +232,7 @@ impl SubscriptionManager {
     /// This will fail if `asset_ids` is empty.
     pub fn subscribe_market(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         self.subscribe_market_with_options(asset_ids, false)
     }
@@ -224,12 +240,13 @@ impl SubscriptionManager {
     /// Subscribe to public market data channel with options.
     ///
     /// When `custom_features` is true, enables receiving additional message types:
-    /// `best_bid_ask`, `new_market`, `market_resolved`.
+    /// `best_bid_ask`, `new_market`, `market_resolved`. Assets that are already subscribed without
+    /// them are subscribed again with custom features enabled.
     ///
     /// This will fail if `asset_ids` is empty.
     pub fn subscribe_market_with_options(
         &self,
-        asset_ids: Vec<String>,
+        asset_ids: Vec<TokenId>,
         custom_features: bool,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         if asset_ids.is_empty() {
@@ -243,33 +260,44 @@ impl SubscriptionManager {
         self.interest.add(MessageInterest::MARKET);
 
         // Increment refcounts and determine which assets are truly new
-        let new_assets: Vec<String> = asset_ids
+        let new_assets: Vec<TokenId> = asset_ids
             .iter()
-            .filter_map(|id| match self.subscribed_assets.entry(id.to_owned()) {
+            .filter_map(|id| match self.subscribed_assets.entry(*id) {
                 Entry::Occupied(mut o) => {
                     *o.get_mut() += 1;
                     None
                 }
                 Entry::Vacant(v) => {
                     v.insert(1);
-                    Some(id.to_owned())
+                    Some(*id)
                 }
             })
             .collect();
 
-        // Only send subscription request for new assets
-        if new_assets.is_empty() {
+        // Custom features must also be enabled on assets already subscribed without them
+        let to_subscribe: Vec<TokenId> = if custom_features {
+            asset_ids
+                .iter()
+                .copied()
+                .filter(|id| self.custom_feature_assets.insert(*id))
+                .collect()
+        } else {
+            new_assets
+        };
+
+        // Only send subscription request for new assets, or those missing custom features
+        if to_subscribe.is_empty() {
             #[cfg(feature = "tracing")]
             tracing::debug!("All requested assets already subscribed, multiplexing");
         } else {
             #[cfg(feature = "tracing")]
             tracing::debug!(
-                count = new_assets.len(),
-                ?new_assets,
+                count = to_subscribe.len(),
+                ?to_subscribe,
                 custom_features,
-                "Subscribing to new market assets"
+                "Subscribing to market assets"
             );
-            let mut request = SubscriptionRequest::market(new_assets);
+            let mut request = SubscriptionRequest::market(to_subscribe);
             if custom_features {
                 request = request.with_custom_features(true);
             }
@@ -277,7 +305,7 @@ impl SubscriptionManager {
         }
 
         // Register subscription
-        let sub_id = format!("market:{}", asset_ids.join(","));
+        let sub_id = format!("market:{}", join(&asset_ids));
         self.active_subs.insert(
             sub_id,
             SubscriptionInfo {
@@ -286,11 +314,20 @@ impl SubscriptionManager {
             },
         );
 
+        Ok(self.market_stream(asset_ids))
+    }
+
+    /// Create a new market data stream for assets that are already subscribed.
+    ///
+    /// Unlike [`Self::subscribe_market`], this neither sends a subscription request nor
+    /// touches reference counts. It is used to resume consuming messages after a stream has
+    /// ended, e.g. because it lagged behind the broadcast channel.
+    pub fn market_stream(&self, asset_ids: Vec<TokenId>) -> impl Stream<Item = Result<WsMessage>> {
         // Create filtered stream with its own receiver
         let mut rx = self.connection.subscribe();
-        let asset_ids_set: HashSet<String> = asset_ids.into_iter().collect();
+        let asset_ids_set: HashSet<TokenId> = asset_ids.into_iter().collect();
 
-        Ok(try_stream! {
+        try_stream! {
             loop {
                 match rx.recv().await {
                     Ok(msg) => {
@@ -329,35 +366,28 @@ impl SubscriptionManager {
                     }
                 }
             }
-        })
+        }
     }
 
     /// Subscribe to authenticated user channel.
     pub fn subscribe_user(
         &self,
-        markets: Vec<String>,
-        auth: &Credentials,
+        markets: Vec<ConditionId>,
+        auth: Credentials,
     ) -> Result<impl Stream<Item = Result<WsMessage>>> {
         self.interest.add(MessageInterest::USER);
 
-        // Store auth for re-subscription on reconnect.
-        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
-        *self
-            .last_auth
-            .write()
-            .unwrap_or_else(PoisonError::into_inner) = Some(auth.clone());
-
         // Increment refcounts and determine which markets are truly new
-        let new_markets: Vec<String> = markets
+        let new_markets: Vec<ConditionId> = markets
             .iter()
-            .filter_map(|id| match self.subscribed_markets.entry(id.to_owned()) {
+            .filter_map(|id| match self.subscribed_markets.entry(*id) {
                 Entry::Occupied(mut o) => {
                     *o.get_mut() += 1;
                     None
                 }
                 Entry::Vacant(v) => {
                     v.insert(1);
-                    Some(id.to_owned())
+                    Some(*id)
                 }
             })
             .collect();
@@ -374,11 +404,18 @@ impl SubscriptionManager {
                 "Subscribing to user channel"
             );
             let request = SubscriptionRequest::user(new_markets);
-            self.connection.send_authenticated(&request, auth)?;
+            self.connection.send_authenticated(&request, &auth)?;
         }
 
+        // Store auth for re-subscription on reconnect.
+        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
+        *self
+            .last_auth
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(auth);
+
         // Register subscription
-        let sub_id = format!("user:{}", markets.join(","));
+        let sub_id = format!("user:{}", join(&markets));
         self.active_subs.insert(
             sub_id,
             SubscriptionInfo {
@@ -387,10 +424,52 @@ impl SubscriptionManager {
             },
         );
 
-        // Create stream for user messages
+        Ok(self.user_stream())
+    }
+
+    /// Replace the credentials of the user channel.
+    ///
+    /// The subscription of every subscribed market is sent again with the new credentials, so
+    /// that it no longer depends on the previous API key.
+    pub fn replace_auth(&self, auth: Credentials) -> Result<()> {
+        let mut subscribed = false;
+        let mut markets: HashSet<ConditionId> = HashSet::new();
+
+        for entry in &self.active_subs {
+            if let SubscriptionTarget::Markets(m) = &entry.value().target {
+                subscribed = true;
+                markets.extend(m.iter().copied());
+            }
+        }
+
+        if subscribed {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                markets_count = markets.len(),
+                "Re-authenticating user channel"
+            );
+
+            let request = SubscriptionRequest::user(markets.into_iter().collect());
+            self.connection.send_authenticated(&request, &auth)?;
+        }
+
+        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
+        *self
+            .last_auth
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(auth);
+
+        Ok(())
+    }
+
+    /// Create a new user event stream for markets that are already subscribed.
+    ///
+    /// Like [`Self::market_stream`], this neither sends a subscription request nor touches
+    /// reference counts.
+    pub fn user_stream(&self) -> impl Stream<Item = Result<WsMessage>> {
         let mut rx = self.connection.subscribe();
 
-        Ok(try_stream! {
+        try_stream! {
             loop {
                 match rx.recv().await {
                     Ok(msg) => {
@@ -408,7 +487,7 @@ impl SubscriptionManager {
                     }
                 }
             }
-        })
+        }
     }
 
     /// Get information about all active subscriptions.
@@ -435,7 +514,7 @@ impl SubscriptionManager {
     /// This decrements the reference count for each asset. Only sends an unsubscribe
     /// request to the server when the reference count reaches zero (no other streams
     /// are using that asset).
-    pub fn unsubscribe_market(&self, asset_ids: &[String]) -> Result<()> {
+    pub fn unsubscribe_market(&self, asset_ids: &[TokenId]) -> Result<()> {
         if asset_ids.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "asset_ids cannot be empty: at least one asset ID must be provided for unsubscription"
@@ -451,7 +530,7 @@ impl SubscriptionManager {
             if let Some(mut refcount) = self.subscribed_assets.get_mut(id) {
                 *refcount = refcount.saturating_sub(1);
                 if *refcount == 0 {
-                    to_unsubscribe.push(id.clone());
+                    to_unsubscribe.push(*id);
                 }
             }
         }
@@ -459,6 +538,7 @@ impl SubscriptionManager {
         // Clean up tracking structures for zero-refcount assets
         for id in &to_unsubscribe {
             self.subscribed_assets.remove(id);
+            self.custom_feature_assets.remove(id);
         }
 
         // Send unsubscribe only for zero-refcount assets
@@ -493,7 +573,7 @@ impl SubscriptionManager {
     /// This decrements the reference count for each market. Only sends an unsubscribe
     /// request to the server when the reference count reaches zero (no other streams
     /// are using that market).
-    pub fn unsubscribe_user(&self, markets: &[String]) -> Result<()> {
+    pub fn unsubscribe_user(&self, markets: &[ConditionId]) -> Result<()> {
         if markets.is_empty() {
             return Err(WsError::SubscriptionFailed(
                 "markets cannot be empty: at least one market ID must be provided for unsubscription"
@@ -509,7 +589,7 @@ impl SubscriptionManager {
             if let Some(mut refcount) = self.subscribed_markets.get_mut(m) {
                 *refcount = refcount.saturating_sub(1);
                 if *refcount == 0 {
-                    to_unsubscribe.push(m.clone());
+                    to_unsubscribe.push(*m);
                 }
             }
         }
@@ -555,3 +635,11 @@ impl SubscriptionManager {
         Ok(())
     }
 }
+
+/// Joins IDs with commas to key a subscription.
+fn join<T: Display>(ids: &[T]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
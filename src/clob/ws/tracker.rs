@@ -0,0 +1,646 @@
+//! Lifecycle tracking for the authenticated user's orders.
+//!
+//! The user channel reports order placements, updates and cancellations as [`OrderMessage`]s and
+//! the settlement of fills as [`TradeMessage`]s, while the REST `orders` endpoint reports which
+//! orders are still resting on the book. [`OrderTracker`] merges both into one
+//! [`TrackedOrder`] per order ID, whose [`OrderLifecycle`] follows the order from placement
+//! through matching to on-chain confirmation (or failure or cancellation).
+//!
+//! Events missed while the user channel was disconnected or while the subscription lagged are
+//! recovered by resyncing from REST, so the tracked state converges on the exchange's.
+
+use std::collections::{HashMap, HashSet};
+
+use async_stream::try_stream;
+use dashmap::DashMap;
+use futures::{Stream, StreamExt as _};
+use reqwest::Client as ReqwestClient;
+
+use super::Client as WsClient;
+use super::error::WsError;
+use super::types::response::{OrderMessage, TradeMessage, WsMessage};
+use crate::Result;
+use crate::auth::state::Authenticated;
+use crate::auth::{Kind as AuthKind, Normal};
+use crate::clob::Client;
+use crate::clob::client::TERMINAL_CURSOR;
+use crate::clob::types::request::OrdersRequest;
+use crate::clob::types::response::OpenOrderResponse;
+use crate::clob::types::{OrderStatusType, Side, TraderSide};
+use crate::transport::HttpTransport;
+use crate::types::{ConditionId, Decimal, TokenId};
+
+/// Where an order is in its lifecycle.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrderLifecycle {
+    /// Resting on the book without any fills
+    Placed,
+    /// Resting on the book with some of its size matched
+    PartiallyMatched,
+    /// Fully matched, with at least one of its trades not yet mined
+    Matched,
+    /// Fully matched and all of its trades mined
+    Mined,
+    /// Fully matched and all of its trades confirmed
+    Confirmed,
+    /// At least one of its trades failed to settle
+    Failed,
+    /// Canceled, either explicitly or by the exchange
+    Canceled,
+}
+
+impl OrderLifecycle {
+    /// Whether the order can still be matched.
+    #[must_use]
+    pub const fn is_open(self) -> bool {
+        matches!(self, Self::Placed | Self::PartiallyMatched)
+    }
+}
+
+/// Settlement status of a single trade, as reported by the user channel.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Settlement {
+    Matched,
+    Mined,
+    Confirmed,
+    Failed,
+}
+
+impl Settlement {
+    fn parse(status: &str) -> Option<Self> {
+        match status.to_ascii_uppercase().as_str() {
+            "MATCHED" | "RETRYING" => Some(Self::Matched),
+            "MINED" => Some(Self::Mined),
+            "CONFIRMED" => Some(Self::Confirmed),
+            "FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Merged view of one of the user's orders.
+///
+/// Orders first seen through a trade, such as immediately filled `FOK` orders, only know their
+/// matched size until an order message or REST snapshot reports the original size.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackedOrder {
+    pub id: String,
+    /// Market of the order, once any event for it has been seen
+    pub market: Option<ConditionId>,
+    /// Token of the order, once any event for it has been seen
+    pub asset_id: Option<TokenId>,
+    pub side: Side,
+    pub price: Decimal,
+    pub original_size: Decimal,
+    pub size_matched: Decimal,
+    pub lifecycle: OrderLifecycle,
+    /// IDs of the trades this order took part in
+    pub trades: Vec<String>,
+    /// Matched size as last reported by an order message or REST
+    reported_matched: Decimal,
+    canceled: bool,
+    /// Size of this order matched by each trade, and how far that trade has settled
+    fills: HashMap<String, (Decimal, Settlement)>,
+}
+
+impl TrackedOrder {
+    fn new(id: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            market: None,
+            asset_id: None,
+            side: Side::Unknown,
+            price: Decimal::ZERO,
+            original_size: Decimal::ZERO,
+            size_matched: Decimal::ZERO,
+            lifecycle: OrderLifecycle::Placed,
+            trades: Vec::new(),
+            reported_matched: Decimal::ZERO,
+            canceled: false,
+            fills: HashMap::new(),
+        }
+    }
+
+    /// Size still resting on the book, zero once the order is no longer open.
+    #[must_use]
+    pub fn remaining(&self) -> Decimal {
+        if self.lifecycle.is_open() {
+            (self.original_size - self.size_matched).max(Decimal::ZERO)
+        } else {
+            Decimal::ZERO
+        }
+    }
+
+    fn apply_open_order(&mut self, order: &OpenOrderResponse) {
+        self.market = Some(order.market);
+        self.asset_id = Some(order.asset_id);
+        self.side = order.side;
+        self.price = order.price;
+        self.original_size = order.original_size;
+        self.reported_matched = self.reported_matched.max(order.size_matched);
+        self.canceled |= order.status == OrderStatusType::Canceled;
+        for trade in &order.associate_trades {
+            self.add_trade(trade);
+        }
+    }
+
+    fn apply_order_message(&mut self, order: &OrderMessage) {
+        self.market = Some(order.market);
+        self.asset_id = Some(order.asset_id);
+        self.side = order.side;
+        self.price = order.price;
+        if let Some(original_size) = order.original_size {
+            self.original_size = original_size;
+        }
+        if let Some(size_matched) = order.size_matched {
+            self.reported_matched = self.reported_matched.max(size_matched);
+        }
+        self.canceled |= order.msg_type.as_deref() == Some("CANCELLATION");
+        for trade in order.associate_trades.iter().flatten() {
+            self.add_trade(trade);
+        }
+    }
+
+    fn apply_fill(&mut self, trade: &TradeMessage, matched: Decimal) {
+        self.market.get_or_insert(trade.market);
+        self.add_trade(&trade.id);
+        if let Some(settlement) = Settlement::parse(&trade.status) {
+            self.fills.insert(trade.id.clone(), (matched, settlement));
+        }
+    }
+
+    fn add_trade(&mut self, trade_id: &str) {
+        if !self.trades.iter().any(|id| id == trade_id) {
+            self.trades.push(trade_id.to_owned());
+        }
+    }
+
+    fn refresh(&mut self) {
+        let filled: Decimal = self.fills.values().map(|(matched, _)| *matched).sum();
+        self.size_matched = self.reported_matched.max(filled);
+        self.original_size = self.original_size.max(self.size_matched);
+
+        let settled = self.fills.values().map(|(_, settlement)| *settlement).min();
+        self.lifecycle = if self
+            .fills
+            .values()
+            .any(|(_, settlement)| *settlement == Settlement::Failed)
+        {
+            OrderLifecycle::Failed
+        } else if self.canceled {
+            OrderLifecycle::Canceled
+        } else if self.size_matched < self.original_size || self.original_size.is_zero() {
+            if self.size_matched.is_zero() {
+                OrderLifecycle::Placed
+            } else {
+                OrderLifecycle::PartiallyMatched
+            }
+        } else {
+            match settled {
+                Some(Settlement::Confirmed) => OrderLifecycle::Confirmed,
+                Some(Settlement::Mined) => OrderLifecycle::Mined,
+                _ => OrderLifecycle::Matched,
+            }
+        };
+    }
+}
+
+/// Size and notional of the open orders on one side of an asset.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Exposure {
+    /// Unmatched size of open buy orders
+    pub buy_size: Decimal,
+    /// Collateral committed to open buy orders
+    pub buy_notional: Decimal,
+    /// Unmatched size of open sell orders
+    pub sell_size: Decimal,
+    /// Collateral receivable from open sell orders
+    pub sell_notional: Decimal,
+}
+
+/// Keeps track of the authenticated user's orders by merging user channel events with REST
+/// snapshots.
+///
+/// # Examples
+///
+/// ```rust, no_run
+/// use std::str::FromStr as _;
+///
+/// use alloy::signers::Signer as _;
+/// use alloy::signers::local::LocalSigner;
+/// use futures::StreamExt as _;
+/// use polymarket_client_sdk::auth::Credentials;
+/// use polymarket_client_sdk::clob::ws::{Client as WsClient, OrderTracker};
+/// use polymarket_client_sdk::clob::{Client, Config};
+/// use polymarket_client_sdk::{POLYGON, PRIVATE_KEY_VAR};
+/// use uuid::Uuid;
+///
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let private_key = std::env::var(PRIVATE_KEY_VAR)?;
+///     let signer = LocalSigner::from_str(&private_key)?.with_chain_id(Some(POLYGON));
+///     let credentials = Credentials::new(
+///         Uuid::parse_str(&std::env::var("POLYMARKET_API_KEY")?)?,
+///         std::env::var("POLYMARKET_API_SECRET")?,
+///         std::env::var("POLYMARKET_API_PASSPHRASE")?,
+///     );
+///
+///     let client = Client::new("https://clob.polymarket.com", Config::default())?
+///         .authentication_builder(&signer)
+///         .credentials(credentials.clone())
+///         .authenticate()
+///         .await?;
+///     let ws = WsClient::default().authenticate(credentials, client.address())?;
+///
+///     let tracker = OrderTracker::new(client);
+///     let stream = tracker.sync(&ws, Vec::new())?;
+///     let mut stream = Box::pin(stream);
+///
+///     while let Some(order) = stream.next().await {
+///         let order = order?;
+///         println!("{} is {:?}", order.id, order.lifecycle);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct OrderTracker<K: AuthKind = Normal, T: HttpTransport = ReqwestClient> {
+    client: Client<Authenticated<K>, T>,
+    orders: DashMap<String, TrackedOrder>,
+}
+
+impl<K: AuthKind, T: HttpTransport> OrderTracker<K, T> {
+    #[must_use]
+    pub fn new(client: Client<Authenticated<K>, T>) -> Self {
+        Self {
+            client,
+            orders: DashMap::new(),
+        }
+    }
+
+    /// Subscribe to the user channel for `markets` (all markets when empty) and keep the
+    /// tracked orders up to date.
+    ///
+    /// The returned stream must be polled for the orders to advance. It starts with a REST
+    /// resync and then yields every order changed by an incoming event. Orders are resynced from
+    /// REST again whenever the user channel reconnects or the subscription lags.
+    pub fn sync<'tracker, W: AuthKind>(
+        &'tracker self,
+        ws: &'tracker WsClient<Authenticated<W>>,
+        markets: Vec<ConditionId>,
+    ) -> Result<impl Stream<Item = Result<TrackedOrder>> + 'tracker> {
+        let stream = ws.subscribe_user_events(markets.clone())?;
+        let mut states = ws.user_connection_states()?;
+
+        Ok(try_stream! {
+            let mut stream = stream.boxed();
+            let mut connected = states.borrow_and_update().is_connected();
+            let mut watching = true;
+
+            for order in self.resync(&markets).await? {
+                yield order;
+            }
+
+            loop {
+                let event = tokio::select! {
+                    message = stream.next() => Event::Message(message),
+                    changed = states.changed(), if watching => Event::State(changed.is_ok()),
+                };
+
+                match event {
+                    Event::Message(None) => break,
+                    Event::Message(Some(Ok(message))) => {
+                        for order in self.apply(&message) {
+                            yield order;
+                        }
+                    }
+                    Event::Message(Some(Err(e)))
+                        if matches!(e.downcast_ref::<WsError>(), Some(WsError::Lagged { .. })) =>
+                    {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(error = %e, "Order tracker lagged, resyncing orders");
+                        stream = ws.resume_user_events()?.boxed();
+                        for order in self.resync(&markets).await? {
+                            yield order;
+                        }
+                    }
+                    Event::Message(Some(Err(e))) => Err(e)?,
+                    Event::State(false) => watching = false,
+                    Event::State(true) => {
+                        let now_connected = states.borrow_and_update().is_connected();
+                        if now_connected && !connected {
+                            #[cfg(feature = "tracing")]
+                            tracing::debug!("User channel reconnected, resyncing orders");
+                            for order in self.resync(&markets).await? {
+                                yield order;
+                            }
+                        }
+                        connected = now_connected;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Reconcile the tracked orders in `markets` (all markets when empty) with REST and return
+    /// those that changed.
+    ///
+    /// Every open order reported by the exchange is merged in. Orders tracked as open that the
+    /// exchange no longer reports were matched or canceled in the meantime and are refreshed
+    /// individually. An order that fails to refresh is left as it is until the next resync.
+    pub async fn resync(&self, markets: &[ConditionId]) -> Result<Vec<TrackedOrder>> {
+        let requests: Vec<OrdersRequest> = if markets.is_empty() {
+            vec![OrdersRequest::default()]
+        } else {
+            markets
+                .iter()
+                .map(|market| OrdersRequest::builder().market(*market).build())
+                .collect()
+        };
+
+        let mut open = Vec::new();
+        for request in &requests {
+            let mut next_cursor = None;
+            loop {
+                let page = self.client.orders(request, next_cursor).await?;
+                let done = page.next_cursor == TERMINAL_CURSOR || page.data.is_empty();
+                open.extend(page.data);
+                if done {
+                    break;
+                }
+                next_cursor = Some(page.next_cursor);
+            }
+        }
+
+        let mut changed = Vec::new();
+        let listed: HashSet<&str> = open.iter().map(|order| order.id.as_str()).collect();
+        let stale: Vec<String> = self
+            .orders
+            .iter()
+            .filter(|order| {
+                order.lifecycle.is_open()
+                    && !listed.contains(order.id.as_str())
+                    && (markets.is_empty()
+                        || order.market.is_some_and(|market| markets.contains(&market)))
+            })
+            .map(|order| order.id.clone())
+            .collect();
+
+        for order in &open {
+            changed.extend(self.update(&order.id, |tracked| tracked.apply_open_order(order)));
+        }
+        for id in stale {
+            let order = match self.client.order(&id).await {
+                Ok(order) => order,
+                Err(e) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(error = %e, order_id = %id, "Failed to refresh order, skipping it");
+                    #[cfg(not(feature = "tracing"))]
+                    let _: &crate::error::Error = &e;
+                    continue;
+                }
+            };
+            changed.extend(self.update(&id, |tracked| tracked.apply_open_order(&order)));
+        }
+
+        Ok(changed)
+    }
+
+    /// Merge a user channel message and return the orders it changed.
+    ///
+    /// [`Self::sync`] calls this for every message it receives. It is public so that events
+    /// from other sources, such as the paper trading client, can be tracked too.
+    #[expect(
+        clippy::must_use_candidate,
+        reason = "Callers may apply messages for their side effects only"
+    )]
+    pub fn apply(&self, message: &WsMessage) -> Vec<TrackedOrder> {
+        match message {
+            WsMessage::Order(order) => self
+                .update(&order.id, |tracked| tracked.apply_order_message(order))
+                .into_iter()
+                .collect(),
+            WsMessage::Trade(trade) => self.apply_trade(trade),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The tracked order with `order_id`, if any.
+    #[must_use]
+    pub fn order(&self, order_id: &str) -> Option<TrackedOrder> {
+        self.orders.get(order_id).map(|order| order.clone())
+    }
+
+    /// All tracked orders, in no particular order.
+    #[must_use]
+    pub fn orders(&self) -> Vec<TrackedOrder> {
+        self.orders.iter().map(|order| order.clone()).collect()
+    }
+
+    /// Tracked orders that can still be matched.
+    #[must_use]
+    pub fn open_orders(&self) -> Vec<TrackedOrder> {
+        self.orders
+            .iter()
+            .filter(|order| order.lifecycle.is_open())
+            .map(|order| order.clone())
+            .collect()
+    }
+
+    /// Unmatched size and notional of the open orders on `asset_id`.
+    #[must_use]
+    pub fn open_exposure(&self, asset_id: TokenId) -> Exposure {
+        self.orders
+            .iter()
+            .filter(|order| order.asset_id == Some(asset_id))
+            .fold(Exposure::default(), |mut exposure, order| {
+                let remaining = order.remaining();
+                match order.side {
+                    Side::Buy => {
+                        exposure.buy_size += remaining;
+                        exposure.buy_notional += remaining * order.price;
+                    }
+                    Side::Sell => {
+                        exposure.sell_size += remaining;
+                        exposure.sell_notional += remaining * order.price;
+                    }
+                    _ => {}
+                }
+                exposure
+            })
+    }
+
+    /// Stop tracking the order with `order_id`, e.g. once it has reached a terminal state.
+    #[must_use]
+    pub fn remove(&self, order_id: &str) -> Option<TrackedOrder> {
+        self.orders.remove(order_id).map(|(_, order)| order)
+    }
+
+    fn apply_trade(&self, trade: &TradeMessage) -> Vec<TrackedOrder> {
+        let key = self.client.state().credentials().key();
+        let mut changed = Vec::new();
+
+        if let Some(taker_order_id) = &trade.taker_order_id
+            && (trade.trader_side == Some(TraderSide::Taker)
+                || self.orders.contains_key(taker_order_id))
+        {
+            changed.extend(self.update(taker_order_id, |tracked| {
+                if tracked.asset_id.is_none() {
+                    tracked.asset_id = Some(trade.asset_id);
+                    tracked.side = trade.side;
+                    tracked.price = trade.price;
+                }
+                tracked.apply_fill(trade, trade.size);
+            }));
+        }
+
+        for maker in &trade.maker_orders {
+            if maker.owner != key && !self.orders.contains_key(&maker.order_id) {
+                continue;
+            }
+
+            changed.extend(self.update(&maker.order_id, |tracked| {
+                if tracked.asset_id.is_none() {
+                    tracked.asset_id = Some(maker.asset_id);
+                    tracked.price = maker.price;
+                }
+                tracked.apply_fill(trade, maker.matched_amount);
+            }));
+        }
+
+        changed
+    }
+
+    /// Apply `merge` to the order with `order_id`, tracking it if it is new, and return it if
+    /// anything changed.
+    fn update<F: FnOnce(&mut TrackedOrder)>(
+        &self,
+        order_id: &str,
+        merge: F,
+    ) -> Option<TrackedOrder> {
+        let mut entry = self
+            .orders
+            .entry(order_id.to_owned())
+            .or_insert_with(|| TrackedOrder::new(order_id));
+        let before = entry.clone();
+
+        merge(&mut entry);
+        entry.refresh();
+
+        (*entry != before).then(|| entry.clone())
+    }
+}
+
+/// Whatever woke up [`OrderTracker::sync`].
+#[expect(
+    clippy::large_enum_variant,
+    reason = "Lives for a single loop iteration, boxing would only add an allocation"
+)]
+enum Event {
+    Message(Option<Result<WsMessage>>),
+    /// Whether the connection state can still be observed
+    State(bool),
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn order_message(msg_type: &str, size_matched: &str) -> OrderMessage {
+        serde_json::from_value(serde_json::json!({
+            "id": "0x1",
+            "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "asset_id": "123",
+            "side": "BUY",
+            "price": "0.5",
+            "type": msg_type,
+            "original_size": "10",
+            "size_matched": size_matched
+        }))
+        .unwrap()
+    }
+
+    fn trade_message(id: &str, status: &str, size: &str) -> TradeMessage {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "asset_id": "123",
+            "side": "BUY",
+            "price": "0.5",
+            "size": size,
+            "status": status,
+            "taker_order_id": "0x1",
+            "trader_side": "TAKER"
+        }))
+        .unwrap()
+    }
+
+    fn apply<F: FnOnce(&mut TrackedOrder)>(order: &mut TrackedOrder, merge: F) {
+        merge(order);
+        order.refresh();
+    }
+
+    #[test]
+    fn lifecycle_should_follow_matching_and_settlement() {
+        let mut order = TrackedOrder::new("0x1");
+
+        apply(&mut order, |o| {
+            o.apply_order_message(&order_message("PLACEMENT", "0"));
+        });
+        assert_eq!(order.lifecycle, OrderLifecycle::Placed);
+        assert_eq!(order.remaining(), dec!(10));
+
+        let first = trade_message("t1", "MATCHED", "4");
+        apply(&mut order, |o| o.apply_fill(&first, first.size));
+        assert_eq!(order.lifecycle, OrderLifecycle::PartiallyMatched);
+        assert_eq!(order.size_matched, dec!(4));
+
+        // The order update reports the same fill, which must not be counted twice
+        apply(&mut order, |o| {
+            o.apply_order_message(&order_message("UPDATE", "4"));
+        });
+        assert_eq!(order.size_matched, dec!(4));
+
+        let second = trade_message("t2", "MATCHED", "6");
+        apply(&mut order, |o| o.apply_fill(&second, second.size));
+        assert_eq!(order.lifecycle, OrderLifecycle::Matched);
+        assert_eq!(order.remaining(), Decimal::ZERO);
+
+        let first = trade_message("t1", "CONFIRMED", "4");
+        apply(&mut order, |o| o.apply_fill(&first, first.size));
+        let second = trade_message("t2", "MINED", "6");
+        apply(&mut order, |o| o.apply_fill(&second, second.size));
+        assert_eq!(order.lifecycle, OrderLifecycle::Mined);
+
+        let second = trade_message("t2", "CONFIRMED", "6");
+        apply(&mut order, |o| o.apply_fill(&second, second.size));
+        assert_eq!(order.lifecycle, OrderLifecycle::Confirmed);
+        assert_eq!(order.trades, ["t1", "t2"]);
+    }
+
+    #[test]
+    fn failed_trade_should_outrank_cancellation() {
+        let mut order = TrackedOrder::new("0x1");
+
+        apply(&mut order, |o| {
+            o.apply_order_message(&order_message("PLACEMENT", "0"));
+        });
+        let trade = trade_message("t1", "MATCHED", "4");
+        apply(&mut order, |o| o.apply_fill(&trade, trade.size));
+        apply(&mut order, |o| {
+            o.apply_order_message(&order_message("CANCELLATION", "4"));
+        });
+        assert_eq!(order.lifecycle, OrderLifecycle::Canceled);
+        assert_eq!(order.remaining(), Decimal::ZERO);
+
+        let trade = trade_message("t1", "FAILED", "4");
+        apply(&mut order, |o| o.apply_fill(&trade, trade.size));
+        assert_eq!(order.lifecycle, OrderLifecycle::Failed);
+    }
+}
@@ -1,5 +1,6 @@
 use serde::Serialize;
 
+use crate::types::{ConditionId, TokenId};
 use crate::ws::WithCredentials;
 
 /// Subscription request message sent to the WebSocket server.
@@ -12,10 +13,10 @@ pub struct SubscriptionRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operation: Option<String>,
     /// List of market IDs
-    pub markets: Vec<String>,
+    pub markets: Vec<ConditionId>,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
-    pub asset_ids: Vec<String>,
+    pub asset_ids: Vec<TokenId>,
     /// Request initial state dump
     #[serde(skip_serializing_if = "Option::is_none")]
     pub initial_dump: Option<bool>,
@@ -29,7 +30,7 @@ impl WithCredentials for SubscriptionRequest {}
 impl SubscriptionRequest {
     /// Create a market subscription request.
     #[must_use]
-    pub fn market(asset_ids: Vec<String>) -> Self {
+    pub fn market(asset_ids: Vec<TokenId>) -> Self {
         Self {
             r#type: "market".to_owned(),
             operation: Some("subscribe".to_owned()),
@@ -42,7 +43,7 @@ impl SubscriptionRequest {
 
     /// Create a market unsubscribe request.
     #[must_use]
-    pub fn market_unsubscribe(asset_ids: Vec<String>) -> Self {
+    pub fn market_unsubscribe(asset_ids: Vec<TokenId>) -> Self {
         Self {
             r#type: "market".to_owned(),
             operation: Some("unsubscribe".to_owned()),
@@ -55,7 +56,7 @@ impl SubscriptionRequest {
 
     /// Create a user subscription request.
     #[must_use]
-    pub fn user(markets: Vec<String>) -> Self {
+    pub fn user(markets: Vec<ConditionId>) -> Self {
         Self {
             r#type: "user".to_owned(),
             operation: Some("subscribe".to_owned()),
@@ -68,7 +69,7 @@ impl SubscriptionRequest {
 
     /// Create a user unsubscribe request.
     #[must_use]
-    pub fn user_unsubscribe(markets: Vec<String>) -> Self {
+    pub fn user_unsubscribe(markets: Vec<ConditionId>) -> Self {
         Self {
             r#type: "user".to_owned(),
             operation: Some("unsubscribe".to_owned()),
@@ -96,7 +97,7 @@ mod tests {
 
     #[test]
     fn serialize_market_subscription_request() {
-        let request = SubscriptionRequest::market(vec!["asset1".to_owned(), "asset2".to_owned()]);
+        let request = SubscriptionRequest::market(vec![TokenId::from(1), TokenId::from(2)]);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"type\":\"market\""));
@@ -106,7 +107,7 @@ mod tests {
 
     #[test]
     fn serialize_user_subscription_request() {
-        let request = SubscriptionRequest::user(vec!["market1".to_owned()]);
+        let request = SubscriptionRequest::user(vec![ConditionId::default()]);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"type\":\"user\""));
@@ -117,7 +118,7 @@ mod tests {
     #[test]
     fn serialize_market_subscription_with_custom_features() {
         let request =
-            SubscriptionRequest::market(vec!["asset1".to_owned()]).with_custom_features(true);
+            SubscriptionRequest::market(vec![TokenId::from(1)]).with_custom_features(true);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"custom_feature_enabled\":true"));
@@ -126,7 +127,7 @@ mod tests {
     #[test]
     fn serialize_market_unsubscribe_request() {
         let request =
-            SubscriptionRequest::market_unsubscribe(vec!["asset1".to_owned(), "asset2".to_owned()]);
+            SubscriptionRequest::market_unsubscribe(vec![TokenId::from(1), TokenId::from(2)]);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"type\":\"market\""));
@@ -137,7 +138,7 @@ mod tests {
 
     #[test]
     fn serialize_user_unsubscribe_request() {
-        let request = SubscriptionRequest::user_unsubscribe(vec!["market1".to_owned()]);
+        let request = SubscriptionRequest::user_unsubscribe(vec![ConditionId::default()]);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"type\":\"user\""));
@@ -148,7 +149,7 @@ mod tests {
     #[test]
     fn with_custom_features_false_serializes() {
         let request =
-            SubscriptionRequest::market(vec!["asset1".to_owned()]).with_custom_features(false);
+            SubscriptionRequest::market(vec![TokenId::from(1)]).with_custom_features(false);
 
         let json = serde_json::to_string(&request).unwrap();
         assert!(json.contains("\"custom_feature_enabled\":false"));
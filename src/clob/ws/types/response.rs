@@ -9,7 +9,7 @@ use crate::auth::ApiKey;
 use crate::clob::types::{Side, TraderSide};
 use crate::clob::ws::interest::MessageInterest;
 use crate::error::Kind;
-use crate::types::Decimal;
+use crate::types::{ConditionId, Decimal, TokenId};
 
 /// Top-level WebSocket message wrapper.
 ///
@@ -70,9 +70,9 @@ impl WsMessage {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BookUpdate {
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Market identifier
-    pub market: String,
+    pub market: ConditionId,
     /// Unix timestamp in milliseconds
     #[serde_as(as = "DisplayFromStr")]
     pub timestamp: i64,
@@ -105,7 +105,7 @@ pub struct OrderBookLevel {
 #[derive(Debug, Clone, Deserialize)]
 pub struct PriceChange {
     /// Market identifier
-    pub market: String,
+    pub market: ConditionId,
     #[serde_as(as = "DisplayFromStr")]
     pub timestamp: i64,
     #[serde(default)]
@@ -116,7 +116,7 @@ pub struct PriceChange {
 #[derive(Debug, Clone, Deserialize)]
 pub struct PriceChangeBatchEntry {
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// New price
     pub price: Decimal,
     /// Total size affected by this price change (if provided)
@@ -141,9 +141,9 @@ pub struct PriceChangeBatchEntry {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TickSizeChange {
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Market identifier
-    pub market: String,
+    pub market: ConditionId,
     /// Previous tick size
     pub old_tick_size: Decimal,
     /// New tick size
@@ -159,9 +159,9 @@ pub struct TickSizeChange {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct LastTradePrice {
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Market identifier
-    pub market: String,
+    pub market: ConditionId,
     /// Last trade price
     pub price: Decimal,
     /// Side of the last trade
@@ -186,9 +186,9 @@ pub struct LastTradePrice {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BestBidAsk {
     /// Market identifier (condition ID)
-    pub market: String,
+    pub market: ConditionId,
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Current best bid price
     pub best_bid: Decimal,
     /// Current best ask price
@@ -212,14 +212,14 @@ pub struct NewMarket {
     /// Market question
     pub question: String,
     /// Market identifier (condition ID)
-    pub market: String,
+    pub market: ConditionId,
     /// Market slug
     pub slug: String,
     /// Market description
     pub description: String,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
-    pub asset_ids: Vec<String>,
+    pub asset_ids: Vec<TokenId>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     pub outcomes: Vec<String>,
     /// Event message object
@@ -242,18 +242,18 @@ pub struct MarketResolved {
     /// Market question
     pub question: String,
     /// Market identifier (condition ID)
-    pub market: String,
+    pub market: ConditionId,
     /// Market slug
     pub slug: String,
     /// Market description
     pub description: String,
     /// List of asset IDs
     #[serde(rename = "assets_ids")]
-    pub asset_ids: Vec<String>,
+    pub asset_ids: Vec<TokenId>,
     /// List of outcomes (e.g., `["Yes", "No"]`)
     pub outcomes: Vec<String>,
     /// Winning asset ID
-    pub winning_asset_id: String,
+    pub winning_asset_id: TokenId,
     /// Winning outcome (e.g., "Yes" or "No")
     pub winning_outcome: String,
     /// Event message object
@@ -285,7 +285,7 @@ pub struct EventMessage {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MakerOrder {
     /// Asset/token identifier of the maker order
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Amount of maker order matched in trade
     pub matched_amount: Decimal,
     /// Maker order ID
@@ -305,9 +305,9 @@ pub struct TradeMessage {
     /// Trade identifier
     pub id: String,
     /// Market identifier (condition ID)
-    pub market: String,
+    pub market: ConditionId,
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Side of the trade (BUY or SELL)
     pub side: Side,
     /// Size of the trade
@@ -361,9 +361,9 @@ pub struct OrderMessage {
     /// Order identifier
     pub id: String,
     /// Market identifier (condition ID)
-    pub market: String,
+    pub market: ConditionId,
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Side of the order (BUY or SELL)
     pub side: Side,
     /// Order price
@@ -421,9 +421,9 @@ pub enum OrderStatus {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MidpointUpdate {
     /// Asset/token identifier
-    pub asset_id: String,
+    pub asset_id: TokenId,
     /// Market identifier
-    pub market: String,
+    pub market: ConditionId,
     /// Calculated midpoint price
     pub midpoint: Decimal,
     /// Unix timestamp in milliseconds
@@ -536,7 +536,7 @@ mod tests {
         let json = r#"{
             "event_type": "book",
             "asset_id": "123",
-            "market": "market1",
+            "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
             "timestamp": "1234567890",
             "bids": [{"price": "0.5", "size": "100"}],
             "asks": [{"price": "0.51", "size": "50"}]
@@ -545,7 +545,7 @@ mod tests {
         let msg: WsMessage = serde_json::from_str(json).unwrap();
         match msg {
             WsMessage::Book(book) => {
-                assert_eq!(book.asset_id, "123");
+                assert_eq!(book.asset_id, TokenId::from(123));
                 assert_eq!(book.bids.len(), 1);
                 assert_eq!(book.asks.len(), 1);
             }
@@ -557,7 +557,7 @@ mod tests {
     fn parse_price_change_message() {
         let json = r#"{
             "event_type": "price_change",
-            "market": "market2",
+            "market": "0x2222222222222222222222222222222222222222222222222222222222222222",
             "timestamp": "1234567890",
             "price_changes": [{
                 "asset_id": "456",
@@ -572,7 +572,7 @@ mod tests {
             WsMessage::PriceChange(price) => {
                 let changes = &price.price_changes[0];
 
-                assert_eq!(changes.asset_id, "456");
+                assert_eq!(changes.asset_id, TokenId::from(456));
                 assert_eq!(changes.side, Side::Buy);
                 assert_eq!(changes.size.unwrap(), Decimal::TEN);
             }
@@ -584,11 +584,11 @@ mod tests {
     fn parse_price_change_interest_message() {
         let json = r#"{
             "event_type": "price_change",
-            "market": "market3",
+            "market": "0x3333333333333333333333333333333333333333333333333333333333333333",
             "timestamp": "1234567890",
             "price_changes": [
                 {
-                    "asset_id": "asset_a",
+                    "asset_id": "10",
                     "price": "0.10",
                     "side": "BUY",
                     "hash": "abc",
@@ -596,7 +596,7 @@ mod tests {
                     "best_ask": "0.12"
                 },
                 {
-                    "asset_id": "asset_b",
+                    "asset_id": "11",
                     "price": "0.90",
                     "size": "5",
                     "side": "SELL"
@@ -609,17 +609,20 @@ mod tests {
 
         match &msgs[0] {
             WsMessage::PriceChange(price) => {
-                assert_eq!(price.market, "market3");
+                assert_eq!(
+                    price.market.to_string(),
+                    "0x3333333333333333333333333333333333333333333333333333333333333333"
+                );
 
                 let changes = &price.price_changes;
                 assert_eq!(changes.len(), 2);
 
-                assert_eq!(changes[0].asset_id, "asset_a");
+                assert_eq!(changes[0].asset_id, TokenId::from(10));
                 assert_eq!(changes[0].best_bid, Some(dec!(0.11)));
                 assert_eq!(changes[0].price, dec!(0.10));
                 assert!(changes[0].size.is_none());
 
-                assert_eq!(changes[1].asset_id, "asset_b");
+                assert_eq!(changes[1].asset_id, TokenId::from(11));
                 assert_eq!(changes[1].best_bid, None);
                 assert_eq!(changes[1].size, Some(dec!(5)));
                 assert_eq!(changes[1].price, dec!(0.90));
@@ -633,26 +636,26 @@ mod tests {
         let json = r#"[
             {
                 "event_type": "book",
-                "asset_id": "asset1",
-                "market": "market1",
+                "asset_id": "1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
                 "timestamp": "1234567890",
                 "bids": [{"price": "0.5", "size": "100"}],
                 "asks": []
             },
             {
                 "event_type": "price_change",
-                "market": "market1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
                 "timestamp": "1234567891",
                 "price_changes": [{
-                    "asset_id": "asset1",
+                    "asset_id": "1",
                     "price": "0.51",
                     "side": "BUY"
                 }]
             },
             {
                 "event_type": "last_trade_price",
-                "asset_id": "asset2",
-                "market": "market1",
+                "asset_id": "2",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
                 "price": "0.6",
                 "timestamp": "1234567892"
             }
@@ -661,9 +664,11 @@ mod tests {
         let msgs = parse_if_interested(json.as_bytes(), &MessageInterest::ALL).unwrap();
         assert_eq!(msgs.len(), 3);
 
-        assert!(matches!(&msgs[0], WsMessage::Book(b) if b.asset_id == "asset1"));
-        assert!(matches!(&msgs[1], WsMessage::PriceChange(p) if p.market == "market1"));
-        assert!(matches!(&msgs[2], WsMessage::LastTradePrice(l) if l.asset_id == "asset2"));
+        assert!(matches!(&msgs[0], WsMessage::Book(b) if b.asset_id == TokenId::from(1)));
+        assert!(
+            matches!(&msgs[1], WsMessage::PriceChange(p) if p.market.to_string() == "0x1111111111111111111111111111111111111111111111111111111111111111")
+        );
+        assert!(matches!(&msgs[2], WsMessage::LastTradePrice(l) if l.asset_id == TokenId::from(2)));
     }
 
     #[test]
@@ -671,8 +676,8 @@ mod tests {
         let json = r#"[
             {
                 "event_type": "book",
-                "asset_id": "asset1",
-                "market": "market1",
+                "asset_id": "1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
                 "timestamp": "1234567890",
                 "bids": [],
                 "asks": []
@@ -680,8 +685,8 @@ mod tests {
             {
                 "event_type": "trade",
                 "id": "trade1",
-                "market": "market1",
-                "asset_id": "asset1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "asset_id": "1",
                 "side": "BUY",
                 "size": "10",
                 "price": "0.5",
@@ -802,7 +807,7 @@ mod tests {
                 assert_eq!(mr.id, "1031769");
                 assert_eq!(mr.winning_outcome, "Yes");
                 assert_eq!(
-                    mr.winning_asset_id,
+                    mr.winning_asset_id.to_string(),
                     "76043073756653678226373981964075571318267289248134717369284518995922789326425"
                 );
                 assert_eq!(mr.asset_ids.len(), 2);
@@ -841,8 +846,8 @@ mod tests {
         let json = r#"[
             {
                 "event_type": "best_bid_ask",
-                "market": "market1",
-                "asset_id": "asset1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+                "asset_id": "1",
                 "best_bid": "0.5",
                 "best_ask": "0.6",
                 "spread": "0.1",
@@ -850,8 +855,8 @@ mod tests {
             },
             {
                 "event_type": "book",
-                "asset_id": "asset1",
-                "market": "market1",
+                "asset_id": "1",
+                "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
                 "timestamp": "1234567890",
                 "bids": [],
                 "asks": []
@@ -881,7 +886,7 @@ mod tests {
             "market": "0x311d0c4b6671ab54af4970c06fcf58662516f5168997bdda209ec3db5aa6b0c1",
             "slug": "nvda-above-240-on-january-30-2026",
             "description": "This market will resolve to Yes or No.",
-            "assets_ids": ["asset1", "asset2"],
+            "assets_ids": ["1", "2"],
             "outcomes": ["Yes", "No"],
             "timestamp": "1766790415550",
             "event_type": "new_market"
@@ -905,9 +910,9 @@ mod tests {
             "market": "0x311d0c4b6671ab54af4970c06fcf58662516f5168997bdda209ec3db5aa6b0c1",
             "slug": "nvda-above-240-on-january-30-2026",
             "description": "This market will resolve to Yes or No.",
-            "assets_ids": ["asset1", "asset2"],
+            "assets_ids": ["1", "2"],
             "outcomes": ["Yes", "No"],
-            "winning_asset_id": "asset1",
+            "winning_asset_id": "1",
             "winning_outcome": "Yes",
             "timestamp": "1766790415550",
             "event_type": "market_resolved"
@@ -927,9 +932,9 @@ mod tests {
     #[test]
     fn parse_last_trade_price_without_optional_fields() {
         let json = r#"{
-            "asset_id": "asset123",
+            "asset_id": "123",
             "event_type": "last_trade_price",
-            "market": "0x123",
+            "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
             "price": "0.5",
             "timestamp": "1750428146322"
         }"#;
@@ -949,8 +954,8 @@ mod tests {
     #[test]
     fn matches_interest_custom_feature_messages() {
         let bba = WsMessage::BestBidAsk(BestBidAsk {
-            market: "m".to_owned(),
-            asset_id: "a".to_owned(),
+            market: ConditionId::default(),
+            asset_id: TokenId::from(1),
             best_bid: dec!(0.5),
             best_ask: dec!(0.6),
             spread: dec!(0.1),
@@ -963,7 +968,7 @@ mod tests {
         let nm = WsMessage::NewMarket(NewMarket {
             id: "1".to_owned(),
             question: "q".to_owned(),
-            market: "m".to_owned(),
+            market: ConditionId::default(),
             slug: "s".to_owned(),
             description: "d".to_owned(),
             asset_ids: vec![],
@@ -977,12 +982,12 @@ mod tests {
         let mr = WsMessage::MarketResolved(MarketResolved {
             id: "1".to_owned(),
             question: "q".to_owned(),
-            market: "m".to_owned(),
+            market: ConditionId::default(),
             slug: "s".to_owned(),
             description: "d".to_owned(),
             asset_ids: vec![],
             outcomes: vec![],
-            winning_asset_id: "w".to_owned(),
+            winning_asset_id: TokenId::from(2),
             winning_outcome: "Yes".to_owned(),
             event_message: None,
             timestamp: 0,
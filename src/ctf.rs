@@ -0,0 +1,441 @@
+//! Conditional Tokens Framework (CTF) operations.
+//!
+//! Outcome tokens are ERC-1155 positions in the [`ContractConfig::conditional_tokens`] contract.
+//! Splitting collateral mints one token of every outcome per unit of collateral, merging burns a
+//! full set of outcome tokens to get the collateral back, and redeeming burns the tokens of a
+//! resolved condition for their payout. Neg risk markets go through the
+//! [`ContractConfig::neg_risk_adapter`] instead, which wraps the collateral before calling the CTF.
+//!
+//! Everything here except [`Executor`] works offline: [`collection_id`] and [`position_id`]
+//! compute token IDs the same way the CTF contract does, and [`ConditionalTokens`] and
+//! [`NegRiskAdapter`] build the [`Call`]s to submit, however you choose to submit them. Amounts
+//! are in the collateral's base units, i.e. `1_000_000` for one USDC.
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use alloy::network::{Ethereum, TransactionBuilder as _};
+use alloy::primitives::{B256, Bytes, U256, keccak256, uint};
+use alloy::providers::{PendingTransactionBuilder, Provider};
+use alloy::rpc::types::TransactionRequest;
+use alloy::sol;
+use alloy::sol_types::SolCall;
+
+use crate::error::Error;
+use crate::types::{Address, ChainId, ConditionId, TokenId};
+use crate::{ContractConfig, Result, contract_config};
+
+sol! {
+    /// Split, merge and redeem functions of the Gnosis Conditional Tokens contract.
+    interface IConditionalTokens {
+        function splitPosition(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function mergePositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] partition,
+            uint256 amount
+        ) external;
+
+        function redeemPositions(
+            address collateralToken,
+            bytes32 parentCollectionId,
+            bytes32 conditionId,
+            uint256[] indexSets
+        ) external;
+    }
+
+    /// Split, merge and redeem functions of the Polymarket Neg Risk Adapter.
+    interface INegRiskAdapter {
+        function splitPosition(bytes32 conditionId, uint256 amount) external;
+
+        function mergePositions(bytes32 conditionId, uint256 amount) external;
+
+        function redeemPositions(bytes32 conditionId, uint256[] amounts) external;
+    }
+}
+
+/// Index sets of the two outcomes of a binary condition, the first (e.g. "Yes") then the second
+/// (e.g. "No").
+pub const BINARY_PARTITION: [U256; 2] = [uint!(1_U256), uint!(2_U256)];
+
+/// Modulus of the `alt_bn128` base field, onto which the CTF maps collection IDs.
+const P: U256 = uint!(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47_U256);
+
+/// `b` in the `alt_bn128` curve equation `y^2 = x^3 + b`.
+const B: U256 = uint!(3_U256);
+
+/// ID of the condition prepared by `oracle` for `question_id` with `outcome_slot_count` outcomes.
+#[must_use]
+pub fn condition_id(oracle: Address, question_id: B256, outcome_slot_count: u32) -> ConditionId {
+    let mut packed = Vec::with_capacity(20 + 32 + 32);
+    packed.extend_from_slice(oracle.as_slice());
+    packed.extend_from_slice(question_id.as_slice());
+    packed.extend_from_slice(&U256::from(outcome_slot_count).to_be_bytes::<32>());
+
+    ConditionId::new(keccak256(packed))
+}
+
+/// ID of the collection of outcomes in `index_set` of `condition_id`, nested under
+/// `parent_collection_id`, which is [`B256::ZERO`] for positions directly backed by collateral.
+///
+/// Like the CTF, this maps the outcome onto an `alt_bn128` curve point, adds it to the parent's
+/// point and compresses the result, so nested collections don't depend on the order their
+/// conditions were applied in.
+pub fn collection_id(
+    parent_collection_id: B256,
+    condition_id: ConditionId,
+    index_set: U256,
+) -> Result<B256> {
+    let mut packed = [0_u8; 64];
+    packed[..32].copy_from_slice(condition_id.as_b256().as_slice());
+    packed[32..].copy_from_slice(&index_set.to_be_bytes::<32>());
+
+    let mut x1 = U256::from_be_bytes(keccak256(packed).0);
+    let odd = x1.bit(255);
+    let mut y1 = loop {
+        x1 = x1.add_mod(U256::from(1), P);
+        if let Some(y) = curve_y(x1) {
+            break y;
+        }
+    };
+    if odd != y1.bit(0) {
+        y1 = P - y1;
+    }
+
+    let parent = U256::from_be_bytes(parent_collection_id.0);
+    if !parent.is_zero() {
+        let odd = parent.bit(254);
+        let x2 = (parent << 2) >> 2;
+        let mut y2 = curve_y(x2)
+            .filter(|_| x2 < P)
+            .ok_or(Error::validation(format!(
+                "Invalid parent collection ID {parent_collection_id}"
+            )))?;
+        if odd != y2.bit(0) {
+            y2 = P - y2;
+        }
+
+        (x1, y1) = add_points((x1, y1), (x2, y2));
+    }
+
+    if y1.bit(0) {
+        x1 ^= U256::from(1) << 254;
+    }
+
+    Ok(B256::from(x1))
+}
+
+/// ID of the ERC-1155 token for the position in `collection_id` backed by `collateral`.
+#[must_use]
+pub fn position_id(collateral: Address, collection_id: B256) -> TokenId {
+    let mut packed = [0_u8; 20 + 32];
+    packed[..20].copy_from_slice(collateral.as_slice());
+    packed[20..].copy_from_slice(collection_id.as_slice());
+
+    TokenId::new(U256::from_be_bytes(keccak256(packed).0))
+}
+
+/// Token IDs of both outcomes of the binary `condition_id` backed directly by `collateral`, in
+/// [`BINARY_PARTITION`] order.
+///
+/// Neg risk markets are backed by the adapter's wrapped collateral rather than the exchange's
+/// collateral, so pass that instead to get their token IDs.
+pub fn binary_position_ids(collateral: Address, condition_id: ConditionId) -> Result<[TokenId; 2]> {
+    let [first, second] = BINARY_PARTITION;
+
+    Ok([
+        position_id(collateral, collection_id(B256::ZERO, condition_id, first)?),
+        position_id(collateral, collection_id(B256::ZERO, condition_id, second)?),
+    ])
+}
+
+/// Square root of `x^3 + b` if `x` is on the curve. `P % 4 == 3`, so the candidate root is
+/// `(x^3 + b)^((P + 1) / 4)`.
+fn curve_y(x: U256) -> Option<U256> {
+    let yy = x.mul_mod(x.mul_mod(x, P), P).add_mod(B, P);
+    let y = yy.pow_mod((P + U256::from(1)) >> 2, P);
+
+    (y.mul_mod(y, P) == yy).then_some(y)
+}
+
+/// Adds two affine points the way the `ecAdd` precompile does, with `(0, 0)` as the point at
+/// infinity.
+fn add_points((x1, y1): (U256, U256), (x2, y2): (U256, U256)) -> (U256, U256) {
+    let sub = |a: U256, b: U256| a.add_mod(P - b, P);
+    let div = |a: U256, b: U256| a.mul_mod(b.inv_mod(P).unwrap_or_default(), P);
+
+    if (x1, y1) == (U256::ZERO, U256::ZERO) {
+        return (x2, y2);
+    }
+    if (x2, y2) == (U256::ZERO, U256::ZERO) {
+        return (x1, y1);
+    }
+
+    let slope = if x1 != x2 {
+        div(sub(y2, y1), sub(x2, x1))
+    } else if y1 == y2 && !y1.is_zero() {
+        div(
+            U256::from(3).mul_mod(x1.mul_mod(x1, P), P),
+            y1.add_mod(y1, P),
+        )
+    } else {
+        return (U256::ZERO, U256::ZERO);
+    };
+
+    let x3 = sub(sub(slope.mul_mod(slope, P), x1), x2);
+    let y3 = sub(slope.mul_mod(sub(x1, x3), P), y1);
+
+    (x3, y3)
+}
+
+/// A contract call to submit as a transaction, from the trading wallet itself or through a wallet
+/// contract that executes it on the wallet's behalf.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    /// The contract to call
+    pub to: Address,
+    /// ABI encoded calldata
+    pub data: Bytes,
+}
+
+impl Call {
+    #[must_use]
+    pub fn new<C: SolCall>(to: Address, call: &C) -> Self {
+        Self {
+            to,
+            data: call.abi_encode().into(),
+        }
+    }
+
+    /// Decodes the calldata as `C`, e.g. [`IConditionalTokens::splitPositionCall`].
+    pub fn decode<C: SolCall>(&self) -> Result<C> {
+        C::abi_decode(&self.data).map_err(|e| Error::validation(format!("Invalid calldata: {e}")))
+    }
+}
+
+impl From<Call> for TransactionRequest {
+    fn from(call: Call) -> Self {
+        TransactionRequest::default()
+            .with_to(call.to)
+            .with_input(call.data)
+    }
+}
+
+/// Builds [`Call`]s to the CTF contract for binary conditions backed directly by collateral,
+/// i.e. markets on the regular exchange.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConditionalTokens {
+    pub address: Address,
+    pub collateral: Address,
+}
+
+impl ConditionalTokens {
+    /// The CTF contract and collateral of the regular exchange on `chain_id`.
+    pub fn new(chain_id: ChainId) -> Result<Self> {
+        let config = contract_config(chain_id, false)
+            .ok_or(Error::missing_contract_config(chain_id, false))?;
+
+        Ok(Self::from(config))
+    }
+
+    /// Splits `amount` of collateral into `amount` of each outcome token of `condition_id`.
+    #[must_use]
+    pub fn split_position(&self, condition_id: ConditionId, amount: U256) -> Call {
+        self.call(&IConditionalTokens::splitPositionCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.as_b256(),
+            partition: BINARY_PARTITION.to_vec(),
+            amount,
+        })
+    }
+
+    /// Merges `amount` of each outcome token of `condition_id` back into `amount` of collateral.
+    #[must_use]
+    pub fn merge_positions(&self, condition_id: ConditionId, amount: U256) -> Call {
+        self.call(&IConditionalTokens::mergePositionsCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.as_b256(),
+            partition: BINARY_PARTITION.to_vec(),
+            amount,
+        })
+    }
+
+    /// Redeems the caller's whole balance of both outcome tokens of the resolved `condition_id`.
+    #[must_use]
+    pub fn redeem_positions(&self, condition_id: ConditionId) -> Call {
+        self.call(&IConditionalTokens::redeemPositionsCall {
+            collateralToken: self.collateral,
+            parentCollectionId: B256::ZERO,
+            conditionId: condition_id.as_b256(),
+            indexSets: BINARY_PARTITION.to_vec(),
+        })
+    }
+
+    /// Token IDs of both outcomes of `condition_id`, see [`binary_position_ids`].
+    pub fn position_ids(&self, condition_id: ConditionId) -> Result<[TokenId; 2]> {
+        binary_position_ids(self.collateral, condition_id)
+    }
+
+    fn call<C: SolCall>(&self, call: &C) -> Call {
+        Call::new(self.address, call)
+    }
+}
+
+impl From<&ContractConfig> for ConditionalTokens {
+    fn from(config: &ContractConfig) -> Self {
+        Self {
+            address: config.conditional_tokens,
+            collateral: config.collateral,
+        }
+    }
+}
+
+/// Builds [`Call`]s to the Neg Risk Adapter, which splits, merges and redeems the outcome tokens
+/// of neg risk markets.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NegRiskAdapter {
+    pub address: Address,
+}
+
+impl NegRiskAdapter {
+    /// The Neg Risk Adapter on `chain_id`.
+    pub fn new(chain_id: ChainId) -> Result<Self> {
+        contract_config(chain_id, true)
+            .and_then(|config| config.neg_risk_adapter)
+            .map(|address| Self { address })
+            .ok_or(Error::missing_contract_config(chain_id, true))
+    }
+
+    /// Splits `amount` of collateral into `amount` of each outcome token of `condition_id`.
+    #[must_use]
+    pub fn split_position(&self, condition_id: ConditionId, amount: U256) -> Call {
+        self.call(&INegRiskAdapter::splitPositionCall {
+            conditionId: condition_id.as_b256(),
+            amount,
+        })
+    }
+
+    /// Merges `amount` of each outcome token of `condition_id` back into `amount` of collateral.
+    #[must_use]
+    pub fn merge_positions(&self, condition_id: ConditionId, amount: U256) -> Call {
+        self.call(&INegRiskAdapter::mergePositionsCall {
+            conditionId: condition_id.as_b256(),
+            amount,
+        })
+    }
+
+    /// Redeems `amounts` of the outcome tokens of the resolved `condition_id`, in
+    /// [`BINARY_PARTITION`] order. Unlike the CTF, the adapter redeems the given amounts rather
+    /// than the caller's whole balance.
+    #[must_use]
+    pub fn redeem_positions(&self, condition_id: ConditionId, amounts: [U256; 2]) -> Call {
+        self.call(&INegRiskAdapter::redeemPositionsCall {
+            conditionId: condition_id.as_b256(),
+            amounts: amounts.to_vec(),
+        })
+    }
+
+    fn call<C: SolCall>(&self, call: &C) -> Call {
+        Call::new(self.address, call)
+    }
+}
+
+/// Submits [`Call`]s as transactions through an alloy [`Provider`], which is expected to sign
+/// them, e.g. one built with a wallet.
+#[derive(Clone, Debug)]
+pub struct Executor<P> {
+    provider: P,
+}
+
+impl<P: Provider> Executor<P> {
+    #[must_use]
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+
+    #[must_use]
+    pub fn provider(&self) -> &P {
+        &self.provider
+    }
+
+    /// Sends `call` as a transaction, returning the pending transaction to watch for its receipt.
+    pub async fn execute(&self, call: Call) -> Result<PendingTransactionBuilder<Ethereum>> {
+        Ok(self.provider.send_transaction(call.into()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, b256};
+
+    use super::*;
+    use crate::POLYGON;
+
+    const CONDITION_ID: ConditionId = ConditionId::new(b256!(
+        "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917"
+    ));
+
+    #[test]
+    fn field_modulus_should_be_alt_bn128() {
+        assert_eq!(
+            P.to_string(),
+            "21888242871839275222246405745257275088696311157297823662689037894645226208583"
+        );
+        assert_eq!(P % U256::from(4), U256::from(3));
+    }
+
+    #[test]
+    fn collection_ids_should_be_valid_parents() {
+        let ctf = ConditionalTokens::new(POLYGON).unwrap();
+        let [first, second] = ctf.position_ids(CONDITION_ID).unwrap();
+        assert_ne!(first, second);
+
+        for index_set in BINARY_PARTITION {
+            let parent = collection_id(B256::ZERO, CONDITION_ID, index_set).unwrap();
+            assert!(parent[0] < 0x80, "the top bit is never set");
+
+            // Only IDs that decompress to a curve point are accepted as parents
+            collection_id(parent, CONDITION_ID, index_set).unwrap();
+        }
+    }
+
+    #[test]
+    fn nested_collection_id_should_not_depend_on_order() {
+        let other = condition_id(
+            address!("0x6A9D222616C90FcA5754cd1333cFD9b7fb6a4F74"),
+            B256::repeat_byte(0x42),
+            2,
+        );
+        let [first, second] = BINARY_PARTITION;
+
+        let outer = collection_id(B256::ZERO, CONDITION_ID, first).unwrap();
+        let inner = collection_id(B256::ZERO, other, second).unwrap();
+
+        assert_eq!(
+            collection_id(outer, other, second).unwrap(),
+            collection_id(inner, CONDITION_ID, first).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_parent_collection_id_should_fail() {
+        let err = collection_id(B256::repeat_byte(0xff), CONDITION_ID, U256::from(1)).unwrap_err();
+
+        assert!(
+            err.to_string().contains("Invalid parent collection ID"),
+            "{err}"
+        );
+    }
+}
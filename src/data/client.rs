@@ -24,6 +24,7 @@
 //! # }
 //! ```
 
+use futures::Stream;
 use reqwest::{
     Client as ReqwestClient, Method,
     header::{HeaderMap, HeaderValue},
@@ -41,6 +42,9 @@ use super::types::response::{
     Activity, BuilderLeaderboardEntry, BuilderVolumeEntry, ClosedPosition, Health, LiveVolume,
     MetaHolder, OpenInterest, Position, Trade, Traded, TraderLeaderboardEntry, Value,
 };
+use crate::pagination::{self, Paginated};
+use crate::retry::RetryPolicy;
+use crate::transport::HttpTransport;
 use crate::{Result, ToQueryParams as _};
 
 /// HTTP client for the Polymarket Data API.
@@ -64,9 +68,15 @@ use crate::{Result, ToQueryParams as _};
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
-pub struct Client {
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client, as in the other API clients"
+)]
+pub struct Client<T: HttpTransport = ReqwestClient> {
     host: Url,
     client: ReqwestClient,
+    transport: T,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl Default for Client {
@@ -97,9 +107,32 @@ impl Client {
 
         Ok(Self {
             host: Url::parse(host)?,
+            transport: client.clone(),
             client,
+            retry_policy: None,
         })
     }
+}
+
+impl<T: HttpTransport> Client<T> {
+    /// Sends requests through `transport` instead of the default [`ReqwestClient`].
+    pub fn with_transport<U: HttpTransport>(self, transport: U) -> Client<U> {
+        Client {
+            host: self.host,
+            client: self.client,
+            transport,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Retries requests that fail with `429` or `5xx` according to `retry_policy`.
+    ///
+    /// Requests are not retried unless a policy is set.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
 
     /// Returns the base URL of the API.
     #[must_use]
@@ -117,7 +150,13 @@ impl Client {
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.transport,
+            request,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await
     }
 
     /// Performs a health check on the API.
@@ -142,6 +181,14 @@ impl Client {
         self.get("positions", req).await
     }
 
+    /// Streams every open position matching `req`, fetching a page at a time from its `offset`.
+    pub fn positions_stream<'client>(
+        &'client self,
+        req: &'client PositionsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Position>> + 'client> {
+        pagination::by_offset(req, move |req| async move { self.positions(&req).await })
+    }
+
     /// Fetches trade history for a user or markets.
     ///
     /// Trades represent executed orders where outcome tokens were bought or sold.
@@ -153,6 +200,14 @@ impl Client {
         self.get("trades", req).await
     }
 
+    /// Streams every trade matching `req`, fetching a page at a time from its `offset`.
+    pub fn trades_stream<'client>(
+        &'client self,
+        req: &'client TradesRequest,
+    ) -> Paginated<impl Stream<Item = Result<Trade>> + 'client> {
+        pagination::by_offset(req, move |req| async move { self.trades(&req).await })
+    }
+
     /// Fetches on-chain activity for a user.
     ///
     /// Returns various on-chain operations including trades, splits, merges,
@@ -165,6 +220,14 @@ impl Client {
         self.get("activity", req).await
     }
 
+    /// Streams every activity matching `req`, fetching a page at a time from its `offset`.
+    pub fn activity_stream<'client>(
+        &'client self,
+        req: &'client ActivityRequest,
+    ) -> Paginated<impl Stream<Item = Result<Activity>> + 'client> {
+        pagination::by_offset(req, move |req| async move { self.activity(&req).await })
+    }
+
     /// Fetches top token holders for specified markets.
     ///
     /// Returns holders grouped by token (outcome) for each market.
@@ -201,6 +264,17 @@ impl Client {
         self.get("closed-positions", req).await
     }
 
+    /// Streams every closed position matching `req`, fetching a page at a time from its `offset`.
+    pub fn closed_positions_stream<'client>(
+        &'client self,
+        req: &'client ClosedPositionsRequest,
+    ) -> Paginated<impl Stream<Item = Result<ClosedPosition>> + 'client> {
+        pagination::by_offset(
+            req,
+            move |req| async move { self.closed_positions(&req).await },
+        )
+    }
+
     /// Fetches trader leaderboard rankings.
     ///
     /// Returns trader rankings filtered by category, time period, and ordering.
@@ -215,6 +289,14 @@ impl Client {
         self.get("v1/leaderboard", req).await
     }
 
+    /// Streams the trader leaderboard, fetching a page at a time from the request's `offset`.
+    pub fn leaderboard_stream<'client>(
+        &'client self,
+        req: &'client TraderLeaderboardRequest,
+    ) -> Paginated<impl Stream<Item = Result<TraderLeaderboardEntry>> + 'client> {
+        pagination::by_offset(req, move |req| async move { self.leaderboard(&req).await })
+    }
+
     /// Fetches the total count of unique markets a user has traded.
     ///
     /// # Errors
@@ -261,6 +343,16 @@ impl Client {
         self.get("v1/builders/leaderboard", req).await
     }
 
+    /// Streams the builder leaderboard, fetching a page at a time from the request's `offset`.
+    pub fn builder_leaderboard_stream<'client>(
+        &'client self,
+        req: &'client BuilderLeaderboardRequest,
+    ) -> Paginated<impl Stream<Item = Result<BuilderLeaderboardEntry>> + 'client> {
+        pagination::by_offset(req, move |req| async move {
+            self.builder_leaderboard(&req).await
+        })
+    }
+
     /// Fetches daily time-series volume data for builders.
     ///
     /// Returns multiple entries per builder (one per day), each including a timestamp.
@@ -4,12 +4,13 @@ use serde::de::StdError;
 use serde::{Deserialize, Serialize};
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as};
 
-use crate::types::Decimal;
+use crate::types::{ConditionId, Decimal};
 
 pub mod request;
 pub mod response;
 
 /// Type alias for 64-character hex hashes (condition IDs, market identifiers).
+#[deprecated(note = "Use `ConditionId` instead")]
 pub type Hash64 = String;
 
 /// Type alias for market title filter strings.
@@ -262,7 +263,7 @@ pub enum LeaderboardOrderBy {
 /// use polymarket_client_sdk::data::types::MarketFilter;
 ///
 /// // Filter by specific markets (condition IDs)
-/// let by_markets = MarketFilter::markets(["0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".to_string()]);
+/// let by_markets = MarketFilter::markets(["0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".parse().unwrap()]);
 ///
 /// // Or filter by events (which may contain multiple markets)
 /// let by_events = MarketFilter::event_ids(["123".to_owned()]);
@@ -273,7 +274,9 @@ pub enum LeaderboardOrderBy {
 pub enum MarketFilter {
     /// Filter by condition IDs (market identifiers).
     #[serde(rename = "market")]
-    Markets(#[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")] Vec<String>),
+    Markets(
+        #[serde_as(as = "StringWithSeparator::<CommaSeparator, ConditionId>")] Vec<ConditionId>,
+    ),
     /// Filter by event IDs (groups of related markets).
     #[serde(rename = "eventId")]
     EventIds(#[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")] Vec<String>),
@@ -282,7 +285,7 @@ pub enum MarketFilter {
 impl MarketFilter {
     /// Creates a filter for specific markets by their condition IDs.
     #[must_use]
-    pub fn markets<I: IntoIterator<Item = String>>(ids: I) -> Self {
+    pub fn markets<I: IntoIterator<Item = ConditionId>>(ids: I) -> Self {
         Self::Markets(ids.into_iter().collect())
     }
 
@@ -5,8 +5,8 @@
 use serde::{Deserialize, Deserializer};
 use serde_with::{DefaultOnNull, serde_as};
 
-use super::{ActivityType, Hash64, Side};
-use crate::types::{Address, Decimal};
+use super::{ActivityType, Side};
+use crate::types::{Address, ConditionId, Decimal, TokenId};
 
 /// Deserializes an optional Side, treating empty strings as None.
 fn deserialize_optional_side<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
@@ -56,9 +56,9 @@ pub struct Position {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier.
-    pub asset: String,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
-    pub condition_id: Hash64,
+    pub condition_id: ConditionId,
     /// Number of outcome tokens held.
     pub size: Decimal,
     /// Average entry price for the position.
@@ -100,7 +100,7 @@ pub struct Position {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
-    pub opposite_asset: String,
+    pub opposite_asset: TokenId,
     /// Market end/resolution date.
     pub end_date: String,
     /// Whether this is a negative risk market.
@@ -118,9 +118,9 @@ pub struct ClosedPosition {
     /// The user's proxy wallet address.
     pub proxy_wallet: Address,
     /// The outcome token asset identifier.
-    pub asset: String,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
-    pub condition_id: Hash64,
+    pub condition_id: ConditionId,
     /// Average entry price for the position.
     pub avg_price: Decimal,
     /// Total amount bought (cumulative).
@@ -146,7 +146,7 @@ pub struct ClosedPosition {
     /// Name of the opposite outcome.
     pub opposite_outcome: String,
     /// Asset identifier of the opposite outcome.
-    pub opposite_asset: String,
+    pub opposite_asset: TokenId,
     /// Market end/resolution date.
     pub end_date: String,
 }
@@ -164,9 +164,9 @@ pub struct Trade {
     /// Trade side (BUY or SELL).
     pub side: Side,
     /// The outcome token asset identifier.
-    pub asset: String,
+    pub asset: TokenId,
     /// The market condition ID (unique market identifier).
-    pub condition_id: Hash64,
+    pub condition_id: ConditionId,
     /// Number of tokens traded.
     pub size: Decimal,
     /// Execution price per token.
@@ -212,7 +212,7 @@ pub struct Activity {
     /// Unix timestamp when the activity occurred.
     pub timestamp: i64,
     /// The market condition ID (unique market identifier).
-    pub condition_id: Hash64,
+    pub condition_id: ConditionId,
     /// Type of activity (TRADE, SPLIT, MERGE, REDEEM, REWARD, CONVERSION).
     #[serde(rename = "type")]
     pub activity_type: ActivityType,
@@ -225,7 +225,7 @@ pub struct Activity {
     /// Price per token (for trades).
     pub price: Option<Decimal>,
     /// Outcome token asset identifier (for trades).
-    pub asset: Option<String>,
+    pub asset: Option<TokenId>,
     /// Trade side (for trades only).
     #[serde(default, deserialize_with = "deserialize_optional_side")]
     pub side: Option<Side>,
@@ -265,7 +265,7 @@ pub struct Holder {
     /// Holder's bio (if public).
     pub bio: Option<String>,
     /// The outcome token asset identifier.
-    pub asset: String,
+    pub asset: TokenId,
     /// Holder's pseudonym (if set).
     pub pseudonym: Option<String>,
     /// Amount of tokens held.
@@ -327,8 +327,8 @@ pub struct Value {
 #[derive(Debug, Clone, Deserialize)]
 #[non_exhaustive]
 pub struct OpenInterest {
-    /// The market condition ID.
-    pub market: Hash64,
+    /// The market condition ID, or `GLOBAL` for the open interest across all markets.
+    pub market: String,
     /// Open interest value in USDC.
     pub value: Decimal,
 }
@@ -340,7 +340,7 @@ pub struct OpenInterest {
 #[non_exhaustive]
 pub struct MarketVolume {
     /// The market condition ID.
-    pub market: Hash64,
+    pub market: ConditionId,
     /// Trading volume in USDC.
     pub value: Decimal,
 }
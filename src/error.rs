@@ -226,6 +226,12 @@ impl From<reqwest::Error> for Error {
     }
 }
 
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
 impl From<header::InvalidHeaderValue> for Error {
     fn from(e: header::InvalidHeaderValue) -> Self {
         Error::with_source(Kind::Internal, e)
@@ -250,6 +256,13 @@ impl From<alloy::signers::Error> for Error {
     }
 }
 
+#[cfg(feature = "ctf")]
+impl From<alloy::transports::TransportError> for Error {
+    fn from(e: alloy::transports::TransportError) -> Self {
+        Error::with_source(Kind::Internal, e)
+    }
+}
+
 impl From<url::ParseError> for Error {
     fn from(e: url::ParseError) -> Self {
         Error::with_source(Kind::Internal, e)
@@ -25,6 +25,7 @@
 //! # }
 //! ```
 
+use futures::Stream;
 use reqwest::{
     Client as ReqwestClient, Method,
     header::{HeaderMap, HeaderValue},
@@ -45,6 +46,9 @@ use super::types::response::{
     SportsMarketTypesResponse, SportsMetadata, Tag, Team,
 };
 use crate::error::Error;
+use crate::pagination::{self, Paginated};
+use crate::retry::RetryPolicy;
+use crate::transport::HttpTransport;
 use crate::{Result, ToQueryParams as _};
 
 /// HTTP client for the Polymarket Gamma API.
@@ -68,9 +72,15 @@ use crate::{Result, ToQueryParams as _};
 /// let client = Client::new("https://custom-api.example.com").unwrap();
 /// ```
 #[derive(Clone, Debug)]
-pub struct Client {
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client, as in the other API clients"
+)]
+pub struct Client<T: HttpTransport = ReqwestClient> {
     host: Url,
     client: ReqwestClient,
+    transport: T,
+    retry_policy: Option<RetryPolicy>,
 }
 
 impl Default for Client {
@@ -101,9 +111,32 @@ impl Client {
 
         Ok(Self {
             host: Url::parse(host)?,
+            transport: client.clone(),
             client,
+            retry_policy: None,
         })
     }
+}
+
+impl<T: HttpTransport> Client<T> {
+    /// Sends requests through `transport` instead of the default [`ReqwestClient`].
+    pub fn with_transport<U: HttpTransport>(self, transport: U) -> Client<U> {
+        Client {
+            host: self.host,
+            client: self.client,
+            transport,
+            retry_policy: self.retry_policy,
+        }
+    }
+
+    /// Retries requests that fail with `429` or `5xx` according to `retry_policy`.
+    ///
+    /// Requests are not retried unless a policy is set.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
 
     /// Returns the base URL of the API.
     #[must_use]
@@ -121,7 +154,13 @@ impl Client {
             .client
             .request(Method::GET, format!("{}{path}{query}", self.host))
             .build()?;
-        crate::request(&self.client, request, None).await
+        crate::request(
+            &self.transport,
+            request,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await
     }
 
     /// Performs a health check on the API.
@@ -133,7 +172,13 @@ impl Client {
             .request(Method::GET, format!("{}status", self.host))
             .build()?;
 
-        let response = self.client.execute(request).await?;
+        let response = crate::execute(
+            &self.transport,
+            request,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await?;
         let status_code = response.status();
 
         if !status_code.is_success() {
@@ -154,6 +199,17 @@ impl Client {
         self.get("teams", request).await
     }
 
+    /// Streams every team matching `request`, a page at a time.
+    pub fn teams_stream<'client>(
+        &'client self,
+        request: &'client TeamsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Team>> + 'client> {
+        pagination::by_offset(
+            request,
+            move |request| async move { self.teams(&request).await },
+        )
+    }
+
     /// Gets sports metadata.
     pub async fn sports(&self) -> Result<Vec<SportsMetadata>> {
         self.get("sports", &()).await
@@ -169,6 +225,17 @@ impl Client {
         self.get("tags", request).await
     }
 
+    /// Streams every tag matching `request`, a page at a time.
+    pub fn tags_stream<'client>(
+        &'client self,
+        request: &'client TagsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Tag>> + 'client> {
+        pagination::by_offset(
+            request,
+            move |request| async move { self.tags(&request).await },
+        )
+    }
+
     /// Gets a tag by ID.
     pub async fn tag_by_id(&self, request: &TagByIdRequest) -> Result<Tag> {
         self.get(&format!("tags/{}", request.id), request).await
@@ -224,6 +291,16 @@ impl Client {
         self.get("events", request).await
     }
 
+    /// Streams every event matching `request`, a page at a time.
+    pub fn events_stream<'client>(
+        &'client self,
+        request: &'client EventsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Event>> + 'client> {
+        pagination::by_offset(request, move |request| async move {
+            self.events(&request).await
+        })
+    }
+
     /// Gets an event by ID.
     pub async fn event_by_id(&self, request: &EventByIdRequest) -> Result<Event> {
         self.get(&format!("events/{}", request.id), request).await
@@ -261,7 +338,23 @@ impl Client {
             .client
             .request(Method::GET, format!("{}markets{query}", self.host))
             .build()?;
-        crate::request(&self.client, req, None).await
+        crate::request(
+            &self.transport,
+            req,
+            &crate::Unsigned,
+            self.retry_policy.as_ref(),
+        )
+        .await
+    }
+
+    /// Streams every market matching `request`, a page at a time.
+    pub fn markets_stream<'client>(
+        &'client self,
+        request: &'client MarketsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Market>> + 'client> {
+        pagination::by_offset(request, move |request| async move {
+            self.markets(&request).await
+        })
     }
 
     /// Gets a market by ID.
@@ -286,6 +379,16 @@ impl Client {
         self.get("series", request).await
     }
 
+    /// Streams every series matching `request`, a page at a time.
+    pub fn series_stream<'client>(
+        &'client self,
+        request: &'client SeriesListRequest,
+    ) -> Paginated<impl Stream<Item = Result<Series>> + 'client> {
+        pagination::by_offset(request, move |request| async move {
+            self.series(&request).await
+        })
+    }
+
     /// Gets a series by ID.
     pub async fn series_by_id(&self, request: &SeriesByIdRequest) -> Result<Series> {
         self.get(&format!("series/{}", request.id), request).await
@@ -296,6 +399,16 @@ impl Client {
         self.get("comments", request).await
     }
 
+    /// Streams every comment matching `request`, a page at a time.
+    pub fn comments_stream<'client>(
+        &'client self,
+        request: &'client CommentsRequest,
+    ) -> Paginated<impl Stream<Item = Result<Comment>> + 'client> {
+        pagination::by_offset(request, move |request| async move {
+            self.comments(&request).await
+        })
+    }
+
     /// Gets comments by comment ID.
     pub async fn comments_by_id(&self, request: &CommentsByIdRequest) -> Result<Vec<Comment>> {
         self.get(&format!("comments/{}", request.id), request).await
@@ -313,6 +426,16 @@ impl Client {
         .await
     }
 
+    /// Streams every comment by the user address of `request`, a page at a time.
+    pub fn comments_by_user_address_stream<'client>(
+        &'client self,
+        request: &'client CommentsByUserAddressRequest,
+    ) -> Paginated<impl Stream<Item = Result<Comment>> + 'client> {
+        pagination::by_offset(request, move |request| async move {
+            self.comments_by_user_address(&request).await
+        })
+    }
+
     /// Gets a public profile by wallet address.
     pub async fn public_profile(&self, request: &PublicProfileRequest) -> Result<PublicProfile> {
         self.get("public-profile", request).await
@@ -9,7 +9,7 @@ use serde::Serialize;
 use serde_with::{StringWithSeparator, formats::CommaSeparator, serde_as, skip_serializing_none};
 
 use crate::gamma::types::{ParentEntityType, RelatedTagsStatus};
-use crate::types::Decimal;
+use crate::types::{ConditionId, Decimal, TokenId};
 
 #[serde_as]
 #[skip_serializing_none]
@@ -183,11 +183,11 @@ pub struct MarketsRequest {
     pub slug: Vec<String>,
     #[serde(skip_serializing)]
     #[builder(default)]
-    pub clob_token_ids: Vec<String>,
-    #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
+    pub clob_token_ids: Vec<TokenId>,
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, ConditionId>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
-    pub condition_ids: Vec<String>,
+    pub condition_ids: Vec<ConditionId>,
     #[serde_as(as = "StringWithSeparator::<CommaSeparator, String>")]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     #[builder(default)]
@@ -371,3 +371,13 @@ pub struct SearchRequest {
     pub exclude_tag_id: Vec<String>,
     pub optimized: Option<bool>,
 }
+
+crate::pagination::offset_paginated!(
+    TeamsRequest,
+    TagsRequest,
+    EventsRequest,
+    MarketsRequest,
+    SeriesListRequest,
+    CommentsRequest,
+    CommentsByUserAddressRequest,
+);
@@ -5,10 +5,11 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_with::json::JsonString;
 use serde_with::serde_as;
 
 use crate::serde_helpers::StringFromAny;
-use crate::types::Decimal;
+use crate::types::{ConditionId, Decimal, TokenId};
 
 /// Image optimization metadata.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -329,13 +330,14 @@ pub struct Event {
 }
 
 /// A prediction market.
+#[serde_as]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct Market {
     pub id: String,
     pub question: Option<String>,
-    pub condition_id: Option<String>,
+    pub condition_id: Option<ConditionId>,
     pub slug: Option<String>,
     pub twitter_card_image: Option<String>,
     pub resolution_source: Option<String>,
@@ -402,7 +404,10 @@ pub struct Market {
     pub volume_1yr: Option<Decimal>,
     pub game_start_time: Option<String>,
     pub seconds_delay: Option<i32>,
-    pub clob_token_ids: Option<String>,
+    /// Token IDs of the market's outcomes, which the API encodes as a JSON string
+    #[serde_as(as = "Option<JsonString>")]
+    #[serde(default)]
+    pub clob_token_ids: Option<Vec<TokenId>>,
     pub disqus_thread: Option<String>,
     pub short_outcomes: Option<String>,
     #[serde(rename = "teamAID")]
@@ -501,7 +506,7 @@ pub struct Market {
 pub struct ClobReward {
     pub id: Option<String>,
     pub asset_address: Option<String>,
-    pub condition_id: Option<String>,
+    pub condition_id: Option<ConditionId>,
     pub start_date: Option<String>,
     pub end_date: Option<String>,
     pub rewards_amount: Option<Decimal>,
@@ -561,7 +566,7 @@ pub struct Series {
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub struct CommentPosition {
-    pub token_id: Option<String>,
+    pub token_id: Option<TokenId>,
     pub position_size: Option<String>,
 }
 
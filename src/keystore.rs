@@ -0,0 +1,613 @@
+//! Encrypted on-disk storage for a signer key and its API credentials.
+//!
+//! A keystore file is a standard [Ethereum keystore v3](https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/)
+//! document, so the private key can still be decrypted by `geth`, `cast` or any other wallet
+//! tooling. Next to the usual `crypto` section it carries a `credentials` section holding the
+//! CLOB [`Credentials`], the optional builder [`Credentials`] and the signer's chain id, sealed
+//! with AES-256-GCM under a key derived by scrypt from the same password. The signer's address is
+//! authenticated along with the credentials, so they cannot be moved to another key's file.
+//!
+//! ```rust, no_run
+//! use polymarket_client_sdk::clob::{Client, Config};
+//! use polymarket_client_sdk::keystore::Keystore;
+//!
+//! #[tokio::main]
+//! async fn main() -> anyhow::Result<()> {
+//!     let password = std::env::var("KEYSTORE_PASSWORD")?;
+//!     let keystore = Keystore::load("polymarket.json", password)?;
+//!
+//!     let client = Client::new("https://clob.polymarket.com", Config::default())?
+//!         .authentication_builder_from_keystore(&keystore)
+//!         .authenticate()
+//!         .await?;
+//!
+//!     println!("{:?}", client.api_keys().await?);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use std::fs::{self, OpenOptions};
+use std::io::{BufWriter, Write as _};
+use std::path::Path;
+
+use aes::Aes128;
+use aes_gcm::aead::{Aead as _, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit as _, Nonce};
+use alloy::primitives::{ChainId, hex, keccak256};
+use alloy::signers::Signer as _;
+use alloy::signers::local::PrivateKeySigner;
+use ctr::Ctr128BE;
+use ctr::cipher::{KeyIvInit as _, StreamCipher as _};
+use rand::Rng as _;
+use secrecy::ExposeSecret as _;
+use secrecy::zeroize::{Zeroize as _, Zeroizing};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::Result;
+use crate::auth::builder::Config as BuilderConfig;
+use crate::auth::{ApiKey, Credentials};
+use crate::error::Error;
+
+const KEYSTORE_VERSION: u8 = 3;
+const KEY_CIPHER: &str = "aes-128-ctr";
+const CREDENTIALS_CIPHER: &str = "aes-256-gcm";
+const KDF: &str = "scrypt";
+const DERIVED_KEY_LEN: usize = 32;
+
+type Aes128Ctr = Ctr128BE<Aes128>;
+
+/// Cost parameters of the scrypt key derivation.
+///
+/// The [`Default`] matches the "standard" parameters `geth` uses, which take around a second to
+/// derive a key. [`ScryptParams::light`] is much cheaper, at the expense of making a stolen file
+/// easier to brute force.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScryptParams {
+    /// Base two logarithm of the CPU/memory cost `n`
+    pub log_n: u8,
+    /// Block size
+    pub r: u32,
+    /// Parallelization
+    pub p: u32,
+}
+
+impl ScryptParams {
+    #[must_use]
+    pub const fn new(log_n: u8, r: u32, p: u32) -> Self {
+        Self { log_n, r, p }
+    }
+
+    /// The "light" parameters of `geth --lightkdf`.
+    #[must_use]
+    pub const fn light() -> Self {
+        Self::new(12, 8, 6)
+    }
+}
+
+impl Default for ScryptParams {
+    fn default() -> Self {
+        Self::new(18, 8, 1)
+    }
+}
+
+/// A local signer and the API credentials that belong to it.
+///
+/// The signer's chain id is stored along with the credentials, so a loaded signer can be passed
+/// straight to [`authentication_builder_from_keystore`](crate::clob::Client::authentication_builder_from_keystore).
+#[derive(Clone, Debug)]
+pub struct Keystore {
+    signer: PrivateKeySigner,
+    credentials: Credentials,
+    builder_credentials: Option<Credentials>,
+}
+
+impl Keystore {
+    #[must_use]
+    pub fn new(signer: PrivateKeySigner, credentials: Credentials) -> Self {
+        Self {
+            signer,
+            credentials,
+            builder_credentials: None,
+        }
+    }
+
+    /// Stores [Builder](https://docs.polymarket.com/developers/builders/builder-intro)
+    /// credentials along with the signer's own.
+    #[must_use]
+    pub fn with_builder_credentials(mut self, credentials: Credentials) -> Self {
+        self.builder_credentials = Some(credentials);
+        self
+    }
+
+    #[must_use]
+    pub fn signer(&self) -> &PrivateKeySigner {
+        &self.signer
+    }
+
+    #[must_use]
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+
+    #[must_use]
+    pub fn builder_credentials(&self) -> Option<&Credentials> {
+        self.builder_credentials.as_ref()
+    }
+
+    /// Returns a local builder config to pass to
+    /// [`promote_to_builder`](crate::clob::Client::promote_to_builder), if builder credentials
+    /// are stored.
+    #[must_use]
+    pub fn builder_config(&self) -> Option<BuilderConfig> {
+        self.builder_credentials.clone().map(BuilderConfig::local)
+    }
+
+    /// Encrypts the keystore with `password` into a keystore v3 JSON document.
+    pub fn encrypt<P: AsRef<[u8]>>(&self, password: P, params: ScryptParams) -> Result<String> {
+        let password = password.as_ref();
+        let address = self.signer.address();
+
+        let secrets = Secrets {
+            chain_id: self.signer.chain_id(),
+            credentials: StoredCredentials::from(&self.credentials),
+            builder_credentials: self
+                .builder_credentials
+                .as_ref()
+                .map(StoredCredentials::from),
+        };
+        let plaintext = Zeroizing::new(serde_json::to_vec(&secrets)?);
+
+        let file = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            id: Uuid::new_v4(),
+            address: Some(hex::encode(address)),
+            crypto: KeyCrypto::seal(&self.signer.to_bytes().0, password, params)?,
+            credentials: Some(CredentialsCrypto::seal(
+                &plaintext,
+                address.as_slice(),
+                password,
+                params,
+            )?),
+        };
+
+        Ok(serde_json::to_string_pretty(&file)?)
+    }
+
+    /// Decrypts a keystore JSON document written by [`encrypt`](Self::encrypt).
+    pub fn decrypt<P: AsRef<[u8]>>(json: &str, password: P) -> Result<Self> {
+        let password = password.as_ref();
+        let file: KeystoreFile = serde_json::from_str(json)?;
+
+        let signer = file.signer(password)?;
+        let sealed = file.credentials.ok_or_else(|| {
+            Error::validation("Keystore has no `credentials` section, only a signer key")
+        })?;
+        let plaintext = sealed.open(signer.address().as_slice(), password)?;
+        let secrets: Secrets = serde_json::from_slice(&plaintext)?;
+
+        Ok(Self {
+            signer: signer.with_chain_id(secrets.chain_id),
+            credentials: Credentials::from(&secrets.credentials),
+            builder_credentials: secrets.builder_credentials.as_ref().map(Credentials::from),
+        })
+    }
+
+    /// Encrypts the keystore with `password` and writes it to `path`, replacing any existing file
+    /// only once the keystore has been written in full. On Unix, the file is only readable by its
+    /// owner.
+    pub fn save<Q: AsRef<Path>, P: AsRef<[u8]>>(
+        &self,
+        path: Q,
+        password: P,
+        params: ScryptParams,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let json = self.encrypt(password, params)?;
+
+        let partial = path.with_extension("partial");
+        let mut options = OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut writer = BufWriter::new(options.open(&partial)?);
+        writer.write_all(json.as_bytes())?;
+        writer.flush()?;
+        drop(writer);
+
+        fs::rename(partial, path)?;
+        Ok(())
+    }
+
+    /// Loads and decrypts a keystore written by [`save`](Self::save).
+    pub fn load<Q: AsRef<Path>, P: AsRef<[u8]>>(path: Q, password: P) -> Result<Self> {
+        Self::decrypt(&fs::read_to_string(path)?, password)
+    }
+
+    /// Loads and decrypts only the signer key of any keystore v3 file that uses scrypt, e.g. one
+    /// written by `geth` or `cast wallet import`, to build a [`Keystore`] from.
+    pub fn load_signer<Q: AsRef<Path>, P: AsRef<[u8]>>(
+        path: Q,
+        password: P,
+    ) -> Result<PrivateKeySigner> {
+        let file: KeystoreFile = serde_json::from_str(&fs::read_to_string(path)?)?;
+        file.signer(password.as_ref())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u8,
+    id: Uuid,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(alias = "Crypto")]
+    crypto: KeyCrypto,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    credentials: Option<CredentialsCrypto>,
+}
+
+impl KeystoreFile {
+    fn signer(&self, password: &[u8]) -> Result<PrivateKeySigner> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(Error::validation(format!(
+                "Unsupported keystore version {}, expected {KEYSTORE_VERSION}",
+                self.version
+            )));
+        }
+
+        let key = self.crypto.open(password)?;
+        PrivateKeySigner::from_slice(&key)
+            .map_err(|e| Error::validation(format!("Keystore holds an invalid private key: {e}")))
+    }
+}
+
+/// The `crypto` section of a keystore v3 file.
+#[derive(Serialize, Deserialize)]
+struct KeyCrypto {
+    cipher: String,
+    cipherparams: CtrParams,
+    #[serde(with = "hex_bytes")]
+    ciphertext: Vec<u8>,
+    kdf: String,
+    kdfparams: KdfParams,
+    #[serde(with = "hex_bytes")]
+    mac: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CtrParams {
+    #[serde(with = "hex_bytes")]
+    iv: Vec<u8>,
+}
+
+impl KeyCrypto {
+    fn seal(key: &[u8], password: &[u8], params: ScryptParams) -> Result<Self> {
+        let kdfparams = KdfParams::generate(params);
+        let derived = kdfparams.derive(password)?;
+        let (encryption_key, mac_key) = derived.split_at(16);
+
+        let iv: [u8; 16] = rand::rng().random();
+        let mut ciphertext = key.to_vec();
+        Aes128Ctr::new_from_slices(encryption_key, &iv)?.apply_keystream(&mut ciphertext);
+
+        Ok(Self {
+            cipher: KEY_CIPHER.to_owned(),
+            cipherparams: CtrParams { iv: iv.to_vec() },
+            mac: mac(mac_key, &ciphertext),
+            ciphertext,
+            kdf: KDF.to_owned(),
+            kdfparams,
+        })
+    }
+
+    fn open(&self, password: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        if self.cipher != KEY_CIPHER {
+            return Err(Error::validation(format!(
+                "Unsupported keystore cipher {}, expected {KEY_CIPHER}",
+                self.cipher
+            )));
+        }
+        check_kdf(&self.kdf)?;
+        let derived = self.kdfparams.derive(password)?;
+        let (encryption_key, mac_key) = derived.split_at(16);
+        if mac(mac_key, &self.ciphertext) != self.mac {
+            return Err(Error::validation("Invalid keystore password"));
+        }
+
+        let mut key = Zeroizing::new(self.ciphertext.clone());
+        Aes128Ctr::new_from_slices(encryption_key, &self.cipherparams.iv)?
+            .apply_keystream(&mut key);
+
+        Ok(key)
+    }
+}
+
+/// The `credentials` section, which is not part of the keystore v3 format.
+#[derive(Serialize, Deserialize)]
+struct CredentialsCrypto {
+    cipher: String,
+    cipherparams: GcmParams,
+    #[serde(with = "hex_bytes")]
+    ciphertext: Vec<u8>,
+    kdf: String,
+    kdfparams: KdfParams,
+}
+
+#[derive(Serialize, Deserialize)]
+struct GcmParams {
+    #[serde(with = "hex_bytes")]
+    nonce: Vec<u8>,
+}
+
+impl CredentialsCrypto {
+    fn seal(plaintext: &[u8], aad: &[u8], password: &[u8], params: ScryptParams) -> Result<Self> {
+        let kdfparams = KdfParams::generate(params);
+        let derived = kdfparams.derive(password)?;
+
+        let nonce: [u8; 12] = rand::rng().random();
+        let ciphertext = Aes256Gcm::new_from_slice(derived.as_slice())?
+            .encrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: plaintext,
+                    aad,
+                },
+            )
+            .map_err(|e| {
+                Error::validation(format!("Unable to encrypt keystore credentials: {e}"))
+            })?;
+
+        Ok(Self {
+            cipher: CREDENTIALS_CIPHER.to_owned(),
+            cipherparams: GcmParams {
+                nonce: nonce.to_vec(),
+            },
+            ciphertext,
+            kdf: KDF.to_owned(),
+            kdfparams,
+        })
+    }
+
+    fn open(&self, aad: &[u8], password: &[u8]) -> Result<Zeroizing<Vec<u8>>> {
+        if self.cipher != CREDENTIALS_CIPHER {
+            return Err(Error::validation(format!(
+                "Unsupported keystore credentials cipher {}, expected {CREDENTIALS_CIPHER}",
+                self.cipher
+            )));
+        }
+        check_kdf(&self.kdf)?;
+        let nonce: [u8; 12] =
+            self.cipherparams.nonce.as_slice().try_into().map_err(|e| {
+                Error::validation(format!("Invalid keystore credentials nonce: {e}"))
+            })?;
+
+        let derived = self.kdfparams.derive(password)?;
+        let plaintext = Aes256Gcm::new_from_slice(derived.as_slice())?
+            .decrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: &self.ciphertext,
+                    aad,
+                },
+            )
+            .map_err(|_tampered| {
+                Error::validation("Keystore credentials do not belong to its signer key")
+            })?;
+
+        Ok(Zeroizing::new(plaintext))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KdfParams {
+    dklen: usize,
+    n: u64,
+    r: u32,
+    p: u32,
+    #[serde(with = "hex_bytes")]
+    salt: Vec<u8>,
+}
+
+impl KdfParams {
+    fn generate(params: ScryptParams) -> Self {
+        let salt: [u8; 32] = rand::rng().random();
+
+        Self {
+            dklen: DERIVED_KEY_LEN,
+            n: 1 << params.log_n,
+            r: params.r,
+            p: params.p,
+            salt: salt.to_vec(),
+        }
+    }
+
+    fn derive(&self, password: &[u8]) -> Result<Zeroizing<[u8; DERIVED_KEY_LEN]>> {
+        if self.dklen != DERIVED_KEY_LEN {
+            return Err(Error::validation(format!(
+                "Unsupported keystore key length {}, expected {DERIVED_KEY_LEN}",
+                self.dklen
+            )));
+        }
+        if !self.n.is_power_of_two() {
+            return Err(Error::validation(format!(
+                "Keystore scrypt n must be a power of two, got {}",
+                self.n
+            )));
+        }
+
+        let log_n = u8::try_from(self.n.trailing_zeros())
+            .map_err(|e| Error::validation(format!("Invalid keystore scrypt n: {e}")))?;
+        let params = scrypt::Params::new(log_n, self.r, self.p, DERIVED_KEY_LEN)
+            .map_err(|e| Error::validation(format!("Invalid keystore scrypt parameters: {e}")))?;
+
+        let mut derived = Zeroizing::new([0_u8; DERIVED_KEY_LEN]);
+        scrypt::scrypt(password, &self.salt, &params, derived.as_mut_slice())
+            .map_err(|e| Error::validation(format!("Unable to derive keystore key: {e}")))?;
+
+        Ok(derived)
+    }
+}
+
+/// What the `credentials` section decrypts to.
+#[derive(Serialize, Deserialize)]
+struct Secrets {
+    chain_id: Option<ChainId>,
+    credentials: StoredCredentials,
+    builder_credentials: Option<StoredCredentials>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredCredentials {
+    key: ApiKey,
+    secret: String,
+    passphrase: String,
+}
+
+impl From<&Credentials> for StoredCredentials {
+    fn from(credentials: &Credentials) -> Self {
+        Self {
+            key: credentials.key,
+            secret: credentials.secret.expose_secret().to_owned(),
+            passphrase: credentials.passphrase.expose_secret().to_owned(),
+        }
+    }
+}
+
+impl From<&StoredCredentials> for Credentials {
+    fn from(credentials: &StoredCredentials) -> Self {
+        Credentials::new(
+            credentials.key,
+            credentials.secret.clone(),
+            credentials.passphrase.clone(),
+        )
+    }
+}
+
+impl Drop for StoredCredentials {
+    fn drop(&mut self) {
+        self.secret.zeroize();
+        self.passphrase.zeroize();
+    }
+}
+
+fn check_kdf(kdf: &str) -> Result<()> {
+    if kdf == KDF {
+        Ok(())
+    } else {
+        Err(Error::validation(format!(
+            "Unsupported keystore KDF {kdf}, expected {KDF}"
+        )))
+    }
+}
+
+/// The keystore v3 MAC, `keccak256(derived_key[16..32] ++ ciphertext)`.
+fn mac(mac_key: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    keccak256([mac_key, ciphertext].concat()).to_vec()
+}
+
+/// Keystore v3 files hex encode bytes without a `0x` prefix.
+mod hex_bytes {
+    use alloy::primitives::hex;
+    use serde::de::Error as _;
+    use serde::{Deserialize as _, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(bytes))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        hex::decode(encoded).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::POLYGON;
+
+    const PASSWORD: &str = "correct horse battery staple";
+    /// Keeps the tests fast, far below any cost fit for a real keystore.
+    const PARAMS: ScryptParams = ScryptParams::new(4, 8, 1);
+
+    fn keystore() -> Keystore {
+        let signer = PrivateKeySigner::random().with_chain_id(Some(POLYGON));
+        let credentials = Credentials::new(Uuid::nil(), "secret".to_owned(), "pass".to_owned());
+
+        Keystore::new(signer, credentials).with_builder_credentials(Credentials::new(
+            Uuid::max(),
+            "builder-secret".to_owned(),
+            "builder-pass".to_owned(),
+        ))
+    }
+
+    #[test]
+    fn encrypt_should_round_trip() -> Result<()> {
+        let keystore = keystore();
+        let json = keystore.encrypt(PASSWORD, PARAMS)?;
+        let decrypted = Keystore::decrypt(&json, PASSWORD)?;
+
+        assert_eq!(decrypted.signer().address(), keystore.signer().address());
+        assert_eq!(decrypted.signer().chain_id(), Some(POLYGON));
+        assert_eq!(decrypted.credentials().key(), Uuid::nil());
+        assert_eq!(decrypted.credentials().secret().expose_secret(), "secret");
+        assert_eq!(decrypted.credentials().passphrase().expose_secret(), "pass");
+
+        let builder = decrypted
+            .builder_credentials()
+            .expect("builder credentials");
+        assert_eq!(builder.key(), Uuid::max());
+        assert_eq!(builder.secret().expose_secret(), "builder-secret");
+        assert!(decrypted.builder_config().is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_should_reject_wrong_password() -> Result<()> {
+        let json = keystore().encrypt(PASSWORD, PARAMS)?;
+
+        let err = Keystore::decrypt(&json, "wrong").unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid keystore password"),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decrypt_should_reject_credentials_of_another_key() -> Result<()> {
+        let first: serde_json::Value =
+            serde_json::from_str(&keystore().encrypt(PASSWORD, PARAMS)?)?;
+        let mut second: serde_json::Value =
+            serde_json::from_str(&keystore().encrypt(PASSWORD, PARAMS)?)?;
+        second["credentials"] = first["credentials"].clone();
+
+        let err = Keystore::decrypt(&second.to_string(), PASSWORD).unwrap_err();
+        assert!(err.to_string().contains("do not belong"), "{err}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn encrypt_should_write_keystore_v3() -> Result<()> {
+        let keystore = keystore();
+        let json: serde_json::Value = serde_json::from_str(&keystore.encrypt(PASSWORD, PARAMS)?)?;
+
+        assert_eq!(json["version"], 3);
+        assert_eq!(json["address"], hex::encode(keystore.signer().address()));
+        assert_eq!(json["crypto"]["cipher"], "aes-128-ctr");
+        assert_eq!(json["crypto"]["kdf"], "scrypt");
+        assert_eq!(json["crypto"]["kdfparams"]["n"], 16);
+        assert_eq!(json["crypto"]["kdfparams"]["dklen"], 32);
+
+        Ok(())
+    }
+}
@@ -1,18 +1,33 @@
 #![cfg_attr(doc, doc = include_str!("../README.md"))]
 
+#[cfg(feature = "approvals")]
+pub mod approvals;
 pub mod auth;
 #[cfg(feature = "bridge")]
 pub mod bridge;
+#[cfg(feature = "cassette")]
+pub mod cassette;
 pub mod clob;
+#[cfg(feature = "ctf")]
+pub mod ctf;
 #[cfg(feature = "data")]
 pub mod data;
 pub mod error;
 #[cfg(feature = "gamma")]
 pub mod gamma;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod pagination;
+#[cfg(feature = "relayer")]
+pub mod relayer;
+pub mod retry;
 #[cfg(feature = "rtds")]
 pub mod rtds;
 pub(crate) mod serde_helpers;
+pub mod transport;
 pub mod types;
+#[cfg(feature = "wallet")]
+pub mod wallet;
 #[cfg(any(feature = "ws", feature = "rtds"))]
 pub mod ws;
 
@@ -27,6 +42,8 @@ use serde::Serialize;
 use serde::de::DeserializeOwned;
 
 use crate::error::Error;
+use crate::retry::{Idempotency, RetryPolicy};
+use crate::transport::HttpTransport;
 use crate::types::{Address, address};
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -77,12 +94,16 @@ static NEG_RISK_CONFIG: phf::Map<ChainId, ContractConfig> = phf_map! {
 static WALLET_CONFIG: phf::Map<ChainId, WalletContractConfig> = phf_map! {
     137_u64 => WalletContractConfig {
         proxy_factory: Some(address!("0xaB45c5A4B0c941a2F231C04C3f49182e1A254052")),
+        relay_hub: Some(address!("0xD216153c06E857cD7f72665E0aF1d7D82172F494")),
         safe_factory: address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"),
+        safe_multisend: address!("0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761"),
     },
     80002_u64 => WalletContractConfig {
         // Proxy factory unsupported on Amoy testnet
         proxy_factory: None,
+        relay_hub: None,
         safe_factory: address!("0xaacFeEa03eb1561C4e67d661e40682Bd20E3541b"),
+        safe_multisend: address!("0xA238CBeb142c10Ef7Ad8442C6D1f9E89e07e7761"),
     },
 };
 
@@ -113,8 +134,13 @@ pub struct WalletContractConfig {
     /// Factory contract for Polymarket Proxy wallets (Magic/email wallets).
     /// Not available on all networks (e.g., Amoy testnet).
     pub proxy_factory: Option<Address>,
+    /// Relay hub through which the relayer forwards calls to proxy wallets.
+    /// Only available where proxy wallets are.
+    pub relay_hub: Option<Address>,
     /// Factory contract for Gnosis Safe wallets.
     pub safe_factory: Address,
+    /// `MultiSend` contract that Safe wallets delegate to when executing several calls at once.
+    pub safe_multisend: Address,
 }
 
 /// Given a `chain_id` and `is_neg_risk`, return the relevant [`ContractConfig`]
@@ -220,11 +246,39 @@ pub trait ToQueryParams: Serialize {
 
 impl<T: Serialize> ToQueryParams for T {}
 
+/// Creates the authentication headers of a request.
+///
+/// Auth headers carry a timestamp and a signature over it, so they are created again before
+/// every attempt instead of being reused when a request is retried.
+pub(crate) trait Sign {
+    async fn headers(&self, request: &Request) -> Result<HeaderMap>;
+}
+
+/// Signs nothing, for requests that are not authenticated.
+pub(crate) struct Unsigned;
+
+impl Sign for Unsigned {
+    async fn headers(&self, _request: &Request) -> Result<HeaderMap> {
+        Ok(HeaderMap::new())
+    }
+}
+
+async fn request<Response: DeserializeOwned>(
+    client: &impl HttpTransport,
+    request: Request,
+    sign: &impl Sign,
+    retry_policy: Option<&RetryPolicy>,
+) -> Result<Response> {
+    let idempotency = Idempotency::of(request.method());
+    request_with(client, request, sign, retry_policy, idempotency).await
+}
+
+/// Like [`request`], but retries `request` according to `idempotency` instead of its method.
 #[cfg_attr(
     feature = "tracing",
     tracing::instrument(
         level = "debug",
-        skip(client, request, headers),
+        skip(client, request, sign),
         fields(
             method = %request.method(),
             path = request.url().path(),
@@ -232,19 +286,17 @@ impl<T: Serialize> ToQueryParams for T {}
         )
     )
 )]
-async fn request<Response: DeserializeOwned>(
-    client: &reqwest::Client,
-    mut request: Request,
-    headers: Option<HeaderMap>,
+async fn request_with<Response: DeserializeOwned>(
+    client: &impl HttpTransport,
+    request: Request,
+    sign: &impl Sign,
+    retry_policy: Option<&RetryPolicy>,
+    idempotency: Idempotency,
 ) -> Result<Response> {
     let method = request.method().clone();
     let path = request.url().path().to_owned();
 
-    if let Some(h) = headers {
-        *request.headers_mut() = h;
-    }
-
-    let response = client.execute(request).await?;
+    let response = execute_with(client, request, sign, retry_policy, idempotency).await?;
     let status_code = response.status();
 
     #[cfg(feature = "tracing")]
@@ -282,6 +334,80 @@ async fn request<Response: DeserializeOwned>(
     }
 }
 
+/// Executes `request` with the headers from `sign`, retrying it according to `retry_policy` when
+/// it fails with a retryable status or connection error. Every retry is signed again. The last
+/// response is returned as is, regardless of its status.
+async fn execute(
+    client: &impl HttpTransport,
+    request: Request,
+    sign: &impl Sign,
+    retry_policy: Option<&RetryPolicy>,
+) -> Result<reqwest::Response> {
+    let idempotency = Idempotency::of(request.method());
+    execute_with(client, request, sign, retry_policy, idempotency).await
+}
+
+/// Like [`execute`], but retries `request` according to `idempotency` instead of its method.
+async fn execute_with(
+    client: &impl HttpTransport,
+    mut request: Request,
+    sign: &impl Sign,
+    retry_policy: Option<&RetryPolicy>,
+    idempotency: Idempotency,
+) -> Result<reqwest::Response> {
+    let headers = sign.headers(&request).await?;
+    request.headers_mut().extend(headers);
+
+    let Some(policy) = retry_policy.filter(|policy| policy.is_retryable(idempotency)) else {
+        return client.execute(request).await;
+    };
+
+    let mut attempt = 0;
+    loop {
+        // Requests with streaming bodies cannot be cloned, and thus cannot be retried
+        let Some(mut next) = request.try_clone() else {
+            return client.execute(request).await;
+        };
+
+        let delay = match client.execute(request).await {
+            Ok(response) if !RetryPolicy::is_retryable_status(response.status()) => {
+                return Ok(response);
+            }
+            Ok(response) => match policy.delay(attempt, Some(response.headers())) {
+                Some(delay) => delay,
+                None => return Ok(response),
+            },
+            Err(e) if is_transient(&e) => match policy.delay(attempt, None) {
+                Some(delay) => delay,
+                None => return Err(e),
+            },
+            Err(e) => return Err(e),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            method = %next.method(),
+            path = next.url().path(),
+            attempt = attempt + 1,
+            ?delay,
+            "Retrying request"
+        );
+
+        tokio::time::sleep(delay).await;
+        let headers = sign.headers(&next).await?;
+        next.headers_mut().extend(headers);
+        request = next;
+        attempt += 1;
+    }
+}
+
+/// Returns whether `error` is a connection failure or timeout worth retrying.
+fn is_transient(error: &Error) -> bool {
+    error
+        .downcast_ref::<reqwest::Error>()
+        .is_some_and(|e| e.is_connect() || e.is_timeout())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,288 @@
+//! Streams over the items of paginated endpoints.
+//!
+//! The APIs paginate in one of two ways. The CLOB returns a [`Page`] whose `next_cursor` is passed
+//! back until it is the terminal cursor, while the Data and Gamma APIs take an `offset` and `limit`
+//! and return a plain list, which has run out once a page comes back short. The `*_stream` methods
+//! of each client hide either scheme behind a [`Paginated`] stream of items, which only fetches a
+//! page once the previous one has been consumed.
+
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use async_stream::try_stream;
+use futures::Stream;
+
+use crate::Result;
+use crate::clob::client::TERMINAL_CURSOR;
+use crate::clob::types::response::Page;
+
+/// A stream of the items of a paginated endpoint, fetched a page at a time.
+///
+/// The stream ends after the last page, after [`max_items`](Self::max_items) items, or after the
+/// first error.
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginated<S> {
+    pages: Pin<Box<S>>,
+    remaining: Option<usize>,
+}
+
+impl<S> Paginated<S> {
+    fn new(pages: S) -> Self {
+        Self {
+            pages: Box::pin(pages),
+            remaining: None,
+        }
+    }
+
+    /// Ends the stream after `max_items` items, without fetching any further pages.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.remaining = Some(max_items);
+        self
+    }
+}
+
+impl<T, S: Stream<Item = Result<T>>> Stream for Paginated<S> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.remaining == Some(0) {
+            return Poll::Ready(None);
+        }
+
+        let item = ready!(self.pages.as_mut().poll_next(cx));
+        if let Some(Ok(_)) = item
+            && let Some(remaining) = &mut self.remaining
+        {
+            *remaining -= 1;
+        }
+
+        Poll::Ready(item)
+    }
+}
+
+/// A request to an endpoint paginated by `offset` and `limit`.
+#[cfg(any(feature = "data", feature = "gamma"))]
+pub(crate) trait OffsetPaginated: Clone {
+    fn offset(&self) -> Option<i32>;
+
+    fn limit(&self) -> Option<i32>;
+
+    fn set_offset(&mut self, offset: i32);
+}
+
+/// Implements [`OffsetPaginated`] for requests with `offset` and `limit` fields.
+#[cfg(any(feature = "data", feature = "gamma"))]
+macro_rules! offset_paginated {
+    ($($request:ty),+ $(,)?) => {$(
+        impl $crate::pagination::OffsetPaginated for $request {
+            fn offset(&self) -> Option<i32> {
+                self.offset
+            }
+
+            fn limit(&self) -> Option<i32> {
+                self.limit
+            }
+
+            fn set_offset(&mut self, offset: i32) {
+                self.offset = Some(offset);
+            }
+        }
+    )+};
+}
+
+#[cfg(any(feature = "data", feature = "gamma"))]
+pub(crate) use offset_paginated;
+
+/// Streams the items of the [`Page`]s returned by `fetch`, passing each page's `next_cursor` to
+/// the next call until it is the terminal cursor.
+pub(crate) fn by_cursor<'stream, T, F, Fut>(
+    mut fetch: F,
+) -> Paginated<impl Stream<Item = Result<T>> + 'stream>
+where
+    T: 'stream,
+    F: FnMut(Option<String>) -> Fut + 'stream,
+    Fut: Future<Output = Result<Page<T>>> + 'stream,
+{
+    Paginated::new(try_stream! {
+        let mut cursor = None;
+
+        loop {
+            let page = fetch(cursor.take()).await?;
+
+            for item in page.data {
+                yield item;
+            }
+
+            if page.next_cursor.is_empty() || page.next_cursor == TERMINAL_CURSOR {
+                break;
+            }
+
+            cursor = Some(page.next_cursor);
+        }
+    })
+}
+
+/// Streams the items returned by `fetch` for `request`, advancing its `offset` by the number of
+/// items in each page until a page is shorter than the request's `limit`. Without a `limit`, the
+/// first page's length is taken as the page size.
+#[cfg(any(feature = "data", feature = "gamma"))]
+pub(crate) fn by_offset<'stream, R, T, F, Fut>(
+    request: &R,
+    mut fetch: F,
+) -> Paginated<impl Stream<Item = Result<T>> + 'stream>
+where
+    R: OffsetPaginated + 'stream,
+    T: 'stream,
+    F: FnMut(R) -> Fut + 'stream,
+    Fut: Future<Output = Result<Vec<T>>> + 'stream,
+{
+    let mut request = request.clone();
+    let mut page_size = request
+        .limit()
+        .and_then(|limit| usize::try_from(limit).ok())
+        .filter(|limit| *limit > 0);
+
+    Paginated::new(try_stream! {
+        let mut offset = request.offset().unwrap_or(0);
+
+        loop {
+            request.set_offset(offset);
+            let items = fetch(request.clone()).await?;
+            let count = items.len();
+            let page_size = *page_size.get_or_insert(count);
+
+            for item in items {
+                yield item;
+            }
+
+            if count == 0 || count < page_size {
+                break;
+            }
+
+            offset = offset.saturating_add(i32::try_from(count).unwrap_or(i32::MAX));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use futures::TryStreamExt as _;
+
+    use super::*;
+
+    fn page(data: Vec<u32>, next_cursor: &str) -> Page<u32> {
+        Page {
+            limit: 2,
+            count: data.len() as u64,
+            data,
+            next_cursor: next_cursor.to_owned(),
+        }
+    }
+
+    #[tokio::test]
+    async fn by_cursor_should_stop_at_terminal_cursor() {
+        let cursors = RefCell::new(Vec::new());
+        let items: Vec<u32> = by_cursor(|cursor| {
+            cursors.borrow_mut().push(cursor.clone());
+            async move {
+                Ok(match cursor.as_deref() {
+                    None => page(vec![1, 2], "MQ=="),
+                    Some("MQ==") => page(vec![3], TERMINAL_CURSOR),
+                    Some(other) => panic!("unexpected cursor {other}"),
+                })
+            }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(*cursors.borrow(), [None, Some("MQ==".to_owned())]);
+    }
+
+    #[tokio::test]
+    async fn max_items_should_stop_fetching_pages() {
+        let calls = RefCell::new(0);
+
+        let items: Vec<u32> = by_cursor(|cursor| {
+            *calls.borrow_mut() += 1;
+            async move {
+                Ok(match cursor.as_deref() {
+                    None => page(vec![1, 2], "MQ=="),
+                    Some("MQ==") => page(vec![3, 4], "Mg=="),
+                    Some(other) => panic!("unexpected cursor {other}"),
+                })
+            }
+        })
+        .max_items(3)
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(*calls.borrow(), 2);
+    }
+
+    #[cfg(any(feature = "data", feature = "gamma"))]
+    mod offset {
+        use super::*;
+
+        #[derive(Clone, Debug)]
+        struct Request {
+            offset: Option<i32>,
+            limit: Option<i32>,
+        }
+
+        offset_paginated!(Request);
+
+        #[tokio::test]
+        async fn by_offset_should_stop_at_short_page() {
+            let offsets = RefCell::new(Vec::new());
+            let request = Request {
+                offset: Some(10),
+                limit: Some(2),
+            };
+
+            let items: Vec<i32> = by_offset(&request, |request: Request| {
+                let offset = request.offset.unwrap();
+                offsets.borrow_mut().push(offset);
+                async move {
+                    Ok(match offset {
+                        10 => vec![10, 11],
+                        12 => vec![12],
+                        other => panic!("unexpected offset {other}"),
+                    })
+                }
+            })
+            .try_collect()
+            .await
+            .unwrap();
+
+            assert_eq!(items, [10, 11, 12]);
+            assert_eq!(*offsets.borrow(), [10, 12]);
+        }
+
+        #[tokio::test]
+        async fn by_offset_without_limit_should_use_first_page_as_page_size() {
+            let calls = RefCell::new(0);
+            let request = Request {
+                offset: None,
+                limit: None,
+            };
+
+            let items: Vec<i32> = by_offset(&request, |request: Request| {
+                *calls.borrow_mut() += 1;
+                let offset = request.offset.unwrap();
+                async move { Ok((offset..(offset + 3).min(7)).collect()) }
+            })
+            .try_collect()
+            .await
+            .unwrap();
+
+            assert_eq!(items, (0..7).collect::<Vec<_>>());
+            assert_eq!(*calls.borrow(), 3);
+        }
+    }
+}
@@ -0,0 +1,561 @@
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use std::time::Duration;
+
+use alloy::dyn_abi::Eip712Domain;
+use alloy::primitives::{B256, Bytes, U256, keccak256};
+use alloy::signers::Signer;
+use alloy::sol;
+use alloy::sol_types::{SolStruct as _, eip712_domain};
+use chrono::Utc;
+use reqwest::{
+    Client as ReqwestClient, Method, Request,
+    header::{HeaderMap, HeaderValue},
+};
+use serde::Deserialize;
+use url::Url;
+
+use super::types::{
+    NonceResponse, RelayPayload, RelayerTransaction, SignatureParams, SubmitRequest,
+    SubmitResponse, TransactionType,
+};
+use crate::auth::builder::{Builder, Config as BuilderConfig};
+use crate::ctf::Call;
+use crate::error::Error;
+use crate::retry::RetryPolicy;
+use crate::transport::HttpTransport;
+use crate::types::{Address, ChainId, Signature};
+use crate::wallet::{Operation, ProxyWallet, SafeWallet};
+use crate::{Result, Sign, Unsigned, wallet_contract_config};
+
+sol! {
+    /// Signed by a Safe's owner to have the Safe factory deploy it.
+    struct CreateProxy {
+        address paymentToken;
+        uint256 payment;
+        address paymentReceiver;
+    }
+
+    /// Batches calls executed by a Safe through a `DELEGATECALL`.
+    interface IMultiSend {
+        function multiSend(bytes transactions) external payable;
+    }
+}
+
+const SAFE_FACTORY_NAME: &str = "Polymarket Contract Proxy Factory";
+
+/// Gas limit of a relayed proxy wallet transaction.
+const PROXY_GAS_LIMIT: u64 = 10_000_000;
+
+#[derive(Deserialize)]
+struct DeployedResponse {
+    deployed: bool,
+}
+
+/// Client for the Polymarket builder relayer, which pays the gas of Safe and proxy wallet
+/// transactions for users attributed to a builder.
+///
+/// Every submitted transaction is attributed with the builder headers generated from the
+/// [`BuilderConfig`], be it local or remote.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::str::FromStr as _;
+///
+/// use polymarket_client_sdk::POLYGON;
+/// use polymarket_client_sdk::auth::builder::Config;
+/// use polymarket_client_sdk::auth::{LocalSigner, Signer as _};
+/// use polymarket_client_sdk::ctf::ConditionalTokens;
+/// use polymarket_client_sdk::relayer::Client;
+/// use polymarket_client_sdk::types::ConditionId;
+///
+/// # async fn example(condition_id: ConditionId) -> Result<(), Box<dyn std::error::Error>> {
+/// let signer = LocalSigner::from_str("0x...")?.with_chain_id(Some(POLYGON));
+/// let config = Config::remote("https://signing.example.com/sign", None)?;
+/// let client = Client::new("https://relayer-v2.polymarket.com", config)?;
+///
+/// client.deploy_safe(&signer).await?;
+///
+/// let redeem = ConditionalTokens::new(POLYGON)?.redeem_positions(condition_id);
+/// let submitted = client.execute_safe(&signer, &[redeem]).await?;
+/// let transaction = client.wait(&submitted.transaction_id).await?;
+/// println!("Redeemed in {:?}", transaction.transaction_hash);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`client` is the underlying HTTP client, as in the other API clients"
+)]
+pub struct Client<T: HttpTransport = ReqwestClient> {
+    host: Url,
+    client: ReqwestClient,
+    transport: T,
+    retry_policy: Option<RetryPolicy>,
+    builder: Builder,
+    poll_interval: Duration,
+    max_polls: u32,
+}
+
+impl Client {
+    /// Creates a new relayer client attributing transactions to the builder of `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host URL is invalid or the HTTP client fails to build.
+    pub fn new(host: &str, config: BuilderConfig) -> Result<Client> {
+        let mut headers = HeaderMap::new();
+
+        headers.insert("User-Agent", HeaderValue::from_static("rs_clob_client"));
+        headers.insert("Accept", HeaderValue::from_static("*/*"));
+        headers.insert("Connection", HeaderValue::from_static("keep-alive"));
+        headers.insert("Content-Type", HeaderValue::from_static("application/json"));
+        let client = ReqwestClient::builder().default_headers(headers).build()?;
+
+        Ok(Self {
+            host: Url::parse(host)?,
+            transport: client.clone(),
+            builder: Builder {
+                config,
+                client: client.clone(),
+            },
+            client,
+            retry_policy: None,
+            poll_interval: Duration::from_secs(2),
+            max_polls: 30,
+        })
+    }
+}
+
+impl<T: HttpTransport> Client<T> {
+    /// Sends requests through `transport` instead of the default [`ReqwestClient`].
+    pub fn with_transport<U: HttpTransport>(self, transport: U) -> Client<U> {
+        Client {
+            host: self.host,
+            client: self.client,
+            transport,
+            retry_policy: self.retry_policy,
+            builder: self.builder,
+            poll_interval: self.poll_interval,
+            max_polls: self.max_polls,
+        }
+    }
+
+    /// Retries requests that fail with `429` or `5xx` according to `retry_policy`.
+    ///
+    /// Requests are not retried unless a policy is set.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Sets how often and how many times [`Client::wait`] polls a transaction's state. Defaults to
+    /// every 2 seconds, 30 times.
+    #[must_use]
+    pub fn with_polling(mut self, poll_interval: Duration, max_polls: u32) -> Self {
+        self.poll_interval = poll_interval;
+        self.max_polls = max_polls;
+        self
+    }
+
+    /// Returns the host URL for the client.
+    #[must_use]
+    pub fn host(&self) -> &Url {
+        &self.host
+    }
+
+    /// The nonce of the next `transaction_type` transaction relayed for `signer`.
+    pub async fn nonce(&self, signer: Address, transaction_type: TransactionType) -> Result<U256> {
+        let request = self
+            .client
+            .request(Method::GET, format!("{}nonce", self.host))
+            .query(&[("address", signer.to_string())])
+            .query(&[("type", transaction_type)])
+            .build()?;
+
+        let response: NonceResponse = self.request(request, &Unsigned).await?;
+        Ok(response.nonce)
+    }
+
+    /// The relay and nonce for the next proxy wallet transaction of `signer`.
+    pub async fn relay_payload(&self, signer: Address) -> Result<RelayPayload> {
+        let request = self
+            .client
+            .request(Method::GET, format!("{}relay-payload", self.host))
+            .query(&[("address", signer.to_string())])
+            .query(&[("type", TransactionType::Proxy)])
+            .build()?;
+
+        self.request(request, &Unsigned).await
+    }
+
+    /// Whether the Safe at `safe` has been deployed.
+    pub async fn deployed(&self, safe: Address) -> Result<bool> {
+        let request = self
+            .client
+            .request(Method::GET, format!("{}deployed", self.host))
+            .query(&[("address", safe.to_string())])
+            .build()?;
+
+        let response: DeployedResponse = self.request(request, &Unsigned).await?;
+        Ok(response.deployed)
+    }
+
+    /// The relayed transaction with `transaction_id`, if the relayer knows it.
+    pub async fn transaction(&self, transaction_id: &str) -> Result<Option<RelayerTransaction>> {
+        let request = self
+            .client
+            .request(Method::GET, format!("{}transaction", self.host))
+            .query(&[("id", transaction_id)])
+            .build()?;
+
+        let transactions: Vec<RelayerTransaction> = self.request(request, &Unsigned).await?;
+        Ok(transactions.into_iter().next())
+    }
+
+    /// Submits a signed wallet transaction, attributed to the builder.
+    pub async fn submit(&self, request: &SubmitRequest) -> Result<SubmitResponse> {
+        let request = self
+            .client
+            .request(Method::POST, format!("{}submit", self.host))
+            .json(request)
+            .build()?;
+
+        self.request(request, &self.builder).await
+    }
+
+    /// Has the Safe of `signer` execute `calls` in order, signing the Safe transaction with its
+    /// next nonce. Several calls are batched into a single transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `calls` is empty or if the Safe has not been deployed yet, see
+    /// [`Client::deploy_safe`].
+    pub async fn execute_safe<S: Signer + Sync>(
+        &self,
+        signer: &S,
+        calls: &[Call],
+    ) -> Result<SubmitResponse> {
+        if calls.is_empty() {
+            return Err(Error::validation("No calls to execute"));
+        }
+
+        let chain_id = chain_id(signer)?;
+        let owner = signer.address();
+        let safe = SafeWallet::for_owner(owner, chain_id)?;
+
+        if !self.deployed(safe.address).await? {
+            return Err(Error::validation(format!(
+                "Safe {} of {owner} is not deployed",
+                safe.address
+            )));
+        }
+
+        let (call, operation) = match calls {
+            [call] => (call.clone(), Operation::Call),
+            calls => (multi_send(chain_id, calls)?, Operation::DelegateCall),
+        };
+
+        let nonce = self.nonce(owner, TransactionType::Safe).await?;
+        let mut transaction = safe.transaction(&call, nonce);
+        transaction.tx.operation = operation as u8;
+
+        let signature = signer.sign_message(transaction.hash().as_slice()).await?;
+        let tx = &transaction.tx;
+
+        let request = SubmitRequest::builder()
+            .transaction_type(TransactionType::Safe)
+            .from(owner)
+            .to(tx.to)
+            .proxy_wallet(safe.address)
+            .data(tx.data.clone())
+            .nonce(nonce)
+            .signature(pack_eth_sign(&signature))
+            .signature_params(SignatureParams::Safe {
+                gas_price: tx.gasPrice,
+                operation: tx.operation,
+                safe_txn_gas: tx.safeTxGas,
+                base_gas: tx.baseGas,
+                gas_token: tx.gasToken,
+                refund_receiver: tx.refundReceiver,
+            })
+            .build();
+
+        self.submit(&request).await
+    }
+
+    /// Has the proxy wallet of `signer` execute `calls` in order through the relay hub.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `calls` is empty.
+    pub async fn execute_proxy<S: Signer + Sync>(
+        &self,
+        signer: &S,
+        calls: &[Call],
+    ) -> Result<SubmitResponse> {
+        if calls.is_empty() {
+            return Err(Error::validation("No calls to execute"));
+        }
+
+        let chain_id = chain_id(signer)?;
+        let owner = signer.address();
+        let proxy = ProxyWallet::for_owner(owner, chain_id)?;
+        let relay_hub = wallet_contract_config(chain_id)
+            .and_then(|config| config.relay_hub)
+            .ok_or(Error::validation(format!(
+                "Relayed proxy transactions are not supported on chain {chain_id}"
+            )))?;
+
+        let call = proxy.call(calls);
+        let payload = self.relay_payload(owner).await?;
+        let gas_price = U256::ZERO;
+        let gas_limit = U256::from(PROXY_GAS_LIMIT);
+        let relayer_fee = U256::ZERO;
+
+        let hash = relay_hash(
+            owner,
+            &call,
+            [relayer_fee, gas_price, gas_limit, payload.nonce],
+            relay_hub,
+            payload.address,
+        );
+        let signature = signer.sign_message(hash.as_slice()).await?;
+
+        let request = SubmitRequest::builder()
+            .transaction_type(TransactionType::Proxy)
+            .from(owner)
+            .to(call.to)
+            .proxy_wallet(proxy.address)
+            .data(call.data)
+            .nonce(payload.nonce)
+            .signature(Bytes::from(signature.as_bytes()))
+            .signature_params(SignatureParams::Proxy {
+                gas_price,
+                gas_limit,
+                relayer_fee,
+                relay_hub,
+                relay: payload.address,
+            })
+            .build();
+
+        self.submit(&request).await
+    }
+
+    /// Deploys the Safe of `signer`, returning [`None`] if it already exists.
+    pub async fn deploy_safe<S: Signer>(&self, signer: &S) -> Result<Option<SubmitResponse>> {
+        let chain_id = chain_id(signer)?;
+        let owner = signer.address();
+        let safe = SafeWallet::for_owner(owner, chain_id)?;
+
+        if self.deployed(safe.address).await? {
+            return Ok(None);
+        }
+
+        let factory = wallet_contract_config(chain_id)
+            .ok_or(Error::validation(format!(
+                "Unable to find wallet contracts for chain {chain_id}"
+            )))?
+            .safe_factory;
+        let create = CreateProxy {
+            paymentToken: Address::ZERO,
+            payment: U256::ZERO,
+            paymentReceiver: Address::ZERO,
+        };
+        let domain = safe_factory_domain(chain_id, factory);
+        let signature = signer
+            .sign_hash(&create.eip712_signing_hash(&domain))
+            .await?;
+
+        let request = SubmitRequest::builder()
+            .transaction_type(TransactionType::SafeCreate)
+            .from(owner)
+            .to(factory)
+            .proxy_wallet(safe.address)
+            .data(Bytes::new())
+            .signature(Bytes::from(signature.as_bytes()))
+            .signature_params(SignatureParams::SafeCreate {
+                payment_token: create.paymentToken,
+                payment: create.payment,
+                payment_receiver: create.paymentReceiver,
+            })
+            .build();
+
+        self.submit(&request).await.map(Some)
+    }
+
+    /// Polls the state of the relayed transaction with `transaction_id` until it is mined.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction failed, or was still pending after polling as often as
+    /// set by [`Client::with_polling`].
+    pub async fn wait(&self, transaction_id: &str) -> Result<RelayerTransaction> {
+        for poll in 0..self.max_polls {
+            if poll > 0 {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+
+            let Some(transaction) = self.transaction(transaction_id).await? else {
+                continue;
+            };
+
+            if transaction.state.is_mined() {
+                return Ok(transaction);
+            }
+            if transaction.state.is_failed() {
+                return Err(Error::validation(format!(
+                    "Relayer transaction {transaction_id} failed in state {:?}",
+                    transaction.state
+                )));
+            }
+        }
+
+        Err(Error::validation(format!(
+            "Relayer transaction {transaction_id} was not mined after {} polls",
+            self.max_polls
+        )))
+    }
+
+    async fn request<Response: serde::de::DeserializeOwned>(
+        &self,
+        request: Request,
+        sign: &impl Sign,
+    ) -> Result<Response> {
+        crate::request(&self.transport, request, sign, self.retry_policy.as_ref()).await
+    }
+}
+
+/// Submissions are attributed to the builder with headers timestamped by the local clock.
+impl Sign for Builder {
+    async fn headers(&self, request: &Request) -> Result<HeaderMap> {
+        self.create_headers(request, Utc::now().timestamp()).await
+    }
+}
+
+fn chain_id<S: Signer>(signer: &S) -> Result<ChainId> {
+    signer.chain_id().ok_or(Error::validation(
+        "Chain id not set, be sure to provide one on the signer",
+    ))
+}
+
+fn safe_factory_domain(chain_id: ChainId, factory: Address) -> Eip712Domain {
+    eip712_domain! {
+        name: SAFE_FACTORY_NAME,
+        chain_id: chain_id,
+        verifying_contract: factory,
+    }
+}
+
+/// Batches `calls` into a call to the `MultiSend` contract, for a Safe to `DELEGATECALL`.
+fn multi_send(chain_id: ChainId, calls: &[Call]) -> Result<Call> {
+    let multisend = wallet_contract_config(chain_id)
+        .ok_or(Error::validation(format!(
+            "Unable to find wallet contracts for chain {chain_id}"
+        )))?
+        .safe_multisend;
+
+    let mut transactions = Vec::new();
+    for call in calls {
+        transactions.push(Operation::Call as u8);
+        transactions.extend_from_slice(call.to.as_slice());
+        transactions.extend_from_slice(&U256::ZERO.to_be_bytes::<32>());
+        transactions.extend_from_slice(&U256::from(call.data.len()).to_be_bytes::<32>());
+        transactions.extend_from_slice(&call.data);
+    }
+
+    Ok(Call::new(
+        multisend,
+        &IMultiSend::multiSendCall {
+            transactions: transactions.into(),
+        },
+    ))
+}
+
+/// The hash the owner of a proxy wallet signs for the relay hub to forward `call`. `amounts` are
+/// the relayer fee, gas price, gas limit and nonce.
+fn relay_hash(
+    from: Address,
+    call: &Call,
+    amounts: [U256; 4],
+    relay_hub: Address,
+    relay: Address,
+) -> B256 {
+    let mut message = b"rlx:".to_vec();
+    message.extend_from_slice(from.as_slice());
+    message.extend_from_slice(call.to.as_slice());
+    message.extend_from_slice(&call.data);
+    for amount in amounts {
+        message.extend_from_slice(&amount.to_be_bytes::<32>());
+    }
+    message.extend_from_slice(relay_hub.as_slice());
+    message.extend_from_slice(relay.as_slice());
+
+    keccak256(message)
+}
+
+/// Packs a signature made with `eth_sign` for a Safe, which tells it apart from a signature of the
+/// raw hash by `v` being 31 or 32 instead of 27 or 28.
+fn pack_eth_sign(signature: &Signature) -> Bytes {
+    let mut bytes = signature.as_bytes();
+    bytes[64] += 4;
+    Bytes::from(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{address, eip191_hash_message};
+
+    use super::*;
+    use crate::POLYGON;
+
+    #[test]
+    fn multi_send_should_pack_every_call() {
+        let first = Call {
+            to: address!("0x1111111111111111111111111111111111111111"),
+            data: Bytes::from_static(&[0xaa, 0xbb]),
+        };
+        let second = Call {
+            to: address!("0x2222222222222222222222222222222222222222"),
+            data: Bytes::new(),
+        };
+
+        let call = multi_send(POLYGON, &[first, second]).unwrap();
+        assert_eq!(
+            call.to,
+            wallet_contract_config(POLYGON).unwrap().safe_multisend
+        );
+
+        let transactions = call
+            .decode::<IMultiSend::multiSendCall>()
+            .unwrap()
+            .transactions;
+        assert_eq!(transactions.len(), 2 * (1 + 20 + 32 + 32) + 2);
+        assert_eq!(transactions[0], 0);
+        assert_eq!(&transactions[1..21], [0x11; 20]);
+        assert_eq!(transactions[84], 2, "length of the first call's data");
+        assert_eq!(&transactions[85..87], [0xaa, 0xbb]);
+        assert_eq!(&transactions[88..108], [0x22; 20]);
+    }
+
+    #[tokio::test]
+    async fn packed_eth_sign_signature_should_recover_owner() {
+        let signer = alloy::signers::local::PrivateKeySigner::random();
+        let hash = B256::repeat_byte(0x42);
+
+        let signature = signer.sign_message(hash.as_slice()).await.unwrap();
+        let packed = pack_eth_sign(&signature);
+
+        assert!(matches!(packed[64], 31 | 32));
+        assert_eq!(
+            signature
+                .recover_address_from_prehash(&eip191_hash_message(hash))
+                .unwrap(),
+            signer.address()
+        );
+    }
+}
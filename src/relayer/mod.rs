@@ -0,0 +1,29 @@
+//! Polymarket builder relayer client and types.
+//!
+//! The relayer submits the transactions of users' Safe and proxy wallets on chain and pays their
+//! gas, so that users attributed to a builder can approve, split, merge, redeem and transfer
+//! without holding MATIC. Transactions are signed by the wallet's owner and attributed with the
+//! builder headers of an [`auth::builder::Config`](crate::auth::builder::Config).
+//!
+//! # Overview
+//!
+//! | Endpoint | Method | Description |
+//! |----------|--------|-------------|
+//! | `/submit` | POST | Submit a signed wallet transaction |
+//! | `/transaction` | GET | Get the state of a submitted transaction |
+//! | `/nonce` | GET | Get the nonce of a wallet's next transaction |
+//! | `/relay-payload` | GET | Get the relay and nonce for a proxy wallet transaction |
+//! | `/deployed` | GET | Check whether a Safe has been deployed |
+//!
+//! [`Client::execute_safe`] and [`Client::execute_proxy`] sign and submit [`Call`](crate::ctf::Call)s
+//! for a wallet, [`Client::deploy_safe`] deploys a Safe that does not exist yet, and
+//! [`Client::wait`] polls a submitted transaction until it is mined or fails.
+//!
+//! # API Base URL
+//!
+//! The relayer is hosted at `https://relayer-v2.polymarket.com`.
+
+pub mod client;
+pub mod types;
+
+pub use client::Client;
@@ -0,0 +1,88 @@
+use alloy::primitives::{Bytes, U256};
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, serde_as};
+
+use crate::types::Address;
+
+/// The kind of wallet transaction relayed.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionType {
+    /// `execTransaction` on the signer's Safe
+    #[serde(rename = "SAFE")]
+    Safe,
+    /// Calls forwarded to the signer's proxy wallet by the proxy wallet factory
+    #[serde(rename = "PROXY")]
+    Proxy,
+    /// Deployment of the signer's Safe
+    #[serde(rename = "SAFE-CREATE")]
+    SafeCreate,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A signed wallet transaction for the relayer to submit on chain.
+///
+/// [`Client`](crate::relayer::Client) builds these for Safe and proxy wallets, see
+/// [`Client::execute_safe`](crate::relayer::Client::execute_safe),
+/// [`Client::execute_proxy`](crate::relayer::Client::execute_proxy) and
+/// [`Client::deploy_safe`](crate::relayer::Client::deploy_safe).
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, PartialEq, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitRequest {
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    /// The signer owning the wallet
+    pub from: Address,
+    pub to: Address,
+    /// The Safe or proxy wallet executing the transaction
+    pub proxy_wallet: Address,
+    pub data: Bytes,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<U256>,
+    pub signature: Bytes,
+    pub signature_params: SignatureParams,
+    #[builder(into)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+}
+
+/// The parameters that were signed alongside [`SubmitRequest::data`].
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(untagged, rename_all_fields = "camelCase")]
+pub enum SignatureParams {
+    Safe {
+        #[serde_as(as = "DisplayFromStr")]
+        gas_price: U256,
+        #[serde_as(as = "DisplayFromStr")]
+        operation: u8,
+        #[serde_as(as = "DisplayFromStr")]
+        safe_txn_gas: U256,
+        #[serde_as(as = "DisplayFromStr")]
+        base_gas: U256,
+        gas_token: Address,
+        refund_receiver: Address,
+    },
+    Proxy {
+        #[serde_as(as = "DisplayFromStr")]
+        gas_price: U256,
+        #[serde_as(as = "DisplayFromStr")]
+        gas_limit: U256,
+        #[serde_as(as = "DisplayFromStr")]
+        relayer_fee: U256,
+        relay_hub: Address,
+        relay: Address,
+    },
+    SafeCreate {
+        payment_token: Address,
+        #[serde_as(as = "DisplayFromStr")]
+        payment: U256,
+        payment_receiver: Address,
+    },
+}
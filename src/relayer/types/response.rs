@@ -0,0 +1,95 @@
+use alloy::primitives::{B256, U256};
+use serde::Deserialize;
+use serde_with::{DisplayFromStr, NoneAsEmptyString, serde_as};
+
+use super::TransactionType;
+use crate::types::Address;
+
+/// The nonce of a wallet's next relayed transaction.
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct NonceResponse {
+    #[serde_as(as = "DisplayFromStr")]
+    pub nonce: U256,
+}
+
+/// The relay that will forward a proxy wallet transaction, and the signer's nonce with it.
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct RelayPayload {
+    /// Address of the relay
+    pub address: Address,
+    #[serde_as(as = "DisplayFromStr")]
+    pub nonce: U256,
+}
+
+/// Where a relayed transaction is in its lifecycle.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub enum TransactionState {
+    #[serde(rename = "STATE_NEW")]
+    New,
+    #[serde(rename = "STATE_EXECUTED")]
+    Executed,
+    #[serde(rename = "STATE_MINED")]
+    Mined,
+    #[serde(rename = "STATE_CONFIRMED")]
+    Confirmed,
+    #[serde(rename = "STATE_INVALID")]
+    Invalid,
+    #[serde(rename = "STATE_FAILED")]
+    Failed,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TransactionState {
+    /// Whether the transaction has been included in a block.
+    #[must_use]
+    pub fn is_mined(self) -> bool {
+        matches!(self, Self::Mined | Self::Confirmed)
+    }
+
+    /// Whether the transaction will never be included in a block.
+    #[must_use]
+    pub fn is_failed(self) -> bool {
+        matches!(self, Self::Invalid | Self::Failed)
+    }
+}
+
+/// The relayer's acknowledgement of a [`SubmitRequest`](super::SubmitRequest).
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitResponse {
+    #[serde(rename = "transactionID")]
+    pub transaction_id: String,
+    pub state: TransactionState,
+    /// Hash of the on-chain transaction, once the relayer has sent it
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub transaction_hash: Option<B256>,
+}
+
+/// A transaction known to the relayer.
+#[serde_as]
+#[non_exhaustive]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerTransaction {
+    #[serde(rename = "transactionID")]
+    pub transaction_id: String,
+    pub state: TransactionState,
+    #[serde(rename = "type")]
+    pub transaction_type: TransactionType,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub transaction_hash: Option<B256>,
+    pub from: Address,
+    pub to: Address,
+    /// The Safe or proxy wallet executing the transaction
+    pub proxy_address: Option<Address>,
+}
@@ -0,0 +1,269 @@
+//! Retry policy for REST requests.
+//!
+//! A [`RetryPolicy`] retries requests that failed with `429 Too Many Requests`, a `5xx` status
+//! or a connection error, using exponential backoff with jitter. When the server sends a
+//! `Retry-After` header, it is honored instead of the computed backoff.
+//!
+//! Only [idempotent](Idempotency::Idempotent) requests are retried by default: requests sent
+//! with `GET`, `HEAD`, `OPTIONS`, `PUT` or `DELETE`, and read endpoints sent as `POST`, such as
+//! the batch market data calls (`/books`, `/prices`, ...). Requests such as `post_order` are
+//! never retried unless [`RetryPolicy::retry_non_idempotent`] is set, since a request that timed
+//! out or failed with a `5xx` may still have been processed by the server.
+//!
+//! Authenticated requests are signed again before every retry, so that a retry waiting out a
+//! backoff is not rejected for a stale `POLY_TIMESTAMP`.
+
+use std::time::Duration;
+
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use rand::Rng as _;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+/// Configuration for retrying failed REST requests.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use polymarket_client_sdk::clob::Config;
+/// use polymarket_client_sdk::retry::RetryPolicy;
+///
+/// let policy = RetryPolicy::builder()
+///     .max_retries(5)
+///     .initial_backoff(Duration::from_millis(100))
+///     .build();
+///
+/// let config = Config::builder().retry_policy(policy).build();
+/// ```
+#[non_exhaustive]
+#[derive(Clone, Debug, Builder)]
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`RetryPolicy` reads better than `retry::Policy` on client configs"
+)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt
+    #[builder(default = 3)]
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled on every subsequent retry
+    #[builder(default = Duration::from_millis(250))]
+    pub initial_backoff: Duration,
+    /// Upper bound for the computed backoff. A `Retry-After` longer than this stops retrying.
+    #[builder(default = Duration::from_secs(30))]
+    pub max_backoff: Duration,
+    /// Whether to randomize each backoff between half and all of its computed value
+    #[builder(default = true)]
+    pub jitter: bool,
+    /// Whether to wait for the duration in a `Retry-After` response header, when present
+    #[builder(default = true)]
+    pub respect_retry_after: bool,
+    /// Whether to also retry non-idempotent requests, e.g. `POST /order`
+    #[builder(default)]
+    pub retry_non_idempotent: bool,
+}
+
+/// Whether sending a request more than once has the same effect as sending it once.
+///
+/// Endpoints that only read data are marked [`Idempotency::Idempotent`] where their requests are
+/// built; every other request is classified by its HTTP method with [`Idempotency::of`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Idempotency {
+    /// Safe to retry under any policy
+    Idempotent,
+    /// Only retried when [`RetryPolicy::retry_non_idempotent`] is set
+    NonIdempotent,
+}
+
+impl Idempotency {
+    /// Classifies a request that was not marked otherwise by its HTTP `method`.
+    #[must_use]
+    pub fn of(method: &Method) -> Self {
+        if method.is_idempotent() {
+            Self::Idempotent
+        } else {
+            Self::NonIdempotent
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether a request with `idempotency` may be retried under this policy.
+    #[must_use]
+    pub fn is_retryable(&self, idempotency: Idempotency) -> bool {
+        self.retry_non_idempotent || idempotency == Idempotency::Idempotent
+    }
+
+    /// Returns whether a response with `status` is worth retrying.
+    #[must_use]
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Returns how long to wait before retry number `attempt` (starting at `0`), or [`None`]
+    /// if the request should not be retried anymore.
+    pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Option<Duration> {
+        if attempt >= self.max_retries {
+            return None;
+        }
+
+        if self.respect_retry_after
+            && let Some(retry_after) = headers.and_then(retry_after)
+        {
+            return (retry_after <= self.max_backoff).then_some(retry_after);
+        }
+
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_backoff);
+
+        if !self.jitter {
+            return Some(backoff);
+        }
+
+        let half = backoff / 2;
+        let max_jitter =
+            u64::try_from(backoff.saturating_sub(half).as_millis()).unwrap_or(u64::MAX);
+        let jitter = Duration::from_millis(rand::rng().random_range(0..=max_jitter));
+
+        Some(half + jitter)
+    }
+}
+
+/// Parses a `Retry-After` header given either as delay seconds or as an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1))
+            .jitter(false)
+            .build()
+    }
+
+    #[test]
+    fn backoff_doubles_until_max() {
+        let policy = RetryPolicy::builder()
+            .max_retries(6)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_secs(1))
+            .jitter(false)
+            .build();
+
+        let delays: Vec<_> = (0..7).map(|attempt| policy.delay(attempt, None)).collect();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_millis(100)),
+                Some(Duration::from_millis(200)),
+                Some(Duration::from_millis(400)),
+                Some(Duration::from_millis(800)),
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(1)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy::builder()
+            .initial_backoff(Duration::from_millis(100))
+            .build();
+
+        for _ in 0..100 {
+            let delay = policy.delay(1, None).unwrap();
+            assert!(
+                (Duration::from_millis(100)..=Duration::from_millis(200)).contains(&delay),
+                "jittered delay {delay:?} out of bounds"
+            );
+        }
+    }
+
+    #[test]
+    fn retry_after_seconds_is_honored() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("1"));
+
+        assert_eq!(
+            policy().delay(0, Some(&headers)),
+            Some(Duration::from_secs(1))
+        );
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("120"));
+        assert_eq!(
+            policy().delay(0, Some(&headers)),
+            None,
+            "Retry-After beyond max_backoff should stop retrying"
+        );
+    }
+
+    #[test]
+    fn retry_after_date_is_honored() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+
+        assert_eq!(
+            policy().delay(0, Some(&headers)),
+            Some(Duration::ZERO),
+            "Retry-After in the past should retry immediately"
+        );
+    }
+
+    #[test]
+    fn only_idempotent_requests_are_retried_by_default() {
+        assert_eq!(Idempotency::of(&Method::GET), Idempotency::Idempotent);
+        assert_eq!(Idempotency::of(&Method::DELETE), Idempotency::Idempotent);
+        assert_eq!(Idempotency::of(&Method::POST), Idempotency::NonIdempotent);
+
+        let policy = RetryPolicy::default();
+        assert!(policy.is_retryable(Idempotency::Idempotent));
+        assert!(!policy.is_retryable(Idempotency::NonIdempotent));
+
+        let policy = RetryPolicy::builder().retry_non_idempotent(true).build();
+        assert!(policy.is_retryable(Idempotency::NonIdempotent));
+    }
+
+    #[test]
+    fn retryable_statuses() {
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(RetryPolicy::is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::BAD_REQUEST));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::NOT_FOUND));
+    }
+}
@@ -8,7 +8,7 @@ use super::types::request::Subscription;
 use super::types::response::{ChainlinkPrice, Comment, CommentType, CryptoPrice, RtdsMessage};
 use crate::Result;
 use crate::auth::state::{Authenticated, State, Unauthenticated};
-use crate::auth::{Credentials, Normal};
+use crate::auth::{Credentials, Normal, SwapCredentials};
 use crate::error::Error;
 use crate::types::Address;
 use crate::ws::ConnectionManager;
@@ -101,11 +101,7 @@ impl Client<Unauthenticated> {
 
         Ok(Client {
             inner: Arc::new(ClientInner {
-                state: Authenticated {
-                    address,
-                    credentials,
-                    kind: Normal,
-                },
+                state: Authenticated::new(address, credentials, Normal),
                 config: inner.config,
                 endpoint: inner.endpoint,
                 connection: inner.connection,
@@ -208,8 +204,8 @@ impl Client<Authenticated<Normal>> {
         &self,
         comment_type: Option<CommentType>,
     ) -> Result<impl Stream<Item = Result<Comment>>> {
-        let subscription = Subscription::comments(comment_type)
-            .with_clob_auth(self.inner.state.credentials.clone());
+        let subscription =
+            Subscription::comments(comment_type).with_clob_auth(self.inner.state.credentials());
         let stream = self.inner.subscriptions.subscribe(subscription)?;
 
         Ok(stream.filter_map(|msg_result| async move {
@@ -237,3 +233,14 @@ impl Client<Authenticated<Normal>> {
         })
     }
 }
+
+impl SwapCredentials for Client<Authenticated<Normal>> {
+    /// Replaces the credentials of this client, and re-authenticates its live comment
+    /// subscriptions with them.
+    fn swap_credentials(&self, credentials: &Credentials) -> Result<()> {
+        self.inner.subscriptions.replace_auth(credentials)?;
+        self.inner.state.replace_credentials(credentials.clone());
+
+        Ok(())
+    }
+}
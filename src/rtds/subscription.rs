@@ -255,6 +255,55 @@ impl SubscriptionManager {
         })
     }
 
+    /// Replace the CLOB credentials of authenticated subscriptions.
+    ///
+    /// Every authenticated subscription is sent again with the new credentials, so that it no
+    /// longer depends on the previous API key.
+    pub fn replace_auth(&self, auth: &Credentials) -> Result<()> {
+        let subscriptions: Vec<Subscription> = self
+            .active_subs
+            .iter()
+            .filter(|entry| entry.value().clob_auth.is_some())
+            .map(|entry| {
+                let info = entry.value();
+                Subscription {
+                    topic: info.topic_type.topic.clone(),
+                    msg_type: info.topic_type.msg_type.clone(),
+                    filters: info.filters.clone(),
+                    clob_auth: Some(auth.clone()),
+                }
+            })
+            .collect();
+
+        if !subscriptions.is_empty() {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                count = subscriptions.len(),
+                "Re-authenticating RTDS subscriptions"
+            );
+
+            let request = SubscriptionRequest::subscribe(subscriptions);
+            self.connection.send(&request)?;
+        }
+
+        for mut entry in self.active_subs.iter_mut() {
+            if entry.clob_auth.is_some() {
+                entry.clob_auth = Some(auth.clone());
+            }
+        }
+
+        // We can recover from poisoned lock because Option<Credentials> has no inconsistent intermediate state.
+        let mut last_auth = self
+            .last_auth
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        if last_auth.is_some() {
+            *last_auth = Some(auth.clone());
+        }
+
+        Ok(())
+    }
+
     /// Get information about all active subscriptions.
     #[must_use]
     pub fn active_subscriptions(&self) -> Vec<SubscriptionInfo> {
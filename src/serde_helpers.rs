@@ -72,6 +72,26 @@ impl serde_with::SerializeAs<String> for StringFromAny {
     }
 }
 
+/// Deserializes a string into `Some` of its parsed value, or `None` when it is missing, null or
+/// blank, as some endpoints send empty strings instead of omitting a value.
+///
+/// Use with `#[serde(default, deserialize_with = "crate::serde_helpers::empty_string_as_none")]`.
+pub(crate) fn empty_string_as_none<'de, D, T>(
+    deserializer: D,
+) -> std::result::Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let s = <Option<String> as serde::Deserialize>::deserialize(deserializer)?;
+
+    match s.as_deref().map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(s) => s.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
 /// Deserialize JSON with unknown field warnings.
 ///
 /// This function deserializes JSON to a target type while detecting and logging
@@ -0,0 +1,66 @@
+//! Pluggable HTTP transport for the REST clients.
+//!
+//! Every REST client sends its requests through an [`HttpTransport`], which defaults to
+//! [`reqwest::Client`]. Supplying a different transport through `with_transport` makes it
+//! possible to run a client against an in-memory fake, to instrument every request or to
+//! record and replay traffic, without a real server.
+//!
+//! Requests are still built with the client's own [`reqwest::Client`], so transports receive
+//! fully formed [`Request`]s (including any authentication headers), but not the client's
+//! default headers, which [`reqwest::Client`] only adds when it sends a request itself.
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use reqwest::{Request, Response};
+
+use crate::Result;
+
+/// Sends HTTP requests on behalf of a client.
+///
+/// Implementations must return the response as is, regardless of its status code. Status
+/// handling, retries and deserialization are performed by the clients.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use polymarket_client_sdk::Result;
+/// use polymarket_client_sdk::transport::HttpTransport;
+/// use reqwest::{Request, Response};
+///
+/// /// Answers every request with an empty JSON array.
+/// #[derive(Debug)]
+/// struct Fake;
+///
+/// #[async_trait]
+/// impl HttpTransport for Fake {
+///     async fn execute(&self, _request: Request) -> Result<Response> {
+///         Ok(Response::from(http::Response::new("[]")))
+///     }
+/// }
+/// ```
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "`Transport` alone is ambiguous next to the WebSocket connections"
+)]
+#[async_trait]
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+    /// Sends `request` and returns its response.
+    async fn execute(&self, request: Request) -> Result<Response>;
+}
+
+#[async_trait]
+impl HttpTransport for reqwest::Client {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        Ok(reqwest::Client::execute(self, request).await?)
+    }
+}
+
+#[async_trait]
+impl<T: HttpTransport + ?Sized> HttpTransport for Arc<T> {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        (**self).execute(request).await
+    }
+}
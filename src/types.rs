@@ -1,7 +1,17 @@
-//! Re-exported types from external crates for convenience.
+//! Re-exported types from external crates for convenience, and the typed IDs shared across APIs.
 //!
 //! These types are commonly used in this SDK and are re-exported here
 //! so users don't need to add these dependencies to their `Cargo.toml`.
+//! [`TokenId`] and [`ConditionId`] keep outcome tokens and markets from being mixed up.
+
+use std::fmt;
+use std::str::FromStr;
+
+use alloy::primitives::{B256, U256};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::Error;
 
 /// Ethereum address type and the [`address!`] macro for compile-time address literals.
 /// [`ChainId`] is a type alias for `u64` representing EVM chain IDs.
@@ -19,3 +29,210 @@ pub use rust_decimal::Decimal;
 /// let price = dec!(0.55);
 /// ```
 pub use rust_decimal_macros::dec;
+
+/// ID of a conditional token, i.e. one outcome of a market.
+///
+/// Token IDs are 256-bit ERC-1155 position IDs. They are sent and displayed as decimal strings,
+/// and parsed from decimal or `0x`-prefixed hex strings. Some endpoints return them as JSON
+/// numbers, which are accepted as well.
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::TokenId;
+///
+/// let token_id: TokenId = "1234".parse().unwrap();
+/// assert_eq!(token_id.to_string(), "1234");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TokenId(U256);
+
+impl TokenId {
+    #[must_use]
+    pub const fn new(id: U256) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn as_u256(&self) -> U256 {
+        self.0
+    }
+}
+
+impl From<U256> for TokenId {
+    fn from(id: U256) -> Self {
+        Self(id)
+    }
+}
+
+impl From<u64> for TokenId {
+    fn from(id: u64) -> Self {
+        Self(U256::from(id))
+    }
+}
+
+impl From<TokenId> for U256 {
+    fn from(id: TokenId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for TokenId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            Some(hex) => U256::from_str_radix(hex, 16),
+            None => U256::from_str_radix(s, 10),
+        };
+
+        parsed
+            .map(Self)
+            .map_err(|e| Error::validation(format!("Invalid token ID {s:?}: {e}")))
+    }
+}
+
+impl fmt::Display for TokenId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Serialize for TokenId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TokenId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TokenIdVisitor;
+
+        impl Visitor<'_> for TokenIdVisitor {
+            type Value = TokenId;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("a token ID as a decimal or hex string, or an integer")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                Ok(TokenId::from(v))
+            }
+        }
+
+        deserializer.deserialize_any(TokenIdVisitor)
+    }
+}
+
+/// ID of a condition on the Conditional Token Framework, which identifies a market.
+///
+/// Condition IDs are 32-byte hashes, sent and displayed as `0x`-prefixed hex strings. The CLOB,
+/// its WebSocket channels and the data API call them `market`; see [`MarketId`].
+///
+/// # Example
+/// ```
+/// use polymarket_client_sdk::types::ConditionId;
+///
+/// let condition_id: ConditionId =
+///     "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1"
+///         .parse()
+///         .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConditionId(B256);
+
+/// The CLOB identifies markets by their [`ConditionId`].
+pub type MarketId = ConditionId;
+
+impl ConditionId {
+    #[must_use]
+    pub const fn new(id: B256) -> Self {
+        Self(id)
+    }
+
+    #[must_use]
+    pub const fn as_b256(&self) -> B256 {
+        self.0
+    }
+}
+
+impl From<B256> for ConditionId {
+    fn from(id: B256) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ConditionId> for B256 {
+    fn from(id: ConditionId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for ConditionId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        B256::from_str(s)
+            .map(Self)
+            .map_err(|e| Error::validation(format!("Invalid condition ID {s:?}: {e}")))
+    }
+}
+
+impl fmt::Display for ConditionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl Serialize for ConditionId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ConditionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn token_id_should_accept_every_wire_shape() {
+        let expected = TokenId::from(255);
+
+        for value in [json!("255"), json!("0xff"), json!(255)] {
+            let token_id: TokenId = serde_json::from_value(value.clone()).unwrap();
+            assert_eq!(token_id, expected, "{value} should parse as 255");
+        }
+        assert_eq!(serde_json::to_value(expected).unwrap(), json!("255"));
+    }
+
+    #[test]
+    fn token_id_should_round_trip_full_width_ids() {
+        let id = "71321045679252212594626385532706912750332728571942532289631379312455583992563";
+        let token_id: TokenId = id.parse().unwrap();
+
+        assert_eq!(token_id.to_string(), id);
+        "not a token".parse::<TokenId>().unwrap_err();
+    }
+
+    #[test]
+    fn condition_id_should_round_trip_as_hex() {
+        let id = "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1";
+        let condition_id: ConditionId = serde_json::from_value(json!(id)).unwrap();
+
+        assert_eq!(condition_id.to_string(), id);
+        assert_eq!(serde_json::to_value(condition_id).unwrap(), json!(id));
+        "0x1234".parse::<ConditionId>().unwrap_err();
+    }
+}
@@ -0,0 +1,377 @@
+//! Transactions executed by the Safe and proxy wallets that hold a trader's funds.
+//!
+//! Orders signed with [`SignatureType::GnosisSafe`](crate::clob::types::SignatureType::GnosisSafe)
+//! or [`SignatureType::Proxy`](crate::clob::types::SignatureType::Proxy) trade out of a wallet
+//! contract at [`derive_safe_wallet`] or [`derive_proxy_wallet`], so approvals, redemptions and
+//! transfers of those funds have to be executed by the wallet as well:
+//!
+//! - a [`SafeWallet`] executes a [`SafeTransaction`] signed by its owner through
+//!   `execTransaction`, which anyone can submit
+//! - a [`ProxyWallet`] executes [`Call`]s forwarded by the proxy wallet factory, which its owner
+//!   has to submit
+//!
+//! Either way the result is a [`Call`], which can be sent with an
+//! [`Executor`](crate::ctf::Executor).
+#![expect(
+    clippy::module_name_repetitions,
+    reason = "Wallet types include the module name to say which kind of wallet they are"
+)]
+#![allow(clippy::exhaustive_enums, reason = "Generated by sol! macro")]
+#![allow(clippy::exhaustive_structs, reason = "Generated by sol! macro")]
+
+use alloy::dyn_abi::Eip712Domain;
+use alloy::network::TransactionBuilder as _;
+use alloy::primitives::{B256, Bytes, U256};
+use alloy::providers::Provider;
+use alloy::rpc::types::TransactionRequest;
+use alloy::signers::Signer;
+use alloy::sol;
+use alloy::sol_types::{SolCall as _, SolStruct as _};
+
+use crate::ctf::Call;
+use crate::error::{Error, Kind};
+use crate::types::{Address, ChainId, Signature, TokenId};
+use crate::{Result, derive_proxy_wallet, derive_safe_wallet, wallet_contract_config};
+
+sol! {
+    /// Transaction executing a call from a Safe, as hashed and signed for `execTransaction`.
+    #[derive(Debug, Default, PartialEq, Eq)]
+    struct SafeTx {
+        address to;
+        uint256 value;
+        bytes data;
+        uint8 operation;
+        uint256 safeTxGas;
+        uint256 baseGas;
+        uint256 gasPrice;
+        address gasToken;
+        address refundReceiver;
+        uint256 nonce;
+    }
+
+    /// Functions of a Gnosis Safe.
+    interface ISafe {
+        function execTransaction(
+            address to,
+            uint256 value,
+            bytes data,
+            uint8 operation,
+            uint256 safeTxGas,
+            uint256 baseGas,
+            uint256 gasPrice,
+            address gasToken,
+            address refundReceiver,
+            bytes signatures
+        ) external payable returns (bool success);
+
+        function nonce() external view returns (uint256);
+    }
+
+    /// Polymarket's proxy wallet factory, which forwards calls to the sender's proxy wallet and
+    /// deploys it first if needed.
+    interface IProxyWalletFactory {
+        struct ProxyCall {
+            uint8 typeCode;
+            address to;
+            uint256 value;
+            bytes data;
+        }
+
+        function proxy(ProxyCall[] calls) external payable returns (bytes[] returnValues);
+    }
+
+    /// Transfer functions of the ERC-20 collateral.
+    interface IERC20Transfer {
+        function transfer(address to, uint256 value) external returns (bool);
+    }
+
+    /// Transfer functions of the ERC-1155 conditional tokens.
+    interface IERC1155Transfer {
+        function safeTransferFrom(address from, address to, uint256 id, uint256 value, bytes data) external;
+    }
+}
+
+/// How a Safe executes a transaction.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Operation {
+    #[default]
+    Call = 0,
+    DelegateCall = 1,
+}
+
+/// `typeCode` of a regular call to the proxy wallet factory.
+const PROXY_CALL: u8 = 1;
+
+/// A Gnosis Safe wallet.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SafeWallet {
+    pub address: Address,
+    pub chain_id: ChainId,
+}
+
+impl SafeWallet {
+    #[must_use]
+    pub fn new(address: Address, chain_id: ChainId) -> Self {
+        Self { address, chain_id }
+    }
+
+    /// The Safe Polymarket deploys for `owner` on `chain_id`.
+    pub fn for_owner(owner: Address, chain_id: ChainId) -> Result<Self> {
+        let address = derive_safe_wallet(owner, chain_id).ok_or(Error::validation(format!(
+            "Unable to derive a Safe wallet for {owner} on chain {chain_id}"
+        )))?;
+
+        Ok(Self::new(address, chain_id))
+    }
+
+    /// A transaction executing `call` as the Safe's `nonce`-th transaction. Gas is paid by
+    /// whoever submits it rather than refunded by the Safe.
+    #[must_use]
+    pub fn transaction(&self, call: &Call, nonce: U256) -> SafeTransaction {
+        SafeTransaction {
+            safe: *self,
+            tx: SafeTx {
+                to: call.to,
+                data: call.data.clone(),
+                operation: Operation::Call as u8,
+                nonce,
+                ..SafeTx::default()
+            },
+        }
+    }
+
+    /// Transactions executing `calls` in order, starting at the Safe's `nonce`-th transaction.
+    #[must_use]
+    pub fn transactions(&self, calls: &[Call], nonce: U256) -> Vec<SafeTransaction> {
+        calls
+            .iter()
+            .zip(0_u64..)
+            .map(|(call, offset)| self.transaction(call, nonce + U256::from(offset)))
+            .collect()
+    }
+
+    /// The EIP-712 domain the Safe's transactions are signed against.
+    #[must_use]
+    pub fn domain(&self) -> Eip712Domain {
+        Eip712Domain {
+            chain_id: Some(U256::from(self.chain_id)),
+            verifying_contract: Some(self.address),
+            ..Eip712Domain::default()
+        }
+    }
+
+    /// The nonce of the Safe's next transaction.
+    pub async fn nonce<P: Provider>(&self, provider: &P) -> Result<U256> {
+        let request = TransactionRequest::default()
+            .with_to(self.address)
+            .with_input(ISafe::nonceCall {}.abi_encode());
+        let output = provider.call(request).await?;
+
+        ISafe::nonceCall::abi_decode_returns(&output)
+            .map_err(|e| Error::with_source(Kind::Internal, e))
+    }
+}
+
+/// A transaction for a [`SafeWallet`] to execute.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SafeTransaction {
+    pub safe: SafeWallet,
+    pub tx: SafeTx,
+}
+
+impl SafeTransaction {
+    /// The `safeTxHash` the Safe's owners sign.
+    #[must_use]
+    pub fn hash(&self) -> B256 {
+        self.tx.eip712_signing_hash(&self.safe.domain())
+    }
+
+    /// Signs the transaction as `signer`, who has to be an owner of the Safe. Polymarket's Safes
+    /// have a single owner, so one signature is enough to execute it.
+    pub async fn sign<S: Signer>(self, signer: &S) -> Result<SignedSafeTransaction> {
+        let signature = signer.sign_hash(&self.hash()).await?;
+
+        Ok(SignedSafeTransaction {
+            transaction: self,
+            signatures: vec![(signer.address(), signature)],
+        })
+    }
+}
+
+/// A [`SafeTransaction`] signed by its owners.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedSafeTransaction {
+    pub transaction: SafeTransaction,
+    /// Signatures of [`SafeTransaction::hash`] and who made them
+    pub signatures: Vec<(Address, Signature)>,
+}
+
+impl SignedSafeTransaction {
+    /// The signatures packed the way `execTransaction` expects, i.e. `r`, `s` and `v` of each
+    /// signature sorted by owner.
+    #[must_use]
+    pub fn packed_signatures(&self) -> Bytes {
+        let mut signatures = self.signatures.clone();
+        signatures.sort_by_key(|(owner, _)| *owner);
+
+        signatures
+            .iter()
+            .flat_map(|(_, signature)| signature.as_bytes())
+            .collect()
+    }
+
+    /// The `execTransaction` call to the Safe, which anyone can submit.
+    #[must_use]
+    pub fn call(&self) -> Call {
+        let SafeTransaction { safe, tx } = &self.transaction;
+
+        Call::new(
+            safe.address,
+            &ISafe::execTransactionCall {
+                to: tx.to,
+                value: tx.value,
+                data: tx.data.clone(),
+                operation: tx.operation,
+                safeTxGas: tx.safeTxGas,
+                baseGas: tx.baseGas,
+                gasPrice: tx.gasPrice,
+                gasToken: tx.gasToken,
+                refundReceiver: tx.refundReceiver,
+                signatures: self.packed_signatures(),
+            },
+        )
+    }
+}
+
+/// A Polymarket proxy wallet, controlled by the owner it was derived from.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProxyWallet {
+    pub address: Address,
+    pub owner: Address,
+    pub factory: Address,
+}
+
+impl ProxyWallet {
+    /// The proxy wallet Polymarket deploys for `owner` on `chain_id`.
+    pub fn for_owner(owner: Address, chain_id: ChainId) -> Result<Self> {
+        let error = || {
+            Error::validation(format!(
+                "Proxy wallets are not supported on chain {chain_id}"
+            ))
+        };
+        let factory = wallet_contract_config(chain_id)
+            .and_then(|config| config.proxy_factory)
+            .ok_or_else(error)?;
+        let address = derive_proxy_wallet(owner, chain_id).ok_or_else(error)?;
+
+        Ok(Self {
+            address,
+            owner,
+            factory,
+        })
+    }
+
+    /// The call to the proxy wallet factory that makes the wallet execute `calls` in order. Only
+    /// the wallet's owner can submit it.
+    #[must_use]
+    pub fn call(&self, calls: &[Call]) -> Call {
+        let calls = calls
+            .iter()
+            .map(|call| IProxyWalletFactory::ProxyCall {
+                typeCode: PROXY_CALL,
+                to: call.to,
+                value: U256::ZERO,
+                data: call.data.clone(),
+            })
+            .collect();
+
+        Call::new(self.factory, &IProxyWalletFactory::proxyCall { calls })
+    }
+}
+
+/// Transfers `amount` of the ERC-20 `token`, e.g. the collateral, from the caller to `to`.
+#[must_use]
+pub fn transfer(token: Address, to: Address, amount: U256) -> Call {
+    Call::new(token, &IERC20Transfer::transferCall { to, value: amount })
+}
+
+/// Transfers `amount` of the outcome token `token_id` from `from` to `to`.
+#[must_use]
+pub fn transfer_position(
+    conditional_tokens: Address,
+    from: Address,
+    to: Address,
+    token_id: TokenId,
+    amount: U256,
+) -> Call {
+    Call::new(
+        conditional_tokens,
+        &IERC1155Transfer::safeTransferFromCall {
+            from,
+            to,
+            id: token_id.as_u256(),
+            value: amount,
+            data: Bytes::new(),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::{b256, keccak256};
+    use alloy::sol_types::SolValue as _;
+
+    use super::*;
+    use crate::{AMOY, POLYGON};
+
+    #[test]
+    fn safe_tx_should_use_safe_type_hashes() {
+        // SAFE_TX_TYPEHASH and DOMAIN_SEPARATOR_TYPEHASH of the Safe contracts
+        assert_eq!(
+            SafeTx::default().eip712_type_hash(),
+            b256!("0xbb8310d486368db6bd6f849402fdd73ad53d316b5a4b2644ad6efe0f941286d8")
+        );
+
+        let safe = SafeWallet::new(Address::repeat_byte(0x11), POLYGON);
+        let separator = keccak256(
+            (
+                b256!("0x47e79534a245952e8b16893a336b85a3d9ea9fa8c573f3d803afb92a79469218"),
+                U256::from(POLYGON),
+                safe.address,
+            )
+                .abi_encode(),
+        );
+        assert_eq!(safe.domain().separator(), separator);
+    }
+
+    #[test]
+    fn transactions_should_use_consecutive_nonces() {
+        let safe = SafeWallet::for_owner(Address::repeat_byte(0x22), POLYGON).unwrap();
+        let call = transfer(Address::repeat_byte(0x33), Address::ZERO, U256::from(1));
+
+        let nonces: Vec<_> = safe
+            .transactions(&[call.clone(), call], U256::from(7))
+            .into_iter()
+            .map(|transaction| transaction.tx.nonce)
+            .collect();
+
+        assert_eq!(nonces, [U256::from(7), U256::from(8)]);
+    }
+
+    #[test]
+    fn proxy_wallet_should_require_a_factory() {
+        let owner = Address::repeat_byte(0x22);
+
+        let proxy = ProxyWallet::for_owner(owner, POLYGON).unwrap();
+        assert_eq!(Some(proxy.address), derive_proxy_wallet(owner, POLYGON));
+
+        let err = ProxyWallet::for_owner(owner, AMOY).unwrap_err();
+        assert!(err.to_string().contains("not supported"), "{err}");
+    }
+}
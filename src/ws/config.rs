@@ -3,10 +3,15 @@
     reason = "Configuration types intentionally mirror the module name for clarity"
 )]
 
+#[cfg(feature = "cassette")]
+use std::sync::Arc;
 use std::time::Duration;
 
 use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
 
+#[cfg(feature = "cassette")]
+use crate::cassette::Recorder;
+
 const DEFAULT_HEARTBEAT_INTERVAL_DURATION: Duration = Duration::from_secs(5);
 const DEFAULT_HEARTBEAT_TIMEOUT_DURATION: Duration = Duration::from_secs(15);
 const DEFAULT_INITIAL_BACKOFF_DURATION: Duration = Duration::from_secs(1);
@@ -23,6 +28,9 @@ pub struct Config {
     pub heartbeat_timeout: Duration,
     /// Reconnection strategy configuration
     pub reconnect: ReconnectConfig,
+    /// Records every text frame sent or received (except heartbeats) to a cassette
+    #[cfg(feature = "cassette")]
+    pub recorder: Option<Arc<Recorder>>,
 }
 
 impl Default for Config {
@@ -31,6 +39,8 @@ impl Default for Config {
             heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL_DURATION,
             heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT_DURATION,
             reconnect: ReconnectConfig::default(),
+            #[cfg(feature = "cassette")]
+            recorder: None,
         }
     }
 }
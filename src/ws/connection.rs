@@ -19,6 +19,8 @@ use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async, tungsten
 use super::config::Config;
 use super::traits::MessageParser;
 use crate::auth::Credentials;
+#[cfg(feature = "cassette")]
+use crate::cassette::Direction;
 use crate::clob::ws::WsError;
 use crate::error::Kind;
 use crate::ws::WithCredentials;
@@ -174,6 +176,7 @@ where
 
                     // Handle connection
                     if let Err(e) = Self::handle_connection(
+                        &endpoint,
                         ws_stream,
                         &mut sender_rx,
                         &broadcast_tx,
@@ -218,6 +221,7 @@ where
 
     /// Handle an active WebSocket connection.
     async fn handle_connection(
+        endpoint: &str,
         ws_stream: WsStream,
         sender_rx: &mut mpsc::UnboundedReceiver<String>,
         broadcast_tx: &broadcast::Sender<M>,
@@ -231,6 +235,11 @@ where
         let (pong_tx, pong_rx) = watch::channel(Instant::now());
         let (ping_tx, mut ping_rx) = mpsc::unbounded_channel();
 
+        #[cfg(feature = "cassette")]
+        let recorder = config.recorder.clone();
+        #[cfg(not(feature = "cassette"))]
+        let _: &str = endpoint;
+
         let heartbeat_handle = tokio::spawn(async move {
             Self::heartbeat_loop(ping_tx, state_rx, &config, pong_rx).await;
         });
@@ -247,6 +256,11 @@ where
                             #[cfg(feature = "tracing")]
                             tracing::trace!(%text, "Received WebSocket text message");
 
+                            #[cfg(feature = "cassette")]
+                            if let Some(recorder) = &recorder {
+                                recorder.record_frame(endpoint, Direction::Received, &text);
+                            }
+
                             // Parse messages using the provided parser
                             match parser.parse(text.as_bytes()) {
                                 Ok(messages) => {
@@ -286,6 +300,11 @@ where
 
                 // Handle outgoing messages from subscriptions
                 Some(text) = sender_rx.recv() => {
+                    #[cfg(feature = "cassette")]
+                    if let Some(recorder) = &recorder {
+                        recorder.record_frame(endpoint, Direction::Sent, &text);
+                    }
+
                     if write.send(Message::Text(text.into())).await.is_err() {
                         break;
                     }
@@ -0,0 +1,169 @@
+#![cfg(feature = "approvals")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+use alloy::primitives::{B256, U256, hex};
+use alloy::providers::{Provider, ProviderBuilder};
+use alloy::sol_types::{SolCall as _, SolValue as _};
+use httpmock::Method::POST;
+use httpmock::{Mock, MockServer};
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::approvals::{
+    Approval, ApprovalManager, Asset, IERC20, IERC1155, Spender,
+};
+use polymarket_client_sdk::types::{Address, address};
+use reqwest::StatusCode;
+use serde_json::json;
+
+const OWNER: Address = address!("0x2222222222222222222222222222222222222222");
+
+fn provider(server: &MockServer) -> impl Provider + use<> {
+    ProviderBuilder::new()
+        .disable_recommended_fillers()
+        .connect_http(server.base_url().parse().unwrap())
+}
+
+/// Answers the `eth_call` checking `approval` for [`OWNER`] with `allowance` for the collateral
+/// or `approved` for the conditional tokens.
+fn mock_status<'server>(
+    server: &'server MockServer,
+    approval: &Approval,
+    allowance: U256,
+    approved: bool,
+) -> Mock<'server> {
+    let (calldata, output) = match approval.asset {
+        Asset::Collateral => (
+            IERC20::allowanceCall {
+                owner: OWNER,
+                spender: approval.spender_address,
+            }
+            .abi_encode(),
+            allowance.abi_encode(),
+        ),
+        Asset::ConditionalTokens => (
+            IERC1155::isApprovedForAllCall {
+                account: OWNER,
+                operator: approval.spender_address,
+            }
+            .abi_encode(),
+            approved.abi_encode(),
+        ),
+        asset => unimplemented!("{asset:?}"),
+    };
+
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/")
+            .body_includes("\"method\":\"eth_call\"")
+            .body_includes(hex::encode_prefixed(calldata));
+        then.status(StatusCode::OK).json_body(json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "result": hex::encode_prefixed(output)
+        }));
+    })
+}
+
+#[tokio::test]
+async fn status_should_report_missing_approvals() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let manager = ApprovalManager::new(provider(&server), POLYGON)?;
+
+    let mocks: Vec<_> = manager
+        .approvals()
+        .iter()
+        .map(|approval| {
+            let granted = approval.spender == Spender::Exchange;
+            let allowance = if granted { U256::MAX } else { U256::ZERO };
+            mock_status(&server, approval, allowance, granted)
+        })
+        .collect();
+
+    let statuses = manager.status(OWNER).await?;
+    assert_eq!(statuses.len(), 6);
+    for status in &statuses {
+        assert_eq!(
+            status.granted,
+            status.approval.spender == Spender::Exchange,
+            "{status:?}"
+        );
+    }
+
+    let missing = manager.missing(OWNER).await?;
+    assert_eq!(missing.len(), 4);
+    assert!(
+        missing
+            .iter()
+            .all(|approval| approval.spender != Spender::Exchange)
+    );
+
+    for mock in mocks {
+        mock.assert_calls(2);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn allowance_below_minimum_should_be_missing() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let manager = ApprovalManager::new(provider(&server), POLYGON)?
+        .with_min_allowance(U256::from(1_000_000_000_000_u64));
+
+    for approval in manager.approvals() {
+        mock_status(&server, approval, U256::from(5_000_000), true);
+    }
+
+    let missing = manager.missing(OWNER).await?;
+    assert_eq!(missing.len(), 3);
+    assert!(
+        missing
+            .iter()
+            .all(|approval| approval.asset == Asset::Collateral)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn approve_missing_should_only_send_missing_approvals() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let manager = ApprovalManager::new(provider(&server), POLYGON)?;
+    let tx_hash = B256::repeat_byte(0xab);
+
+    let mut sends = Vec::new();
+    for approval in manager.approvals() {
+        let granted = approval.asset == Asset::ConditionalTokens;
+        mock_status(&server, approval, U256::ZERO, granted);
+
+        let call = approval.call();
+        sends.push((
+            granted,
+            server.mock(|when, then| {
+                when.method(POST)
+                    .path("/")
+                    .body_includes("\"method\":\"eth_sendTransaction\"")
+                    .body_includes(format!("\"from\":\"{OWNER}\""))
+                    .body_includes(call.to.to_string().to_lowercase())
+                    .body_includes(call.data.to_string());
+                then.status(StatusCode::OK).json_body(json!({
+                    "jsonrpc": "2.0",
+                    "id": 0,
+                    "result": tx_hash
+                }));
+            }),
+        ));
+    }
+
+    let pending = manager.approve_missing(OWNER).await?;
+    assert_eq!(pending.len(), 3);
+    assert!(pending.iter().all(|tx| *tx.tx_hash() == tx_hash));
+
+    for (granted, mock) in sends {
+        mock.assert_calls(usize::from(!granted));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,279 @@
+#![cfg(feature = "cassette")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::path::PathBuf;
+use std::str::FromStr as _;
+use std::sync::Arc;
+
+use alloy::signers::Signer as _;
+use alloy::signers::local::LocalSigner;
+use httpmock::Method::{GET, POST};
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::cassette::{Cassette, Interaction, Recorder, RecordingTransport};
+use polymarket_client_sdk::clob::types::request::MidpointRequest;
+use polymarket_client_sdk::clob::types::{SignableOrder, TickSize};
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::error::Kind;
+use polymarket_client_sdk::types::TokenId;
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::common::{API_KEY, PASSPHRASE, PRIVATE_KEY, SECRET, ensure_requirements};
+
+fn cassette_path() -> PathBuf {
+    std::env::temp_dir().join(format!("cassette-{}.jsonl", Uuid::new_v4()))
+}
+
+/// Records a `derive-api-key` call followed by two `midpoint` calls against a mock server and
+/// returns the server's base URL.
+async fn record(path: &PathBuf) -> anyhow::Result<String> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+    server.mock(|when, then| {
+        when.method(GET).path("/auth/derive-api-key");
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": Uuid::max().to_string(),
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        }));
+    });
+    let mut first = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "mid": "0.5" }));
+    });
+
+    let recorder = Arc::new(Recorder::create(path)?);
+    let transport = RecordingTransport::new(reqwest::Client::new(), recorder);
+    let client = Client::new(&server.base_url(), Config::default())?.with_transport(transport)?;
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    client.derive_api_key(&signer, None).await?;
+    client.midpoint(&request).await?;
+
+    first.delete();
+    server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "mid": "0.6" }));
+    });
+    client.midpoint(&request).await?;
+
+    Ok(server.base_url())
+}
+
+#[tokio::test]
+async fn recording_transport_should_write_redacted_interactions() -> anyhow::Result<()> {
+    let path = cassette_path();
+    record(&path).await?;
+
+    let lines: Vec<Value> = std::fs::read_to_string(&path)?
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<_, _>>()?;
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(lines.len(), 3);
+
+    let derive = &lines[0];
+    assert_eq!(derive["type"], "http");
+    assert_eq!(derive["request"]["method"], "GET");
+    assert_eq!(derive["request"]["headers"]["poly_signature"], "REDACTED");
+    assert_eq!(derive["request"]["headers"]["poly_nonce"], "0");
+
+    let credentials: Value = serde_json::from_str(derive["response"]["body"].as_str().unwrap())?;
+    assert_eq!(credentials["apiKey"], API_KEY.to_string());
+    assert_ne!(credentials["secret"], SECRET);
+    assert_ne!(credentials["passphrase"], PASSPHRASE);
+
+    assert_eq!(lines[1]["response"]["status"], 200);
+    assert_eq!(lines[1]["response"]["body"], r#"{"mid":"0.5"}"#);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn recording_transport_should_redact_api_key_from_orders_and_key_listings()
+-> anyhow::Result<()> {
+    let path = cassette_path();
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let api_key = Uuid::from_u128(0x5eed);
+
+    server.mock(|when, then| {
+        when.method(GET).path("/auth/derive-api-key");
+        then.status(StatusCode::OK).json_body(json!({
+            "apiKey": api_key.to_string(),
+            "passphrase": PASSPHRASE,
+            "secret": SECRET
+        }));
+    });
+    ensure_requirements(&server, TokenId::from(1), TickSize::Hundredth);
+    server.mock(|when, then| {
+        when.method(POST).path("/order");
+        then.status(StatusCode::OK).json_body(json!({
+            "error_msg": "",
+            "makingAmount": "",
+            "orderID": "0x23b457271bce9fa09b4f79125c9ec09e968235a462de82e318ef4eb6fe0ffeb0",
+            "status": "live",
+            "success": true,
+            "takingAmount": ""
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/auth/api-keys");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "apiKeys": [api_key] }));
+    });
+
+    let recorder = Arc::new(Recorder::create(&path)?);
+    let transport = RecordingTransport::new(reqwest::Client::new(), recorder);
+    let client = Client::new(&server.base_url(), Config::default())?
+        .with_transport(transport)?
+        .authentication_builder(&signer)
+        .authenticate()
+        .await?;
+
+    let order = client.sign(&signer, SignableOrder::default()).await?;
+    client.post_order(order).await?;
+    client.api_keys().await?;
+
+    let cassette = std::fs::read_to_string(&path)?;
+    std::fs::remove_file(&path)?;
+
+    assert!(cassette.contains("owner"), "{cassette}");
+    assert!(cassette.contains("apiKeys"), "{cassette}");
+    assert!(
+        !cassette.contains(&api_key.to_string()),
+        "API key leaked into the cassette: {cassette}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn replay_transport_should_serve_recorded_responses_in_order() -> anyhow::Result<()> {
+    let path = cassette_path();
+    let host = record(&path).await?;
+
+    let cassette = Cassette::load(&path)?;
+    std::fs::remove_file(&path)?;
+    assert!(
+        cassette
+            .interactions
+            .iter()
+            .all(|interaction| matches!(interaction, Interaction::Http { .. }))
+    );
+
+    let transport = Arc::new(cassette.transport());
+    let client = Client::new(&host, Config::default())?.with_transport(Arc::clone(&transport))?;
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+
+    assert_eq!(client.midpoint(&request).await?.mid, dec!(0.5));
+    assert_eq!(client.midpoint(&request).await?.mid, dec!(0.6));
+
+    let err = client.midpoint(&request).await.unwrap_err();
+    assert_eq!(err.kind(), Kind::Validation);
+    assert_eq!(transport.remaining(), 1, "derive-api-key was not replayed");
+
+    Ok(())
+}
+
+#[cfg(feature = "ws")]
+mod websocket {
+    use std::io::Write as _;
+    use std::time::Duration;
+
+    use futures_util::StreamExt as _;
+    use polymarket_client_sdk::cassette::{Direction, ReplayServer};
+    use polymarket_client_sdk::clob::ws::Client as WsClient;
+    use polymarket_client_sdk::ws::config::Config as WsConfig;
+    use tokio::time::timeout;
+
+    use super::*;
+
+    const ASSET_ID: &str = "123";
+
+    #[tokio::test]
+    async fn replay_server_should_serve_frames_after_subscription() -> anyhow::Result<()> {
+        let path = cassette_path();
+        let book = json!({
+            "event_type": "book",
+            "asset_id": ASSET_ID,
+            "market": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "bids": [{ "price": ".48", "size": "30" }],
+            "asks": [{ "price": ".52", "size": "25" }],
+            "timestamp": "123456789000",
+            "hash": "0x1234"
+        });
+        let frames = [
+            json!({
+                "type": "ws",
+                "endpoint": "wss://ws-subscriptions-clob.polymarket.com/ws/market",
+                "direction": "sent",
+                "frame": json!({ "type": "market", "assets_ids": [ASSET_ID] }).to_string()
+            }),
+            json!({
+                "type": "ws",
+                "endpoint": "wss://ws-subscriptions-clob.polymarket.com/ws/market",
+                "direction": "received",
+                "frame": book.to_string()
+            }),
+        ];
+        let mut file = std::fs::File::create(&path)?;
+        for frame in frames {
+            writeln!(file, "{frame}")?;
+        }
+
+        let cassette = Cassette::load(&path)?;
+        std::fs::remove_file(&path)?;
+
+        let server = ReplayServer::start(&cassette).await?;
+        let mut config = WsConfig::default();
+        config.recorder = Some(Arc::new(Recorder::create(&path)?));
+        let client = WsClient::new(&server.endpoint(), config)?;
+        let stream = client.subscribe_orderbook(vec![ASSET_ID.parse()?])?;
+        let mut stream = Box::pin(stream);
+
+        let update = timeout(Duration::from_secs(2), stream.next())
+            .await?
+            .unwrap()?;
+        assert_eq!(update.asset_id.to_string(), ASSET_ID);
+        assert_eq!(update.bids.len(), 1);
+
+        let recorded = Cassette::load(&path)?;
+        std::fs::remove_file(&path)?;
+        let directions: Vec<_> = recorded
+            .interactions
+            .iter()
+            .map(|interaction| match interaction {
+                Interaction::Ws {
+                    endpoint,
+                    direction,
+                    ..
+                } => {
+                    assert_eq!(endpoint, &format!("{}/ws/market", server.endpoint()));
+                    *direction
+                }
+                other => panic!("unexpected interaction {other:?}"),
+            })
+            .collect();
+        assert_eq!(directions.first(), Some(&Direction::Sent));
+        assert_eq!(directions.last(), Some(&Direction::Received));
+
+        Ok(())
+    }
+}
@@ -12,24 +12,25 @@ use alloy::primitives::U256;
 use chrono::{DateTime, Utc};
 use httpmock::MockServer;
 use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::clob::clock::ClockSync;
 use polymarket_client_sdk::clob::types::SignatureType;
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
 use uuid::Uuid;
 
 use crate::common::{
-    POLY_ADDRESS, POLY_API_KEY, POLY_PASSPHRASE, PRIVATE_KEY, create_authenticated,
-    ensure_requirements,
+    MARKET, POLY_ADDRESS, POLY_API_KEY, POLY_PASSPHRASE, PRIVATE_KEY, create_authenticated,
+    create_authenticated_with_config, ensure_requirements,
 };
 
 mod unauthenticated {
 
     use chrono::{TimeDelta, TimeZone as _};
     use futures_util::future;
-    use futures_util::stream::StreamExt as _;
+    use futures_util::stream::{StreamExt as _, TryStreamExt as _};
     use polymarket_client_sdk::clob::types::request::{
         LastTradePriceRequest, MidpointRequest, OrderBookSummaryRequest, PriceHistoryRequest,
         PriceRequest, SpreadRequest,
@@ -96,7 +97,9 @@ mod unauthenticated {
                 .json_body(json!({ "mid": "0.5" }));
         });
 
-        let request = MidpointRequest::builder().token_id("1").build();
+        let request = MidpointRequest::builder()
+            .token_id(TokenId::from(1))
+            .build();
         let response = client.midpoint(&request).await?;
 
         let expected = MidpointResponse::builder().mid(dec!(0.5)).build();
@@ -121,11 +124,13 @@ mod unauthenticated {
             ));
         });
 
-        let request = MidpointRequest::builder().token_id("1").build();
+        let request = MidpointRequest::builder()
+            .token_id(TokenId::from(1))
+            .build();
         let response = client.midpoints(&[request]).await?;
 
         let expected = MidpointsResponse::builder()
-            .midpoints(HashMap::from_iter([("1".to_owned(), dec!(0.5))]))
+            .midpoints(HashMap::from_iter([(TokenId::from(1), dec!(0.5))]))
             .build();
 
         assert_eq!(response, expected);
@@ -149,7 +154,7 @@ mod unauthenticated {
         });
 
         let request = PriceRequest::builder()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .side(Side::Buy)
             .build();
         let response = client.price(&request).await?;
@@ -160,7 +165,7 @@ mod unauthenticated {
         mock.assert();
 
         let request = PriceRequest::builder()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .side(Side::Sell)
             .build();
         let err = client.price(&request).await.unwrap_err();
@@ -193,10 +198,10 @@ mod unauthenticated {
         let mut price_map = HashMap::new();
         let mut side_map = HashMap::new();
         side_map.insert(Side::Buy, dec!(0.5));
-        price_map.insert("1".to_owned(), side_map);
+        price_map.insert(TokenId::from(1), side_map);
 
         let request = PriceRequest::builder()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .side(Side::Buy)
             .build();
         let response = client.prices(&[request]).await?;
@@ -226,7 +231,7 @@ mod unauthenticated {
         let mut side_map = HashMap::new();
         side_map.insert(Side::Buy, dec!(0.5));
         side_map.insert(Side::Sell, dec!(0.6));
-        price_map.insert("1".to_owned(), side_map);
+        price_map.insert(TokenId::from(1), side_map);
 
         let expected = PricesResponse::builder().prices(price_map).build();
 
@@ -244,7 +249,7 @@ mod unauthenticated {
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
                 .path("/prices-history")
-                .query_param("market", "0x123")
+                .query_param("market", MARKET)
                 .query_param("interval", "1h")
                 .query_param("fidelity", "10");
             then.status(StatusCode::OK).json_body(json!({
@@ -257,7 +262,7 @@ mod unauthenticated {
         });
 
         let request = PriceHistoryRequest::builder()
-            .market("0x123")
+            .market(MARKET.parse()?)
             .time_range(Interval::OneHour)
             .fidelity(10_u32)
             .build();
@@ -285,7 +290,7 @@ mod unauthenticated {
         let mock = server.mock(|when, then| {
             when.method(httpmock::Method::GET)
                 .path("/prices-history")
-                .query_param("market", "0x123")
+                .query_param("market", MARKET)
                 .query_param("startTs", "1000")
                 .query_param("endTs", "2000");
             then.status(StatusCode::OK).json_body(json!({
@@ -297,7 +302,7 @@ mod unauthenticated {
         });
 
         let request = PriceHistoryRequest::builder()
-            .market("0x123")
+            .market(MARKET.parse()?)
             .time_range(TimeRange::from_range(1000, 2000))
             .build();
         let response = client.price_history(&request).await?;
@@ -328,7 +333,7 @@ mod unauthenticated {
                 .json_body(json!({ "spread": "0.5" }));
         });
 
-        let request = SpreadRequest::builder().token_id("1").build();
+        let request = SpreadRequest::builder().token_id(TokenId::from(1)).build();
         let response = client.spread(&request).await?;
 
         let expected = SpreadResponse::builder().spread(dec!(0.5)).build();
@@ -353,9 +358,9 @@ mod unauthenticated {
         });
 
         let mut spread_map = HashMap::new();
-        spread_map.insert("1".to_owned(), Decimal::TWO);
+        spread_map.insert(TokenId::from(1), Decimal::TWO);
 
-        let request = SpreadRequest::builder().token_id("1").build();
+        let request = SpreadRequest::builder().token_id(TokenId::from(1)).build();
         let response = client.spreads(&[request]).await?;
 
         let expected = SpreadsResponse::builder().spreads(spread_map).build();
@@ -379,7 +384,7 @@ mod unauthenticated {
                 .json_body(json!({ "minimum_tick_size": "0.1" }));
         });
 
-        let response = client.tick_size("1").await?;
+        let response = client.tick_size(TokenId::from(1)).await?;
 
         let expected = TickSizeResponse::builder()
             .minimum_tick_size(TickSize::Tenth)
@@ -391,6 +396,137 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn calibrate_clock_should_keep_fastest_sample() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .clock_sync(ClockSync::builder().samples(3).build())
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let server_time = Utc::now().timestamp() + 100;
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK).json_body(server_time);
+        });
+
+        assert_eq!(client.clock_skew(), None);
+        let skew = client.calibrate_clock().await?;
+
+        mock.assert_calls(3);
+        assert!(
+            (skew.offset - TimeDelta::seconds(100)).abs() <= TimeDelta::seconds(1),
+            "{skew:?}"
+        );
+        assert_eq!(client.clock_skew(), Some(skew));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn calibrate_clock_without_clock_sync_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let err = client.calibrate_clock().await.unwrap_err();
+
+        assert!(err.to_string().contains("clock_sync"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn order_books_should_refresh_a_stale_clock() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .clock_sync(
+                ClockSync::builder()
+                    .samples(1)
+                    .refresh_interval(std::time::Duration::from_millis(50))
+                    .build(),
+            )
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let time = server.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/time");
+            then.status(StatusCode::OK)
+                .json_body(Utc::now().timestamp());
+        });
+        server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/books");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        client.calibrate_clock().await?;
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(TokenId::from(1))
+            .build();
+        client.order_books(&[request]).await?;
+
+        time.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalidate_market_params_should_refetch_one_token() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let first = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.1" }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+
+        client.tick_size(TokenId::from(1)).await?;
+        client.tick_size(TokenId::from(2)).await?;
+        client.invalidate_market_params(TokenId::from(1));
+        client.tick_size(TokenId::from(1)).await?;
+        client.tick_size(TokenId::from(2)).await?;
+
+        first.assert_calls(2);
+        second.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn cache_ttl_should_expire_cached_params() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .cache_ttl(std::time::Duration::ZERO)
+            .build();
+        let client = Client::new(&server.base_url(), config)?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/fee-rate")
+                .query_param("token_id", "1");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "base_fee": 0 }));
+        });
+
+        client.fee_rate_bps(TokenId::from(1)).await?;
+        client.fee_rate_bps(TokenId::from(1)).await?;
+
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn neg_risk_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -404,7 +540,7 @@ mod unauthenticated {
                 .json_body(json!({ "neg_risk": true }));
         });
 
-        let response = client.neg_risk("1").await?;
+        let response = client.neg_risk(TokenId::from(1)).await?;
 
         let expected = NegRiskResponse::builder().neg_risk(true).build();
 
@@ -427,7 +563,7 @@ mod unauthenticated {
                 .json_body(json!({ "base_fee": 0 }));
         });
 
-        let response = client.fee_rate_bps("1").await?;
+        let response = client.fee_rate_bps(TokenId::from(1)).await?;
 
         let expected = FeeRateResponse::builder().base_fee(0).build();
 
@@ -447,7 +583,7 @@ mod unauthenticated {
                 .path("/book")
                 .query_param("token_id", "1");
             then.status(StatusCode::OK).json_body(json!({
-                "market": "0xaabbcc",
+                "market": MARKET,
                 "asset_id": "100",
                 "tick_size": TickSize::Hundredth.as_decimal(),
                 "min_order_size": "100",
@@ -476,16 +612,18 @@ mod unauthenticated {
             }));
         });
 
-        let request = OrderBookSummaryRequest::builder().token_id("1").build();
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(TokenId::from(1))
+            .build();
         let response = client.order_book(&request).await?;
 
         let expected = OrderBookSummaryResponse::builder()
-            .market("0xaabbcc")
+            .market(MARKET.parse()?)
             .neg_risk(false)
             .timestamp(Utc.timestamp_millis_opt(123_456_789).unwrap())
             .min_order_size(Decimal::ONE_HUNDRED)
             .tick_size(TickSize::Hundredth)
-            .asset_id("100")
+            .asset_id(TokenId::from(100))
             .bids(vec![
                 OrderSummary::builder()
                     .price(dec!(0.3))
@@ -511,7 +649,7 @@ mod unauthenticated {
         assert_eq!(response, expected);
         assert_eq!(
             expected.hash()?,
-            "98800af6a111791440d0936f5bc0fe1ac15da6eda09cc050227fb82ab8ceca7f"
+            "bde8a5c51beaabc9a5ef8ba508acb3d97fa6d82d99cfcf3cb9741fb94e3e0ad1"
         );
         mock.assert();
 
@@ -528,8 +666,8 @@ mod unauthenticated {
                 .path("/books")
                 .json_body(json!([{ "token_id": "1" }]));
             then.status(StatusCode::OK).json_body(json!([{
-                "market": "market",
-                "asset_id": "asset",
+                "market": MARKET,
+                "asset_id": "200",
                 "tick_size": TickSize::Hundredth.as_decimal(),
                 "min_order_size": "5",
                 "neg_risk": false,
@@ -541,17 +679,19 @@ mod unauthenticated {
             }]));
         });
 
-        let request = OrderBookSummaryRequest::builder().token_id("1").build();
+        let request = OrderBookSummaryRequest::builder()
+            .token_id(TokenId::from(1))
+            .build();
         let response = client.order_books(&[request]).await?;
 
         let expected = vec![
             OrderBookSummaryResponse::builder()
-                .market("market")
+                .market(MARKET.parse()?)
                 .neg_risk(false)
                 .timestamp(DateTime::<Utc>::UNIX_EPOCH + TimeDelta::milliseconds(1))
                 .min_order_size(dec!(5))
                 .tick_size(TickSize::Hundredth)
-                .asset_id("asset")
+                .asset_id(TokenId::from(200))
                 .asks(vec![
                     OrderSummary::builder()
                         .price(Decimal::TWO)
@@ -581,7 +721,7 @@ mod unauthenticated {
         });
 
         let request = LastTradePriceRequest::builder()
-            .token_id("1".to_owned())
+            .token_id(TokenId::from(1))
             .build();
         let response = client.last_trade_price(&request).await?;
 
@@ -610,13 +750,13 @@ mod unauthenticated {
         });
 
         let request = LastTradePriceRequest::builder()
-            .token_id("1".to_owned())
+            .token_id(TokenId::from(1))
             .build();
         let response = client.last_trades_prices(&[request]).await?;
 
         let expected = vec![
             LastTradesPricesResponse::builder()
-                .token_id("1".to_owned())
+                .token_id(TokenId::from(1))
                 .price(dec!(0.12))
                 .side(Side::Buy)
                 .build(),
@@ -634,7 +774,8 @@ mod unauthenticated {
         let client = Client::new(&server.base_url(), Config::default())?;
 
         let mock = server.mock(|when, then| {
-            when.method(httpmock::Method::GET).path("/markets/1");
+            when.method(httpmock::Method::GET)
+                .path(format!("/markets/{MARKET}"));
             then.status(StatusCode::OK).json_body(json!({
                 "enable_order_book": true,
                 "active": true,
@@ -644,7 +785,7 @@ mod unauthenticated {
                 "accepting_order_timestamp": "2024-01-15T12:34:56Z",
                 "minimum_order_size": "1",
                 "minimum_tick_size": "0.01",
-                "condition_id": "1",
+                "condition_id": MARKET,
                 "question_id": "q_67890",
                 "question": "Will BTC close above $50k today?",
                 "description": "A market about BTC daily close price",
@@ -669,13 +810,13 @@ mod unauthenticated {
                 "is_50_50_outcome": false,
                 "tokens": [
                     {
-                        "token_id": "YES_TOKEN",
+                        "token_id": "101",
                         "outcome": "YES",
                         "price": "0.55",
                         "winner": false
                     },
                     {
-                        "token_id": "NO_TOKEN",
+                        "token_id": "102",
                         "outcome": "NO",
                         "price": "0.45",
                         "winner": false
@@ -689,7 +830,7 @@ mod unauthenticated {
             }));
         });
 
-        let response = client.market("1").await?;
+        let response = client.market(MARKET.parse()?).await?;
 
         let expected = MarketResponse::builder()
             .enable_order_book(true)
@@ -700,7 +841,7 @@ mod unauthenticated {
             .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
             .minimum_order_size(Decimal::ONE)
             .minimum_tick_size(TickSize::Hundredth.as_decimal())
-            .condition_id("1")
+            .condition_id(MARKET.parse()?)
             .question_id("q_67890")
             .question("Will BTC close above $50k today?")
             .description("A market about BTC daily close price")
@@ -725,13 +866,13 @@ mod unauthenticated {
             .is_50_50_outcome(false)
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.55))
                     .winner(false)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.45))
                     .winner(false)
@@ -764,7 +905,7 @@ mod unauthenticated {
                         "accepting_order_timestamp": "2024-01-15T12:34:56Z",
                         "minimum_order_size": "1",
                         "minimum_tick_size": "0.01",
-                        "condition_id": "1",
+                        "condition_id": MARKET,
                         "question_id": "q_67890",
                         "question": "Will BTC close above $50k today?",
                         "description": "A market about BTC daily close price",
@@ -789,13 +930,13 @@ mod unauthenticated {
                         "is_50_50_outcome": false,
                         "tokens": [
                             {
-                                "token_id": "YES_TOKEN",
+                                "token_id": "101",
                                 "outcome": "YES",
                                 "price": "0.55",
                                 "winner": false
                             },
                             {
-                                "token_id": "NO_TOKEN",
+                                "token_id": "102",
                                 "outcome": "NO",
                                 "price": "0.45",
                                 "winner": false
@@ -825,7 +966,7 @@ mod unauthenticated {
             .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
             .minimum_order_size(Decimal::ONE)
             .minimum_tick_size(TickSize::Hundredth.as_decimal())
-            .condition_id("1")
+            .condition_id(MARKET.parse()?)
             .question_id("q_67890")
             .question("Will BTC close above $50k today?")
             .description("A market about BTC daily close price")
@@ -850,13 +991,13 @@ mod unauthenticated {
             .is_50_50_outcome(false)
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.55))
                     .winner(false)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.45))
                     .winner(false)
@@ -888,16 +1029,16 @@ mod unauthenticated {
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
                     {
-                        "condition_id": "cond_12345",
+                        "condition_id": MARKET,
                         "tokens": [
                             {
-                                "token_id": "YES_TOKEN",
+                                "token_id": "101",
                                 "outcome": "YES",
                                 "price": "0.55",
                                 "winner": false
                             },
                             {
-                                "token_id": "NO_TOKEN",
+                                "token_id": "102",
                                 "outcome": "NO",
                                 "price": "0.45",
                                 "winner": false
@@ -923,16 +1064,16 @@ mod unauthenticated {
         let response = client.simplified_markets(None).await?;
 
         let simplified = SimplifiedMarketResponse::builder()
-            .condition_id("cond_12345")
+            .condition_id(MARKET.parse()?)
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.55))
                     .winner(false)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.45))
                     .winner(false)
@@ -962,6 +1103,53 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn simplified_markets_should_accept_empty_ids() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [
+                    {
+                        "condition_id": "",
+                        "tokens": [
+                            {
+                                "token_id": "",
+                                "outcome": "",
+                                "price": "0",
+                                "winner": false
+                            }
+                        ],
+                        "rewards": {
+                            "rates": null,
+                            "min_size": "0",
+                            "max_spread": "0"
+                        },
+                        "archived": true,
+                        "accepting_orders": false,
+                        "active": false,
+                        "closed": true
+                    }
+                ],
+                "limit": 1,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let response = client.simplified_markets(None).await?;
+
+        let market = &response.data[0];
+        assert_eq!(market.condition_id, None);
+        assert_eq!(market.tokens[0].token_id, None);
+        mock.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn sampling_simplified_markets_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -973,16 +1161,16 @@ mod unauthenticated {
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
                     {
-                        "condition_id": "cond_12345",
+                        "condition_id": MARKET,
                         "tokens": [
                             {
-                                "token_id": "YES_TOKEN",
+                                "token_id": "101",
                                 "outcome": "YES",
                                 "price": "0.55",
                                 "winner": false
                             },
                             {
-                                "token_id": "NO_TOKEN",
+                                "token_id": "102",
                                 "outcome": "NO",
                                 "price": "0.45",
                                 "winner": false
@@ -1008,16 +1196,16 @@ mod unauthenticated {
         let response = client.sampling_simplified_markets(None).await?;
 
         let simplified = SimplifiedMarketResponse::builder()
-            .condition_id("cond_12345")
+            .condition_id(MARKET.parse()?)
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.55))
                     .winner(false)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.45))
                     .winner(false)
@@ -1065,7 +1253,7 @@ mod unauthenticated {
                     "accepting_order_timestamp": "2024-01-15T12:34:56Z",
                     "minimum_order_size": "1",
                     "minimum_tick_size": "0.01",
-                    "condition_id": "1",
+                    "condition_id": MARKET,
                     "question_id": "q_67890",
                     "question": "Will BTC close above $50k today?",
                     "description": "A market about BTC daily close price",
@@ -1090,13 +1278,13 @@ mod unauthenticated {
                     "is_50_50_outcome": false,
                     "tokens": [
                         {
-                            "token_id": "YES_TOKEN",
+                            "token_id": "101",
                             "outcome": "YES",
                             "price": "0.55",
                             "winner": false
                         },
                         {
-                            "token_id": "NO_TOKEN",
+                            "token_id": "102",
                             "outcome": "NO",
                             "price": "0.45",
                             "winner": false
@@ -1160,7 +1348,7 @@ mod unauthenticated {
             .accepting_order_timestamp("2024-01-15T12:34:56Z".parse::<DateTime<Utc>>().unwrap())
             .minimum_order_size(Decimal::ONE)
             .minimum_tick_size(TickSize::Hundredth.as_decimal())
-            .condition_id("1")
+            .condition_id(MARKET.parse()?)
             .question_id("q_67890")
             .question("Will BTC close above $50k today?")
             .description("A market about BTC daily close price")
@@ -1185,13 +1373,13 @@ mod unauthenticated {
             .is_50_50_outcome(false)
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.55))
                     .winner(false)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.45))
                     .winner(false)
@@ -1208,6 +1396,57 @@ mod unauthenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn simplified_markets_stream_should_follow_cursor() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url(), Config::default())?;
+
+        let market = json!({
+            "condition_id": MARKET,
+            "tokens": [],
+            "rewards": {
+                "rates": null,
+                "min_size": "10.0",
+                "max_spread": "0.05"
+            },
+            "archived": false,
+            "accepting_orders": true,
+            "active": true,
+            "closed": false
+        });
+
+        let first = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets")
+                .query_param_missing("next_cursor");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [market, market],
+                "limit": 2,
+                "count": 2,
+                "next_cursor": "Mg=="
+            }));
+        });
+        let second = server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/simplified-markets")
+                .query_param("next_cursor", "Mg==");
+            then.status(StatusCode::OK).json_body(json!({
+                "data": [market],
+                "limit": 2,
+                "count": 1,
+                "next_cursor": "LTE="
+            }));
+        });
+
+        let markets: Vec<_> = client.simplified_markets_stream().try_collect().await?;
+
+        assert_eq!(markets.len(), 3);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn check_geoblock_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1269,7 +1508,7 @@ mod authenticated {
     use alloy::primitives::Signature;
     use alloy::signers::Signer as _;
     use alloy::signers::local::LocalSigner;
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, TimeDelta};
     use httpmock::Method::{DELETE, GET, POST};
     use polymarket_client_sdk::clob::types::request::{
         BalanceAllowanceRequest, CancelMarketOrderRequest, DeleteNotificationsRequest,
@@ -1319,6 +1558,33 @@ mod authenticated {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn clock_sync_should_reuse_measured_offset() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .clock_sync(ClockSync::builder().samples(2).build())
+            .build();
+        // Two samples measure the offset once, which signs both the L1 and the L2 headers
+        let client = create_authenticated_with_config(&server, config).await?;
+
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_TIMESTAMP, TIMESTAMP);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        client.api_keys().await?;
+
+        mock.assert();
+        let skew = client.clock_skew().unwrap();
+        assert!(skew.offset < TimeDelta::zero(), "{skew:?}");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn delete_api_keys_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1340,6 +1606,135 @@ mod authenticated {
         Ok(())
     }
 
+    const ROTATED_API_KEY: Uuid = Uuid::from_u128(1);
+    const ROTATED_PASSPHRASE: &str = "rotated";
+
+    fn mock_create_api_key(server: &MockServer, nonce: u32) -> httpmock::Mock<'_> {
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/auth/api-key")
+                .header(POLY_NONCE, nonce.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": ROTATED_API_KEY.to_string(),
+                "passphrase": ROTATED_PASSPHRASE,
+                "secret": SECRET
+            }));
+        })
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_swap_in_new_key() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let create = mock_create_api_key(&server, 7);
+        let keys = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, ROTATED_API_KEY)
+                .header(POLY_PASSPHRASE, ROTATED_PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY, ROTATED_API_KEY]}));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK).body("\"\"");
+        });
+
+        let rotated = client.rotate_api_key(&signer).nonce(7).rotate().await?;
+
+        assert_eq!(rotated.nonce, 7);
+        assert_eq!(rotated.previous_key, API_KEY);
+        assert_eq!(rotated.credentials.key(), ROTATED_API_KEY);
+        create.assert();
+        delete.assert();
+        keys.assert_calls(1);
+
+        client.api_keys().await?;
+        keys.assert_calls(2);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_roll_back_when_delete_fails() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        mock_create_api_key(&server, 7);
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, ROTATED_API_KEY);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY, ROTATED_API_KEY]}));
+        });
+        let delete_previous = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, API_KEY);
+            then.status(StatusCode::INTERNAL_SERVER_ERROR)
+                .json_body(json!({"error": "unavailable"}));
+        });
+        let delete_rotated = server.mock(|when, then| {
+            when.method(DELETE)
+                .path("/auth/api-key")
+                .header(POLY_API_KEY, ROTATED_API_KEY)
+                .header(POLY_PASSPHRASE, ROTATED_PASSPHRASE);
+            then.status(StatusCode::OK).body("\"\"");
+        });
+        let previous_keys = server.mock(|when, then| {
+            when.method(GET)
+                .path("/auth/api-keys")
+                .header(POLY_API_KEY, API_KEY)
+                .header(POLY_PASSPHRASE, PASSPHRASE);
+            then.status(StatusCode::OK)
+                .json_body(json!({"apiKeys": [API_KEY]}));
+        });
+
+        let err = client
+            .rotate_api_key(&signer)
+            .nonce(7)
+            .rotate()
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("unavailable"), "{err}");
+        delete_previous.assert();
+        delete_rotated.assert();
+
+        client.api_keys().await?;
+        previous_keys.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rotate_api_key_should_reject_other_signer() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+
+        let create = mock_create_api_key(&server, 7);
+
+        let err = client
+            .rotate_api_key(&signer)
+            .nonce(7)
+            .rotate()
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("does not match"), "{err}");
+        create.assert_calls(0);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn closed_only_mode_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -1400,17 +1795,20 @@ mod authenticated {
             .authenticate()
             .await?;
 
-        ensure_requirements(&server, "123", TickSize::Thousandth);
+        ensure_requirements(&server, TokenId::from(123), TickSize::Thousandth);
 
         assert_eq!(
-            client.tick_size("123").await?.minimum_tick_size,
+            client
+                .tick_size(TokenId::from(123))
+                .await?
+                .minimum_tick_size,
             TickSize::Thousandth
         );
 
         let taker = address!("0xf7fB45986800e2D259BAa25B56466bd02dA37a44");
         let signable_order = client
             .limit_order()
-            .token_id("123")
+            .token_id(TokenId::from(123))
             .price(dec!(0.512))
             .size(Decimal::ONE_HUNDRED)
             .side(Side::Buy)
@@ -1459,7 +1857,7 @@ mod authenticated {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements(&server, "1", TickSize::Hundredth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Hundredth);
 
         let mock = server.mock(|when, then| {
             when.method(POST)
@@ -1519,7 +1917,7 @@ mod authenticated {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements(&server, "1", TickSize::Hundredth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Hundredth);
 
         let mock = server.mock(|when, then| {
             when.method(POST)
@@ -1569,8 +1967,8 @@ mod authenticated {
             "status": "LIVE",
             "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
             "maker_address": "0x2222222222222222222222222222222222222222",
-            "market": "market_abc",
-            "asset_id": "asset_xyz",
+            "market": MARKET,
+            "asset_id": "200",
             "side": "buy",
             "original_size": "10.0",
             "size_matched": "2.5",
@@ -1601,8 +1999,8 @@ mod authenticated {
             .status(OrderStatusType::Live)
             .owner(Uuid::max())
             .maker_address(address!("0x2222222222222222222222222222222222222222"))
-            .market("market_abc")
-            .asset_id("asset_xyz")
+            .market(MARKET.parse()?)
+            .asset_id(TokenId::from(200))
             .side(Side::Buy)
             .original_size(dec!(10.0))
             .size_matched(dec!(2.5))
@@ -1632,8 +2030,8 @@ mod authenticated {
                     "status": "LIVE",
                     "owner": "ffffffff-ffff-ffff-ffff-ffffffffffff",
                     "maker_address": "0x2222222222222222222222222222222222222222",
-                    "market": "market_abc",
-                    "asset_id": "asset_xyz",
+                    "market": MARKET,
+                    "asset_id": "200",
                     "side": "buy",
                     "original_size": "10.0",
                     "size_matched": "2.5",
@@ -1671,8 +2069,8 @@ mod authenticated {
             .status(OrderStatusType::Live)
             .owner(Uuid::max())
             .maker_address(address!("0x2222222222222222222222222222222222222222"))
-            .market("market_abc")
-            .asset_id("asset_xyz")
+            .market(MARKET.parse()?)
+            .asset_id(TokenId::from(200))
             .side(Side::Buy)
             .original_size(dec!(10.0))
             .size_matched(dec!(2.5))
@@ -1847,14 +2245,14 @@ mod authenticated {
                 .header(POLY_PASSPHRASE, PASSPHRASE)
                 .path("/cancel-market-orders");
             then.status(StatusCode::OK).json_body(json!({
-                "market": "m",
-                "asset_id": "a",
+                "market": MARKET,
+                "asset_id": "200",
             }));
         });
 
         let request = CancelMarketOrderRequest::builder()
-            .market("m")
-            .asset_id("a")
+            .market(MARKET.parse()?)
+            .asset_id(TokenId::from(200))
             .build();
 
         client.cancel_market_orders(&request).await?;
@@ -1876,15 +2274,15 @@ mod authenticated {
                 .header(POLY_API_KEY, API_KEY)
                 .header(POLY_PASSPHRASE, PASSPHRASE)
                 .query_param("id", "1")
-                .query_param("market", "market");
+                .query_param("market", MARKET);
 
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
                     {
                         "id": "1",
                         "taker_order_id": "taker_123",
-                        "market": "market",
-                        "asset_id": "asset_xyz",
+                        "market": MARKET,
+                        "asset_id": "200",
                         "side": "BUY",
                         "size": "12.5",
                         "fee_rate_bps": "5",
@@ -1904,7 +2302,7 @@ mod authenticated {
                                 "matched_amount": "5.0",
                                 "price": "0.42",
                                 "fee_rate_bps": "5",
-                                "asset_id": "asset_xyz",
+                                "asset_id": "200",
                                 "outcome": "YES",
                                 "side": "SELL"
                             },
@@ -1915,7 +2313,7 @@ mod authenticated {
                                 "matched_amount": "7.5",
                                 "price": "0.42",
                                 "fee_rate_bps": "5",
-                                "asset_id": "asset_xyz",
+                                "asset_id": "200",
                                 "outcome": "YES",
                                 "side": "SELL"
                             }
@@ -1930,14 +2328,17 @@ mod authenticated {
             }));
         });
 
-        let request = TradesRequest::builder().id("1").market("market").build();
+        let request = TradesRequest::builder()
+            .id("1")
+            .market(MARKET.parse()?)
+            .build();
         let response = client.trades(&request, None).await?;
 
         let trade = TradeResponse::builder()
             .id("1")
             .taker_order_id("taker_123")
-            .market("market")
-            .asset_id("asset_xyz")
+            .market(MARKET.parse()?)
+            .asset_id(TokenId::from(200))
             .side(Side::Buy)
             .size(dec!(12.5))
             .fee_rate_bps(dec!(5))
@@ -1957,7 +2358,7 @@ mod authenticated {
                     .matched_amount(dec!(5.0))
                     .price(dec!(0.42))
                     .fee_rate_bps(dec!(5))
-                    .asset_id("asset_xyz")
+                    .asset_id(TokenId::from(200))
                     .outcome("YES")
                     .side(Side::Sell)
                     .build(),
@@ -1968,7 +2369,7 @@ mod authenticated {
                     .matched_amount(dec!(7.5))
                     .price(dec!(0.42))
                     .fee_rate_bps(dec!(5))
-                    .asset_id("asset_xyz")
+                    .asset_id(TokenId::from(200))
                     .outcome("YES")
                     .side(Side::Sell)
                     .build(),
@@ -2039,12 +2440,12 @@ mod authenticated {
                 .r#type(1)
                 .owner(API_KEY)
                 .payload(NotificationPayload::builder()
-                    .asset_id("71321045679252212594626385532706912750332728571942532289631379312455583992563")
-                    .condition_id("0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1")
+                    .asset_id("71321045679252212594626385532706912750332728571942532289631379312455583992563".parse()?)
+                    .condition_id("0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1".parse()?)
                     .event_slug("will-trump-win-the-2024-iowa-caucus")
                     .icon("https://polymarket-upload.s3.us-east-2.amazonaws.com/trump1+copy.png")
                     .image("https://polymarket-upload.s3.us-east-2.amazonaws.com/trump1+copy.png")
-                    .market("0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1")
+                    .market("0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1".parse()?)
                     .market_slug("will-trump-win-the-2024-iowa-caucus")
                     .matched_size(dec!(20))
                     .order_id("0x2ae21876d2702d8b71308d0999062db9625a691ce4593c5f10230eeeff945e70")
@@ -2121,7 +2522,7 @@ mod authenticated {
 
         let request = BalanceAllowanceRequest::builder()
             .asset_type(AssetType::Collateral)
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .build();
         let response = client.balance_allowance(request).await?;
 
@@ -2155,7 +2556,7 @@ mod authenticated {
 
         let request = BalanceAllowanceRequest::builder()
             .asset_type(AssetType::Collateral)
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .build();
         client.update_balance_allowance(request).await?;
 
@@ -2235,7 +2636,7 @@ mod authenticated {
             then.status(StatusCode::OK).json_body(json!({
                 "data": [{
                     "date": "2025-12-08",
-                    "condition_id": "1",
+                    "condition_id": MARKET,
                     "asset_address": "0x0000000000000000000000000000000000000001",
                     "maker_address": "0x0000000000000000000000000000000000000002",
                     "earnings": 1,
@@ -2254,7 +2655,7 @@ mod authenticated {
             .data(vec![
                 UserEarningResponse::builder()
                     .date(date)
-                    .condition_id("1")
+                    .condition_id(MARKET.parse()?)
                     .asset_address(address!("0x0000000000000000000000000000000000000001"))
                     .maker_address(address!("0x0000000000000000000000000000000000000002"))
                     .earnings(Decimal::ONE)
@@ -2332,7 +2733,7 @@ mod authenticated {
             then.status(StatusCode::OK).json_body(json!(
                 [
                     {
-                        "condition_id": "cond_123",
+                        "condition_id": MARKET,
                         "question": "Will BTC be above $50k on December 31, 2025?",
                         "market_slug": "btc-above-50k-2025-12-31",
                         "event_slug": "btc-above-50k-2025",
@@ -2342,13 +2743,13 @@ mod authenticated {
                         "market_competitiveness": "0.80",
                         "tokens": [
                             {
-                                "token_id": "YES_TOKEN",
+                                "token_id": "101",
                                 "outcome": "YES",
                                 "price": "0.55",
                                 "winner": true
                             },
                             {
-                                "token_id": "NO_TOKEN",
+                                "token_id": "102",
                                 "outcome": "NO",
                                 "price": "0.45",
                                 "winner": false
@@ -2398,7 +2799,7 @@ mod authenticated {
 
         let expected = vec![
             UserRewardsEarningResponse::builder()
-                .condition_id("cond_123")
+                .condition_id(MARKET.parse()?)
                 .question("Will BTC be above $50k on December 31, 2025?")
                 .market_slug("btc-above-50k-2025-12-31")
                 .event_slug("btc-above-50k-2025")
@@ -2408,13 +2809,13 @@ mod authenticated {
                 .market_competitiveness(dec!(0.80))
                 .tokens(vec![
                     Token::builder()
-                        .token_id("YES_TOKEN")
+                        .token_id(TokenId::from(101))
                         .outcome("YES")
                         .price(dec!(0.55))
                         .winner(true)
                         .build(),
                     Token::builder()
-                        .token_id("NO_TOKEN")
+                        .token_id(TokenId::from(102))
                         .outcome("NO")
                         .price(dec!(0.45))
                         .winner(false)
@@ -2498,7 +2899,7 @@ mod authenticated {
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
                     {
-                        "condition_id": "cond_abc123",
+                        "condition_id": MARKET,
                         "rewards_max_spread": "0.05",
                         "rewards_min_size": "20.0",
                         "rewards_config": [
@@ -2528,7 +2929,7 @@ mod authenticated {
         let response = client.current_rewards(None).await?;
 
         let market_reward = CurrentRewardResponse::builder()
-            .condition_id("cond_abc123")
+            .condition_id(MARKET.parse()?)
             .rewards_max_spread(dec!(0.05))
             .rewards_min_size(dec!(20.0))
             .rewards_config(vec![
@@ -2568,7 +2969,7 @@ mod authenticated {
 
         let mock = server.mock(|when, then| {
             when.method(GET)
-                .path("/rewards/markets/1")
+                .path(format!("/rewards/markets/{MARKET}"))
                 .header(POLY_ADDRESS, client.address().to_string().to_lowercase())
                 .header(POLY_API_KEY, API_KEY)
                 .header(POLY_PASSPHRASE, PASSPHRASE)
@@ -2576,7 +2977,7 @@ mod authenticated {
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
                     {
-                        "condition_id": "1",
+                        "condition_id": MARKET,
                         "question": "Will BTC reach $100k in 2025?",
                         "market_slug": "btc-100k-2025",
                         "event_slug": "btc-2025",
@@ -2586,13 +2987,13 @@ mod authenticated {
                         "market_competitiveness": 0.05,
                         "tokens": [
                             {
-                                "token_id": "YES_TOKEN",
+                                "token_id": "101",
                                 "outcome": "YES",
                                 "price": "0.58",
                                 "winner": true
                             },
                             {
-                                "token_id": "NO_TOKEN",
+                                "token_id": "102",
                                 "outcome": "NO",
                                 "price": "0.42",
                                 "winner": false
@@ -2627,11 +3028,11 @@ mod authenticated {
         });
 
         let response = client
-            .raw_rewards_for_market("1", Some("1".to_owned()))
+            .raw_rewards_for_market(MARKET.parse()?, Some("1".to_owned()))
             .await?;
 
         let market_reward = MarketRewardResponse::builder()
-            .condition_id("1")
+            .condition_id(MARKET.parse()?)
             .question("Will BTC reach $100k in 2025?")
             .market_slug("btc-100k-2025")
             .event_slug("btc-2025")
@@ -2641,13 +3042,13 @@ mod authenticated {
             .market_competitiveness(dec!(0.05))
             .tokens(vec![
                 Token::builder()
-                    .token_id("YES_TOKEN")
+                    .token_id(TokenId::from(101))
                     .outcome("YES")
                     .price(dec!(0.58))
                     .winner(true)
                     .build(),
                 Token::builder()
-                    .token_id("NO_TOKEN")
+                    .token_id(TokenId::from(102))
                     .outcome("NO")
                     .price(dec!(0.42))
                     .winner(false)
@@ -2921,7 +3322,7 @@ mod builder_authenticated {
                 .header(POLY_BUILDER_SIGNATURE, "signature")
                 .header(POLY_BUILDER_TIMESTAMP, "1")
                 .query_param("id", "1")
-                .query_param("market", "market");
+                .query_param("market", MARKET);
 
             then.status(StatusCode::OK).json_body(json!({
                 "data": [
@@ -2930,8 +3331,8 @@ mod builder_authenticated {
                         "tradeType": "limit",
                         "takerOrderHash": "0xtakerorderhash",
                         "builder": "builder_1",
-                        "market": "market",
-                        "assetId": "asset_xyz",
+                        "market": MARKET,
+                        "assetId": "200",
                         "side": "buy",
                         "size": "10.0",
                         "sizeUsdc": "100.0",
@@ -2957,7 +3358,10 @@ mod builder_authenticated {
             }));
         });
 
-        let request = TradesRequest::builder().id("1").market("market").build();
+        let request = TradesRequest::builder()
+            .id("1")
+            .market(MARKET.parse()?)
+            .build();
         let response = client.builder_trades(&request, None).await?;
 
         let trade = BuilderTradeResponse::builder()
@@ -2965,8 +3369,8 @@ mod builder_authenticated {
             .trade_type("limit")
             .taker_order_hash("0xtakerorderhash")
             .builder("builder_1")
-            .market("market")
-            .asset_id("asset_xyz")
+            .market(MARKET.parse()?)
+            .asset_id(TokenId::from(200))
             .side(Side::Buy)
             .size(dec!(10.0))
             .size_usdc(dec!(100.0))
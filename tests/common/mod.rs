@@ -19,7 +19,7 @@ use polymarket_client_sdk::auth::Normal;
 use polymarket_client_sdk::auth::state::Authenticated;
 use polymarket_client_sdk::clob::types::{SignatureType, TickSize};
 use polymarket_client_sdk::clob::{Client, Config};
-use polymarket_client_sdk::types::Decimal;
+use polymarket_client_sdk::types::{Decimal, TokenId};
 use reqwest::StatusCode;
 use serde_json::json;
 use uuid::Uuid;
@@ -33,7 +33,8 @@ pub const SIGNATURE: &str = "0xfdfb5abf512e439ea61c8595c18e527e718bf16010acf57ce
 pub const TIMESTAMP: &str = "100000";
 
 pub const BUILDER_PASSPHRASE: &str = "passphrase";
-pub const TOKEN_1: &str = "1";
+pub const MARKET: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
+pub const TOKEN_1: TokenId = TokenId::new(U256::from_limbs([1, 0, 0, 0]));
 
 pub const POLY_ADDRESS: &str = "POLY_ADDRESS";
 pub const POLY_API_KEY: &str = "POLY_API_KEY";
@@ -55,6 +56,16 @@ pub const USDC_DECIMALS: u32 = 6;
 pub type TestClient = Client<Authenticated<Normal>>;
 
 pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestClient> {
+    let config = Config::builder().use_server_time(true).build();
+    create_authenticated_with_config(server, config).await
+}
+
+/// Like [`create_authenticated`], but with a custom `config`. It must use the server time, or a
+/// clock sync taking two samples, for the mocked L1 headers to match.
+pub async fn create_authenticated_with_config(
+    server: &MockServer,
+    config: Config,
+) -> anyhow::Result<TestClient> {
     let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
 
     let mock = server.mock(|when, then| {
@@ -76,7 +87,6 @@ pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestCli
             .json_body(TIMESTAMP.parse::<i64>().unwrap());
     });
 
-    let config = Config::builder().use_server_time(true).build();
     let client = Client::new(&server.base_url(), config)?
         .authentication_builder(&signer)
         .authenticate()
@@ -88,7 +98,7 @@ pub async fn create_authenticated(server: &MockServer) -> anyhow::Result<TestCli
     Ok(client)
 }
 
-pub fn ensure_requirements(server: &MockServer, token_id: &str, tick_size: TickSize) {
+pub fn ensure_requirements(server: &MockServer, token_id: TokenId, tick_size: TickSize) {
     server.mock(|when, then| {
         when.method(httpmock::Method::GET).path("/neg-risk");
         then.status(StatusCode::OK)
@@ -104,7 +114,7 @@ pub fn ensure_requirements(server: &MockServer, token_id: &str, tick_size: TickS
     server.mock(|when, then| {
         when.method(httpmock::Method::GET)
             .path("/tick-size")
-            .query_param("token_id", token_id);
+            .query_param("token_id", token_id.to_string());
         then.status(StatusCode::OK).json_body(json!({
                 "minimum_tick_size": tick_size.as_decimal(),
         }));
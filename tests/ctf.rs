@@ -0,0 +1,174 @@
+#![cfg(feature = "ctf")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+use alloy::primitives::{B256, U256, b256};
+use polymarket_client_sdk::ctf::{
+    BINARY_PARTITION, ConditionalTokens, IConditionalTokens, INegRiskAdapter, NegRiskAdapter,
+};
+use polymarket_client_sdk::types::ConditionId;
+use polymarket_client_sdk::{POLYGON, contract_config};
+
+const CONDITION_ID: ConditionId = ConditionId::new(b256!(
+    "0x1111111111111111111111111111111111111111111111111111111111111111"
+));
+
+mod calls {
+    use super::*;
+
+    #[test]
+    fn ctf_calls_should_target_the_exchange_collateral() -> anyhow::Result<()> {
+        let config = contract_config(POLYGON, false).unwrap();
+        let ctf = ConditionalTokens::new(POLYGON)?;
+        let amount = U256::from(5_000_000);
+
+        let split = ctf.split_position(CONDITION_ID, amount);
+        assert_eq!(split.to, config.conditional_tokens);
+        let decoded = split.decode::<IConditionalTokens::splitPositionCall>()?;
+        assert_eq!(decoded.collateralToken, config.collateral);
+        assert_eq!(decoded.parentCollectionId, B256::ZERO);
+        assert_eq!(decoded.conditionId, CONDITION_ID.as_b256());
+        assert_eq!(decoded.partition, BINARY_PARTITION);
+        assert_eq!(decoded.amount, amount);
+
+        let merge = ctf.merge_positions(CONDITION_ID, amount);
+        let decoded = merge.decode::<IConditionalTokens::mergePositionsCall>()?;
+        assert_eq!(decoded.partition, BINARY_PARTITION);
+        assert_eq!(decoded.amount, amount);
+
+        let redeem = ctf.redeem_positions(CONDITION_ID);
+        let decoded = redeem.decode::<IConditionalTokens::redeemPositionsCall>()?;
+        assert_eq!(decoded.indexSets, BINARY_PARTITION);
+        assert!(
+            redeem
+                .decode::<IConditionalTokens::mergePositionsCall>()
+                .is_err(),
+            "calldata should only decode as the function it calls"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn neg_risk_calls_should_target_the_adapter() -> anyhow::Result<()> {
+        let adapter = NegRiskAdapter::new(POLYGON)?;
+        assert_eq!(
+            Some(adapter.address),
+            contract_config(POLYGON, true).unwrap().neg_risk_adapter
+        );
+
+        let split = adapter.split_position(CONDITION_ID, U256::from(1_000_000));
+        assert_eq!(split.to, adapter.address);
+        let decoded = split.decode::<INegRiskAdapter::splitPositionCall>()?;
+        assert_eq!(decoded.amount, U256::from(1_000_000));
+
+        let redeem = adapter.redeem_positions(CONDITION_ID, [U256::from(3_000_000), U256::ZERO]);
+        let decoded = redeem.decode::<INegRiskAdapter::redeemPositionsCall>()?;
+        assert_eq!(decoded.conditionId, CONDITION_ID.as_b256());
+        assert_eq!(decoded.amounts, [U256::from(3_000_000), U256::ZERO]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_chain_should_fail() {
+        ConditionalTokens::new(1).unwrap_err();
+        NegRiskAdapter::new(1).unwrap_err();
+    }
+}
+
+mod positions {
+    use polymarket_client_sdk::ctf::binary_position_ids;
+    use polymarket_client_sdk::types::TokenId;
+
+    use super::*;
+
+    #[test]
+    fn binary_position_ids_should_match_published_clob_token_ids() -> anyhow::Result<()> {
+        // "Will NVIDIA (NVDA) close above $240 end of January?", as announced on the market channel.
+        let condition_id: ConditionId =
+            "0x311d0c4b6671ab54af4970c06fcf58662516f5168997bdda209ec3db5aa6b0c1".parse()?;
+        let yes: TokenId =
+            "76043073756653678226373981964075571318267289248134717369284518995922789326425"
+                .parse()?;
+        let no: TokenId =
+            "31690934263385727664202099278545688007799199447969475608906331829650099442770"
+                .parse()?;
+
+        let collateral = contract_config(POLYGON, false).unwrap().collateral;
+        assert_eq!(binary_position_ids(collateral, condition_id)?, [yes, no]);
+        assert_eq!(
+            ConditionalTokens::new(POLYGON)?.position_ids(condition_id)?,
+            [yes, no]
+        );
+
+        Ok(())
+    }
+}
+
+/// Runs the [`Executor`] against a stand-in JSON-RPC node.
+mod executor {
+    use alloy::providers::ProviderBuilder;
+    use httpmock::Method::POST;
+    use httpmock::MockServer;
+    use polymarket_client_sdk::ctf::Executor;
+    use reqwest::StatusCode;
+    use serde_json::json;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn execute_should_send_the_call_as_a_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let tx_hash = B256::repeat_byte(0xab);
+
+        let call = ConditionalTokens::new(POLYGON)?.redeem_positions(CONDITION_ID);
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("\"method\":\"eth_sendTransaction\"")
+                .body_includes(call.to.to_string().to_lowercase())
+                .body_includes(call.data.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": tx_hash
+            }));
+        });
+
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect_http(server.base_url().parse()?);
+        let pending = Executor::new(provider).execute(call).await?;
+
+        assert_eq!(*pending.tx_hash(), tx_hash);
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn rejected_transaction_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(POST).path("/");
+            then.status(StatusCode::OK).json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "error": { "code": -32000, "message": "insufficient funds for gas" }
+            }));
+        });
+
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect_http(server.base_url().parse()?);
+        let call = NegRiskAdapter::new(POLYGON)?.merge_positions(CONDITION_ID, U256::from(1));
+        let err = Executor::new(provider).execute(call).await.unwrap_err();
+
+        assert!(err.to_string().contains("insufficient funds"), "{err}");
+
+        Ok(())
+    }
+}
@@ -1,18 +1,22 @@
 #![cfg(feature = "data")]
 
-use polymarket_client_sdk::types::{Address, address};
+use alloy::primitives::b256;
+use polymarket_client_sdk::types::{Address, ConditionId, address};
 
 const TEST_USER: Address = address!("1234567890abcdef1234567890abcdef12345678");
 const TEST_CONDITION_ID_STR: &str =
     "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890";
+const TEST_CONDITION_ID: ConditionId = ConditionId::new(b256!(
+    "abcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890"
+));
 const TEST_ASSET_STR: &str = "0x1111111111111111111111111111111111111111111111111111111111111111";
 
 fn test_user() -> Address {
     TEST_USER
 }
 
-fn test_condition_id() -> String {
-    TEST_CONDITION_ID_STR.to_owned()
+fn test_condition_id() -> ConditionId {
+    TEST_CONDITION_ID
 }
 
 fn test_asset() -> String {
@@ -102,7 +106,7 @@ mod positions {
         assert_eq!(response.len(), 1);
         let pos = &response[0];
         assert_eq!(pos.proxy_wallet, test_user());
-        assert_eq!(pos.condition_id.as_str(), test_condition_id().as_str());
+        assert_eq!(pos.condition_id, test_condition_id());
         assert_eq!(pos.size, dec!(100.5));
         assert_eq!(pos.title, "Will BTC hit $100k?");
         assert!(!pos.redeemable);
@@ -143,6 +147,7 @@ mod positions {
 }
 
 mod trades {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::data::{Client, types::Side, types::request::TradesRequest};
     use reqwest::StatusCode;
@@ -188,7 +193,7 @@ mod trades {
         assert_eq!(response.len(), 1);
         let trade = &response[0];
         assert_eq!(trade.proxy_wallet, test_user());
-        assert_eq!(trade.condition_id.as_str(), test_condition_id().as_str());
+        assert_eq!(trade.condition_id, test_condition_id());
         assert_eq!(trade.side, Side::Buy);
         assert_eq!(trade.size, dec!(50.0));
         assert_eq!(trade.price, dec!(0.55));
@@ -197,6 +202,95 @@ mod trades {
 
         Ok(())
     }
+
+    fn trade(timestamp: i64) -> serde_json::Value {
+        json!({
+            "proxyWallet": "0x1234567890abcdef1234567890abcdef12345678",
+            "side": "SELL",
+            "asset": "0x1111111111111111111111111111111111111111111111111111111111111111",
+            "conditionId": "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890",
+            "size": 10.0,
+            "price": 0.5,
+            "timestamp": timestamp,
+            "title": "Market Title",
+            "slug": "market-slug",
+            "icon": "https://example.com/icon.png",
+            "eventSlug": "event-slug",
+            "outcome": "No",
+            "outcomeIndex": 1,
+            "name": "Trader Name",
+            "pseudonym": "TraderX",
+            "bio": "A trader",
+            "profileImage": "https://example.com/avatar.png",
+            "profileImageOptimized": "https://example.com/avatar-opt.png",
+            "transactionHash": "0x2222222222222222222222222222222222222222222222222222222222222222"
+        })
+    }
+
+    #[tokio::test]
+    async fn trades_stream_should_page_until_short_page() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade(1), trade(2)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([trade(3)]));
+        });
+
+        let request = TradesRequest::builder().limit(2)?.build();
+        let trades: Vec<_> = client.trades_stream(&request).try_collect().await?;
+
+        let timestamps: Vec<_> = trades.iter().map(|trade| trade.timestamp).collect();
+        assert_eq!(timestamps, [1, 2, 3]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn trades_stream_should_stop_at_max_items() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/trades")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade(1), trade(2)]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET).path("/trades").query_param("offset", "2");
+            then.status(StatusCode::OK)
+                .json_body(json!([trade(3), trade(4)]));
+        });
+
+        let request = TradesRequest::builder().limit(2)?.build();
+        let trades: Vec<_> = client
+            .trades_stream(&request)
+            .max_items(2)
+            .try_collect()
+            .await?;
+
+        assert_eq!(trades.len(), 2);
+        first.assert();
+        second.assert_calls(0);
+
+        Ok(())
+    }
 }
 
 mod activity {
@@ -255,10 +349,7 @@ mod activity {
 
         assert_eq!(response.len(), 2);
         assert_eq!(response[0].proxy_wallet, test_user());
-        assert_eq!(
-            response[0].condition_id.as_str(),
-            test_condition_id().as_str()
-        );
+        assert_eq!(response[0].condition_id, test_condition_id());
         assert_eq!(response[0].activity_type, ActivityType::Trade);
         assert_eq!(response[0].side, Some(Side::Buy));
         assert_eq!(response[1].activity_type, ActivityType::Redeem);
@@ -423,10 +514,7 @@ mod closed_positions {
 
         assert_eq!(response.len(), 1);
         assert_eq!(response[0].proxy_wallet, test_user());
-        assert_eq!(
-            response[0].condition_id.as_str(),
-            test_condition_id().as_str()
-        );
+        assert_eq!(response[0].condition_id, test_condition_id());
         assert_eq!(response[0].realized_pnl, dec!(55.0));
         assert_eq!(response[0].cur_price, dec!(1.0));
         assert_eq!(response[0].timestamp, 1_703_980_800);
@@ -569,7 +657,7 @@ mod open_interest {
     use rust_decimal_macros::dec;
     use serde_json::json;
 
-    use super::test_condition_id;
+    use super::{TEST_CONDITION_ID_STR, test_condition_id};
 
     #[tokio::test]
     async fn open_interest_should_succeed() -> anyhow::Result<()> {
@@ -598,9 +686,9 @@ mod open_interest {
             .await?;
 
         assert_eq!(response.len(), 2);
-        assert_eq!(response[0].market.as_str(), test_condition_id().as_str());
+        assert_eq!(response[0].market, TEST_CONDITION_ID_STR);
         assert_eq!(response[0].value, dec!(1_500_000.0));
-        assert_eq!(response[1].market.as_str(), market2.as_str());
+        assert_eq!(response[1].market, market2);
         mock.assert();
 
         Ok(())
@@ -631,7 +719,7 @@ mod open_interest {
         let response = client.open_interest(&request).await?;
 
         assert_eq!(response.len(), 1);
-        assert_eq!(response[0].market.as_str(), test_condition_id().as_str());
+        assert_eq!(response[0].market, TEST_CONDITION_ID_STR);
         mock.assert();
 
         Ok(())
@@ -684,9 +772,9 @@ mod live_volume {
         assert_eq!(response[0].total, dec!(250_000.0));
         let markets = &response[0].markets;
         assert_eq!(markets.len(), 2);
-        assert_eq!(markets[0].market.as_str(), test_condition_id().as_str());
+        assert_eq!(markets[0].market, test_condition_id());
         assert_eq!(markets[0].value, dec!(150_000.0));
-        assert_eq!(markets[1].market.as_str(), market2.as_str());
+        assert_eq!(markets[1].market.to_string(), market2);
         mock.assert();
 
         Ok(())
@@ -964,7 +1052,7 @@ mod types {
     };
     use rust_decimal_macros::dec;
 
-    use super::address;
+    use super::{address, b256};
 
     #[test]
     fn bounded_limits() {
@@ -1061,12 +1149,12 @@ mod types {
 
     #[test]
     fn market_filter_query_string() {
-        let hash1 = "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".to_owned();
-        let hash2 = "0xaa22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".to_owned();
+        let hash1 = b256!("dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
+        let hash2 = b256!("aa22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917");
 
         let req = PositionsRequest::builder()
             .user(address!("56687bf447db6ffa42ffe2204a05edaa20f55839"))
-            .filter(MarketFilter::markets([hash1, hash2]))
+            .filter(MarketFilter::markets([hash1.into(), hash2.into()]))
             .build();
 
         let qs = req.query_params(None);
@@ -1252,14 +1340,16 @@ mod request_query_string_extended {
     };
     use rust_decimal_macros::dec;
 
-    use super::{Address, address};
+    use super::{Address, ConditionId, address, b256};
 
     fn test_addr() -> Address {
         address!("56687bf447db6ffa42ffe2204a05edaa20f55839")
     }
 
-    fn test_hash() -> String {
-        "0xdd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917".to_owned()
+    fn test_hash() -> ConditionId {
+        ConditionId::new(b256!(
+            "dd22472e552920b8438158ea7238bfadfa4f736aa4cee91a6b86c39ead110917"
+        ))
     }
 
     #[test]
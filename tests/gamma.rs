@@ -135,6 +135,7 @@ mod sports {
 }
 
 mod tags {
+    use futures_util::TryStreamExt as _;
     use httpmock::{Method::GET, MockServer};
     use polymarket_client_sdk::gamma::{
         Client,
@@ -182,6 +183,38 @@ mod tags {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn tags_stream_should_page_by_offset() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = Client::new(&server.base_url())?;
+
+        let first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags")
+                .query_param("limit", "2")
+                .query_param("offset", "0");
+            then.status(StatusCode::OK)
+                .json_body(json!([{ "id": "1" }, { "id": "2" }]));
+        });
+        let second = server.mock(|when, then| {
+            when.method(GET)
+                .path("/tags")
+                .query_param("limit", "2")
+                .query_param("offset", "2");
+            then.status(StatusCode::OK).json_body(json!([]));
+        });
+
+        let request = TagsRequest::builder().limit(2).build();
+        let tags: Vec<_> = client.tags_stream(&request).try_collect().await?;
+
+        let ids: Vec<_> = tags.iter().map(|tag| tag.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        first.assert();
+        second.assert();
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn tag_by_id_should_succeed() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -449,6 +482,7 @@ mod markets {
         Client,
         types::request::{MarketByIdRequest, MarketBySlugRequest, MarketsRequest},
     };
+    use polymarket_client_sdk::types::TokenId;
     use reqwest::StatusCode;
     use serde_json::json;
 
@@ -556,15 +590,15 @@ mod markets {
         let mock = server.mock(|when, then| {
             when.method(GET)
                 .path("/markets")
-                .query_param("clob_token_ids", "token1")
-                .query_param("clob_token_ids", "token2");
+                .query_param("clob_token_ids", "1")
+                .query_param("clob_token_ids", "2");
             then.status(StatusCode::OK).json_body(json!([
                 {"id": "1", "question": "Market 1?", "slug": "market-1"}
             ]));
         });
 
         let request = MarketsRequest::builder()
-            .clob_token_ids(vec!["token1".to_owned(), "token2".to_owned()])
+            .clob_token_ids(vec![TokenId::from(1), TokenId::from(2)])
             .build();
         let response = client.markets(&request).await?;
 
@@ -584,8 +618,8 @@ mod markets {
             when.method(GET)
                 .path("/markets")
                 .query_param("limit", "50")
-                .query_param("clob_token_ids", "abc")
-                .query_param("clob_token_ids", "def");
+                .query_param("clob_token_ids", "10")
+                .query_param("clob_token_ids", "11");
             then.status(StatusCode::OK).json_body(json!([
                 {"id": "1", "question": "Market 1?", "slug": "market-1"},
                 {"id": "2", "question": "Market 2?", "slug": "market-2"}
@@ -594,7 +628,7 @@ mod markets {
 
         let request = MarketsRequest::builder()
             .limit(50)
-            .clob_token_ids(vec!["abc".to_owned(), "def".to_owned()])
+            .clob_token_ids(vec![TokenId::from(10), TokenId::from(11)])
             .build();
         let response = client.markets(&request).await?;
 
@@ -996,6 +1030,7 @@ mod query_string {
         SeriesListRequest, TagByIdRequest, TagBySlugRequest, TagsRequest, TeamsRequest,
     };
     use polymarket_client_sdk::gamma::types::{ParentEntityType, RelatedTagsStatus};
+    use polymarket_client_sdk::types::TokenId;
     use rust_decimal_macros::dec;
     use serde::Serialize;
 
@@ -1245,8 +1280,12 @@ mod query_string {
             .ascending(false)
             .id(vec!["1".to_owned(), "2".to_owned()])
             .slug(vec!["market-1".to_owned()])
-            .clob_token_ids(vec!["token1".to_owned(), "token2".to_owned()])
-            .condition_ids(vec!["cond1".to_owned()])
+            .clob_token_ids(vec![TokenId::from(1), TokenId::from(2)])
+            .condition_ids(vec![
+                "0x1111111111111111111111111111111111111111111111111111111111111111"
+                    .parse()
+                    .unwrap(),
+            ])
             .market_maker_address(vec!["0x123".to_owned()])
             .liquidity_num_min(dec!(1000))
             .liquidity_num_max(dec!(100_000))
@@ -1278,10 +1317,12 @@ mod query_string {
         // clob_token_ids is handled separately via clob_token_ids_query() for repeated params
         assert!(!qs.contains("clob_token_ids"));
         let clob_qs = request.clob_token_ids_query();
-        assert!(clob_qs.contains("clob_token_ids=token1"));
-        assert!(clob_qs.contains("clob_token_ids=token2"));
+        assert!(clob_qs.contains("clob_token_ids=1"));
+        assert!(clob_qs.contains("clob_token_ids=2"));
         assert!(clob_qs.contains('&')); // Repeated params format
-        assert!(qs.contains("condition_ids=cond1"));
+        assert!(qs.contains(
+            "condition_ids=0x1111111111111111111111111111111111111111111111111111111111111111"
+        ));
         assert!(qs.contains("market_maker_address=0x123"));
         assert!(qs.contains("liquidity_num_min=1000"));
         assert!(qs.contains("liquidity_num_max=100000"));
@@ -0,0 +1,136 @@
+#![cfg(feature = "keystore")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::path::PathBuf;
+use std::str::FromStr as _;
+
+use alloy::signers::Signer as _;
+use alloy::signers::k256::elliptic_curve::rand_core::OsRng;
+use alloy::signers::local::{LocalSigner, PrivateKeySigner};
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::auth::{Credentials, ExposeSecret as _};
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::keystore::{Keystore, ScryptParams};
+use reqwest::StatusCode;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::common::{
+    API_KEY, BUILDER_API_KEY, BUILDER_PASSPHRASE, PASSPHRASE, POLY_ADDRESS, POLY_API_KEY,
+    POLY_PASSPHRASE, PRIVATE_KEY, SECRET,
+};
+
+const PASSWORD: &str = "correct horse battery staple";
+const PARAMS: ScryptParams = ScryptParams::new(4, 8, 1);
+
+fn temp_path() -> PathBuf {
+    std::env::temp_dir().join(format!("keystore-{}.json", Uuid::new_v4()))
+}
+
+fn keystore() -> anyhow::Result<Keystore> {
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+    let builder = Credentials::new(
+        BUILDER_API_KEY,
+        SECRET.to_owned(),
+        BUILDER_PASSPHRASE.to_owned(),
+    );
+
+    Ok(Keystore::new(signer, credentials).with_builder_credentials(builder))
+}
+
+#[test]
+fn save_should_round_trip() -> anyhow::Result<()> {
+    let keystore = keystore()?;
+    let path = temp_path();
+
+    keystore.save(&path, PASSWORD, PARAMS)?;
+    let loaded = Keystore::load(&path, PASSWORD);
+    std::fs::remove_file(&path)?;
+    let loaded = loaded?;
+
+    assert_eq!(loaded.signer().address(), keystore.signer().address());
+    assert_eq!(loaded.signer().chain_id(), Some(POLYGON));
+    assert_eq!(loaded.credentials().key(), API_KEY);
+    assert_eq!(loaded.credentials().secret().expose_secret(), SECRET);
+    let builder = loaded.builder_credentials().unwrap();
+    assert_eq!(builder.key(), BUILDER_API_KEY);
+    assert_eq!(builder.passphrase().expose_secret(), BUILDER_PASSPHRASE);
+
+    Ok(())
+}
+
+#[test]
+fn saved_key_should_be_readable_by_eth_keystore() -> anyhow::Result<()> {
+    let keystore = keystore()?;
+    let path = temp_path();
+
+    keystore.save(&path, PASSWORD, PARAMS)?;
+    let signer = PrivateKeySigner::decrypt_keystore(&path, PASSWORD);
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(signer?.address(), keystore.signer().address());
+
+    Ok(())
+}
+
+#[test]
+fn load_signer_should_read_eth_keystore_files() -> anyhow::Result<()> {
+    let dir = std::env::temp_dir();
+    let name = format!("keystore-{}.json", Uuid::new_v4());
+    let key = LocalSigner::from_str(PRIVATE_KEY)?.to_bytes();
+
+    let (written, _) =
+        PrivateKeySigner::encrypt_keystore(&dir, &mut OsRng, key, PASSWORD, Some(&name))?;
+    let path = dir.join(name);
+    let signer = Keystore::load_signer(&path, PASSWORD);
+    let keystore = Keystore::load(&path, PASSWORD);
+    std::fs::remove_file(&path)?;
+
+    assert_eq!(signer?.address(), written.address());
+    let err = keystore.unwrap_err();
+    assert!(
+        err.to_string().contains("no `credentials` section"),
+        "{err}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn authentication_builder_from_keystore_should_use_stored_credentials() -> anyhow::Result<()>
+{
+    let server = MockServer::start();
+    let keystore = keystore()?;
+
+    let client = Client::new(&server.base_url(), Config::default())?
+        .authentication_builder_from_keystore(&keystore)
+        .authenticate()
+        .await?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET)
+            .path("/auth/api-keys")
+            .header(
+                POLY_ADDRESS,
+                keystore.signer().address().to_string().to_lowercase(),
+            )
+            .header(POLY_API_KEY, API_KEY)
+            .header(POLY_PASSPHRASE, PASSPHRASE);
+        then.status(StatusCode::OK)
+            .json_body(json!({"apiKeys": [API_KEY]}));
+    });
+
+    client.api_keys().await?;
+
+    mock.assert();
+
+    Ok(())
+}
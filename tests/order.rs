@@ -12,12 +12,13 @@ use chrono::{DateTime, Utc};
 use httpmock::MockServer;
 use polymarket_client_sdk::clob::types::response::OrderSummary;
 use polymarket_client_sdk::clob::types::{Amount, OrderType, Side, SignatureType, TickSize};
-use polymarket_client_sdk::types::{Address, Decimal, address};
+use polymarket_client_sdk::types::{Address, Decimal, TokenId, address};
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 
 use crate::common::{
-    TOKEN_1, USDC_DECIMALS, create_authenticated, ensure_requirements, to_decimal,
+    MARKET, TIMESTAMP, TOKEN_1, USDC_DECIMALS, create_authenticated,
+    create_authenticated_with_config, ensure_requirements, to_decimal,
 };
 
 /// Tests for the lifecycle of a [`Client`] as it moves from [`Unauthenticated`] to [`Authenticated`]
@@ -37,12 +38,12 @@ mod lifecycle {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        ensure_requirements(&server, "1", TickSize::Tenth);
-        ensure_requirements(&server, "2", TickSize::Thousandth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(2), TickSize::Thousandth);
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -52,7 +53,7 @@ mod lifecycle {
 
         let signable_order_2 = client
             .limit_order()
-            .token_id("2")
+            .token_id(TokenId::from(2))
             .price(dec!(0.512))
             .size(Decimal::ONE_HUNDRED)
             .side(Side::Buy)
@@ -88,12 +89,12 @@ mod lifecycle {
             .authenticate()
             .await?;
 
-        ensure_requirements(&server, "1", TickSize::Tenth);
-        ensure_requirements(&server, "2", TickSize::Thousandth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(2), TickSize::Thousandth);
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -103,7 +104,7 @@ mod lifecycle {
 
         let signable_order_2 = client
             .limit_order()
-            .token_id("2")
+            .token_id(TokenId::from(2))
             .price(dec!(0.512))
             .size(Decimal::ONE_HUNDRED)
             .side(Side::Buy)
@@ -142,11 +143,11 @@ mod lifecycle {
             .authenticate()
             .await?;
 
-        ensure_requirements(&server, "1", TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Tenth);
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -169,7 +170,7 @@ mod lifecycle {
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -212,11 +213,11 @@ mod lifecycle {
 
         mock.assert();
 
-        ensure_requirements(&server, "1", TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Tenth);
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -233,11 +234,11 @@ mod lifecycle {
         assert_eq!(signable_order.order.side, Side::Buy as u8);
         assert_ne!(signable_order.order.maker, signable_order.order.signer);
 
-        ensure_requirements(&server, "2", TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(2), TickSize::Tenth);
 
         let signable_order = client
             .limit_order()
-            .token_id("2")
+            .token_id(TokenId::from(2))
             .size(Decimal::TEN)
             .price(dec!(0.2))
             .nonce(2)
@@ -285,11 +286,11 @@ mod lifecycle {
 
         mock.assert();
 
-        ensure_requirements(&server, "1", TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(1), TickSize::Tenth);
 
         let signable_order = client
             .limit_order()
-            .token_id("1")
+            .token_id(TokenId::from(1))
             .size(Decimal::ONE_HUNDRED)
             .price(dec!(0.1))
             .nonce(1)
@@ -306,7 +307,7 @@ mod lifecycle {
         assert_eq!(signable_order.order.side, Side::Buy as u8);
         assert_ne!(signable_order.order.maker, signable_order.order.signer);
 
-        ensure_requirements(&server, "2", TickSize::Tenth);
+        ensure_requirements(&server, TokenId::from(2), TickSize::Tenth);
 
         client.deauthenticate()?;
         let client = Client::new(&server.base_url(), Config::default())?
@@ -316,7 +317,7 @@ mod lifecycle {
 
         let signable_order = client
             .limit_order()
-            .token_id("2")
+            .token_id(TokenId::from(2))
             .size(Decimal::TEN)
             .price(dec!(0.2))
             .nonce(2)
@@ -564,6 +565,9 @@ mod lifecycle {
 }
 
 mod limit {
+    use chrono::TimeDelta;
+    use polymarket_client_sdk::clob::Config;
+    use polymarket_client_sdk::clob::clock::ClockSync;
     use polymarket_client_sdk::error::Validation;
 
     use super::*;
@@ -593,6 +597,40 @@ mod limit {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn gtd_expiration_should_use_server_clock() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let config = Config::builder()
+            .clock_sync(ClockSync::builder().samples(2).build())
+            .build();
+        let client = create_authenticated_with_config(&server, config).await?;
+
+        ensure_requirements(&server, TOKEN_1, TickSize::Tenth);
+
+        // The mocked server clock reads `TIMESTAMP`, so an hour from now locally is an hour
+        // after `TIMESTAMP` on the server
+        let expiration = Utc::now() + TimeDelta::hours(1);
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(21.04))
+            .side(Side::Buy)
+            .order_type(OrderType::GTD)
+            .expiration(expiration)
+            .build()
+            .await?;
+
+        let expected = TIMESTAMP.parse::<u64>()? + 3600;
+        let expiration = signable_order.order.expiration.to::<u64>();
+        assert!(
+            expiration.abs_diff(expected) <= 1,
+            "{expiration} != {expected}"
+        );
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn should_fail_on_missing_fields() -> anyhow::Result<()> {
         let server = MockServer::start();
@@ -730,7 +768,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(10_520_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -770,7 +808,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(11_782_400));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -810,7 +848,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(1_178_240));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -850,7 +888,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(117_824));
             assert_eq!(signable_order.order.takerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -992,7 +1030,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(10_520_000));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1032,7 +1070,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(11_782_400));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1072,7 +1110,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(1_178_240));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1112,7 +1150,7 @@ mod limit {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(21_040_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(117_824));
             assert_eq!(signable_order.order.expiration, U256::from(50000));
@@ -1248,20 +1286,20 @@ mod limit {
         let server = MockServer::start();
         let client = create_authenticated(&server).await?;
 
-        let token_id = "123".to_owned();
-        let token_id_2 = "456".to_owned();
+        let token_id = TokenId::from(123);
+        let token_id_2 = TokenId::from(456);
 
-        ensure_requirements(&server, &token_id, TickSize::Thousandth);
-        ensure_requirements(&server, &token_id_2, TickSize::Hundredth);
+        ensure_requirements(&server, token_id, TickSize::Thousandth);
+        ensure_requirements(&server, token_id_2, TickSize::Hundredth);
 
         assert_eq!(
-            client.tick_size(&token_id).await?.minimum_tick_size,
+            client.tick_size(token_id).await?.minimum_tick_size,
             TickSize::Thousandth
         );
 
         let signable_order = client
             .limit_order()
-            .token_id(token_id.clone())
+            .token_id(token_id)
             .price(dec!(0.512))
             .size(Decimal::ONE_HUNDRED)
             .side(Side::Buy)
@@ -1270,7 +1308,7 @@ mod limit {
 
         assert_eq!(signable_order.order.maker, client.address());
         assert_eq!(signable_order.order.taker, Address::ZERO);
-        assert_eq!(signable_order.order.tokenId.to_string(), token_id);
+        assert_eq!(TokenId::from(signable_order.order.tokenId), token_id);
         assert_eq!(signable_order.order.makerAmount, U256::from(51_200_000));
         assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000));
         assert_eq!(signable_order.order.expiration, U256::ZERO);
@@ -1281,7 +1319,7 @@ mod limit {
 
         let signable_order = client
             .limit_order()
-            .token_id(token_id_2.clone())
+            .token_id(token_id_2)
             .price(dec!(0.78))
             .size(dec!(12.82))
             .side(Side::Buy)
@@ -1290,7 +1328,7 @@ mod limit {
 
         assert_eq!(signable_order.order.maker, client.address());
         assert_eq!(signable_order.order.taker, Address::ZERO);
-        assert_eq!(signable_order.order.tokenId.to_string(), token_id_2);
+        assert_eq!(TokenId::from(signable_order.order.tokenId), token_id_2);
         assert_eq!(signable_order.order.makerAmount, U256::from(9_999_600));
         assert_eq!(signable_order.order.takerAmount, U256::from(12_820_000));
         assert_eq!(signable_order.order.expiration, U256::ZERO);
@@ -1301,7 +1339,7 @@ mod limit {
 
         let _order = client
             .limit_order()
-            .token_id(token_id_2.clone())
+            .token_id(token_id_2)
             .order_type(OrderType::GTC)
             .price(dec!(0.78))
             .size(dec!(12.82))
@@ -1321,7 +1359,7 @@ mod market {
 
     fn ensure_requirements_for_market_price(
         server: &MockServer,
-        token_id: &str,
+        token_id: TokenId,
         bids: &[OrderSummary],
         asks: &[OrderSummary],
     ) {
@@ -1330,9 +1368,9 @@ mod market {
         server.mock(|when, then| {
             when.method(httpmock::Method::GET)
                 .path("/book")
-                .query_param("token_id", token_id);
+                .query_param("token_id", token_id.to_string());
             then.status(StatusCode::OK).json_body(json!({
-                "market": "market",
+                "market": MARKET,
                 "asset_id": token_id,
                 "timestamp": "1000",
                 "bids": bids,
@@ -1346,7 +1384,7 @@ mod market {
         server.mock(|when, then| {
             when.method(httpmock::Method::GET)
                 .path("/tick-size")
-                .query_param("token_id", token_id);
+                .query_param("token_id", token_id.to_string());
             then.status(StatusCode::OK).json_body(json!({
                 "minimum_tick_size": minimum_tick_size.as_decimal(),
             }));
@@ -1355,7 +1393,7 @@ mod market {
         server.mock(|when, then| {
             when.method(httpmock::Method::GET)
                 .path("/fee-rate")
-                .query_param("token_id", token_id);
+                .query_param("token_id", token_id.to_string());
             then.status(StatusCode::OK)
                 .json_body(json!({ "base_fee": 0 }));
         });
@@ -1921,7 +1959,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(200_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -1970,7 +2008,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(178_571_400));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2019,7 +2057,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(1_785_714_280));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2068,7 +2106,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(
                 signable_order.order.takerAmount,
@@ -2872,7 +2910,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(50_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2921,7 +2959,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(56_000_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -2970,7 +3008,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(5_600_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -3019,7 +3057,7 @@ mod market {
             assert_eq!(signable_order.order.maker, client.address());
             assert_eq!(signable_order.order.signer, client.address());
             assert_eq!(signable_order.order.taker, Address::ZERO);
-            assert_eq!(signable_order.order.tokenId.to_string(), TOKEN_1);
+            assert_eq!(TokenId::from(signable_order.order.tokenId), TOKEN_1);
             assert_eq!(signable_order.order.makerAmount, U256::from(100_000_000));
             assert_eq!(signable_order.order.takerAmount, U256::from(560_000));
             assert_eq!(signable_order.order.expiration, U256::from(0));
@@ -3122,4 +3160,454 @@ mod market {
         assert_eq!(msg, "Sell Orders must specify their `amount`s in shares");
         Ok(())
     }
+
+    #[tokio::test]
+    async fn should_fail_on_excessive_slippage() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        ensure_requirements_for_market_price(
+            &server,
+            TOKEN_1,
+            &[OrderSummary::builder()
+                .price(dec!(0.4))
+                .size(Decimal::ONE_HUNDRED)
+                .build()],
+            &[
+                OrderSummary::builder()
+                    .price(dec!(0.6))
+                    .size(Decimal::ONE_HUNDRED)
+                    .build(),
+                OrderSummary::builder()
+                    .price(dec!(0.5))
+                    .size(Decimal::ONE_HUNDRED)
+                    .build(),
+            ],
+        );
+
+        let preview = client
+            .preview_market_order(TOKEN_1, Side::Buy, Amount::shares(dec!(150))?)
+            .await?;
+        assert_eq!(preview.midpoint, Some(dec!(0.45)));
+        assert_eq!(preview.worst_price, dec!(0.6));
+        assert!(preview.fills_fully, "the asks hold 200 shares");
+
+        // Filling 50 shares stays within the 0.5 level, 1111 bps above the midpoint
+        client
+            .market_order()
+            .token_id(TOKEN_1)
+            .amount(Amount::shares(dec!(50))?)
+            .side(Side::Buy)
+            .max_slippage_bps(dec!(1200))
+            .build()
+            .await?;
+
+        let err = client
+            .market_order()
+            .token_id(TOKEN_1)
+            .amount(Amount::shares(dec!(150))?)
+            .side(Side::Buy)
+            .max_slippage_bps(dec!(1200))
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert!(
+            msg.starts_with("Expected slippage of")
+                && msg.ends_with("exceeds the maximum of 1200 bps"),
+            "unexpected message {msg}"
+        );
+
+        Ok(())
+    }
+}
+
+/// Tests for building and signing orders from [`MarketParams`] without querying the CLOB
+mod offline {
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use polymarket_client_sdk::POLYGON;
+    use polymarket_client_sdk::clob::types::MarketParams;
+    use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+    use polymarket_client_sdk::error::Validation;
+
+    use super::*;
+    use crate::common::PRIVATE_KEY;
+
+    fn book(asks: Vec<OrderSummary>) -> OrderBookSummaryResponse {
+        OrderBookSummaryResponse::builder()
+            .market(MARKET.parse().unwrap())
+            .asset_id(TOKEN_1)
+            .timestamp(DateTime::<Utc>::UNIX_EPOCH)
+            .asks(asks)
+            .min_order_size(dec!(5))
+            .neg_risk(true)
+            .tick_size(TickSize::Hundredth)
+            .build()
+    }
+
+    #[tokio::test]
+    async fn gtd_order_should_build_without_measuring_a_stale_clock() -> anyhow::Result<()> {
+        use std::time::Duration;
+
+        use chrono::TimeDelta;
+        use polymarket_client_sdk::clob::Config;
+        use polymarket_client_sdk::clob::clock::ClockSync;
+
+        use crate::common::create_authenticated_with_config;
+
+        let server = MockServer::start();
+        let config = Config::builder()
+            .clock_sync(
+                ClockSync::builder()
+                    .samples(2)
+                    .refresh_interval(Duration::from_millis(50))
+                    .build(),
+            )
+            .build();
+        let client = create_authenticated_with_config(&server, config).await?;
+        let skew = client.clock_skew().unwrap();
+
+        // Let the offset go stale, then fail every request the build makes
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.reset();
+        let any_request = server.mock(|_, then| {
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        let expiration = Utc::now() + TimeDelta::hours(1);
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.5))
+            .size(dec!(10))
+            .side(Side::Buy)
+            .order_type(OrderType::GTD)
+            .expiration(expiration)
+            .market_params(
+                MarketParams::builder()
+                    .tick_size(TickSize::Hundredth)
+                    .fee_rate_bps(0)
+                    .neg_risk(false)
+                    .build(),
+            )
+            .build()
+            .await?;
+
+        any_request.assert_calls(0);
+        let expected = (expiration + skew.offset).timestamp().cast_unsigned();
+        assert_eq!(signable_order.order.expiration.to::<u64>(), expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn limit_order_should_build_and_sign_without_requests() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        // No market data is mocked, so any request would fail the build or the signature
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(100)
+            .neg_risk(true)
+            .build();
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(100))
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await?;
+
+        assert_eq!(signable_order.neg_risk, Some(true));
+        assert_eq!(signable_order.order.feeRateBps, U256::from(100));
+        assert_eq!(signable_order.order.makerAmount, U256::from(34_000_000));
+        assert_eq!(signable_order.order.takerAmount, U256::from(100_000_000));
+
+        let signed_order = client.sign(&signer, signable_order).await?;
+        assert_eq!(signed_order.order.makerAmount, U256::from(34_000_000));
+
+        let err = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.345))
+            .size(dec!(100))
+            .side(Side::Buy)
+            .market_params(
+                MarketParams::builder()
+                    .tick_size(TickSize::Hundredth)
+                    .fee_rate_bps(0)
+                    .neg_risk(false)
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap_err();
+        assert!(
+            err.downcast_ref::<Validation>().is_some(),
+            "tick size validation should still apply: {err}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn neg_risk_market_params_should_sign_for_neg_risk_exchange() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(true)
+            .build();
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(100))
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await?;
+        let signed_order = client.sign(&signer, signable_order).await?;
+
+        let neg_risk_hash = signed_order.order_hash(POLYGON, true)?;
+        assert_eq!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&neg_risk_hash)?,
+            signer.address(),
+            "order should be signed for the neg risk exchange"
+        );
+        let regular_hash = signed_order.order_hash(POLYGON, false)?;
+        assert_ne!(
+            signed_order
+                .signature
+                .recover_address_from_prehash(&regular_hash)?,
+            signer.address()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_order_should_price_from_supplied_book() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let params = MarketParams::builder()
+            .tick_size(TickSize::Hundredth)
+            .fee_rate_bps(0)
+            .neg_risk(false)
+            .book(book(vec![
+                OrderSummary::builder()
+                    .price(dec!(0.6))
+                    .size(dec!(100))
+                    .build(),
+                OrderSummary::builder()
+                    .price(dec!(0.5))
+                    .size(dec!(100))
+                    .build(),
+            ]))
+            .build();
+
+        let signable_order = client
+            .market_order()
+            .token_id(TOKEN_1)
+            .amount(Amount::usdc(dec!(80))?)
+            .side(Side::Buy)
+            .market_params(params)
+            .build()
+            .await?;
+
+        // $50 at 0.5 is not enough, so the order has to reach into the 0.6 level
+        let price = to_decimal(signable_order.order.makerAmount)
+            / to_decimal(signable_order.order.takerAmount);
+        assert_eq!(price.round_dp(2), dec!(0.6));
+        assert_eq!(signable_order.neg_risk, Some(false));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_order_without_price_or_book_should_fail() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = create_authenticated(&server).await?;
+
+        let err = client
+            .market_order()
+            .token_id(TOKEN_1)
+            .amount(Amount::usdc(dec!(80))?)
+            .side(Side::Buy)
+            .market_params(
+                MarketParams::builder()
+                    .tick_size(TickSize::Hundredth)
+                    .fee_rate_bps(0)
+                    .neg_risk(false)
+                    .build(),
+            )
+            .build()
+            .await
+            .unwrap_err();
+        let msg = &err.downcast_ref::<Validation>().unwrap().reason;
+
+        assert_eq!(
+            msg,
+            "Unable to price a market order from MarketParams without a book"
+        );
+
+        Ok(())
+    }
+}
+
+mod verification {
+    use alloy::signers::Signer as _;
+    use alloy::signers::local::LocalSigner;
+    use polymarket_client_sdk::clob::types::{MarketParams, SignedOrder};
+    use polymarket_client_sdk::clob::{Client, Config};
+    use polymarket_client_sdk::error::Validation;
+    use polymarket_client_sdk::{POLYGON, derive_safe_wallet};
+    use serde_json::json;
+
+    use super::*;
+    use crate::common::{API_KEY, PASSPHRASE, POLY_ADDRESS, PRIVATE_KEY, SECRET};
+
+    async fn sign(
+        server: &MockServer,
+        signature_type: SignatureType,
+        funder: Option<Address>,
+    ) -> anyhow::Result<SignedOrder> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        server.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/auth/derive-api-key")
+                .header(POLY_ADDRESS, signer.address().to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "apiKey": API_KEY.to_string(),
+                "passphrase": PASSPHRASE,
+                "secret": SECRET
+            }));
+        });
+
+        let mut builder = Client::new(&server.base_url(), Config::default())?
+            .authentication_builder(&signer)
+            .signature_type(signature_type);
+        if let Some(funder) = funder {
+            builder = builder.funder(funder);
+        }
+        let client = builder.authenticate().await?;
+
+        let signable_order = client
+            .limit_order()
+            .token_id(TOKEN_1)
+            .price(dec!(0.34))
+            .size(dec!(100))
+            .side(Side::Buy)
+            .market_params(
+                MarketParams::builder()
+                    .tick_size(TickSize::Hundredth)
+                    .fee_rate_bps(0)
+                    .neg_risk(false)
+                    .build(),
+            )
+            .build()
+            .await?;
+
+        Ok(client.sign(&signer, signable_order).await?)
+    }
+
+    fn reason(err: &polymarket_client_sdk::error::Error) -> &str {
+        &err.downcast_ref::<Validation>().unwrap().reason
+    }
+
+    #[tokio::test]
+    async fn order_hash_should_be_signed_by_signer() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+        let signed_order = sign(&server, SignatureType::Eoa, None).await?;
+
+        let hash = signed_order.order_hash(POLYGON, false)?;
+        assert_eq!(
+            signed_order.signature.recover_address_from_prehash(&hash)?,
+            signer.address()
+        );
+        assert_ne!(hash, signed_order.order_hash(POLYGON, true)?);
+        signed_order.verify(POLYGON, false)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn tampered_order_should_not_verify() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let mut signed_order = sign(&server, SignatureType::Eoa, None).await?;
+
+        let err = signed_order.verify(POLYGON, true).unwrap_err();
+        assert!(
+            reason(&err).starts_with("Order is signed by"),
+            "signing for the other exchange should not verify: {err}"
+        );
+
+        signed_order.order.makerAmount = U256::from(35_000_000);
+        let err = signed_order.verify(POLYGON, false).unwrap_err();
+        assert!(reason(&err).starts_with("Order is signed by"), "{err}");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn safe_order_should_verify_against_derived_wallet() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?;
+        let signed_order = sign(&server, SignatureType::GnosisSafe, None).await?;
+
+        assert_eq!(
+            Some(signed_order.order.maker),
+            derive_safe_wallet(signer.address(), POLYGON)
+        );
+        signed_order.verify(POLYGON, false)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn maker_not_controlled_by_signer_should_not_verify() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let funder = address!("0xaDEFf2158d668f64308C62ef227C5CcaCAAf976D");
+
+        for signature_type in [SignatureType::Proxy, SignatureType::GnosisSafe] {
+            let signed_order = sign(&server, signature_type, Some(funder)).await?;
+
+            let err = signed_order.verify(POLYGON, false).unwrap_err();
+            assert!(
+                reason(&err).starts_with(&format!("Order maker {funder} is not the")),
+                "{err}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn signed_order_should_verify_after_round_trip() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signed_order = sign(&server, SignatureType::GnosisSafe, None).await?;
+
+        let json = serde_json::to_string(&signed_order)?;
+        let deserialized: SignedOrder = serde_json::from_str(&json)?;
+
+        assert_eq!(deserialized, signed_order);
+        assert_eq!(serde_json::to_string(&deserialized)?, json);
+        deserialized.verify(POLYGON, false)?;
+
+        Ok(())
+    }
 }
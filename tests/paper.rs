@@ -0,0 +1,452 @@
+#![cfg(feature = "paper")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::str::FromStr as _;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use alloy::signers::Signer as _;
+use alloy::signers::local::LocalSigner;
+use chrono::{DateTime, Utc};
+use futures_util::StreamExt as _;
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::clob::paper::{Client as PaperClient, Config as PaperConfig};
+use polymarket_client_sdk::clob::types::request::{
+    BalanceAllowanceRequest, OrdersRequest, TradesRequest,
+};
+use polymarket_client_sdk::clob::types::response::{OrderBookSummaryResponse, OrderSummary};
+use polymarket_client_sdk::clob::types::{
+    Amount, AssetType, MarketParams, OrderStatusType, OrderType, Side, SignedOrder, TickSize,
+    TraderSide,
+};
+use polymarket_client_sdk::clob::ws::WsMessage;
+use polymarket_client_sdk::error::{Kind, Status};
+use polymarket_client_sdk::types::Decimal;
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+use crate::common::{MARKET, PRIVATE_KEY, TOKEN_1, TestClient, create_authenticated};
+
+/// Seconds since the epoch reported by [`clock`], so tests can move time forward.
+static NOW: AtomicI64 = AtomicI64::new(40_000);
+
+fn clock() -> DateTime<Utc> {
+    DateTime::from_timestamp(NOW.load(Ordering::SeqCst), 0).unwrap()
+}
+
+async fn setup(fee_rate_bps: u32) -> anyhow::Result<(MockServer, TestClient)> {
+    let server = MockServer::start();
+    let client = create_authenticated(&server).await?;
+
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/neg-risk");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "neg_risk": false }));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/fee-rate");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "base_fee": fee_rate_bps }));
+    });
+    server.mock(|when, then| {
+        when.method(httpmock::Method::GET).path("/tick-size");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "minimum_tick_size": TickSize::Hundredth.as_decimal() }));
+    });
+
+    Ok((server, client))
+}
+
+async fn order(
+    client: &TestClient,
+    side: Side,
+    price: Decimal,
+    size: Decimal,
+    order_type: OrderType,
+) -> anyhow::Result<SignedOrder> {
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let mut builder = client
+        .limit_order()
+        .token_id(TOKEN_1)
+        .price(price)
+        .size(size)
+        .side(side)
+        .order_type(order_type);
+    if order_type == OrderType::GTD {
+        builder = builder.expiration(DateTime::from_timestamp(50_000, 0).unwrap());
+    }
+    let signable = builder.build().await?;
+
+    Ok(client.sign(&signer, signable).await?)
+}
+
+fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBookSummaryResponse {
+    let levels = |levels: &[(Decimal, Decimal)]| {
+        levels
+            .iter()
+            .map(|(price, size)| OrderSummary::builder().price(*price).size(*size).build())
+            .collect::<Vec<_>>()
+    };
+
+    OrderBookSummaryResponse::builder()
+        .market(MARKET.parse().unwrap())
+        .asset_id(TOKEN_1)
+        .timestamp(clock())
+        .bids(levels(bids))
+        .asks(levels(asks))
+        .min_order_size(dec!(5))
+        .neg_risk(false)
+        .tick_size(TickSize::Hundredth)
+        .build()
+}
+
+fn paper(collateral: Decimal, shares: Decimal) -> PaperClient {
+    PaperClient::new(
+        PaperConfig::builder()
+            .collateral(collateral)
+            .positions([(TOKEN_1, shares)].into())
+            .clock(clock)
+            .build(),
+    )
+}
+
+async fn balance(paper: &PaperClient, asset_type: AssetType) -> anyhow::Result<Decimal> {
+    let request = BalanceAllowanceRequest::builder()
+        .asset_type(asset_type)
+        .token_id(TOKEN_1)
+        .build();
+
+    Ok(paper.balance_allowance(request).await?.balance)
+}
+
+fn assert_rejected(err: &polymarket_client_sdk::error::Error) {
+    assert_eq!(err.kind(), Kind::Status, "expected a rejection, got {err}");
+    let status = err.downcast_ref::<Status>().unwrap();
+    assert_eq!(
+        status.status_code,
+        StatusCode::BAD_REQUEST,
+        "rejections should mirror the exchange's 400"
+    );
+}
+
+#[tokio::test]
+async fn gtc_order_should_take_liquidity_then_rest_until_crossed() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(100), Decimal::ZERO);
+    let mut events = Box::pin(paper.subscribe_user_events());
+    paper.update_book(&book(
+        &[(dec!(0.4), dec!(50))],
+        &[(dec!(0.5), dec!(10)), (dec!(0.6), dec!(10))],
+    ));
+
+    let buy = order(&client, Side::Buy, dec!(0.55), dec!(15), OrderType::GTC).await?;
+    let order_hash = buy.order_hash(POLYGON, false)?;
+    let response = paper.post_order(buy).await?;
+    assert!(response.success, "order should be accepted");
+    assert_eq!(response.order_id, order_hash.to_string());
+    assert_eq!(response.status, OrderStatusType::Live);
+    assert_eq!(response.making_amount, dec!(5));
+    assert_eq!(response.taking_amount, dec!(10));
+    assert_eq!(response.trade_ids.len(), 1);
+
+    let open = paper.orders(&OrdersRequest::default(), None).await?;
+    assert_eq!(open.count, 1);
+    assert_eq!(open.data[0].id, response.order_id);
+    assert_eq!(open.data[0].market.to_string(), MARKET);
+    assert_eq!(open.data[0].size_matched, dec!(10));
+    assert_eq!(open.data[0].price, dec!(0.55));
+
+    // 5 USDC spent, 2.75 USDC reserved by the resting remainder
+    assert_eq!(
+        balance(&paper, AssetType::Collateral).await?,
+        dec!(95_000_000)
+    );
+    assert_eq!(
+        balance(&paper, AssetType::Conditional).await?,
+        dec!(10_000_000)
+    );
+
+    let message: WsMessage = serde_json::from_value(json!({
+        "event_type": "price_change",
+        "market": MARKET,
+        "timestamp": "1",
+        "price_changes": [{ "asset_id": TOKEN_1, "price": "0.55", "size": "20", "side": "SELL" }]
+    }))?;
+    paper.apply(&message)?;
+
+    let open = paper.orders(&OrdersRequest::default(), None).await?;
+    assert_eq!(open.count, 0);
+
+    let trades = paper.trades(&TradesRequest::default(), None).await?;
+    assert_eq!(trades.count, 2);
+    assert_eq!(trades.data[0].trader_side, TraderSide::Taker);
+    assert_eq!(trades.data[0].price, dec!(0.5));
+    assert_eq!(trades.data[1].trader_side, TraderSide::Maker);
+    assert_eq!(trades.data[1].price, dec!(0.55));
+    assert_eq!(trades.data[1].maker_orders[0].order_id, response.order_id);
+
+    assert_eq!(
+        balance(&paper, AssetType::Collateral).await?,
+        dec!(92_250_000)
+    );
+    assert_eq!(
+        balance(&paper, AssetType::Conditional).await?,
+        dec!(15_000_000)
+    );
+
+    let kinds: Vec<_> = events
+        .by_ref()
+        .take(4)
+        .map(|event| match event.unwrap() {
+            WsMessage::Trade(trade) => format!("TRADE {}", trade.size),
+            WsMessage::Order(order) => order.msg_type.unwrap(),
+            other => panic!("unexpected event {other:?}"),
+        })
+        .collect()
+        .await;
+    assert_eq!(kinds, ["TRADE 10", "PLACEMENT", "TRADE 5", "UPDATE"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fok_order_should_be_rejected_without_enough_liquidity() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(100), Decimal::ZERO);
+    paper.update_book(&book(&[], &[(dec!(0.5), dec!(10))]));
+
+    let buy = order(&client, Side::Buy, dec!(0.5), dec!(15), OrderType::FOK).await?;
+    let err = paper.post_order(buy).await.unwrap_err();
+    assert_rejected(&err);
+
+    assert_eq!(
+        balance(&paper, AssetType::Collateral).await?,
+        dec!(100_000_000)
+    );
+    assert_eq!(
+        paper.trades(&TradesRequest::default(), None).await?.count,
+        0
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn price_change_without_size_should_keep_level() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(100), Decimal::ZERO);
+    paper.update_book(&book(&[], &[(dec!(0.5), dec!(10))]));
+
+    let message: WsMessage = serde_json::from_value(json!({
+        "event_type": "price_change",
+        "market": MARKET,
+        "timestamp": "1",
+        "price_changes": [{ "asset_id": TOKEN_1, "price": "0.5", "side": "SELL" }]
+    }))?;
+    paper.apply(&message)?;
+
+    let buy = order(&client, Side::Buy, dec!(0.5), dec!(10), OrderType::FOK).await?;
+    let response = paper.post_order(buy).await?;
+    assert_eq!(response.status, OrderStatusType::Matched);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fak_order_should_cancel_unfilled_remainder() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(Decimal::ZERO, dec!(20));
+    paper.update_book(&book(&[(dec!(0.4), dec!(5))], &[]));
+
+    let sell = order(&client, Side::Sell, dec!(0.4), dec!(8), OrderType::FAK).await?;
+    let response = paper.post_order(sell).await?;
+    assert_eq!(response.status, OrderStatusType::Matched);
+    assert_eq!(response.making_amount, dec!(5));
+    assert_eq!(response.taking_amount, dec!(2));
+
+    assert_eq!(
+        paper.orders(&OrdersRequest::default(), None).await?.count,
+        0
+    );
+    assert_eq!(
+        balance(&paper, AssetType::Conditional).await?,
+        dec!(15_000_000)
+    );
+
+    // The book is now empty, so a second FAK has nothing to match
+    let sell = order(&client, Side::Sell, dec!(0.4), dec!(8), OrderType::FAK).await?;
+    let responses = paper.post_orders(vec![sell]).await?;
+    assert!(!responses[0].success, "FAK without liquidity should fail");
+    assert!(
+        responses[0].error_msg.is_some(),
+        "failure should be explained"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn taker_fills_should_pay_fees() -> anyhow::Result<()> {
+    let (_server, client) = setup(100).await?;
+    let paper = paper(Decimal::ZERO, dec!(10));
+    paper.update_book(&book(&[(dec!(0.4), dec!(10))], &[]));
+
+    let sell = order(&client, Side::Sell, dec!(0.4), dec!(10), OrderType::FOK).await?;
+    let response = paper.post_order(sell).await?;
+
+    // 1% of min(0.4, 0.6) * 10 shares
+    assert_eq!(response.taking_amount, dec!(3.96));
+    assert_eq!(
+        balance(&paper, AssetType::Collateral).await?,
+        dec!(3_960_000)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn market_buy_should_fill_despite_truncated_shares() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(100), Decimal::ZERO);
+    paper.update_book(&book(&[], &[(dec!(0.57), dec!(100))]));
+
+    // $10 at 0.57 takes 17.5438 shares, so the signed amounts imply a price just above 0.57
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let signable = client
+        .market_order()
+        .token_id(TOKEN_1)
+        .amount(Amount::usdc(dec!(10))?)
+        .price(dec!(0.57))
+        .side(Side::Buy)
+        .order_type(OrderType::FOK)
+        .build()
+        .await?;
+    let buy = client.sign(&signer, signable).await?;
+    let response = paper.post_order(buy).await?;
+
+    assert_eq!(response.status, OrderStatusType::Matched);
+    assert_eq!(response.taking_amount, dec!(17.5438));
+    assert_eq!(
+        balance(&paper, AssetType::Conditional).await?,
+        dec!(17_543_800)
+    );
+    let trades = paper.trades(&TradesRequest::default(), None).await?;
+    assert_eq!(trades.data[0].price, dec!(0.57));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn market_sell_should_fill_at_the_book() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(Decimal::ZERO, dec!(20));
+    paper.update_book(&book(&[(dec!(0.43), dec!(50))], &[]));
+
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let signable = client
+        .market_order()
+        .token_id(TOKEN_1)
+        .amount(Amount::shares(dec!(10.5))?)
+        .price(dec!(0.43))
+        .side(Side::Sell)
+        .order_type(OrderType::FOK)
+        .build()
+        .await?;
+    let sell = client.sign(&signer, signable).await?;
+    let response = paper.post_order(sell).await?;
+
+    assert_eq!(response.status, OrderStatusType::Matched);
+    assert_eq!(response.making_amount, dec!(10.5));
+    assert_eq!(response.taking_amount, dec!(4.515));
+    assert_eq!(
+        balance(&paper, AssetType::Collateral).await?,
+        dec!(4_515_000)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn orders_should_be_validated_against_book_and_balance() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(1), Decimal::ZERO);
+
+    let buy = order(&client, Side::Buy, dec!(0.5), dec!(10), OrderType::GTC).await?;
+    assert_rejected(&paper.post_order(buy).await.unwrap_err());
+
+    paper.update_book(&book(&[], &[(dec!(0.6), dec!(10))]));
+    let buy = order(&client, Side::Buy, dec!(0.5), dec!(10), OrderType::GTC).await?;
+    assert_rejected(&paper.post_order(buy).await.unwrap_err());
+
+    // Signed against a finer tick than the book's, like the exchange the price is not rounded
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let params = MarketParams::builder()
+        .tick_size(TickSize::Thousandth)
+        .fee_rate_bps(0)
+        .neg_risk(false)
+        .build();
+    let signable = client
+        .limit_order()
+        .token_id(TOKEN_1)
+        .price(dec!(0.555))
+        .size(dec!(1))
+        .side(Side::Buy)
+        .market_params(params)
+        .build()
+        .await?;
+    let buy = client.sign(&signer, signable).await?;
+    let err = paper.post_order(buy).await.unwrap_err();
+    assert_rejected(&err);
+    assert!(err.to_string().contains("invalid price (0.555)"), "{err}");
+
+    let tick_size_change: WsMessage = serde_json::from_value(json!({
+        "event_type": "tick_size_change",
+        "asset_id": TOKEN_1,
+        "market": MARKET,
+        "old_tick_size": "0.01",
+        "new_tick_size": "0.1",
+        "timestamp": "1"
+    }))?;
+    paper.apply(&tick_size_change)?;
+    let buy = order(&client, Side::Buy, dec!(0.05), dec!(10), OrderType::GTC).await?;
+    assert_rejected(&paper.post_order(buy).await.unwrap_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn gtd_order_should_expire() -> anyhow::Result<()> {
+    let (_server, client) = setup(0).await?;
+    let paper = paper(dec!(10), Decimal::ZERO);
+    paper.update_book(&book(&[], &[(dec!(0.6), dec!(10))]));
+
+    let buy = order(&client, Side::Buy, dec!(0.5), dec!(10), OrderType::GTD).await?;
+    let response = paper.post_order(buy).await?;
+    assert_eq!(response.status, OrderStatusType::Live);
+
+    let cancel = paper.cancel_order("unknown").await?;
+    assert!(cancel.not_canceled.contains_key("unknown"), "unknown order");
+    assert_eq!(
+        paper.orders(&OrdersRequest::default(), None).await?.count,
+        1
+    );
+
+    NOW.store(50_000, Ordering::SeqCst);
+    let open = paper.orders(&OrdersRequest::default(), None).await;
+    NOW.store(40_000, Ordering::SeqCst);
+    assert_eq!(open?.count, 0);
+
+    let cancel = paper.cancel_order(&response.order_id).await?;
+    assert!(
+        cancel.canceled.is_empty(),
+        "expired orders cannot be canceled"
+    );
+
+    Ok(())
+}
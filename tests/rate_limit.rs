@@ -0,0 +1,140 @@
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+use std::num::NonZeroU32;
+use std::time::{Duration, Instant};
+
+use futures_util::future;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use polymarket_client_sdk::clob::rate_limit::{Config as RateLimitConfig, EndpointFamily, Quota};
+use polymarket_client_sdk::clob::types::request::MidpointRequest;
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::retry::RetryPolicy;
+use polymarket_client_sdk::types::TokenId;
+use reqwest::StatusCode;
+use serde_json::json;
+
+#[tokio::test]
+async fn requests_over_budget_should_queue_instead_of_failing() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let rate_limits = RateLimitConfig::builder()
+        .market_data(Quota::new(
+            NonZeroU32::new(2).unwrap(),
+            Duration::from_millis(200),
+        ))
+        .build();
+    let config = Config::builder().rate_limits(rate_limits).build();
+    let client = Client::new(&server.base_url(), config)?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "mid": "0.5" }));
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    let start = Instant::now();
+    let responses =
+        future::join_all(std::iter::repeat_with(|| client.midpoint(&request)).take(4)).await;
+
+    assert!(
+        responses.iter().all(Result::is_ok),
+        "throttled requests should still succeed"
+    );
+    assert!(
+        start.elapsed() >= Duration::from_millis(200),
+        "the last request should wait for two refill intervals"
+    );
+    mock.assert_calls(4);
+
+    let stats = client.rate_limit_stats(EndpointFamily::MarketData);
+    assert_eq!(stats.requests, 4);
+    assert_eq!(stats.throttled, 2);
+    assert!(
+        stats.max_wait > Duration::from_millis(100) && stats.max_wait <= Duration::from_millis(200),
+        "unexpected max wait {:?}",
+        stats.max_wait
+    );
+
+    let stats = client.rate_limit_stats(EndpointFamily::OrderPlacement);
+    assert_eq!(stats.requests, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn requests_should_not_wait_without_budgets() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = Client::new(&server.base_url(), Config::default())?;
+
+    server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::OK)
+            .json_body(json!({ "mid": "0.5" }));
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    for _ in 0..3 {
+        client.midpoint(&request).await?;
+    }
+
+    let stats = client.rate_limit_stats(EndpointFamily::MarketData);
+    assert_eq!(stats.requests, 3);
+    assert_eq!(stats.throttled, 0);
+    assert_eq!(stats.total_wait, Duration::ZERO);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retries_should_wait_for_the_rate_limiter() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let rate_limits = RateLimitConfig::builder()
+        .market_data(Quota::new(
+            NonZeroU32::new(1).unwrap(),
+            Duration::from_millis(100),
+        ))
+        .build();
+    let retry_policy = RetryPolicy::builder()
+        .max_retries(3)
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+        .build();
+    let config = Config::builder()
+        .rate_limits(rate_limits)
+        .retry_policy(retry_policy)
+        .build();
+    let client = Client::new(&server.base_url(), config)?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::TOO_MANY_REQUESTS)
+            .header("Retry-After", "0");
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    let start = Instant::now();
+    client.midpoint(&request).await.unwrap_err();
+
+    mock.assert_calls(4);
+    assert!(
+        start.elapsed() >= Duration::from_millis(300),
+        "every retry should wait for a refill, took {:?}",
+        start.elapsed()
+    );
+
+    let stats = client.rate_limit_stats(EndpointFamily::MarketData);
+    assert_eq!(stats.requests, 4);
+    assert_eq!(stats.throttled, 3);
+
+    Ok(())
+}
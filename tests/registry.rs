@@ -0,0 +1,231 @@
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use polymarket_client_sdk::clob::registry::MarketRegistry;
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::types::{ConditionId, TokenId};
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::common::MARKET;
+
+fn market(condition_id: &str, neg_risk: bool, tokens: [(&str, &str); 2]) -> Value {
+    json!({
+        "enable_order_book": true,
+        "active": true,
+        "closed": false,
+        "archived": false,
+        "accepting_orders": true,
+        "minimum_order_size": "5",
+        "minimum_tick_size": "0.01",
+        "condition_id": condition_id,
+        "question_id": "q_67890",
+        "question": "Will BTC close above $50k today?",
+        "description": "A market about BTC daily close price",
+        "market_slug": "btc-close-above-50k",
+        "seconds_delay": 0,
+        "fpmm": "",
+        "maker_base_fee": "0",
+        "taker_base_fee": "1000",
+        "notifications_enabled": true,
+        "neg_risk": neg_risk,
+        "neg_risk_market_id": "",
+        "neg_risk_request_id": "",
+        "icon": "",
+        "image": "",
+        "rewards": {
+            "rates": null,
+            "min_size": "0",
+            "max_spread": "0"
+        },
+        "is_50_50_outcome": false,
+        "tokens": [
+            { "token_id": tokens[0].0, "outcome": tokens[0].1, "price": "0.5" },
+            { "token_id": tokens[1].0, "outcome": tokens[1].1, "price": "0.5" }
+        ],
+        "tags": []
+    })
+}
+
+async fn hydrated(server: &MockServer) -> anyhow::Result<MarketRegistry> {
+    const OTHER_MARKET: &str = "0x2222222222222222222222222222222222222222222222222222222222222222";
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/markets")
+            .query_param_missing("next_cursor");
+        then.status(StatusCode::OK).json_body(json!({
+            "data": [market(MARKET, false, [("101", "Yes"), ("102", "No")])],
+            "limit": 1,
+            "count": 1,
+            "next_cursor": "MQ=="
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/markets")
+            .query_param("next_cursor", "MQ==");
+        then.status(StatusCode::OK).json_body(json!({
+            "data": [market(OTHER_MARKET, true, [("201", "Up"), ("202", "Down")])],
+            "limit": 1,
+            "count": 1,
+            "next_cursor": "LTE="
+        }));
+    });
+
+    let client = Client::new(&server.base_url(), Config::default())?;
+    let mut registry = MarketRegistry::new();
+    registry.hydrate(&client).await?;
+
+    Ok(registry)
+}
+
+#[tokio::test]
+async fn hydrate_should_resolve_tokens_both_ways() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let registry = hydrated(&server).await?;
+
+    let condition_id: ConditionId = MARKET.parse()?;
+    let (yes, no) = (TokenId::from(101), TokenId::from(102));
+
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.condition_id(yes), Some(condition_id));
+    assert_eq!(registry.outcome(no), Some("No"));
+    assert_eq!(registry.complement(no), Some(yes));
+    assert_eq!(registry.token_id(condition_id, "YES"), Some(yes));
+    assert_eq!(registry.neg_risk(yes), Some(false));
+    assert_eq!(registry.neg_risk(TokenId::from(202)), Some(true));
+    assert_eq!(registry.minimum_tick_size(yes), Some(dec!(0.01)));
+    assert_eq!(registry.maker_base_fee(yes), Some(dec!(0)));
+    assert_eq!(registry.taker_base_fee(no), Some(dec!(1000)));
+    assert_eq!(registry.condition_id(TokenId::from(999)), None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn hydrate_should_skip_legacy_markets_without_ids() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    server.mock(|when, then| {
+        when.method(GET).path("/markets");
+        then.status(StatusCode::OK).json_body(json!({
+            "data": [
+                market("", false, [("", "Yes"), ("", "No")]),
+                market(MARKET, false, [("101", "Yes"), ("102", "No")])
+            ],
+            "limit": 2,
+            "count": 2,
+            "next_cursor": "LTE="
+        }));
+    });
+
+    let client = Client::new(&server.base_url(), Config::default())?;
+    let mut registry = MarketRegistry::new();
+    let added = registry.hydrate(&client).await?;
+
+    assert_eq!(added, 1);
+    assert_eq!(registry.len(), 1);
+    assert_eq!(
+        registry.complement(TokenId::from(101)),
+        Some(TokenId::from(102))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn snapshot_should_round_trip() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let registry = hydrated(&server).await?;
+
+    let path = std::env::temp_dir().join(format!("registry-{}.json", Uuid::new_v4()));
+    registry.save(&path)?;
+    let loaded = MarketRegistry::load(&path);
+    std::fs::remove_file(&path)?;
+    let loaded = loaded?;
+
+    let condition_id: ConditionId = MARKET.parse()?;
+    assert_eq!(loaded.len(), registry.len());
+    assert_eq!(loaded.market(condition_id), registry.market(condition_id));
+    assert_eq!(
+        loaded.complement(TokenId::from(201)),
+        Some(TokenId::from(202))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn load_should_reject_unknown_snapshot_version() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("registry-{}.json", Uuid::new_v4()));
+    std::fs::write(&path, r#"{"version":0,"markets":[]}"#)?;
+    let loaded = MarketRegistry::load(&path);
+    std::fs::remove_file(&path)?;
+
+    let err = loaded.unwrap_err();
+    assert!(err.to_string().contains("snapshot version 0"), "{err}");
+
+    Ok(())
+}
+
+#[cfg(feature = "gamma")]
+#[tokio::test]
+async fn hydrate_gamma_should_skip_markets_without_tokens() -> anyhow::Result<()> {
+    use polymarket_client_sdk::gamma;
+    use polymarket_client_sdk::gamma::types::request::MarketsRequest;
+
+    let server = MockServer::start();
+    let client = gamma::Client::new(&server.base_url())?;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/markets")
+            .query_param("limit", "2")
+            .query_param("offset", "0");
+        then.status(StatusCode::OK).json_body(json!([
+            {
+                "id": "1",
+                "conditionId": MARKET,
+                "question": "Will BTC close above $50k today?",
+                "outcomes": "[\"Yes\", \"No\"]",
+                "clobTokenIds": "[\"101\", \"102\"]",
+                "negRisk": true,
+                "orderPriceMinTickSize": 0.001
+            },
+            {
+                "id": "2",
+                "question": "Not yet on the order book"
+            }
+        ]));
+    });
+
+    server.mock(|when, then| {
+        when.method(GET).path("/markets").query_param("offset", "2");
+        then.status(StatusCode::OK).json_body(json!([]));
+    });
+
+    let request = MarketsRequest::builder().limit(2).build();
+    let mut registry = MarketRegistry::new();
+    let added = registry.hydrate_gamma(&client, &request).await?;
+
+    assert_eq!(added, 1);
+    assert_eq!(
+        registry.complement(TokenId::from(101)),
+        Some(TokenId::from(102))
+    );
+    assert_eq!(registry.neg_risk(TokenId::from(102)), Some(true));
+    assert_eq!(
+        registry.minimum_tick_size(TokenId::from(101)),
+        Some(dec!(0.001))
+    );
+
+    Ok(())
+}
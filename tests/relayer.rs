@@ -0,0 +1,470 @@
+#![cfg(feature = "relayer")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::str::FromStr as _;
+use std::time::Duration;
+
+use alloy::primitives::{Bytes, U256, b256};
+use alloy::signers::Signer as _;
+use alloy::signers::local::{LocalSigner, PrivateKeySigner};
+use httpmock::Method::{GET, POST};
+use httpmock::{Mock, MockServer};
+use polymarket_client_sdk::auth::Credentials;
+use polymarket_client_sdk::auth::builder::Config as BuilderConfig;
+use polymarket_client_sdk::ctf::ConditionalTokens;
+use polymarket_client_sdk::error::Kind;
+use polymarket_client_sdk::relayer::Client;
+use polymarket_client_sdk::relayer::types::{TransactionState, TransactionType};
+use polymarket_client_sdk::types::{Address, ConditionId};
+use polymarket_client_sdk::wallet::{ProxyWallet, SafeWallet, transfer};
+use polymarket_client_sdk::{POLYGON, contract_config, wallet_contract_config};
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::common::{
+    BUILDER_API_KEY, BUILDER_PASSPHRASE, POLY_BUILDER_API_KEY, POLY_BUILDER_PASSPHRASE,
+    POLY_BUILDER_SIGNATURE, POLY_BUILDER_TIMESTAMP, PRIVATE_KEY, SECRET,
+};
+
+const CONDITION_ID: ConditionId = ConditionId::new(b256!(
+    "0x1111111111111111111111111111111111111111111111111111111111111111"
+));
+const TRANSACTION_ID: &str = "0190b317-a1d3-7bec-9b91-eeb6dcd3a620";
+
+fn signer() -> PrivateKeySigner {
+    LocalSigner::from_str(PRIVATE_KEY)
+        .unwrap()
+        .with_chain_id(Some(POLYGON))
+}
+
+fn client(server: &MockServer) -> anyhow::Result<Client> {
+    let credentials = Credentials::new(
+        BUILDER_API_KEY,
+        SECRET.to_owned(),
+        BUILDER_PASSPHRASE.to_owned(),
+    );
+
+    Ok(Client::new(
+        &server.base_url(),
+        BuilderConfig::local(credentials),
+    )?)
+}
+
+fn mock_deployed(server: &MockServer, safe: Address, deployed: bool) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/deployed")
+            .query_param("address", safe.to_string());
+        then.status(StatusCode::OK)
+            .json_body(json!({ "deployed": deployed }));
+    })
+}
+
+fn mock_submit(server: &MockServer, body: serde_json::Value) -> Mock<'_> {
+    server.mock(|when, then| {
+        when.method(POST)
+            .path("/submit")
+            .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY.to_string())
+            .header(POLY_BUILDER_PASSPHRASE, BUILDER_PASSPHRASE)
+            .header_exists(POLY_BUILDER_SIGNATURE)
+            .header_exists(POLY_BUILDER_TIMESTAMP)
+            .json_body(body);
+        then.status(StatusCode::OK).json_body(json!({
+            "transactionID": TRANSACTION_ID,
+            "state": "STATE_NEW",
+            "transactionHash": ""
+        }));
+    })
+}
+
+mod safe {
+    use super::*;
+
+    #[tokio::test]
+    async fn execute_safe_should_submit_signed_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let signer = signer();
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+        let redeem = ConditionalTokens::new(POLYGON)?.redeem_positions(CONDITION_ID);
+
+        let deployed = mock_deployed(&server, safe.address, true);
+        let nonce = server.mock(|when, then| {
+            when.method(GET)
+                .path("/nonce")
+                .query_param("address", signer.address().to_string())
+                .query_param("type", "SAFE");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "nonce": "5" }));
+        });
+
+        let hash = safe.transaction(&redeem, U256::from(5)).hash();
+        let mut signature = signer.sign_message(hash.as_slice()).await?.as_bytes();
+        signature[64] += 4;
+
+        let submit = mock_submit(
+            &server,
+            json!({
+                "type": "SAFE",
+                "from": signer.address(),
+                "to": redeem.to,
+                "proxyWallet": safe.address,
+                "data": redeem.data,
+                "nonce": "5",
+                "signature": Bytes::from(signature),
+                "signatureParams": {
+                    "gasPrice": "0",
+                    "operation": "0",
+                    "safeTxnGas": "0",
+                    "baseGas": "0",
+                    "gasToken": Address::ZERO,
+                    "refundReceiver": Address::ZERO
+                }
+            }),
+        );
+
+        let response = client.execute_safe(&signer, &[redeem]).await?;
+
+        assert_eq!(response.transaction_id, TRANSACTION_ID);
+        assert_eq!(response.state, TransactionState::New);
+        assert_eq!(response.transaction_hash, None);
+        deployed.assert();
+        nonce.assert();
+        submit.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_safe_should_batch_calls_through_multisend() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let signer = signer();
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+        let collateral = contract_config(POLYGON, false).unwrap().collateral;
+        let multisend = wallet_contract_config(POLYGON).unwrap().safe_multisend;
+
+        mock_deployed(&server, safe.address, true);
+        server.mock(|when, then| {
+            when.method(GET).path("/nonce");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "nonce": "0" }));
+        });
+        let submit = server.mock(|when, then| {
+            when.method(POST).path("/submit").json_body_includes(
+                json!({
+                    "to": multisend,
+                    "signatureParams": { "operation": "1" }
+                })
+                .to_string(),
+            );
+            then.status(StatusCode::OK).json_body(json!({
+                "transactionID": TRANSACTION_ID,
+                "state": "STATE_NEW"
+            }));
+        });
+
+        let calls = [
+            ConditionalTokens::new(POLYGON)?.merge_positions(CONDITION_ID, U256::from(5)),
+            transfer(collateral, Address::repeat_byte(0x22), U256::from(5)),
+        ];
+        client.execute_safe(&signer, &calls).await?;
+
+        submit.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_safe_should_fail_if_safe_is_not_deployed() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let signer = signer();
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+
+        mock_deployed(&server, safe.address, false);
+        let submit = server.mock(|when, then| {
+            when.method(POST).path("/submit");
+            then.status(StatusCode::OK);
+        });
+
+        let redeem = ConditionalTokens::new(POLYGON)?.redeem_positions(CONDITION_ID);
+        let err = client.execute_safe(&signer, &[redeem]).await.unwrap_err();
+
+        assert!(err.to_string().contains("is not deployed"), "{err}");
+        submit.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_safe_should_reject_empty_calls() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let any = server.mock(|_, then| {
+            then.status(StatusCode::OK);
+        });
+
+        let err = client.execute_safe(&signer(), &[]).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        any.assert_calls(0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn deploy_safe_should_only_deploy_missing_safe() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let signer = signer();
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+        let factory = wallet_contract_config(POLYGON).unwrap().safe_factory;
+
+        let mut deployed = mock_deployed(&server, safe.address, false);
+        let submit = server.mock(|when, then| {
+            when.method(POST)
+                .path("/submit")
+                .header_exists(POLY_BUILDER_SIGNATURE)
+                .json_body_includes(
+                    json!({
+                        "type": "SAFE-CREATE",
+                        "from": signer.address(),
+                        "to": factory,
+                        "proxyWallet": safe.address,
+                        "data": "0x",
+                        "signatureParams": {
+                            "paymentToken": Address::ZERO,
+                            "payment": "0",
+                            "paymentReceiver": Address::ZERO
+                        }
+                    })
+                    .to_string(),
+                )
+                .json_body_excludes(r#"{"nonce":"0"}"#);
+            then.status(StatusCode::OK).json_body(json!({
+                "transactionID": TRANSACTION_ID,
+                "state": "STATE_NEW"
+            }));
+        });
+
+        let response = client.deploy_safe(&signer).await?;
+        assert_eq!(response.unwrap().transaction_id, TRANSACTION_ID);
+
+        deployed.delete();
+        mock_deployed(&server, safe.address, true);
+        assert_eq!(client.deploy_safe(&signer).await?, None);
+
+        submit.assert_calls(1);
+
+        Ok(())
+    }
+}
+
+mod proxy {
+    use super::*;
+
+    #[tokio::test]
+    async fn execute_proxy_should_submit_through_relay_hub() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let signer = signer();
+        let proxy = ProxyWallet::for_owner(signer.address(), POLYGON)?;
+        let relay = Address::repeat_byte(0x33);
+        let relay_hub = wallet_contract_config(POLYGON).unwrap().relay_hub.unwrap();
+        let redeem = ConditionalTokens::new(POLYGON)?.redeem_positions(CONDITION_ID);
+
+        let payload = server.mock(|when, then| {
+            when.method(GET)
+                .path("/relay-payload")
+                .query_param("address", signer.address().to_string())
+                .query_param("type", "PROXY");
+            then.status(StatusCode::OK)
+                .json_body(json!({ "address": relay, "nonce": "12" }));
+        });
+        let submit = server.mock(|when, then| {
+            when.method(POST).path("/submit").json_body_includes(
+                json!({
+                    "type": "PROXY",
+                    "from": signer.address(),
+                    "to": proxy.factory,
+                    "proxyWallet": proxy.address,
+                    "data": proxy.call(std::slice::from_ref(&redeem)).data,
+                    "nonce": "12",
+                    "signatureParams": {
+                        "gasPrice": "0",
+                        "gasLimit": "10000000",
+                        "relayerFee": "0",
+                        "relayHub": relay_hub,
+                        "relay": relay
+                    }
+                })
+                .to_string(),
+            );
+            then.status(StatusCode::OK).json_body(json!({
+                "transactionID": TRANSACTION_ID,
+                "state": "STATE_NEW"
+            }));
+        });
+
+        let response = client.execute_proxy(&signer, &[redeem]).await?;
+
+        assert_eq!(response.transaction_id, TRANSACTION_ID);
+        payload.assert();
+        submit.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_proxy_should_reject_empty_calls() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let any = server.mock(|_, then| {
+            then.status(StatusCode::OK);
+        });
+
+        let err = client.execute_proxy(&signer(), &[]).await.unwrap_err();
+
+        assert_eq!(err.kind(), Kind::Validation);
+        any.assert_calls(0);
+
+        Ok(())
+    }
+}
+
+mod attribution {
+    use super::*;
+
+    #[tokio::test]
+    async fn remote_config_should_attribute_submissions() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let builder_config = BuilderConfig::remote(
+            &format!("{}/sign", server.base_url()),
+            Some("token".to_owned()),
+        )?;
+        let client = Client::new(&server.base_url(), builder_config)?;
+        let signer = signer();
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+
+        let sign = server.mock(|when, then| {
+            when.method(POST)
+                .path("/sign")
+                .header("authorization", "Bearer token")
+                .json_body_includes(r#"{"method":"POST","path":"/submit"}"#);
+            then.status(StatusCode::OK).json_body(json!({
+                POLY_BUILDER_API_KEY: BUILDER_API_KEY,
+                POLY_BUILDER_PASSPHRASE: BUILDER_PASSPHRASE,
+                POLY_BUILDER_SIGNATURE: "signature",
+                POLY_BUILDER_TIMESTAMP: "1",
+            }));
+        });
+        mock_deployed(&server, safe.address, false);
+        let submit = server.mock(|when, then| {
+            when.method(POST)
+                .path("/submit")
+                .header(POLY_BUILDER_API_KEY, BUILDER_API_KEY.to_string())
+                .header(POLY_BUILDER_PASSPHRASE, BUILDER_PASSPHRASE)
+                .header(POLY_BUILDER_SIGNATURE, "signature")
+                .header(POLY_BUILDER_TIMESTAMP, "1");
+            then.status(StatusCode::OK).json_body(json!({
+                "transactionID": TRANSACTION_ID,
+                "state": "STATE_NEW"
+            }));
+        });
+
+        client.deploy_safe(&signer).await?;
+
+        sign.assert();
+        submit.assert();
+
+        Ok(())
+    }
+}
+
+mod wait {
+    use super::*;
+
+    fn mock_transaction<'server>(
+        server: &'server MockServer,
+        state: &str,
+        transaction_hash: &str,
+    ) -> Mock<'server> {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/transaction")
+                .query_param("id", TRANSACTION_ID);
+            then.status(StatusCode::OK).json_body(json!([{
+                "transactionID": TRANSACTION_ID,
+                "transactionHash": transaction_hash,
+                "from": "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266",
+                "to": "0x4D97DCd97eC945f40cF65F87097ACe5EA0476045",
+                "proxyAddress": "0xd93BE3A1e2E0D2fBc46A5fA4e6a7bA0bfE3b6fD3",
+                "data": "0x",
+                "nonce": "5",
+                "value": "",
+                "signature": "0x",
+                "state": state,
+                "type": "SAFE",
+                "metadata": "",
+                "createdAt": "2025-01-01T00:00:00.000Z",
+                "updatedAt": "2025-01-01T00:00:10.000Z"
+            }]));
+        })
+    }
+
+    #[tokio::test]
+    async fn wait_should_return_mined_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let hash = b256!("0xabababababababababababababababababababababababababababababababab");
+
+        let mut pending = mock_transaction(&server, "STATE_EXECUTED", "");
+        let client = client.with_polling(Duration::from_millis(10), 5);
+
+        let waiting = tokio::spawn(async move { client.wait(TRANSACTION_ID).await });
+        tokio::time::sleep(Duration::from_millis(25)).await;
+        pending.delete();
+        mock_transaction(&server, "STATE_MINED", &hash.to_string());
+
+        let transaction = waiting.await??;
+        assert_eq!(transaction.state, TransactionState::Mined);
+        assert_eq!(transaction.transaction_type, TransactionType::Safe);
+        assert_eq!(transaction.transaction_hash, Some(hash));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_should_fail_on_failed_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?;
+        let mock = mock_transaction(&server, "STATE_FAILED", "");
+
+        let err = client.wait(TRANSACTION_ID).await.unwrap_err();
+
+        assert!(err.to_string().contains("failed"), "{err}");
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn wait_should_give_up_after_max_polls() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let client = client(&server)?.with_polling(Duration::from_millis(1), 3);
+        let mock = mock_transaction(&server, "STATE_NEW", "");
+
+        let err = client.wait(TRANSACTION_ID).await.unwrap_err();
+
+        assert!(err.to_string().contains("not mined after 3 polls"), "{err}");
+        mock.assert_calls(3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use alloy::signers::Signer as _;
+use alloy::signers::local::LocalSigner;
+use async_trait::async_trait;
+use httpmock::Method::{GET, POST};
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::auth::Credentials;
+use polymarket_client_sdk::clob::types::request::{MidpointRequest, OrderBookSummaryRequest};
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::error::{Kind, Status};
+use polymarket_client_sdk::retry::RetryPolicy;
+use polymarket_client_sdk::transport::HttpTransport;
+use polymarket_client_sdk::types::TokenId;
+use reqwest::{Request, Response, StatusCode};
+use serde_json::json;
+use uuid::Uuid;
+
+fn policy() -> RetryPolicy {
+    RetryPolicy::builder()
+        .max_retries(2)
+        .initial_backoff(Duration::from_millis(1))
+        .build()
+}
+
+#[tokio::test]
+async fn idempotent_request_should_be_retried_until_exhausted() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let config = Config::builder().retry_policy(policy()).build();
+    let client = Client::new(&server.base_url(), config)?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::SERVICE_UNAVAILABLE)
+            .header("Retry-After", "0");
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    let err = client.midpoint(&request).await.unwrap_err();
+
+    assert_eq!(err.kind(), Kind::Status);
+    let status = err.downcast_ref::<Status>().unwrap();
+    assert_eq!(status.status_code, StatusCode::SERVICE_UNAVAILABLE);
+    mock.assert_calls(3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn client_error_should_not_be_retried() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let config = Config::builder().retry_policy(policy()).build();
+    let client = Client::new(&server.base_url(), config)?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::BAD_REQUEST)
+            .json_body(json!({ "error": "invalid token id" }));
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    client.midpoint(&request).await.unwrap_err();
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn post_read_request_should_be_retried_by_default() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let config = Config::builder().retry_policy(policy()).build();
+    let client = Client::new(&server.base_url(), config)?;
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/books");
+        then.status(StatusCode::TOO_MANY_REQUESTS)
+            .header("Retry-After", "0");
+    });
+
+    let request = OrderBookSummaryRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    client.order_books(&[request]).await.unwrap_err();
+
+    mock.assert_calls(3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_idempotent_request_should_not_be_retried_by_default() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let config = Config::builder().retry_policy(policy()).build();
+    let client = Client::new(&server.base_url(), config)?;
+    let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/auth/api-key");
+        then.status(StatusCode::TOO_MANY_REQUESTS);
+    });
+
+    client.create_api_key(&signer, None).await.unwrap_err();
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn non_idempotent_request_should_be_retried_when_opted_in() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let policy = RetryPolicy::builder()
+        .max_retries(1)
+        .initial_backoff(Duration::from_millis(1))
+        .retry_non_idempotent(true)
+        .build();
+    let config = Config::builder().retry_policy(policy).build();
+    let client = Client::new(&server.base_url(), config)?;
+    let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+
+    let mock = server.mock(|when, then| {
+        when.method(POST).path("/auth/api-key");
+        then.status(StatusCode::BAD_GATEWAY);
+    });
+
+    client.create_api_key(&signer, None).await.unwrap_err();
+
+    mock.assert_calls(2);
+
+    Ok(())
+}
+
+/// Answers every request with `503 Service Unavailable` and `Retry-After: 1`, recording the
+/// `POLY_TIMESTAMP` header it was sent with.
+#[derive(Clone, Debug, Default)]
+struct Unavailable {
+    timestamps: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl HttpTransport for Unavailable {
+    async fn execute(&self, request: Request) -> polymarket_client_sdk::Result<Response> {
+        let timestamp = request.headers()["POLY_TIMESTAMP"].to_str().unwrap();
+        self.timestamps.lock().unwrap().push(timestamp.to_owned());
+
+        let response = http::Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header("Retry-After", "1")
+            .body("")
+            .unwrap();
+
+        Ok(Response::from(response))
+    }
+}
+
+#[tokio::test]
+async fn authenticated_request_should_be_signed_again_when_retried() -> anyhow::Result<()> {
+    let transport = Unavailable::default();
+    let policy = RetryPolicy::builder().max_retries(1).build();
+    let config = Config::builder().retry_policy(policy).build();
+    let signer = LocalSigner::random().with_chain_id(Some(POLYGON));
+    let credentials = Credentials::new(
+        Uuid::nil(),
+        "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=".to_owned(),
+        "passphrase".to_owned(),
+    );
+    let client = Client::new("https://clob.example.com", config)?
+        .with_transport(transport.clone())?
+        .authentication_builder(&signer)
+        .credentials(credentials)
+        .authenticate()
+        .await?;
+
+    client.api_keys().await.unwrap_err();
+
+    // The retry waits for `Retry-After`, so a reused timestamp would be a second old
+    let timestamps = transport.timestamps.lock().unwrap().clone();
+    assert_eq!(timestamps.len(), 2);
+    assert!(timestamps[1].parse::<i64>()? > timestamps[0].parse::<i64>()?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn requests_should_not_be_retried_without_policy() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client = Client::new(&server.base_url(), Config::default())?;
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/midpoint");
+        then.status(StatusCode::SERVICE_UNAVAILABLE);
+    });
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    client.midpoint(&request).await.unwrap_err();
+
+    mock.assert();
+
+    Ok(())
+}
+
+#[cfg(feature = "data")]
+#[tokio::test]
+async fn data_client_should_retry_with_policy() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let client =
+        polymarket_client_sdk::data::Client::new(&server.base_url())?.with_retry_policy(policy());
+
+    let mock = server.mock(|when, then| {
+        when.method(GET).path("/");
+        then.status(StatusCode::INTERNAL_SERVER_ERROR);
+    });
+
+    client.health().await.unwrap_err();
+
+    mock.assert_calls(3);
+
+    Ok(())
+}
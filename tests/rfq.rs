@@ -13,6 +13,7 @@ use polymarket_client_sdk::clob::types::{
     CreateRfqQuoteRequest, CreateRfqRequestRequest, RfqQuotesRequest, RfqRequestsRequest, Side,
     SignatureType,
 };
+use polymarket_client_sdk::types::TokenId;
 use reqwest::StatusCode;
 use rust_decimal_macros::dec;
 use serde_json::json;
@@ -46,8 +47,8 @@ mod request {
         });
 
         let request = CreateRfqRequestRequest::builder()
-            .asset_in("12345")
-            .asset_out("0")
+            .asset_in(TokenId::from(12345))
+            .asset_out(TokenId::from(0))
             .amount_in(dec!(50000000))
             .amount_out(dec!(3000000))
             .user_type(SignatureType::Eoa)
@@ -185,8 +186,8 @@ mod quote {
 
         let request = CreateRfqQuoteRequest::builder()
             .request_id("01968f1e-1182-71c4-9d40-172db9be82af")
-            .asset_in("0")
-            .asset_out("12345")
+            .asset_in(TokenId::from(0))
+            .asset_out(TokenId::from(12345))
             .amount_in(dec!(3000000))
             .amount_out(dec!(50000000))
             .user_type(SignatureType::Eoa)
@@ -291,7 +292,7 @@ mod execution {
             .quote_id("0196f484-9fbd-74c1-bfc1-75ac21c1cf84")
             .maker_amount(dec!(50000000))
             .taker_amount(dec!(3000000))
-            .token_id("12345")
+            .token_id(TokenId::from(12345))
             .maker(maker)
             .signer(maker)
             .taker(Address::ZERO)
@@ -331,7 +332,7 @@ mod execution {
             .quote_id("0196f484-9fbd-74c1-bfc1-75ac21c1cf84")
             .maker_amount(dec!(50000000))
             .taker_amount(dec!(3000000))
-            .token_id("12345")
+            .token_id(TokenId::from(12345))
             .maker(maker)
             .signer(maker)
             .taker(Address::ZERO)
@@ -374,8 +375,8 @@ mod error_handling {
         });
 
         let request = CreateRfqRequestRequest::builder()
-            .asset_in("12345")
-            .asset_out("0")
+            .asset_in(TokenId::from(12345))
+            .asset_out(TokenId::from(0))
             .amount_in(dec!(50000000))
             .amount_out(dec!(3000000))
             .user_type(SignatureType::Eoa)
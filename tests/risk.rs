@@ -0,0 +1,229 @@
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::num::NonZeroU32;
+use std::str::FromStr as _;
+
+use alloy::signers::Signer as _;
+use alloy::signers::local::LocalSigner;
+use httpmock::Method::GET;
+use httpmock::MockServer;
+use polymarket_client_sdk::POLYGON;
+use polymarket_client_sdk::clob::Config;
+use polymarket_client_sdk::clob::risk::{RiskCheck, RiskLimits, RiskViolation};
+use polymarket_client_sdk::clob::types::{Side, SignableOrder, TickSize};
+use polymarket_client_sdk::error::{Error, Kind};
+use polymarket_client_sdk::types::{Decimal, TokenId};
+use reqwest::StatusCode;
+use rust_decimal_macros::dec;
+use serde_json::json;
+
+use crate::common::{
+    MARKET, PRIVATE_KEY, TOKEN_1, TestClient, create_authenticated_with_config, ensure_requirements,
+};
+
+async fn setup(server: &MockServer, risk_limits: RiskLimits) -> anyhow::Result<TestClient> {
+    let config = Config::builder()
+        .use_server_time(true)
+        .risk_limits(risk_limits)
+        .build();
+    let client = create_authenticated_with_config(server, config).await?;
+    ensure_requirements(server, TOKEN_1, TickSize::Hundredth);
+
+    Ok(client)
+}
+
+async fn limit_order(client: &TestClient, price: Decimal, size: Decimal) -> SignableOrder {
+    client
+        .limit_order()
+        .token_id(TOKEN_1)
+        .price(price)
+        .size(size)
+        .side(Side::Buy)
+        .build()
+        .await
+        .unwrap()
+}
+
+fn violated(err: &Error) -> RiskCheck {
+    assert_eq!(err.kind(), Kind::Validation, "unexpected error {err}");
+    err.downcast_ref::<RiskViolation>().unwrap().check
+}
+
+#[tokio::test]
+async fn order_notional_over_limit_should_not_be_signed() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = setup(
+        &server,
+        RiskLimits::builder().max_order_notional(dec!(50)).build(),
+    )
+    .await?;
+
+    let order = limit_order(&client, dec!(0.5), dec!(100)).await;
+    client.sign(&signer, order).await?;
+
+    let order = limit_order(&client, dec!(0.5), dec!(100.02)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::OrderNotional);
+    assert!(
+        err.to_string().contains("max_order_notional"),
+        "the error should name the limit: {err}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn price_outside_band_should_be_rejected() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = setup(&server, RiskLimits::builder().price_band(dec!(0.1)).build()).await?;
+
+    let midpoint = server.mock(|when, then| {
+        when.method(GET)
+            .path("/midpoint")
+            .query_param("token_id", TOKEN_1.to_string());
+        then.status(StatusCode::OK)
+            .json_body(json!({ "mid": "0.5" }));
+    });
+
+    let order = limit_order(&client, dec!(0.6), dec!(10)).await;
+    client.sign(&signer, order).await?;
+
+    let order = limit_order(&client, dec!(0.61), dec!(10)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::PriceBand);
+    midpoint.assert_calls(2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn open_notional_should_include_resting_orders() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = setup(
+        &server,
+        RiskLimits::builder()
+            .max_token_notional(dec!(10))
+            .max_market_notional(dec!(10.5))
+            .build(),
+    )
+    .await?;
+
+    server.mock(|when, then| {
+        when.method(GET)
+            .path("/book")
+            .query_param("token_id", TOKEN_1.to_string());
+        then.status(StatusCode::OK).json_body(json!({
+            "market": MARKET,
+            "asset_id": TOKEN_1,
+            "tick_size": TickSize::Hundredth.as_decimal(),
+            "min_order_size": "5",
+            "neg_risk": false,
+            "timestamp": "123456789",
+            "bids": [],
+            "asks": []
+        }));
+    });
+    let open_order = |id: &str, asset_id: TokenId, price: &str| {
+        json!({
+            "id": id,
+            "status": "LIVE",
+            "owner": "00000000-0000-0000-0000-000000000000",
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": MARKET,
+            "asset_id": asset_id,
+            "side": "BUY",
+            "original_size": "10",
+            "size_matched": "5",
+            "price": price,
+            "associate_trades": [],
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    };
+    let orders = server.mock(|when, then| {
+        when.method(GET)
+            .path("/data/orders")
+            .query_param("market", MARKET);
+        then.status(StatusCode::OK).json_body(json!({
+            "data": [open_order("1", TOKEN_1, "0.8"), open_order("2", TokenId::from(2), "0.2")],
+            "limit": 2,
+            "count": 2,
+            "next_cursor": "LTE="
+        }));
+    });
+
+    // 4 resting on the token and 1 on its complement
+    let order = limit_order(&client, dec!(0.5), dec!(11)).await;
+    client.sign(&signer, order).await?;
+
+    let order = limit_order(&client, dec!(0.5), dec!(12)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::MarketNotional);
+
+    let order = limit_order(&client, dec!(0.5), dec!(14)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::TokenNotional);
+    orders.assert_calls(3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn orders_per_second_should_be_limited() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = setup(
+        &server,
+        RiskLimits::builder()
+            .max_orders_per_second(NonZeroU32::new(2).unwrap())
+            .build(),
+    )
+    .await?;
+
+    for _ in 0..2 {
+        let order = limit_order(&client, dec!(0.5), dec!(10)).await;
+        client.sign(&signer, order).await?;
+    }
+
+    let order = limit_order(&client, dec!(0.5), dec!(10)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::OrderRate);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn kill_switch_should_block_signing_and_posting() -> anyhow::Result<()> {
+    let server = MockServer::start();
+    let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+    let client = setup(&server, RiskLimits::default()).await?;
+
+    let order = limit_order(&client, dec!(0.5), dec!(10)).await;
+    let signed = client.sign(&signer, order).await?;
+
+    client.halt_trading();
+    assert!(client.is_trading_halted(), "the kill switch should engage");
+
+    let err = client.post_order(signed).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::KillSwitch);
+
+    let order = limit_order(&client, dec!(0.5), dec!(10)).await;
+    let err = client.sign(&signer, order).await.unwrap_err();
+    assert_eq!(violated(&err), RiskCheck::KillSwitch);
+
+    client.resume_trading();
+    let order = limit_order(&client, dec!(0.5), dec!(10)).await;
+    client.sign(&signer, order).await?;
+
+    Ok(())
+}
@@ -0,0 +1,127 @@
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use polymarket_client_sdk::Result;
+use polymarket_client_sdk::clob::types::request::MidpointRequest;
+use polymarket_client_sdk::clob::{Client, Config};
+use polymarket_client_sdk::error::{Kind, Status};
+use polymarket_client_sdk::transport::HttpTransport;
+use polymarket_client_sdk::types::TokenId;
+use reqwest::{Method, Request, Response, StatusCode};
+use rust_decimal_macros::dec;
+
+/// Answers every request with a fixed status and body, recording what was sent.
+#[derive(Clone, Debug)]
+struct Fake {
+    status: StatusCode,
+    body: &'static str,
+    requests: Arc<Mutex<Vec<(Method, String)>>>,
+}
+
+impl Fake {
+    fn new(status: StatusCode, body: &'static str) -> Self {
+        Self {
+            status,
+            body,
+            requests: Arc::default(),
+        }
+    }
+
+    fn requests(&self) -> Vec<(Method, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl HttpTransport for Fake {
+    async fn execute(&self, request: Request) -> Result<Response> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push((request.method().clone(), request.url().to_string()));
+
+        let response = http::Response::builder()
+            .status(self.status)
+            .body(self.body)
+            .unwrap();
+
+        Ok(Response::from(response))
+    }
+}
+
+#[tokio::test]
+async fn clob_client_should_send_requests_through_transport() -> anyhow::Result<()> {
+    let transport = Fake::new(StatusCode::OK, r#"{ "mid": "0.5" }"#);
+    let client = Client::new("https://clob.example.com", Config::default())?
+        .with_transport(transport.clone())?;
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    let response = client.midpoint(&request).await?;
+
+    assert_eq!(response.mid, dec!(0.5));
+    assert_eq!(
+        transport.requests(),
+        vec![(
+            Method::GET,
+            "https://clob.example.com/midpoint?token_id=1".to_owned()
+        )]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transport_error_status_should_surface_as_status_error() -> anyhow::Result<()> {
+    let transport = Fake::new(StatusCode::NOT_FOUND, r#"{ "error": "not found" }"#);
+    let client =
+        Client::new("https://clob.example.com", Config::default())?.with_transport(transport)?;
+
+    let request = MidpointRequest::builder()
+        .token_id(TokenId::from(1))
+        .build();
+    let err = client.midpoint(&request).await.unwrap_err();
+
+    assert_eq!(err.kind(), Kind::Status);
+    let status = err.downcast_ref::<Status>().unwrap();
+    assert_eq!(status.status_code, StatusCode::NOT_FOUND);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn cloned_clob_client_should_not_swap_transport() -> anyhow::Result<()> {
+    let client = Client::new("https://clob.example.com", Config::default())?;
+    let _clone = client.clone();
+
+    let err = client
+        .with_transport(Fake::new(StatusCode::OK, "{}"))
+        .unwrap_err();
+    assert_eq!(err.kind(), Kind::Synchronization);
+
+    Ok(())
+}
+
+#[cfg(feature = "data")]
+#[tokio::test]
+async fn data_client_should_send_requests_through_transport() -> anyhow::Result<()> {
+    let transport = Fake::new(StatusCode::OK, r#"{ "data": "OK" }"#);
+    let client = polymarket_client_sdk::data::Client::new("https://data.example.com")?
+        .with_transport(transport.clone());
+
+    let response = client.health().await?;
+
+    assert_eq!(response.data, "OK");
+    assert_eq!(
+        transport.requests(),
+        vec![(Method::GET, "https://data.example.com/".to_owned())]
+    );
+
+    Ok(())
+}
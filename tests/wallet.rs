@@ -0,0 +1,176 @@
+#![cfg(feature = "wallet")]
+#![allow(
+    clippy::unwrap_used,
+    reason = "Do not need additional syntax for setting up tests, and https://github.com/rust-lang/rust-clippy/issues/13981"
+)]
+
+mod common;
+
+use std::str::FromStr as _;
+
+use alloy::primitives::{B256, U256, b256, hex};
+use alloy::providers::ProviderBuilder;
+use alloy::signers::Signer as _;
+use alloy::signers::local::LocalSigner;
+use alloy::sol_types::SolValue as _;
+use httpmock::Method::POST;
+use httpmock::MockServer;
+use polymarket_client_sdk::ctf::{ConditionalTokens, Executor};
+use polymarket_client_sdk::types::{Address, ConditionId, TokenId};
+use polymarket_client_sdk::wallet::{
+    IProxyWalletFactory, ISafe, Operation, ProxyWallet, SafeWallet, transfer, transfer_position,
+};
+use polymarket_client_sdk::{POLYGON, contract_config, derive_safe_wallet};
+use reqwest::StatusCode;
+use serde_json::json;
+
+use crate::common::PRIVATE_KEY;
+
+const CONDITION_ID: ConditionId = ConditionId::new(b256!(
+    "0x1111111111111111111111111111111111111111111111111111111111111111"
+));
+
+mod safe {
+    use super::*;
+
+    #[tokio::test]
+    async fn signed_transaction_should_execute_call_from_safe() -> anyhow::Result<()> {
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+        assert_eq!(
+            Some(safe.address),
+            derive_safe_wallet(signer.address(), POLYGON)
+        );
+
+        let redeem = ConditionalTokens::new(POLYGON)?.redeem_positions(CONDITION_ID);
+        let transaction = safe.transaction(&redeem, U256::from(3));
+        let hash = transaction.hash();
+        let signed = transaction.sign(&signer).await?;
+
+        let (owner, signature) = signed.signatures[0];
+        assert_eq!(owner, signer.address());
+        assert_eq!(signature.recover_address_from_prehash(&hash)?, owner);
+
+        let call = signed.call();
+        assert_eq!(call.to, safe.address);
+
+        let exec = call.decode::<ISafe::execTransactionCall>()?;
+        assert_eq!(exec.to, redeem.to);
+        assert_eq!(exec.data, redeem.data);
+        assert_eq!(exec.value, U256::ZERO);
+        assert_eq!(exec.operation, Operation::Call as u8);
+        assert_eq!(exec.gasPrice, U256::ZERO);
+        assert_eq!(exec.signatures.len(), 65);
+        assert!(
+            matches!(exec.signatures[64], 27 | 28),
+            "the Safe expects v to be 27 or 28 for signatures of the hash"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonce_should_be_read_from_safe() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let safe = SafeWallet::new(Address::repeat_byte(0x11), POLYGON);
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("\"method\":\"eth_call\"")
+                .body_includes(safe.address.to_string().to_lowercase());
+            then.status(StatusCode::OK).json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": hex::encode_prefixed(U256::from(42).abi_encode())
+            }));
+        });
+
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect_http(server.base_url().parse()?);
+        let nonce = safe.nonce(&provider).await?;
+
+        assert_eq!(nonce, U256::from(42));
+        mock.assert();
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn executor_should_submit_signed_transaction() -> anyhow::Result<()> {
+        let server = MockServer::start();
+        let signer = LocalSigner::from_str(PRIVATE_KEY)?.with_chain_id(Some(POLYGON));
+        let safe = SafeWallet::for_owner(signer.address(), POLYGON)?;
+        let tx_hash = B256::repeat_byte(0xab);
+
+        let collateral = contract_config(POLYGON, false).unwrap().collateral;
+        let payout = transfer(
+            collateral,
+            Address::repeat_byte(0x22),
+            U256::from(1_000_000),
+        );
+        let call = safe
+            .transaction(&payout, U256::ZERO)
+            .sign(&signer)
+            .await?
+            .call();
+
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/")
+                .body_includes("\"method\":\"eth_sendTransaction\"")
+                .body_includes(call.data.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "jsonrpc": "2.0",
+                "id": 0,
+                "result": tx_hash
+            }));
+        });
+
+        let provider = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .connect_http(server.base_url().parse()?);
+        let pending = Executor::new(provider).execute(call).await?;
+
+        assert_eq!(*pending.tx_hash(), tx_hash);
+        mock.assert();
+
+        Ok(())
+    }
+}
+
+mod proxy {
+    use super::*;
+
+    #[test]
+    fn call_should_forward_every_call_through_factory() -> anyhow::Result<()> {
+        let owner = Address::repeat_byte(0x22);
+        let proxy = ProxyWallet::for_owner(owner, POLYGON)?;
+        let config = contract_config(POLYGON, false).unwrap();
+
+        let calls = [
+            ConditionalTokens::new(POLYGON)?.merge_positions(CONDITION_ID, U256::from(5)),
+            transfer_position(
+                config.conditional_tokens,
+                proxy.address,
+                owner,
+                TokenId::from(7),
+                U256::from(5),
+            ),
+        ];
+        let call = proxy.call(&calls);
+        assert_eq!(call.to, proxy.factory);
+
+        let forwarded = call.decode::<IProxyWalletFactory::proxyCall>()?.calls;
+        assert_eq!(forwarded.len(), 2);
+        for (forwarded, call) in forwarded.iter().zip(&calls) {
+            assert_eq!(forwarded.typeCode, 1, "a regular call");
+            assert_eq!(forwarded.to, call.to);
+            assert_eq!(forwarded.value, U256::ZERO);
+            assert_eq!(forwarded.data, call.data);
+        }
+
+        Ok(())
+    }
+}
@@ -12,13 +12,14 @@ use std::time::Duration;
 
 use futures_util::{SinkExt as _, StreamExt as _};
 use polymarket_client_sdk::clob::ws::{Client, WsMessage};
-use polymarket_client_sdk::types::Address;
+use polymarket_client_sdk::types::{Address, TokenId};
 use polymarket_client_sdk::ws::config::Config;
 use serde_json::json;
 use tokio::net::TcpListener;
 use tokio::sync::{broadcast, mpsc};
 use tokio::time::timeout;
 use tokio_tungstenite::tungstenite::Message;
+use uuid::Uuid;
 
 /// Mock WebSocket server.
 struct MockWsServer {
@@ -115,14 +116,20 @@ impl MockWsServer {
 /// <https://docs.polymarket.com/developers/CLOB/websocket/market-channel>
 /// <https://docs.polymarket.com/developers/CLOB/websocket/user-channel>
 mod payloads {
+    use alloy::primitives::{b256, uint};
+    use polymarket_client_sdk::types::{ConditionId, TokenId};
     use serde_json::{Value, json};
 
-    pub const ASSET_ID: &str =
-        "65818619657568813474341868652308942079804919287380422192892211131408793125422";
+    pub const ASSET_ID: TokenId = TokenId::new(uint!(
+        65818619657568813474341868652308942079804919287380422192892211131408793125422_U256
+    ));
 
-    pub const OTHER_ASSET_ID: &str =
-        "99999999999999999999999999999999999999999999999999999999999999999";
-    pub const MARKET: &str = "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af";
+    pub const OTHER_ASSET_ID: TokenId = TokenId::new(uint!(
+        99999999999999999999999999999999999999999999999999999999999999999_U256
+    ));
+    pub const MARKET: ConditionId = ConditionId::new(b256!(
+        "bd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af"
+    ));
 
     pub fn book() -> Value {
         json!({
@@ -144,7 +151,7 @@ mod payloads {
         })
     }
 
-    pub fn price_change_batch(asset_id: &str) -> Value {
+    pub fn price_change_batch(asset_id: TokenId) -> Value {
         json!({
             "market": "0x5f65177b394277fd294cd75650044e32ba009a95022d88a0c1d565897d72f8f1",
             "price_changes": [
@@ -174,7 +181,7 @@ mod payloads {
         })
     }
 
-    pub fn last_trade_price(asset_id: &str) -> Value {
+    pub fn last_trade_price(asset_id: TokenId) -> Value {
         json!({
             "asset_id": asset_id,
             "event_type": "last_trade_price",
@@ -253,14 +260,14 @@ mod market_channel {
         let client = Client::new(&endpoint, config).unwrap();
 
         let stream = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
         // Verify subscription request was sent
         let sub_request = server.recv_subscription().await.unwrap();
         assert!(sub_request.contains("\"type\":\"market\""));
-        assert!(sub_request.contains(payloads::ASSET_ID));
+        assert!(sub_request.contains(&payloads::ASSET_ID.to_string()));
 
         // Send book update from docs
         server.send(&payloads::book().to_string());
@@ -287,9 +294,11 @@ mod market_channel {
         let config = Config::default();
         let client = Client::new(&endpoint, config).unwrap();
 
-        let asset_id =
-            "71321045679252212594626385532706912750332728571942532289631379312455583992563";
-        let stream = client.subscribe_prices(vec![asset_id.to_owned()]).unwrap();
+        let asset_id: TokenId =
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                .parse()
+                .unwrap();
+        let stream = client.subscribe_prices(vec![asset_id]).unwrap();
         let mut stream = Box::pin(stream);
 
         let _: Option<String> = server.recv_subscription().await;
@@ -317,16 +326,14 @@ mod market_channel {
 
         let subscribed_asset = payloads::ASSET_ID;
 
-        let stream = client
-            .subscribe_orderbook(vec![subscribed_asset.to_owned()])
-            .unwrap();
+        let stream = client.subscribe_orderbook(vec![subscribed_asset]).unwrap();
         let mut stream = Box::pin(stream);
 
         let _: Option<String> = server.recv_subscription().await;
 
         // Send message for non-subscribed asset (should be filtered)
         let mut other_book = payloads::book();
-        other_book["asset_id"] = serde_json::Value::String(OTHER_ASSET_ID.to_owned());
+        other_book["asset_id"] = serde_json::Value::String(OTHER_ASSET_ID.to_string());
         server.send(&other_book.to_string());
 
         // Send message for subscribed asset
@@ -347,7 +354,7 @@ mod market_channel {
         let client = Client::new(&endpoint, config).unwrap();
 
         let stream = client
-            .subscribe_midpoints(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_midpoints(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -375,7 +382,7 @@ mod market_channel {
         let client = Client::new(&endpoint, config).unwrap();
 
         let stream = client
-            .subscribe_midpoints(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_midpoints(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -403,7 +410,7 @@ mod market_channel {
 }
 
 mod user_channel {
-    use polymarket_client_sdk::auth::Credentials;
+    use polymarket_client_sdk::auth::{Credentials, SwapCredentials as _};
     use polymarket_client_sdk::clob::types::Side;
     use rust_decimal_macros::dec;
     use tokio::time::sleep;
@@ -453,7 +460,7 @@ mod user_channel {
                     "0xff354cd7ca7539dfa9c28d90943ab5779a4eac34b9b37a757d7b32bdfb11790b"
                 );
                 assert_eq!(
-                    order.market,
+                    order.market.to_string(),
                     "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af"
                 );
                 assert_eq!(order.price, dec!(0.57));
@@ -495,7 +502,7 @@ mod user_channel {
             WsMessage::Trade(trade) => {
                 assert_eq!(trade.id, "28c4d2eb-bbea-40e7-a9f0-b2fdb56b2c2e");
                 assert_eq!(
-                    trade.market,
+                    trade.market.to_string(),
                     "0xbd31dc8a20211944f6b70f31557f1001557b59905b7738480ca09bd4532f84af"
                 );
                 assert_eq!(trade.price, dec!(0.57));
@@ -593,30 +600,24 @@ mod user_channel {
         let asset_id = payloads::ASSET_ID;
 
         // First subscription - should send request
-        let _stream1 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream1 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let sub1 = server.recv_subscription().await.unwrap();
-        assert!(sub1.contains(asset_id));
+        assert!(sub1.contains(&asset_id.to_string()));
 
         // Second subscription to SAME asset - should NOT send request (multiplexed)
-        let _stream2 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream2 = client.subscribe_orderbook(vec![asset_id]).unwrap();
 
         // Third subscription to DIFFERENT asset - should send request
-        let _stream3 = client
-            .subscribe_orderbook(vec![OTHER_ASSET_ID.to_owned()])
-            .unwrap();
+        let _stream3 = client.subscribe_orderbook(vec![OTHER_ASSET_ID]).unwrap();
 
         // The next message we receive should be for other_asset only
         let sub2 = server.recv_subscription().await.unwrap();
         assert!(
-            sub2.contains(OTHER_ASSET_ID),
+            sub2.contains(&OTHER_ASSET_ID.to_string()),
             "Should receive subscription for new asset"
         );
         assert!(
-            !sub2.contains(asset_id),
+            !sub2.contains(&asset_id.to_string()),
             "Should NOT contain duplicate of already-subscribed asset"
         );
     }
@@ -638,22 +639,50 @@ mod user_channel {
         let market = payloads::MARKET;
 
         // Subscribe to user events for a specific market
-        let _stream = client
-            .subscribe_user_events(vec![market.to_owned()])
-            .unwrap();
+        let _stream = client.subscribe_user_events(vec![market]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
         // Unsubscribe from user events
-        client
-            .unsubscribe_user_events(&[market.to_owned()])
-            .unwrap();
+        client.unsubscribe_user_events(&[market]).unwrap();
 
         let unsub = server.recv_subscription().await.unwrap();
         assert!(
             unsub.contains("\"operation\":\"unsubscribe\""),
             "Should send unsubscribe request, got: {unsub}"
         );
-        assert!(unsub.contains(market));
+        assert!(unsub.contains(&market.to_string()));
+    }
+
+    #[tokio::test]
+    async fn swap_credentials_resubscribes_with_new_key() {
+        let mut server = MockWsServer::start().await;
+        let base_endpoint = format!("ws://{}", server.addr);
+
+        let client = Client::new(&base_endpoint, Config::default())
+            .unwrap()
+            .authenticate(test_credentials(), Address::ZERO)
+            .unwrap();
+
+        // Wait for connections to establish
+        sleep(Duration::from_millis(100)).await;
+
+        let market = payloads::MARKET;
+        let _stream = client.subscribe_user_events(vec![market]).unwrap();
+        let _: Option<String> = server.recv_subscription().await;
+
+        let rotated = Uuid::from_u128(1);
+        let credentials = Credentials::new(rotated, SECRET.to_owned(), "rotated".to_owned());
+        client.swap_credentials(&credentials).unwrap();
+
+        // Skip any subscription still authenticated with the previous key
+        let mut resub = server.recv_subscription().await.unwrap();
+        while !resub.contains(&rotated.to_string()) {
+            resub = server
+                .recv_subscription()
+                .await
+                .expect("Should authenticate with the new key");
+        }
+        assert!(resub.contains(&market.to_string()));
     }
 
     #[tokio::test]
@@ -675,7 +704,7 @@ mod user_channel {
 
         // Should still be able to subscribe to market data
         let stream = unauth_client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -813,14 +842,12 @@ mod reconnection {
         let client = Client::new(&endpoint, config()).unwrap();
 
         let asset_id = payloads::ASSET_ID;
-        let stream = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let stream = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let mut stream = Box::pin(stream);
 
         // Verify initial subscription
         let sub_request = server.recv_subscription().await.unwrap();
-        assert!(sub_request.contains(asset_id));
+        assert!(sub_request.contains(&asset_id.to_string()));
 
         // Verify we can receive messages before disconnect
         server.send(&payloads::book().to_string());
@@ -840,7 +867,7 @@ mod reconnection {
             resub.is_some(),
             "Should receive re-subscription after reconnect"
         );
-        assert!(resub.unwrap().contains(asset_id));
+        assert!(resub.unwrap().contains(&asset_id.to_string()));
 
         // Send message after reconnection and verify it's received
         server.send(&payloads::book().to_string());
@@ -862,12 +889,12 @@ mod reconnection {
         let asset2 = payloads::OTHER_ASSET_ID;
 
         // Subscribe to both assets
-        let _stream1 = client.subscribe_orderbook(vec![asset1.to_owned()]).unwrap();
+        let _stream1 = client.subscribe_orderbook(vec![asset1]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
-        let _stream2 = client.subscribe_orderbook(vec![asset2.to_owned()]).unwrap();
+        let _stream2 = client.subscribe_orderbook(vec![asset2]).unwrap();
         let sub2 = server.recv_subscription().await.unwrap();
-        assert!(sub2.contains(asset2));
+        assert!(sub2.contains(&asset2.to_string()));
 
         // Disconnect and reconnect
         server.disconnect_all();
@@ -879,7 +906,7 @@ mod reconnection {
         assert!(resub.is_some(), "Should receive re-subscription");
         let resub_str = resub.unwrap();
         assert!(
-            resub_str.contains(asset1) && resub_str.contains(asset2),
+            resub_str.contains(&asset1.to_string()) && resub_str.contains(&asset2.to_string()),
             "Re-subscription should contain all tracked assets, got: {resub_str}"
         );
     }
@@ -899,23 +926,19 @@ mod unsubscribe {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe once
-        let _stream = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let sub = server.recv_subscription().await.unwrap();
-        assert!(sub.contains(asset_id));
+        assert!(sub.contains(&asset_id.to_string()));
 
         // Unsubscribe - should send unsubscribe request since refcount goes to 0
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
 
         let unsub = server.recv_subscription().await.unwrap();
         assert!(
             unsub.contains("\"operation\":\"unsubscribe\""),
             "Should send unsubscribe request, got: {unsub}"
         );
-        assert!(unsub.contains(asset_id));
+        assert!(unsub.contains(&asset_id.to_string()));
     }
 
     #[tokio::test]
@@ -928,30 +951,22 @@ mod unsubscribe {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe twice to same asset
-        let _stream1 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream1 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
-        let _stream2 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream2 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         // Second subscribe should not send (multiplexed)
 
         // Unsubscribe once - refcount goes from 2 to 1, should NOT send request
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
 
         // Subscribe to different asset to verify server is still responsive
-        let _stream3 = client
-            .subscribe_orderbook(vec![OTHER_ASSET_ID.to_owned()])
-            .unwrap();
+        let _stream3 = client.subscribe_orderbook(vec![OTHER_ASSET_ID]).unwrap();
 
         let next_msg = server.recv_subscription().await.unwrap();
         // Should be a subscribe for OTHER_ASSET_ID, not an unsubscribe for ASSET_ID
         assert!(
-            next_msg.contains(OTHER_ASSET_ID),
+            next_msg.contains(&OTHER_ASSET_ID.to_string()),
             "Should receive subscribe for new asset, not unsubscribe. Got: {next_msg}"
         );
         assert!(
@@ -970,30 +985,18 @@ mod unsubscribe {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe three times
-        let _stream1 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream1 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
-        let _stream2 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
-        let _stream3 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream2 = client.subscribe_orderbook(vec![asset_id]).unwrap();
+        let _stream3 = client.subscribe_orderbook(vec![asset_id]).unwrap();
 
         // Unsubscribe twice - still one stream left
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
 
         // Third unsubscribe - now refcount hits 0, should send request
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
 
         let unsub = server.recv_subscription().await.unwrap();
         assert!(
@@ -1012,23 +1015,17 @@ mod unsubscribe {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe
-        let _stream1 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream1 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let sub1 = server.recv_subscription().await.unwrap();
-        assert!(sub1.contains(asset_id));
+        assert!(sub1.contains(&asset_id.to_string()));
 
         // Fully unsubscribe
-        client
-            .unsubscribe_orderbook(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[asset_id]).unwrap();
         let unsub = server.recv_subscription().await.unwrap();
         assert!(unsub.contains("\"operation\":\"unsubscribe\""));
 
         // Re-subscribe should send a new subscription request
-        let stream2 = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let stream2 = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let mut stream2 = Box::pin(stream2);
 
         let sub2 = server.recv_subscription().await.unwrap();
@@ -1036,7 +1033,7 @@ mod unsubscribe {
             sub2.contains("\"type\":\"market\""),
             "Should send new subscribe request after full unsubscribe"
         );
-        assert!(sub2.contains(asset_id));
+        assert!(sub2.contains(&asset_id.to_string()));
 
         // Verify stream works
         server.send(&payloads::book().to_string());
@@ -1056,7 +1053,7 @@ mod unsubscribe {
 
         // Subscribe to something first
         let _stream = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
@@ -1076,20 +1073,14 @@ mod unsubscribe {
         let nonexistent_asset = OTHER_ASSET_ID;
 
         // Subscribe to one asset
-        let _stream = client
-            .subscribe_orderbook(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream = client.subscribe_orderbook(vec![asset_id]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
         // Unsubscribe from asset we never subscribed to - should be no-op
-        client
-            .unsubscribe_orderbook(&[nonexistent_asset.to_owned()])
-            .unwrap();
+        client.unsubscribe_orderbook(&[nonexistent_asset]).unwrap();
 
         // Subscribe to another asset to verify server didn't receive unsubscribe
-        let _stream2 = client
-            .subscribe_orderbook(vec![nonexistent_asset.to_owned()])
-            .unwrap();
+        let _stream2 = client.subscribe_orderbook(vec![nonexistent_asset]).unwrap();
 
         let next_msg = server.recv_subscription().await.unwrap();
         // Should be a subscribe, not an unsubscribe
@@ -1126,7 +1117,7 @@ mod client_state {
 
         // Subscribe to trigger connection
         let _stream = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
@@ -1144,7 +1135,7 @@ mod client_state {
 
         // Subscribe to trigger connection
         let _stream = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
@@ -1164,14 +1155,14 @@ mod client_state {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let _stream1 = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
         assert_eq!(client.subscription_count(), 1);
 
         let _stream2 = client
-            .subscribe_prices(vec![payloads::OTHER_ASSET_ID.to_owned()])
+            .subscribe_prices(vec![payloads::OTHER_ASSET_ID])
             .unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
@@ -1192,15 +1183,15 @@ mod unsubscribe_variants {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe via prices
-        let _stream = client.subscribe_prices(vec![asset_id.to_owned()]).unwrap();
+        let _stream = client.subscribe_prices(vec![asset_id]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
         // Unsubscribe via prices
-        client.unsubscribe_prices(&[asset_id.to_owned()]).unwrap();
+        client.unsubscribe_prices(&[asset_id]).unwrap();
 
         let unsub = server.recv_subscription().await.unwrap();
         assert!(unsub.contains("\"operation\":\"unsubscribe\""));
-        assert!(unsub.contains(asset_id));
+        assert!(unsub.contains(&asset_id.to_string()));
     }
 
     #[tokio::test]
@@ -1213,19 +1204,15 @@ mod unsubscribe_variants {
         let asset_id = payloads::ASSET_ID;
 
         // Subscribe via midpoints
-        let _stream = client
-            .subscribe_midpoints(vec![asset_id.to_owned()])
-            .unwrap();
+        let _stream = client.subscribe_midpoints(vec![asset_id]).unwrap();
         let _: Option<String> = server.recv_subscription().await;
 
         // Unsubscribe via midpoints
-        client
-            .unsubscribe_midpoints(&[asset_id.to_owned()])
-            .unwrap();
+        client.unsubscribe_midpoints(&[asset_id]).unwrap();
 
         let unsub = server.recv_subscription().await.unwrap();
         assert!(unsub.contains("\"operation\":\"unsubscribe\""));
-        assert!(unsub.contains(asset_id));
+        assert!(unsub.contains(&asset_id.to_string()));
     }
 }
 
@@ -1284,7 +1271,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_best_bid_ask(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_best_bid_ask(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1314,7 +1301,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_new_markets(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_new_markets(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1344,7 +1331,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_market_resolutions(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_market_resolutions(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1373,7 +1360,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_best_bid_ask(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_best_bid_ask(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1399,7 +1386,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_new_markets(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_new_markets(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1428,7 +1415,7 @@ mod custom_features {
         let client = Client::new(&endpoint, Config::default()).unwrap();
 
         let stream = client
-            .subscribe_market_resolutions(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_market_resolutions(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1466,7 +1453,7 @@ mod message_parsing {
         let client = Client::new(&endpoint, config).unwrap();
 
         let stream = client
-            .subscribe_orderbook(vec![payloads::ASSET_ID.to_owned()])
+            .subscribe_orderbook(vec![payloads::ASSET_ID])
             .unwrap();
         let mut stream = Box::pin(stream);
 
@@ -1494,14 +1481,16 @@ mod message_parsing {
         let config = Config::default();
         let client = Client::new(&endpoint, config).unwrap();
 
-        let asset_a =
-            "71321045679252212594626385532706912750332728571942532289631379312455583992563";
-        let asset_b =
-            "88888888888888888888888888888888888888888888888888888888888888888888888888888";
+        let asset_a: TokenId =
+            "71321045679252212594626385532706912750332728571942532289631379312455583992563"
+                .parse()
+                .unwrap();
+        let asset_b: TokenId =
+            "88888888888888888888888888888888888888888888888888888888888888888888888888888"
+                .parse()
+                .unwrap();
 
-        let stream = client
-            .subscribe_prices(vec![asset_a.to_owned(), asset_b.to_owned()])
-            .unwrap();
+        let stream = client.subscribe_prices(vec![asset_a, asset_b]).unwrap();
         let mut stream = Box::pin(stream);
 
         let _: Option<String> = server.recv_subscription().await;
@@ -1560,14 +1549,16 @@ mod message_parsing {
 
     #[test]
     fn parses_last_trade_price() {
-        let asset_id =
-            "114122071509644379678018727908709560226618148003371446110114509806601493071694";
+        let asset_id: TokenId =
+            "114122071509644379678018727908709560226618148003371446110114509806601493071694"
+                .parse()
+                .unwrap();
         let payload = payloads::last_trade_price(asset_id).to_string();
         let ltp: LastTradePrice = serde_json::from_str(&payload).unwrap();
 
         assert_eq!(ltp.asset_id, asset_id);
         assert_eq!(
-            ltp.market,
+            ltp.market.to_string(),
             "0x6a67b9d828d53862160e470329ffea5246f338ecfffdf2cab45211ec578b0347"
         );
         assert_eq!(ltp.price, dec!(0.456));
@@ -1575,3 +1566,430 @@ mod message_parsing {
         assert_eq!(ltp.timestamp, 1_750_428_146_322);
     }
 }
+
+mod local_orderbook {
+    use httpmock::MockServer;
+    use polymarket_client_sdk::clob::types::response::OrderBookSummaryResponse;
+    use polymarket_client_sdk::clob::ws::OrderBookManager;
+    use polymarket_client_sdk::clob::{Client as ClobClient, Config as ClobConfig};
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn price_change(price: &str, size: &str, best_bid: &str) -> String {
+        json!({
+            "market": payloads::MARKET,
+            "price_changes": [
+                {
+                    "asset_id": payloads::ASSET_ID,
+                    "price": price,
+                    "size": size,
+                    "side": "BUY",
+                    "hash": "0xabcdef",
+                    "best_bid": best_bid,
+                    "best_ask": ".52"
+                }
+            ],
+            "timestamp": "123456790000",
+            "event_type": "price_change"
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn applies_deltas_on_top_of_book_snapshot() {
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), Config::default()).unwrap();
+        let books = OrderBookManager::new(ClobClient::default());
+
+        let stream = books.sync(&client, vec![payloads::ASSET_ID]).unwrap();
+        let mut stream = Box::pin(stream);
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::book().to_string());
+        let snapshot = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.bids[0].price, dec!(0.50));
+        assert_eq!(snapshot.asks[0].price, dec!(0.52));
+
+        server.send(&price_change(".51", "40", ".51"));
+        let snapshot = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.bids[0].price, dec!(0.51));
+        assert_eq!(snapshot.bids[0].size, dec!(40));
+        assert_eq!(snapshot.hash.as_deref(), Some("0xabcdef"));
+        assert_eq!(books.midpoint(payloads::ASSET_ID), Some(dec!(0.515)));
+
+        // Not seeded from REST yet, so market metadata is unknown
+        OrderBookSummaryResponse::try_from(snapshot).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn resyncs_from_rest_on_desync() {
+        let http = MockServer::start_async().await;
+        let mock = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/book")
+                .query_param("token_id", payloads::ASSET_ID.to_string());
+            then.status(StatusCode::OK).json_body(json!({
+                "market": payloads::MARKET,
+                "asset_id": payloads::ASSET_ID,
+                "timestamp": "123456791000",
+                "hash": "0xfedcba",
+                "bids": [{ "price": ".45", "size": "100" }],
+                "asks": [{ "price": ".52", "size": "25" }],
+                "min_order_size": "5",
+                "neg_risk": false,
+                "tick_size": "0.01"
+            }));
+        });
+
+        let mut server = MockWsServer::start().await;
+        let client = Client::new(&server.ws_url("/ws/market"), Config::default()).unwrap();
+        let books = OrderBookManager::new(
+            ClobClient::new(&http.base_url(), ClobConfig::default()).unwrap(),
+        );
+
+        let stream = books.sync(&client, vec![payloads::ASSET_ID]).unwrap();
+        let mut stream = Box::pin(stream);
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&payloads::book().to_string());
+        let _snapshot = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        // The server reports a best bid the local book does not have
+        server.send(&price_change(".51", "40", ".45"));
+        let snapshot = timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(snapshot.bids.len(), 1);
+        assert_eq!(snapshot.bids[0].price, dec!(0.45));
+        assert_eq!(snapshot.hash.as_deref(), Some("0xfedcba"));
+
+        let response = OrderBookSummaryResponse::try_from(snapshot).unwrap();
+        assert_eq!(response.min_order_size, dec!(5));
+    }
+}
+
+mod order_tracker {
+    use httpmock::MockServer;
+    use polymarket_client_sdk::auth::Credentials;
+    use polymarket_client_sdk::clob::ws::{OrderLifecycle, OrderTracker, TrackedOrder};
+    use polymarket_client_sdk::types::ConditionId;
+    use reqwest::StatusCode;
+    use rust_decimal_macros::dec;
+    use serde_json::Value;
+
+    use super::*;
+    use crate::common::{API_KEY, PASSPHRASE, SECRET, create_authenticated};
+
+    const RESTING: &str = "0xa";
+    const TAKER: &str = "0xb";
+
+    fn open_order(id: &str, status: &str) -> Value {
+        json!({
+            "id": id,
+            "status": status,
+            "owner": API_KEY.to_string(),
+            "maker_address": "0x2222222222222222222222222222222222222222",
+            "market": payloads::MARKET,
+            "asset_id": payloads::ASSET_ID,
+            "side": "BUY",
+            "original_size": "10",
+            "size_matched": "0",
+            "price": "0.4",
+            "associate_trades": [],
+            "outcome": "YES",
+            "created_at": 1_705_322_096,
+            "expiration": "0",
+            "order_type": "GTC"
+        })
+    }
+
+    fn page(orders: &[Value]) -> Value {
+        json!({ "data": orders, "limit": 500, "count": 1, "next_cursor": "LTE=" })
+    }
+
+    fn trade(status: &str) -> String {
+        json!({
+            "event_type": "trade",
+            "id": "trade-1",
+            "market": payloads::MARKET,
+            "asset_id": payloads::ASSET_ID,
+            "side": "SELL",
+            "price": "0.6",
+            "size": "5",
+            "status": status,
+            "taker_order_id": TAKER,
+            "trader_side": "TAKER",
+            "maker_orders": [],
+            "type": "TRADE"
+        })
+        .to_string()
+    }
+
+    async fn next<S>(stream: &mut S) -> TrackedOrder
+    where
+        S: futures_util::Stream<Item = polymarket_client_sdk::Result<TrackedOrder>> + Unpin,
+    {
+        timeout(Duration::from_secs(2), stream.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn merges_rest_snapshot_with_user_events() -> anyhow::Result<()> {
+        let http = MockServer::start_async().await;
+        let client = create_authenticated(&http).await?;
+        let mut orders = http.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/data/orders");
+            then.status(StatusCode::OK)
+                .json_body(page(&[open_order(RESTING, "LIVE")]));
+        });
+
+        let mut server = MockWsServer::start().await;
+        let credentials = Credentials::new(API_KEY, SECRET.to_owned(), PASSPHRASE.to_owned());
+        let ws = Client::new(&format!("ws://{}", server.addr), Config::default())?
+            .authenticate(credentials, client.address())?;
+        let tracker = OrderTracker::new(client);
+
+        let stream = tracker.sync(&ws, Vec::new())?;
+        let mut stream = Box::pin(stream);
+
+        let resting = next(&mut stream).await;
+        assert_eq!(resting.id, RESTING);
+        assert_eq!(resting.lifecycle, OrderLifecycle::Placed);
+        orders.assert();
+        let _: Option<String> = server.recv_subscription().await;
+
+        server.send(&trade("MATCHED"));
+        let taker = next(&mut stream).await;
+        assert_eq!(taker.id, TAKER);
+        assert_eq!(taker.size_matched, dec!(5));
+        assert_eq!(taker.lifecycle, OrderLifecycle::Matched);
+
+        server.send(&trade("CONFIRMED"));
+        assert_eq!(next(&mut stream).await.lifecycle, OrderLifecycle::Confirmed);
+
+        let exposure = tracker.open_exposure(payloads::ASSET_ID);
+        assert_eq!(exposure.buy_size, dec!(10));
+        assert_eq!(exposure.buy_notional, dec!(4));
+        assert_eq!(exposure.sell_size, dec!(0));
+
+        // The resting order disappears from the open orders while we are not listening
+        orders.delete();
+        http.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(page(&[]));
+        });
+        let canceled = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/data/order/{RESTING}"));
+            then.status(StatusCode::OK)
+                .json_body(open_order(RESTING, "CANCELED"));
+        });
+
+        let changed = tracker.resync(&[]).await?;
+        canceled.assert();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].lifecycle, OrderLifecycle::Canceled);
+        assert!(tracker.open_orders().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn resync_is_scoped_to_markets_and_skips_failed_refreshes() -> anyhow::Result<()> {
+        let http = MockServer::start_async().await;
+        let client = create_authenticated(&http).await?;
+        let mut orders = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/data/orders")
+                .query_param("market", payloads::MARKET.to_string());
+            then.status(StatusCode::OK)
+                .json_body(page(&[open_order(RESTING, "LIVE")]));
+        });
+        let tracker = OrderTracker::new(client);
+
+        let changed = tracker.resync(&[payloads::MARKET]).await?;
+        orders.assert();
+        assert_eq!(changed.len(), 1);
+
+        orders.delete();
+        http.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/data/orders");
+            then.status(StatusCode::OK).json_body(page(&[]));
+        });
+        let failing = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path(format!("/data/order/{RESTING}"));
+            then.status(StatusCode::INTERNAL_SERVER_ERROR);
+        });
+
+        // Orders of other markets are not refreshed
+        tracker.resync(&[ConditionId::default()]).await?;
+        failing.assert_calls(0);
+
+        let changed = tracker.resync(&[payloads::MARKET]).await?;
+        failing.assert_calls(1);
+        assert!(changed.is_empty());
+        assert_eq!(tracker.open_orders().len(), 1);
+
+        Ok(())
+    }
+}
+
+mod cache_sync {
+    use httpmock::MockServer;
+    use polymarket_client_sdk::clob::types::TickSize;
+    use polymarket_client_sdk::clob::{Client as ClobClient, Config as ClobConfig};
+    use reqwest::StatusCode;
+
+    use super::*;
+    use crate::custom_features::market_resolved;
+
+    #[tokio::test]
+    async fn tick_size_change_replaces_cached_tick_size() -> anyhow::Result<()> {
+        let http = MockServer::start_async().await;
+        let mock = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/tick-size")
+                .query_param("token_id", payloads::ASSET_ID.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "minimum_tick_size": "0.01" }));
+        });
+
+        let mut server = MockWsServer::start().await;
+        let ws = Client::new(&server.ws_url("/ws/market"), Config::default())?;
+        let clob = ClobClient::new(&http.base_url(), ClobConfig::default())?;
+
+        let tick_size = clob.tick_size(payloads::ASSET_ID).await?;
+        assert_eq!(tick_size.minimum_tick_size, TickSize::Hundredth);
+
+        let stream = clob.sync_caches(&ws, vec![payloads::ASSET_ID])?;
+        let mut stream = Box::pin(stream);
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(sub_request.contains("\"custom_feature_enabled\":true"));
+
+        server.send(&payloads::tick_size_change().to_string());
+        let message = timeout(Duration::from_secs(2), stream.next())
+            .await?
+            .unwrap()?;
+        assert!(matches!(message, WsMessage::TickSizeChange(_)));
+
+        let tick_size = clob.tick_size(payloads::ASSET_ID).await?;
+        assert_eq!(tick_size.minimum_tick_size, TickSize::Thousandth);
+        mock.assert_calls(1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn market_resolved_invalidates_cached_params() -> anyhow::Result<()> {
+        let http = MockServer::start_async().await;
+        let mock = http.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/neg-risk")
+                .query_param("token_id", payloads::ASSET_ID.to_string());
+            then.status(StatusCode::OK)
+                .json_body(json!({ "neg_risk": true }));
+        });
+
+        let mut server = MockWsServer::start().await;
+        let ws = Client::new(&server.ws_url("/ws/market"), Config::default())?;
+        let clob = ClobClient::new(&http.base_url(), ClobConfig::default())?;
+
+        clob.neg_risk(payloads::ASSET_ID).await?;
+        clob.neg_risk(payloads::ASSET_ID).await?;
+        mock.assert_calls(1);
+
+        let stream = clob.sync_caches(&ws, vec![payloads::ASSET_ID])?;
+        let mut stream = Box::pin(stream);
+        let _: Option<String> = server.recv_subscription().await;
+
+        // Messages that do not affect the caches are not yielded
+        server.send(&payloads::book().to_string());
+        server.send(&market_resolved().to_string());
+        let message = timeout(Duration::from_secs(2), stream.next())
+            .await?
+            .unwrap()?;
+        assert!(matches!(message, WsMessage::MarketResolved(_)));
+
+        clob.neg_risk(payloads::ASSET_ID).await?;
+        mock.assert_calls(2);
+
+        Ok(())
+    }
+
+    /// Receive subscription requests until one matches `predicate`.
+    async fn recv_until(
+        server: &mut MockWsServer,
+        predicate: impl Fn(&str) -> bool,
+    ) -> Option<String> {
+        while let Some(request) = server.recv_subscription().await {
+            if predicate(&request) {
+                return Some(request);
+            }
+        }
+        None
+    }
+
+    #[tokio::test]
+    async fn enables_custom_features_on_subscribed_assets() -> anyhow::Result<()> {
+        let mut server = MockWsServer::start().await;
+        let ws = Client::new(&server.ws_url("/ws/market"), Config::default())?;
+        let clob = ClobClient::default();
+
+        let _orderbook = ws.subscribe_orderbook(vec![payloads::ASSET_ID])?;
+        let sub_request = server.recv_subscription().await.unwrap();
+        assert!(!sub_request.contains("custom_feature_enabled"));
+
+        let _stream = clob.sync_caches(&ws, vec![payloads::ASSET_ID])?;
+        let sub_request = recv_until(&mut server, |request| {
+            request.contains("\"custom_feature_enabled\":true")
+        })
+        .await
+        .expect("custom features should be enabled on the subscribed asset");
+        assert!(sub_request.contains(&payloads::ASSET_ID.to_string()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn dropping_the_stream_unsubscribes() -> anyhow::Result<()> {
+        let mut server = MockWsServer::start().await;
+        let ws = Client::new(&server.ws_url("/ws/market"), Config::default())?;
+        let clob = ClobClient::default();
+
+        let stream = clob.sync_caches(&ws, vec![payloads::ASSET_ID])?;
+        let _: Option<String> = server.recv_subscription().await;
+        drop(stream);
+
+        let unsub_request = recv_until(&mut server, |request| {
+            request.contains("\"operation\":\"unsubscribe\"")
+        })
+        .await
+        .expect("dropping the stream should unsubscribe");
+        assert!(unsub_request.contains(&payloads::ASSET_ID.to_string()));
+        assert_eq!(ws.subscription_count(), 0);
+
+        Ok(())
+    }
+}